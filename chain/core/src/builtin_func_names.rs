@@ -14,8 +14,12 @@ pub const SET_USERNAME_FUNC_NAME: &str = "SetUserName";
 pub const MIGRATE_USERNAME_FUNC_NAME: &str = "migrateUserName";
 pub const DELETE_USERNAME_FUNC_NAME: &str = "DeleteUserName";
 pub const UPGRADE_CONTRACT_FUNC_NAME: &str = "upgradeContract";
+pub const DELETE_CONTRACT_FUNC_NAME: &str = "DeleteContract";
 pub const ESDT_MODIFY_ROYALTIES_FUNC_NAME: &str = "ESDTModifyRoyalties";
 pub const ESDT_SET_NEW_URIS_FUNC_NAME: &str = "ESDTSetNewURIs";
 pub const ESDT_MODIFY_CREATOR_FUNC_NAME: &str = "ESDTModifyCreator";
 pub const ESDT_METADATA_RECREATE_FUNC_NAME: &str = "ESDTMetaDataRecreate";
 pub const ESDT_METADATA_UPDATE_FUNC_NAME: &str = "ESDTMetaDataUpdate";
+pub const SET_GUARDIAN_FUNC_NAME: &str = "SetGuardian";
+pub const GUARD_ACCOUNT_FUNC_NAME: &str = "GuardAccount";
+pub const UNGUARD_ACCOUNT_FUNC_NAME: &str = "UnGuardAccount";
@@ -1,8 +1,23 @@
+mod bls;
+mod elliptic_curve;
+
+pub use bls::*;
+pub use elliptic_curve::*;
+
+use ripemd::Ripemd160;
 use sha2::Sha256;
 use sha3::{Digest, Keccak256};
 
 pub const SHA256_RESULT_LEN: usize = 32;
 pub const KECCAK256_RESULT_LEN: usize = 32;
+pub const RIPEMD160_RESULT_LEN: usize = 20;
+
+/// Same codes as `MessageHashType` in the framework.
+pub const ECDSA_PLAIN_MSG: u8 = 0;
+pub const ECDSA_SHA256: u8 = 1;
+pub const ECDSA_DOUBLE_SHA256: u8 = 2;
+pub const ECDSA_KECCAK256: u8 = 3;
+pub const ECDSA_RIPEMD160: u8 = 4;
 
 pub fn sha256(data: &[u8]) -> [u8; SHA256_RESULT_LEN] {
     let mut hasher = Sha256::new();
@@ -16,6 +31,12 @@ pub fn keccak256(data: &[u8]) -> [u8; KECCAK256_RESULT_LEN] {
     hasher.finalize().into()
 }
 
+pub fn ripemd160(data: &[u8]) -> [u8; RIPEMD160_RESULT_LEN] {
+    let mut hasher = Ripemd160::new();
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
 pub fn verify_ed25519(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
     use ed25519_dalek::{Signature, Verifier, VerifyingKey};
 
@@ -42,3 +63,78 @@ pub fn verify_ed25519(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
     let result = verifying_key.verify(message, &sig);
     result.is_ok()
 }
+
+/// Verifies a DER-encoded secp256k1 signature.
+///
+/// The message is first hashed according to the hash type, same as in the Go VM.
+pub fn verify_secp256k1(key: &[u8], message: &[u8], signature: &[u8], hash_type: u8) -> bool {
+    use k256::ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey};
+
+    let hash = match hash_type {
+        ECDSA_PLAIN_MSG => message.to_vec(),
+        ECDSA_SHA256 => sha256(message).to_vec(),
+        ECDSA_DOUBLE_SHA256 => sha256(&sha256(message)).to_vec(),
+        ECDSA_KECCAK256 => keccak256(message).to_vec(),
+        ECDSA_RIPEMD160 => ripemd160(message).to_vec(),
+        _ => return false,
+    };
+
+    let Ok(verifying_key) = VerifyingKey::from_sec1_bytes(key) else {
+        return false;
+    };
+    let Ok(sig) = Signature::from_der(signature) else {
+        return false;
+    };
+    // signatures with a high S value are also accepted on chain
+    let sig = sig.normalize_s().unwrap_or(sig);
+
+    verifying_key.verify_prehash(&hash, &sig).is_ok()
+}
+
+/// Encodes the r and s components of a secp256k1 signature in DER format.
+pub fn encode_secp256k1_der_signature(r: &[u8], s: &[u8]) -> Vec<u8> {
+    let r = der_integer(r);
+    let s = der_integer(s);
+
+    let mut result = Vec::with_capacity(2 + r.len() + s.len());
+    result.push(0x30);
+    result.push((r.len() + s.len()) as u8);
+    result.extend_from_slice(&r);
+    result.extend_from_slice(&s);
+    result
+}
+
+fn der_integer(value: &[u8]) -> Vec<u8> {
+    let first_non_zero = value.iter().position(|b| *b != 0).unwrap_or(value.len());
+    let mut value = &value[first_non_zero..];
+    if value.is_empty() {
+        value = &[0];
+    }
+
+    let mut result = Vec::with_capacity(3 + value.len());
+    result.push(0x02);
+    if value[0] & 0x80 != 0 {
+        result.push(value.len() as u8 + 1);
+        result.push(0);
+    } else {
+        result.push(value.len() as u8);
+    }
+    result.extend_from_slice(value);
+    result
+}
+
+/// Verifies a secp256r1 signature, given as the 64-byte concatenation of r and s.
+///
+/// The message is hashed with sha256.
+pub fn verify_secp256r1(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+
+    let Ok(verifying_key) = VerifyingKey::from_sec1_bytes(key) else {
+        return false;
+    };
+    let Ok(sig) = Signature::from_slice(signature) else {
+        return false;
+    };
+
+    verifying_key.verify(message, &sig).is_ok()
+}
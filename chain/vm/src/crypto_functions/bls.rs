@@ -0,0 +1,179 @@
+use std::sync::OnceLock;
+
+use ark_bls12_381::{g1, Bls12_381, Fq, Fq2, FqConfig, G1Affine, G2Affine, G2Projective};
+use ark_ec::{pairing::Pairing, AffineRepr, CurveConfig, CurveGroup};
+use ark_ff::{BigInteger, Field, MontConfig, One, PrimeField, Zero};
+use hex_literal::hex;
+use sha2::{Digest, Sha512};
+
+pub const BLS_PUBLIC_KEY_LEN: usize = 96;
+pub const BLS_SIGNATURE_LEN: usize = 48;
+
+const FP_LEN: usize = 48;
+
+/// Set on the last byte of a serialized point when its y coordinate is odd.
+const Y_ODD_FLAG: u8 = 0x80;
+
+/// G1 is `y² = x³ + 4`, G2 is `y² = x³ + 4(1 + u)`.
+const CURVE_B: u32 = 4;
+
+/// The G2 generator used on chain, `mapToG2(1)`, serialized.
+const G2_GENERATOR: [u8; BLS_PUBLIC_KEY_LEN] = hex!(
+    "cc1ef2d9d8c53b60568548e55c1a3ae34e07ce638182fcbd6476c381db2806dfbb612c123cab0a1400cf1af81a013d0f"
+    "b3a4f19603d35ca16f6709162ca8fc9e5ffbbdbfa6dc2ad8f655660e3f4c928ce26401a98e65c2168a900f08a5f71d97"
+);
+
+/// Verifies a BLS12-381 signature, the way the herumi BLS library does it on chain.
+///
+/// Public keys are G2 points, signatures are G1 points,
+/// both serialized as little endian x coordinates, with the parity of y in the highest bit.
+pub fn verify_bls(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let Some(key) = decode_g2(key) else {
+        return false;
+    };
+    verify_with_point(key, message, signature)
+}
+
+/// Signature shares are verified the same as regular signatures.
+pub fn verify_bls_signature_share(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    verify_bls(key, message, signature)
+}
+
+/// Verifies a signature aggregated from signatures of the same message, against all the public keys.
+pub fn verify_bls_aggregated_signature(keys: &[Vec<u8>], message: &[u8], signature: &[u8]) -> bool {
+    if keys.is_empty() {
+        return false;
+    }
+
+    let mut aggregated_key = G2Projective::zero();
+    for key in keys {
+        let Some(key) = decode_g2(key) else {
+            return false;
+        };
+        aggregated_key += key;
+    }
+    verify_with_point(aggregated_key.into_affine(), message, signature)
+}
+
+fn verify_with_point(key: G2Affine, message: &[u8], signature: &[u8]) -> bool {
+    let Some(signature) = decode_g1(signature) else {
+        return false;
+    };
+    let Some(hash) = hash_to_g1(message) else {
+        return false;
+    };
+
+    Bls12_381::pairing(signature, g2_generator()) == Bls12_381::pairing(hash, key)
+}
+
+fn g2_generator() -> G2Affine {
+    static GENERATOR: OnceLock<G2Affine> = OnceLock::new();
+    *GENERATOR.get_or_init(|| decode_g2(&G2_GENERATOR).expect("invalid BLS G2 generator"))
+}
+
+/// Only accepts canonical encodings, i.e. values below the field modulus.
+fn decode_fq(bytes: &[u8]) -> Option<Fq> {
+    let value = Fq::from_le_bytes_mod_order(bytes);
+    (value.into_bigint().to_bytes_le() == bytes).then_some(value)
+}
+
+fn is_odd(value: &Fq) -> bool {
+    value.into_bigint().is_odd()
+}
+
+/// Splits off the y parity flag.
+fn split_y_odd_flag(bytes: &[u8]) -> (Vec<u8>, bool) {
+    let mut x_bytes = bytes.to_vec();
+    let last = x_bytes.last_mut().unwrap();
+    let y_odd = *last & Y_ODD_FLAG != 0;
+    *last &= !Y_ODD_FLAG;
+    (x_bytes, y_odd)
+}
+
+fn decode_g1(bytes: &[u8]) -> Option<G1Affine> {
+    if bytes.len() != BLS_SIGNATURE_LEN {
+        return None;
+    }
+
+    let (x_bytes, y_odd) = split_y_odd_flag(bytes);
+    let x = decode_fq(&x_bytes)?;
+    let mut y = (x * x * x + Fq::from(CURVE_B)).sqrt()?;
+    if is_odd(&y) != y_odd {
+        y = -y;
+    }
+
+    let point = G1Affine::new_unchecked(x, y);
+    point
+        .is_in_correct_subgroup_assuming_on_curve()
+        .then_some(point)
+}
+
+/// The parity flag refers to the real part of y.
+fn decode_g2(bytes: &[u8]) -> Option<G2Affine> {
+    if bytes.len() != BLS_PUBLIC_KEY_LEN {
+        return None;
+    }
+
+    let (x_bytes, y_odd) = split_y_odd_flag(bytes);
+    let x = Fq2::new(
+        decode_fq(&x_bytes[..FP_LEN])?,
+        decode_fq(&x_bytes[FP_LEN..])?,
+    );
+    let g2_b = Fq2::new(Fq::from(CURVE_B), Fq::from(CURVE_B));
+    let mut y = (x * x * x + g2_b).sqrt()?;
+    if is_odd(&y.c0) != y_odd {
+        y = -y;
+    }
+
+    let point = G2Affine::new_unchecked(x, y);
+    point
+        .is_in_correct_subgroup_assuming_on_curve()
+        .then_some(point)
+}
+
+/// Square root as computed by mcl, `a^((p + 1) / 4)`.
+///
+/// The choice of root matters when hashing, so the generic square root cannot be used.
+fn mcl_sqrt(value: Fq) -> Option<Fq> {
+    let exponent = FqConfig::MODULUS_PLUS_ONE_DIV_FOUR.unwrap();
+    let root = value.pow(exponent);
+    (root.square() == value).then_some(root)
+}
+
+/// Hashes the message with sha512, then maps the first 48 bytes of the digest to G1.
+fn hash_to_g1(message: &[u8]) -> Option<G1Affine> {
+    let digest = Sha512::digest(message);
+    let mut t_bytes = digest[..FP_LEN].to_vec();
+    // keep 381 bits, or 380 if the value is still not below the modulus
+    t_bytes[FP_LEN - 1] &= 0x1f;
+    let t = decode_fq(&t_bytes).unwrap_or_else(|| {
+        t_bytes[FP_LEN - 1] &= 0x0f;
+        Fq::from_le_bytes_mod_order(&t_bytes)
+    });
+
+    let point = map_to_g1(t)?;
+    Some(point.mul_bigint(g1::Config::COFACTOR).into_affine())
+}
+
+/// The Fouque-Tibouchi map to the curve, as implemented by mcl (`calcBN`).
+fn map_to_g1(t: Fq) -> Option<G1Affine> {
+    if t.is_zero() {
+        return Some(G1Affine::identity());
+    }
+
+    let c1 = mcl_sqrt(-Fq::from(3u32))?;
+    let c2 = (c1 - Fq::one()) / Fq::from(2u32);
+    let w = c1 * t * (t.square() + Fq::from(CURVE_B) + Fq::one()).inverse()?;
+
+    let x0 = c2 - t * w;
+    let x1 = -x0 - Fq::one();
+    let x2 = w.square().inverse()? + Fq::one();
+    let (x, mut y) = [x0, x1, x2]
+        .into_iter()
+        .find_map(|x| mcl_sqrt(x * x * x + Fq::from(CURVE_B)).map(|y| (x, y)))?;
+    if t.legendre().is_qnr() {
+        y = -y;
+    }
+
+    Some(G1Affine::new_unchecked(x, y))
+}
@@ -0,0 +1,366 @@
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+pub const EC_P224_NAME: &[u8] = b"p224";
+pub const EC_P256_NAME: &[u8] = b"p256";
+pub const EC_P384_NAME: &[u8] = b"p384";
+pub const EC_P521_NAME: &[u8] = b"p521";
+
+const UNCOMPRESSED_POINT_PREFIX: u8 = 4;
+const COMPRESSED_POINT_PREFIX: u8 = 2;
+
+/// Masks applied to the first byte of a randomly generated private key, indexed by `bit_size % 8`.
+const PRIVATE_KEY_MASK: [u8; 8] = [0xff, 0x1, 0x3, 0x7, 0xf, 0x1f, 0x3f, 0x7f];
+
+/// NIST short Weierstrass curve, `y² = x³ - 3x + b`, over the prime field of order `field_order`.
+///
+/// Mirrors the generic curve arithmetic of the Go VM: points are affine coordinates,
+/// and the point at infinity is represented as `(0, 0)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EllipticCurve {
+    pub name: Vec<u8>,
+    pub field_order: BigUint,
+    pub base_point_order: BigUint,
+    pub eq_constant: BigUint,
+    pub x_base_point: BigUint,
+    pub y_base_point: BigUint,
+    pub size_of_field: u32,
+}
+
+fn parse_hex(s: &str) -> BigUint {
+    BigUint::parse_bytes(s.as_bytes(), 16).expect("invalid curve constant")
+}
+
+impl EllipticCurve {
+    /// Creates one of the supported curves: `p224`, `p256`, `p384` or `p521`.
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        match name {
+            EC_P224_NAME => Some(Self::p224()),
+            EC_P256_NAME => Some(Self::p256()),
+            EC_P384_NAME => Some(Self::p384()),
+            EC_P521_NAME => Some(Self::p521()),
+            _ => None,
+        }
+    }
+
+    pub fn p224() -> Self {
+        EllipticCurve {
+            name: EC_P224_NAME.to_vec(),
+            field_order: parse_hex("ffffffffffffffffffffffffffffffff000000000000000000000001"),
+            base_point_order: parse_hex("ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d"),
+            eq_constant: parse_hex("b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4"),
+            x_base_point: parse_hex("b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21"),
+            y_base_point: parse_hex("bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34"),
+            size_of_field: 224,
+        }
+    }
+
+    pub fn p256() -> Self {
+        EllipticCurve {
+            name: EC_P256_NAME.to_vec(),
+            field_order: parse_hex(
+                "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            ),
+            base_point_order: parse_hex(
+                "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+            ),
+            eq_constant: parse_hex(
+                "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+            ),
+            x_base_point: parse_hex(
+                "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+            ),
+            y_base_point: parse_hex(
+                "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+            ),
+            size_of_field: 256,
+        }
+    }
+
+    pub fn p384() -> Self {
+        EllipticCurve {
+            name: EC_P384_NAME.to_vec(),
+            field_order: parse_hex(
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff",
+            ),
+            base_point_order: parse_hex(
+                "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+            ),
+            eq_constant: parse_hex(
+                "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+            ),
+            x_base_point: parse_hex(
+                "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+            ),
+            y_base_point: parse_hex(
+                "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+            ),
+            size_of_field: 384,
+        }
+    }
+
+    pub fn p521() -> Self {
+        EllipticCurve {
+            name: EC_P521_NAME.to_vec(),
+            field_order: (BigUint::one() << 521usize) - 1u32,
+            base_point_order: parse_hex(
+                "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+            ),
+            eq_constant: parse_hex(
+                "0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00",
+            ),
+            x_base_point: parse_hex(
+                "00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+            ),
+            y_base_point: parse_hex(
+                "011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+            ),
+            size_of_field: 521,
+        }
+    }
+
+    /// Length in bytes of a field element, also the length of a private key.
+    pub fn byte_length(&self) -> usize {
+        (self.size_of_field as usize).div_ceil(8)
+    }
+
+    /// Evaluates `x³ - 3x + b`.
+    fn polynomial(&self, x: &BigUint) -> BigUint {
+        let p = &self.field_order;
+        let x3 = x * x * x;
+        let three_x = x * 3u32;
+        (x3 + &self.eq_constant + p * 3u32 - three_x % p) % p
+    }
+
+    pub fn is_on_curve(&self, x: &BigUint, y: &BigUint) -> bool {
+        let p = &self.field_order;
+        if x >= p || y >= p {
+            return false;
+        }
+        (y * y) % p == self.polynomial(x)
+    }
+
+    fn sub_mod(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        let p = &self.field_order;
+        (a + p - b % p) % p
+    }
+
+    fn inverse_mod(&self, a: &BigUint) -> BigUint {
+        let p = &self.field_order;
+        a.modpow(&(p - 2u32), p)
+    }
+
+    pub fn add(
+        &self,
+        x1: &BigUint,
+        y1: &BigUint,
+        x2: &BigUint,
+        y2: &BigUint,
+    ) -> (BigUint, BigUint) {
+        if x1.is_zero() && y1.is_zero() {
+            return (x2.clone(), y2.clone());
+        }
+        if x2.is_zero() && y2.is_zero() {
+            return (x1.clone(), y1.clone());
+        }
+
+        let p = &self.field_order;
+        if x1 == x2 {
+            if (y1 + y2) % p == BigUint::zero() {
+                return (BigUint::zero(), BigUint::zero());
+            }
+            return self.double(x1, y1);
+        }
+
+        let lambda = self.sub_mod(y2, y1) * self.inverse_mod(&self.sub_mod(x2, x1)) % p;
+        self.point_from_lambda(&lambda, x1, y1, x2)
+    }
+
+    pub fn double(&self, x: &BigUint, y: &BigUint) -> (BigUint, BigUint) {
+        if y.is_zero() {
+            return (BigUint::zero(), BigUint::zero());
+        }
+
+        let p = &self.field_order;
+        let numerator = self.sub_mod(&(x * x * 3u32), &BigUint::from(3u32));
+        let lambda = numerator * self.inverse_mod(&((y << 1usize) % p)) % p;
+        self.point_from_lambda(&lambda, x, y, x)
+    }
+
+    fn point_from_lambda(
+        &self,
+        lambda: &BigUint,
+        x1: &BigUint,
+        y1: &BigUint,
+        x2: &BigUint,
+    ) -> (BigUint, BigUint) {
+        let p = &self.field_order;
+        let x3 = self.sub_mod(&self.sub_mod(&(lambda * lambda), x1), x2);
+        let y3 = self.sub_mod(&(lambda * self.sub_mod(x1, &x3)), y1);
+        (x3, y3 % p)
+    }
+
+    /// Computes `k * (x, y)`, with the scalar `k` given as big-endian bytes.
+    pub fn scalar_mult(&self, x: &BigUint, y: &BigUint, k: &[u8]) -> (BigUint, BigUint) {
+        let k = BigUint::from_bytes_be(k) % &self.base_point_order;
+        let mut result = (BigUint::zero(), BigUint::zero());
+        for i in (0..k.bits()).rev() {
+            result = self.double(&result.0, &result.1);
+            if k.bit(i) {
+                result = self.add(&result.0, &result.1, x, y);
+            }
+        }
+        result
+    }
+
+    /// Computes `k * G`, where `G` is the base point of the curve.
+    pub fn scalar_base_mult(&self, k: &[u8]) -> (BigUint, BigUint) {
+        self.scalar_mult(&self.x_base_point, &self.y_base_point, k)
+    }
+
+    /// Serializes a point in the uncompressed form specified in SEC 1, section 2.3.3.
+    pub fn marshal(&self, x: &BigUint, y: &BigUint) -> Vec<u8> {
+        let byte_length = self.byte_length();
+        let mut result = vec![0u8; 1 + 2 * byte_length];
+        result[0] = UNCOMPRESSED_POINT_PREFIX;
+        copy_padded(x, &mut result[1..1 + byte_length]);
+        copy_padded(y, &mut result[1 + byte_length..]);
+        result
+    }
+
+    /// Serializes a point in the compressed form specified in SEC 1, section 2.3.3.
+    pub fn marshal_compressed(&self, x: &BigUint, y: &BigUint) -> Vec<u8> {
+        let byte_length = self.byte_length();
+        let mut result = vec![0u8; 1 + byte_length];
+        result[0] = COMPRESSED_POINT_PREFIX | y.bit(0) as u8;
+        copy_padded(x, &mut result[1..]);
+        result
+    }
+
+    /// Deserializes a point produced by `marshal`. Returns `None` if the point is not on the curve.
+    pub fn unmarshal(&self, data: &[u8]) -> Option<(BigUint, BigUint)> {
+        let byte_length = self.byte_length();
+        if data.len() != 1 + 2 * byte_length || data[0] != UNCOMPRESSED_POINT_PREFIX {
+            return None;
+        }
+
+        let x = BigUint::from_bytes_be(&data[1..1 + byte_length]);
+        let y = BigUint::from_bytes_be(&data[1 + byte_length..]);
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    /// Deserializes a point produced by `marshal_compressed`.
+    /// Returns `None` if the point is not on the curve.
+    pub fn unmarshal_compressed(&self, data: &[u8]) -> Option<(BigUint, BigUint)> {
+        let byte_length = self.byte_length();
+        if data.len() != 1 + byte_length || data[0] & !1 != COMPRESSED_POINT_PREFIX {
+            return None;
+        }
+
+        let p = &self.field_order;
+        let x = BigUint::from_bytes_be(&data[1..]);
+        if &x >= p {
+            return None;
+        }
+
+        let mut y = mod_sqrt(&self.polynomial(&x), p)?;
+        if y.bit(0) != (data[0] & 1 == 1) {
+            y = (p - &y) % p;
+        }
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    /// Generates a key pair, using the same algorithm as Go's `elliptic.GenerateKey`.
+    ///
+    /// Returns the private key bytes and the public key coordinates.
+    pub fn generate_key<F>(&self, mut random_bytes: F) -> (Vec<u8>, BigUint, BigUint)
+    where
+        F: FnMut(usize) -> Vec<u8>,
+    {
+        let bit_size = self.base_point_order.bits() as usize;
+        let byte_length = bit_size.div_ceil(8);
+        loop {
+            let mut private_key = random_bytes(byte_length);
+            private_key.resize(byte_length, 0);
+            private_key[0] &= PRIVATE_KEY_MASK[bit_size % 8];
+            // avoid leading zeros, as Go does
+            private_key[1] ^= 0x42;
+
+            let k = BigUint::from_bytes_be(&private_key);
+            if k >= self.base_point_order || k.is_zero() {
+                continue;
+            }
+
+            let (x, y) = self.scalar_base_mult(&private_key);
+            return (private_key, x, y);
+        }
+    }
+}
+
+/// Writes the big-endian representation of `value` into `dest`, left-padded with zeros.
+fn copy_padded(value: &BigUint, dest: &mut [u8]) {
+    let bytes = value.to_bytes_be();
+    if value.is_zero() || bytes.len() > dest.len() {
+        return;
+    }
+    let offset = dest.len() - bytes.len();
+    dest[offset..].copy_from_slice(&bytes);
+}
+
+/// Square root modulo an odd prime, using the Tonelli-Shanks algorithm.
+fn mod_sqrt(a: &BigUint, p: &BigUint) -> Option<BigUint> {
+    let one = BigUint::one();
+    let a = a % p;
+    if a.is_zero() {
+        return Some(a);
+    }
+
+    let legendre_exp = (p - &one) >> 1usize;
+    if a.modpow(&legendre_exp, p) != one {
+        return None;
+    }
+
+    if p.bit(1) {
+        // p ≡ 3 (mod 4)
+        return Some(a.modpow(&((p + &one) >> 2usize), p));
+    }
+
+    let mut q = p - &one;
+    let mut s = 0u64;
+    while !q.bit(0) {
+        q >>= 1usize;
+        s += 1;
+    }
+
+    let p_minus_one = p - &one;
+    let mut z = BigUint::from(2u32);
+    while z.modpow(&legendre_exp, p) != p_minus_one {
+        z += 1u32;
+    }
+
+    let mut m = s;
+    let mut c = z.modpow(&q, p);
+    let mut t = a.modpow(&q, p);
+    let mut r = a.modpow(&((&q + &one) >> 1usize), p);
+    while t != one {
+        let mut i = 0u64;
+        let mut t_pow = t.clone();
+        while t_pow != one {
+            t_pow = &t_pow * &t_pow % p;
+            i += 1;
+        }
+
+        let b = c.modpow(&(BigUint::one() << (m - i - 1) as usize), p);
+        m = i;
+        c = &b * &b % p;
+        t = t * &c % p;
+        r = r * &b % p;
+    }
+    Some(r)
+}
@@ -4,6 +4,11 @@ pub fn address_hex(address: &VMAddress) -> String {
     alloc::format!("0x{}", hex::encode(address.as_bytes()))
 }
 
+pub fn address_bech32(address: &VMAddress) -> String {
+    let hrp = bech32::Hrp::parse("erd").expect("invalid hrp");
+    bech32::encode::<bech32::Bech32>(hrp, address.as_bytes()).expect("bech32 encode error")
+}
+
 pub fn key_hex(key: &[u8]) -> String {
     alloc::format!("0x{}", hex::encode(key))
 }
@@ -0,0 +1,4 @@
+mod gas_schedule;
+mod hook_cost_names;
+
+pub use gas_schedule::GasSchedule;
@@ -0,0 +1,151 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use super::hook_cost_names::hook_cost_name;
+
+/// Sections of the gas schedule that contain VM hook costs.
+const API_COST_SECTIONS: &[&str] = &[
+    "BaseOpsAPICost",
+    "BigIntAPICost",
+    "BigFloatAPICost",
+    "SmallIntAPICost",
+    "ManagedBufferAPICost",
+    "ManagedMapAPICost",
+    "CryptoAPICost",
+];
+
+/// Section of the gas schedule that contains builtin function costs.
+const BUILTIN_COST_SECTION: &str = "BuiltInCost";
+
+/// Gas costs, as configured in the protocol gas schedule TOML files (e.g. `gasScheduleV8.toml`).
+///
+/// Only VM hooks and builtin functions are metered. WASM opcodes are not, since the debugger
+/// runs contracts natively.
+///
+/// Each VM hook is charged with the same schedule entry as in the Go VM,
+/// e.g. the `managed_caller` hook costs `GetCaller`.
+/// Entries that are missing from the schedule are free, so tests can configure only the costs they are interested in.
+#[derive(Clone, Debug, Default)]
+pub struct GasSchedule {
+    api_costs: HashMap<String, u64>,
+    builtin_costs: HashMap<String, u64>,
+}
+
+impl GasSchedule {
+    /// Parses the contents of a gas schedule TOML file.
+    pub fn from_toml_str(toml_str: &str) -> Result<Self, toml::de::Error> {
+        let table: toml::Table = toml_str.parse()?;
+
+        let mut gas_schedule = GasSchedule::default();
+        for section_name in API_COST_SECTIONS {
+            add_section_costs(&table, section_name, &mut gas_schedule.api_costs, |name| {
+                name.to_string()
+            });
+        }
+        add_section_costs(
+            &table,
+            BUILTIN_COST_SECTION,
+            &mut gas_schedule.builtin_costs,
+            normalize_cost_name,
+        );
+        Ok(gas_schedule)
+    }
+
+    /// Loads a gas schedule TOML file. Panics if the file is missing or invalid.
+    pub fn load_file<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref();
+        let toml_str = fs::read_to_string(path)
+            .unwrap_or_else(|err| panic!("error reading gas schedule {}: {err}", path.display()));
+        Self::from_toml_str(&toml_str)
+            .unwrap_or_else(|err| panic!("error parsing gas schedule {}: {err}", path.display()))
+    }
+
+    /// Cost of calling a VM hook once.
+    ///
+    /// Panics in debug builds if the hook is not priced by any schedule entry.
+    pub fn hook_cost(&self, hook_name: &str) -> u64 {
+        let Some(cost_name) = hook_cost_name(hook_name) else {
+            if cfg!(debug_assertions) {
+                panic!("VM hook `{hook_name}` has no gas schedule entry");
+            }
+            return 0;
+        };
+        self.api_costs.get(cost_name).copied().unwrap_or_default()
+    }
+
+    /// Cost of a builtin function call, not including the execution of the contract it might call.
+    pub fn builtin_function_cost(&self, func_name: &str) -> u64 {
+        self.builtin_costs
+            .get(&normalize_cost_name(func_name))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+fn add_section_costs(
+    table: &toml::Table,
+    section_name: &str,
+    costs: &mut HashMap<String, u64>,
+    cost_key: fn(&str) -> String,
+) {
+    let Some(section) = table.get(section_name).and_then(toml::Value::as_table) else {
+        return;
+    };
+    for (name, value) in section {
+        if let Some(cost) = value.as_integer() {
+            costs.insert(cost_key(name), cost.max(0) as u64);
+        }
+    }
+}
+
+fn normalize_cost_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '_')
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const GAS_SCHEDULE_TOML: &str = r#"
+[BuiltInCost]
+    ESDTTransfer = 200000
+    ESDTNFTCreate = 150000
+
+[BaseOpsAPICost]
+    GetSCAddress = 1000
+    GetCaller = 1500
+
+[ManagedBufferAPICost]
+    MBufferNew = 2000
+"#;
+
+    #[test]
+    fn test_gas_schedule_costs() {
+        let gas_schedule = GasSchedule::from_toml_str(GAS_SCHEDULE_TOML).unwrap();
+        assert_eq!(gas_schedule.hook_cost("managed_sc_address"), 1000);
+        assert_eq!(gas_schedule.hook_cost("managed_caller"), 1500);
+        assert_eq!(
+            gas_schedule.hook_cost("managed_get_original_caller_addr"),
+            1500
+        );
+        assert_eq!(gas_schedule.hook_cost("mbuffer_new"), 2000);
+        assert_eq!(gas_schedule.hook_cost("mbuffer_append"), 0);
+        assert_eq!(gas_schedule.builtin_function_cost("ESDTTransfer"), 200000);
+        assert_eq!(gas_schedule.builtin_function_cost("ESDTNFTCreate"), 150000);
+        assert_eq!(gas_schedule.builtin_function_cost("ESDTNFTBurn"), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "VM hook `unknown_hook` has no gas schedule entry")]
+    fn test_gas_schedule_unknown_hook() {
+        let gas_schedule = GasSchedule::from_toml_str(GAS_SCHEDULE_TOML).unwrap();
+        gas_schedule.hook_cost("unknown_hook");
+    }
+
+    #[test]
+    fn test_gas_schedule_invalid() {
+        assert!(GasSchedule::from_toml_str("[BuiltInCost").is_err());
+    }
+}
@@ -0,0 +1,192 @@
+/// The gas schedule entry that prices a VM hook, the same one the Go VM charges for it.
+///
+/// Several hooks share an entry, e.g. all hooks that deploy or upgrade contracts cost `CreateContract`.
+pub(super) fn hook_cost_name(hook_name: &str) -> Option<&'static str> {
+    let cost_name = match hook_name {
+        // BaseOpsAPICost
+        "get_gas_left" => "GetGasLeft",
+        "get_shard_of_address" => "GetShardOfAddress",
+        "is_smart_contract" => "IsSmartContract",
+        "signal_error" | "managed_signal_error" => "SignalError",
+        "get_block_hash" => "GetBlockHash",
+        "get_esdt_local_roles" | "get_storage_lock" | "is_storage_locked" => "StorageLoad",
+        "set_storage_lock" | "clear_storage_lock" => "Int64StorageStore",
+        "get_num_arguments" => "GetNumArguments",
+        "check_no_payment"
+        | "get_current_esdt_nft_nonce"
+        | "get_num_esdt_transfers"
+        | "managed_get_multi_esdt_call_value"
+        | "managed_get_back_transfers" => "GetCallValue",
+        "get_block_timestamp" | "get_prev_block_timestamp" => "GetBlockTimeStamp",
+        "get_block_nonce" | "get_prev_block_nonce" => "GetBlockNonce",
+        "get_block_round" | "get_prev_block_round" => "GetBlockRound",
+        "get_block_epoch" | "get_prev_block_epoch" => "GetBlockEpoch",
+        "get_state_root_hash" | "managed_get_state_root_hash" => "GetStateRootHash",
+        "managed_get_block_random_seed" | "managed_get_prev_block_random_seed" => {
+            "GetBlockRandomSeed"
+        },
+        "finish" => "Finish",
+        "clean_return_data" => "CleanReturnData",
+        "delete_from_return_data" => "DeleteFromReturnData",
+        "get_original_tx_hash" | "managed_get_original_tx_hash" => "GetOriginalTxHash",
+        "get_current_tx_hash" => "GetCurrentTxHash",
+        "get_prev_tx_hash" => "GetPrevTxHash",
+        "managed_sc_address" => "GetSCAddress",
+        "managed_owner_address" => "GetOwnerAddress",
+        "managed_caller" | "managed_get_original_caller_addr" | "managed_get_relayer_addr" => {
+            "GetCaller"
+        },
+        "managed_write_log" => "Log",
+        "managed_get_esdt_token_data"
+        | "managed_is_esdt_frozen"
+        | "managed_is_esdt_limited_transfer"
+        | "managed_is_esdt_paused" => "GetExternalBalance",
+        "managed_async_call" => "AsyncCallStep",
+        "managed_create_async_call" => "CreateAsyncCall",
+        "managed_get_callback_closure" => "GetCallbackClosure",
+        "upgrade_from_source_contract"
+        | "deploy_from_source_contract"
+        | "delete_contract"
+        | "managed_upgrade_from_source_contract"
+        | "managed_upgrade_contract"
+        | "managed_delete_contract"
+        | "managed_deploy_from_source_contract"
+        | "managed_create_contract" => "CreateContract",
+        "managed_execute_read_only" => "ExecuteReadOnly",
+        "managed_execute_on_same_context" => "ExecuteOnSameContext",
+        "managed_execute_on_dest_context" => "ExecuteOnDestContext",
+        "managed_multi_transfer_esdt_nft_execute" | "managed_transfer_value_execute" => {
+            "TransferValue"
+        },
+        "managed_get_code_metadata" => "GetCodeMetadata",
+        "managed_is_builtin_function" => "IsBuiltinFunction",
+
+        // BigFloatAPICost
+        "big_float_new_from_parts" | "big_float_new_from_frac" | "big_float_new_from_sci" => {
+            "BigFloatNewFromParts"
+        },
+        "big_float_add" => "BigFloatAdd",
+        "big_float_sub" => "BigFloatSub",
+        "big_float_mul" => "BigFloatMul",
+        "big_float_div" => "BigFloatDiv",
+        "big_float_neg" => "BigFloatNeg",
+        "big_float_clone" => "BigFloatClone",
+        "big_float_cmp" => "BigFloatCmp",
+        "big_float_abs" => "BigFloatAbs",
+        "big_float_sign" => "BigFloatSign",
+        "big_float_sqrt" => "BigFloatSqrt",
+        "big_float_pow" => "BigFloatPow",
+        "big_float_floor" => "BigFloatFloor",
+        "big_float_ceil" => "BigFloatCeil",
+        "big_float_truncate" => "BigFloatTruncate",
+        "big_float_set_int64" => "BigFloatSetInt64",
+        "big_float_is_int" => "BigFloatIsInt",
+        "big_float_set_big_int" => "BigFloatSetBigInt",
+        "big_float_get_const_pi" | "big_float_get_const_e" => "BigFloatGetConst",
+
+        // BigIntAPICost
+        "big_int_get_call_value" => "BigIntGetCallValue",
+        "big_int_get_external_balance" | "big_int_get_esdt_external_balance" => {
+            "BigIntGetExternalBalance"
+        },
+        "big_int_new" => "BigIntNew",
+        "big_int_set_unsigned_bytes" => "BigIntSetUnsignedBytes",
+        "big_int_set_signed_bytes" => "BigIntSetSignedBytes",
+        "big_int_is_int64" => "BigIntIsInt64",
+        "big_int_get_int64" => "BigIntGetInt64",
+        "big_int_set_int64" => "BigIntSetInt64",
+        "big_int_add" => "BigIntAdd",
+        "big_int_sub" => "BigIntSub",
+        "big_int_mul" => "BigIntMul",
+        "big_int_tdiv" => "BigIntTDiv",
+        "big_int_tmod" => "BigIntTMod",
+        "big_int_sqrt" => "BigIntSqrt",
+        "big_int_pow" => "BigIntPow",
+        "big_int_log2" => "BigIntLog",
+        "big_int_abs" => "BigIntAbs",
+        "big_int_neg" => "BigIntNeg",
+        "big_int_sign" => "BigIntSign",
+        "big_int_cmp" => "BigIntCmp",
+        "big_int_and" => "BigIntAnd",
+        "big_int_or" => "BigIntOr",
+        "big_int_xor" => "BigIntXor",
+        "big_int_shr" => "BigIntShr",
+        "big_int_shl" => "BigIntShl",
+        "big_int_finish_unsigned" => "BigIntFinishUnsigned",
+        "big_int_finish_signed" | "big_int_to_string" => "BigIntFinishSigned",
+
+        // ManagedBufferAPICost
+        "mbuffer_new" => "MBufferNew",
+        "mbuffer_new_from_bytes" => "MBufferNewFromBytes",
+        "mbuffer_get_length" => "MBufferGetLength",
+        "mbuffer_get_bytes" | "mbuffer_eq" => "MBufferGetBytes",
+        "mbuffer_get_byte_slice" => "MBufferGetByteSlice",
+        "mbuffer_copy_byte_slice" => "MBufferCopyByteSlice",
+        "mbuffer_set_bytes" | "mbuffer_set_byte_slice" | "managed_buffer_to_hex" => {
+            "MBufferSetBytes"
+        },
+        "mbuffer_append" => "MBufferAppend",
+        "mbuffer_append_bytes" => "MBufferAppendBytes",
+        "mbuffer_to_big_int_unsigned" => "MBufferToBigIntUnsigned",
+        "mbuffer_to_big_int_signed" => "MBufferToBigIntSigned",
+        "mbuffer_from_big_int_unsigned" => "MBufferFromBigIntUnsigned",
+        "mbuffer_from_big_int_signed" => "MBufferFromBigIntSigned",
+        "mbuffer_to_big_float" => "MBufferToBigFloat",
+        "mbuffer_from_big_float" => "MBufferFromBigFloat",
+        "mbuffer_storage_store" => "MBufferStorageStore",
+        "mbuffer_storage_load" | "mbuffer_storage_load_from_address" => "MBufferStorageLoad",
+        "mbuffer_get_argument" => "MBufferGetArgument",
+        "mbuffer_finish" => "MBufferFinish",
+        "mbuffer_set_random" => "MBufferSetRandom",
+
+        // ManagedMapAPICost
+        "managed_map_new" => "ManagedMapNew",
+        "managed_map_put" => "ManagedMapPut",
+        "managed_map_get" => "ManagedMapGet",
+        "managed_map_remove" => "ManagedMapRemove",
+        "managed_map_contains" => "ManagedMapContains",
+
+        // SmallIntAPICost
+        "small_int_get_unsigned_argument" => "SmallIntGetUnsignedArgument",
+        "small_int_get_signed_argument" => "SmallIntGetSignedArgument",
+        "small_int_finish_unsigned" => "SmallIntFinishUnsigned",
+        "small_int_finish_signed" => "SmallIntFinishSigned",
+
+        // CryptoAPICost
+        "managed_sha256" => "SHA256",
+        "managed_keccak256" => "Keccak256",
+        "ripemd160" | "managed_ripemd160" => "Ripemd160",
+        "verify_bls" | "managed_verify_bls" => "VerifyBLS",
+        "managed_verify_ed25519" => "VerifyEd25519",
+        "verify_custom_secp256k1"
+        | "managed_verify_custom_secp256k1"
+        | "verify_secp256k1"
+        | "managed_verify_secp256k1" => "VerifySecp256k1",
+        "managed_verify_secp256r1" => "VerifySecp256r1",
+        "managed_verify_blssignature_share" => "VerifyBLSSignatureShare",
+        "managed_verify_blsaggregated_signature" => "VerifyBLSMultiSig",
+        "encode_secp256k1_der_signature" | "managed_encode_secp256k1_der_signature" => {
+            "EncodeDERSig"
+        },
+        "add_ec" => "AddECC",
+        "double_ec" => "DoubleECC",
+        "is_on_curve_ec" => "IsOnCurveECC",
+        "scalar_base_mult_ec"
+        | "managed_scalar_base_mult_ec"
+        | "scalar_mult_ec"
+        | "managed_scalar_mult_ec" => "ScalarMultECC",
+        "marshal_ec" | "managed_marshal_ec" => "MarshalECC",
+        "marshal_compressed_ec" | "managed_marshal_compressed_ec" => "MarshalCompressedECC",
+        "unmarshal_ec" | "managed_unmarshal_ec" => "UnmarshalECC",
+        "unmarshal_compressed_ec" | "managed_unmarshal_compressed_ec" => "UnmarshalCompressedECC",
+        "generate_key_ec" | "managed_generate_key_ec" => "GenerateKeyECC",
+        "create_ec"
+        | "managed_create_ec"
+        | "get_curve_length_ec"
+        | "get_priv_key_byte_length_ec"
+        | "elliptic_curve_get_values" => "EllipticCurveNew",
+
+        _ => return None,
+    };
+    Some(cost_name)
+}
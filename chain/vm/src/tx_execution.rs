@@ -4,6 +4,7 @@ mod exec_call;
 mod exec_contract_endpoint;
 mod exec_create;
 mod exec_general_tx;
+mod exec_sync_call;
 mod system_sc;
 
 pub use blockchain_vm::{BlockchainVM, BlockchainVMRef};
@@ -2,11 +2,16 @@ use std::{ops::Deref, sync::Arc};
 
 use multiversx_chain_vm_executor::Executor;
 
+use crate::schedule::GasSchedule;
+
 use super::BuiltinFunctionContainer;
 
 pub struct BlockchainVM {
     pub builtin_functions: BuiltinFunctionContainer,
     pub executor: Box<dyn Executor + Send + Sync>,
+
+    /// Gas is only metered if a gas schedule is configured.
+    pub gas_schedule: Option<GasSchedule>,
 }
 
 #[derive(Clone)]
@@ -17,14 +22,30 @@ impl BlockchainVM {
         BlockchainVM {
             builtin_functions: BuiltinFunctionContainer,
             executor,
+            gas_schedule: None,
         }
     }
+
+    /// Gas cost of a builtin function, 0 if gas is not metered.
+    pub fn builtin_function_gas_cost(&self, func_name: &str) -> u64 {
+        self.gas_schedule
+            .as_ref()
+            .map(|gas_schedule| gas_schedule.builtin_function_cost(func_name))
+            .unwrap_or_default()
+    }
 }
 
 impl BlockchainVMRef {
     pub fn new(executor: Box<dyn Executor + Send + Sync>) -> Self {
         BlockchainVMRef(Arc::new(BlockchainVM::new(executor)))
     }
+
+    /// Enables gas metering. Can only be called while no transaction is running.
+    pub fn set_gas_schedule(&mut self, gas_schedule: Option<GasSchedule>) {
+        Arc::get_mut(&mut self.0)
+            .expect("cannot change the gas schedule while the VM is in use")
+            .gas_schedule = gas_schedule;
+    }
 }
 
 impl Deref for BlockchainVMRef {
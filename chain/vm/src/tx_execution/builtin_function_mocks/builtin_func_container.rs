@@ -4,7 +4,10 @@ use super::{
         ESDTLocalBurn, ESDTLocalMint, ESDTNftAddQuantity, ESDTNftAddUri, ESDTNftBurn,
         ESDTNftCreate, ESDTNftUpdateAttributes,
     },
-    general::{ChangeOwner, ClaimDeveloperRewards, DeleteUsername, SetUsername, UpgradeContract},
+    general::{
+        ChangeOwner, ClaimDeveloperRewards, DeleteContract, DeleteUsername, SetUsername,
+        UpgradeContract,
+    },
     transfer::{ESDTMultiTransfer, ESDTNftTransfer, ESDTTransfer},
     BuiltinFunctionEsdtTransferInfo,
 };
@@ -114,6 +117,7 @@ impl<'a> BuiltinFunctionCall<'a> {
             SET_USERNAME_FUNC_NAME => self.execute_bf(SetUsername, f),
             DELETE_USERNAME_FUNC_NAME => self.execute_bf(DeleteUsername, f),
             UPGRADE_CONTRACT_FUNC_NAME => self.execute_bf(UpgradeContract, f),
+            DELETE_CONTRACT_FUNC_NAME => self.execute_bf(DeleteContract, f),
             MIGRATE_USERNAME_FUNC_NAME => {
                 panic!("builtin function {MIGRATE_USERNAME_FUNC_NAME} was dropped")
             },
@@ -126,7 +130,13 @@ impl<'a> BuiltinFunctionCall<'a> {
         B: BuiltinFunction,
         F: FnOnce(),
     {
-        builtin_func.execute(self.tx_input, self.tx_cache, self.vm, f)
+        let gas_cost = self
+            .vm
+            .builtin_function_gas_cost(self.tx_input.func_name.as_str());
+        let (mut tx_result, blockchain_updates) =
+            builtin_func.execute(self.tx_input, self.tx_cache, self.vm, f);
+        tx_result.gas_used += gas_cost;
+        (tx_result, blockchain_updates)
     }
 
     fn check_role_and_execute<B, F>(
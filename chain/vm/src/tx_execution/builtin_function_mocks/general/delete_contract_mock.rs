@@ -0,0 +1,104 @@
+use num_traits::Zero;
+
+use crate::{
+    chain_core::builtin_func_names::DELETE_CONTRACT_FUNC_NAME,
+    tx_execution::BlockchainVMRef,
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    types::VMAddress,
+};
+
+use super::super::builtin_func_trait::BuiltinFunction;
+
+/// Deletes a contract, together with its code and storage.
+///
+/// Only the owner can delete a contract. The EGLD balance and all ESDT, SFT and NFT balances
+/// go to the beneficiary, which is the only argument.
+pub struct DeleteContract;
+
+impl BuiltinFunction for DeleteContract {
+    fn name(&self) -> &str {
+        DELETE_CONTRACT_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if tx_input.args.len() != 1 {
+            return (
+                TxResult::from_vm_error("DeleteContract expects 1 argument"),
+                BlockchainUpdate::empty(),
+            );
+        }
+
+        if !tx_input.egld_value.is_zero() {
+            return (
+                TxResult::from_vm_error("DeleteContract is not payable"),
+                BlockchainUpdate::empty(),
+            );
+        }
+
+        let beneficiary = VMAddress::from_slice(&tx_input.args[0]);
+        if beneficiary == tx_input.to {
+            return (
+                TxResult::from_vm_error("beneficiary cannot be the deleted contract"),
+                BlockchainUpdate::empty(),
+            );
+        }
+        if !tx_cache.with_account_or_else(&beneficiary, |_| true, || false) {
+            return (
+                TxResult::from_vm_error("beneficiary account not found"),
+                BlockchainUpdate::empty(),
+            );
+        }
+
+        let (caller_is_owner, egld_balance, esdt_balances) =
+            tx_cache.with_account(&tx_input.to, |account| {
+                let esdt_balances: Vec<_> = account
+                    .esdt
+                    .iter()
+                    .flat_map(|(token_identifier, esdt_data)| {
+                        esdt_data
+                            .instances
+                            .get_instances()
+                            .values()
+                            .filter(|instance| !instance.balance.is_zero())
+                            .map(|instance| (token_identifier.clone(), instance.clone()))
+                    })
+                    .collect();
+                (
+                    account.contract_path.is_some()
+                        && account.contract_owner == Some(tx_input.from.clone()),
+                    account.egld_balance.clone(),
+                    esdt_balances,
+                )
+            });
+
+        if !caller_is_owner {
+            return (
+                TxResult::from_vm_error("operation in account not permitted"),
+                BlockchainUpdate::empty(),
+            );
+        }
+
+        tx_cache.delete_account(&tx_input.to);
+        tx_cache.increase_egld_balance(&beneficiary, &egld_balance);
+        for (token_identifier, instance) in esdt_balances {
+            tx_cache.increase_esdt_balance(
+                &beneficiary,
+                &token_identifier,
+                instance.nonce,
+                &instance.balance,
+                instance.metadata,
+            );
+        }
+
+        (TxResult::empty(), tx_cache.into_blockchain_updates())
+    }
+}
@@ -3,6 +3,7 @@ use crate::{
     tx_execution::{create_transfer_value_log, BlockchainVMRef},
     tx_mock::{BlockchainUpdate, CallType, TxCache, TxFunctionName, TxInput, TxResult},
     types::VMCodeMetadata,
+    vm_err_msg,
 };
 
 use super::super::builtin_func_trait::BuiltinFunction;
@@ -31,6 +32,13 @@ impl BuiltinFunction for UpgradeContract {
             );
         }
 
+        if tx_cache.contract_code(&tx_input.to).is_none() {
+            return (
+                TxResult::from_vm_error(vm_err_msg::CONTRACT_NOT_FOUND),
+                BlockchainUpdate::empty(),
+            );
+        }
+
         let new_code = tx_input.args[0].clone();
         let code_metadata = VMCodeMetadata::from(&tx_input.args[1]);
 
@@ -57,6 +65,10 @@ impl BuiltinFunction for UpgradeContract {
             gas_limit: tx_input.gas_limit,
             gas_price: tx_input.gas_price,
             tx_hash: tx_input.tx_hash,
+            original_tx_hash: tx_input.original_tx_hash,
+            prev_tx_hash: tx_input.prev_tx_hash,
+            original_caller: tx_input.original_caller,
+            relayer: tx_input.relayer,
             call_type: CallType::UpgradeFromSource,
             ..Default::default()
         };
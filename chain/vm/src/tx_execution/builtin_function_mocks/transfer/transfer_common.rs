@@ -79,6 +79,10 @@ where
         gas_limit: tx_input.gas_limit,
         gas_price: tx_input.gas_price,
         tx_hash: tx_input.tx_hash,
+        original_tx_hash: tx_input.original_tx_hash,
+        prev_tx_hash: tx_input.prev_tx_hash,
+        original_caller: tx_input.original_caller,
+        relayer: tx_input.relayer,
         ..Default::default()
     };
 
@@ -85,13 +85,19 @@ impl BlockchainVMRef {
     where
         F: FnOnce(),
     {
-        state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
+        state.subtract_tx_gas(tx_input.gas_payer(), tx_input.gas_limit, tx_input.gas_price);
 
-        let (tx_result, blockchain_updates) = state.with_shared(|state_arc| {
+        let gas_limit = tx_input.gas_limit;
+        let (mut tx_result, blockchain_updates) = state.with_shared(|state_arc| {
             let tx_cache = TxCache::new(state_arc);
             self.execute_builtin_function_or_default(tx_input, tx_cache, f)
         });
 
+        // builtin function costs are only checked against the gas limit here, at the top level
+        if self.gas_schedule.is_some() && tx_result.gas_used > gas_limit {
+            tx_result = TxResult::from_out_of_gas(gas_limit);
+        }
+
         if tx_result.result_status.is_success() {
             blockchain_updates.apply(state);
         }
@@ -39,11 +39,11 @@ impl BlockchainVMRef {
 fn get_contract_identifier(tx_context: &TxContext) -> Vec<u8> {
     tx_context
         .tx_cache
-        .with_account(&tx_context.tx_input_box.to, |account| {
+        .with_account(tx_context.tx_input_box.code_address(), |account| {
             account.contract_path.clone().unwrap_or_else(|| {
                 panic!(
                     "Recipient account is not a smart contract {}",
-                    address_hex(&tx_context.tx_input_box.to)
+                    address_hex(tx_context.tx_input_box.code_address())
                 )
             })
         })
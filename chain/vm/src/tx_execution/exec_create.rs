@@ -22,7 +22,7 @@ impl BlockchainVMRef {
         // nonce gets increased irrespective of whether the tx fails or not
         // must be done after computing the new address
         state.increase_account_nonce(&tx_input.from);
-        state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
+        state.subtract_tx_gas(tx_input.gas_payer(), tx_input.gas_limit, tx_input.gas_price);
 
         let (tx_result, new_address, blockchain_updates) = state.with_shared(|state_arc| {
             let tx_cache = TxCache::new(state_arc);
@@ -0,0 +1,53 @@
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    types::VMAddress,
+    vm_err_msg,
+};
+
+use super::BlockchainVMRef;
+
+impl BlockchainVMRef {
+    /// Runs the code of `code_address` in the context of `tx_input.to`,
+    /// i.e. with its storage, balances and address.
+    pub fn execute_on_same_context<F>(
+        &self,
+        mut tx_input: TxInput,
+        code_address: VMAddress,
+        tx_cache: TxCache,
+        f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        let is_contract = tx_cache.with_account_or_else(
+            &code_address,
+            |account| account.contract_path.is_some(),
+            || false,
+        );
+        if !is_contract {
+            return (
+                TxResult::from_vm_error(vm_err_msg::CONTRACT_NOT_FOUND),
+                BlockchainUpdate::empty(),
+            );
+        }
+
+        tx_input.code_address = Some(code_address);
+        self.default_execution(tx_input, tx_cache, f)
+    }
+
+    /// Executes a call in which the callee, and any calls it makes in turn, cannot change the state.
+    ///
+    /// The restrictions are enforced by the VM hooks, based on the `readonly` flag of the input.
+    pub fn execute_read_only<F>(
+        &self,
+        mut tx_input: TxInput,
+        tx_cache: TxCache,
+        f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        tx_input.readonly = true;
+        self.execute_builtin_function_or_default(tx_input, tx_cache, f)
+    }
+}
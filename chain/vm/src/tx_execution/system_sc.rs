@@ -1,15 +1,20 @@
+mod system_sc_config;
+mod system_sc_freeze;
 mod system_sc_issue;
 mod system_sc_special_roles;
-mod system_sc_unimplemented;
+mod system_sc_token_management;
 
 use crate::{
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::VMAddress,
+    world_mock::EsdtTokenInfo,
 };
 use hex_literal::hex;
+use system_sc_config::*;
+use system_sc_freeze::*;
 use system_sc_issue::*;
 use system_sc_special_roles::*;
-use system_sc_unimplemented::*;
+use system_sc_token_management::*;
 
 /// Address of the system smart contract that manages ESDT.
 /// Bech32: erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u
@@ -20,15 +25,21 @@ pub fn is_system_sc_address(address: &VMAddress) -> bool {
     address.as_array() == &ESDT_SYSTEM_SC_ADDRESS_ARRAY
 }
 
+/// Failures are boxed, since a `TxResult` is much larger than the `Ok` values of the helpers.
+type SystemSCError = Box<TxResult>;
+
+/// Result of a system SC function that can fail before producing any blockchain updates.
+type SystemSCResult = Result<(TxResult, BlockchainUpdate), SystemSCError>;
+
 pub fn execute_system_sc(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     let func_name = &tx_input.func_name;
-    match func_name.as_str() {
-        "issue" => issue(tx_input, tx_cache),
-        "issueSemiFungible" => issue_semi_fungible(tx_input, tx_cache),
-        "issueNonFungible" => issue_non_fungible(tx_input, tx_cache),
-        "registerMetaESDT" => register_meta_esdt(tx_input, tx_cache),
+    let result = match func_name.as_str() {
+        "issue" => Ok(issue(tx_input, tx_cache)),
+        "issueSemiFungible" => Ok(issue_semi_fungible(tx_input, tx_cache)),
+        "issueNonFungible" => Ok(issue_non_fungible(tx_input, tx_cache)),
+        "registerMetaESDT" => Ok(register_meta_esdt(tx_input, tx_cache)),
         "changeSFTToMetaESDT" => change_sft_to_meta_esdt(tx_input, tx_cache),
-        "registerAndSetAllRoles" => register_and_set_all_roles(tx_input, tx_cache),
+        "registerAndSetAllRoles" => Ok(register_and_set_all_roles(tx_input, tx_cache)),
         "ESDTBurn" => esdt_burn(tx_input, tx_cache),
         "mint" => mint(tx_input, tx_cache),
         "freeze" => freeze(tx_input, tx_cache),
@@ -45,7 +56,7 @@ pub fn execute_system_sc(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Blo
         "transferOwnership" => transfer_ownership(tx_input, tx_cache),
         "getTokenProperties" => get_token_properties(tx_input, tx_cache),
         "getSpecialRoles" => get_special_roles(tx_input, tx_cache),
-        "setSpecialRole" => set_special_role(tx_input, tx_cache),
+        "setSpecialRole" => Ok(set_special_role(tx_input, tx_cache)),
         "unSetSpecialRole" => unset_special_role(tx_input, tx_cache),
         "transferNFTCreateRole" => transfer_nft_create_role(tx_input, tx_cache),
         "stopNFTCreate" => stop_nft_create(tx_input, tx_cache),
@@ -56,5 +67,46 @@ pub fn execute_system_sc(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Blo
         "unsetBurnRoleGlobally" => unset_burn_role_globally(tx_input, tx_cache),
         "sendAllTransferRoleAddresses" => send_all_transfer_role_addresses(tx_input, tx_cache),
         invalid_func_name => panic!("invalid system SC function: {invalid_func_name}"),
+    };
+    result.unwrap_or_else(|err_result| (*err_result, BlockchainUpdate::empty()))
+}
+
+fn system_sc_error<S>(message: S) -> SystemSCError
+where
+    S: Into<String>,
+{
+    Box::new(TxResult::from_vm_error(message))
+}
+
+fn check_num_args(tx_input: &TxInput, expected: usize) -> Result<(), SystemSCError> {
+    if tx_input.args.len() < expected {
+        return Err(system_sc_error(format!(
+            "{} too few arguments",
+            tx_input.func_name.as_str()
+        )));
     }
+    Ok(())
+}
+
+fn require(condition: bool, message: &str) -> Result<(), SystemSCError> {
+    if !condition {
+        return Err(system_sc_error(message));
+    }
+    Ok(())
+}
+
+/// Loads the global token data and checks that the caller owns the token.
+fn load_token_info_as_owner(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+) -> Result<EsdtTokenInfo, SystemSCError> {
+    let token_info = tx_cache
+        .get_esdt_token_info(token_identifier)
+        .ok_or_else(|| system_sc_error("no ticker with given name"))?;
+    require(
+        token_info.owner == tx_input.from,
+        "can be called by owner only",
+    )?;
+    Ok(token_info)
 }
@@ -0,0 +1,37 @@
+use num_bigint::BigUint;
+
+use crate::{
+    tx_mock::{TxCache, TxInput, TxResult},
+    types::{top_encode_big_uint, top_encode_u64, VMAddress},
+};
+
+use super::{system_sc_error, SystemSCResult};
+
+/// Same as the mainnet ESDT system SC configuration: 0.05 EGLD.
+const BASE_ISSUING_COST: u64 = 50_000_000_000_000_000;
+const MIN_TOKEN_NAME_LENGTH: u64 = 3;
+const MAX_TOKEN_NAME_LENGTH: u64 = 20;
+
+/// The owner of the ESDT system SC is a protocol address, it cannot be used from scenarios.
+const ERR_SYSTEM_SC_OWNER_ONLY: &str = "can be called by the ESDT system SC owner only";
+
+pub fn claim(_tx_input: TxInput, _tx_cache: TxCache) -> SystemSCResult {
+    Err(system_sc_error(ERR_SYSTEM_SC_OWNER_ONLY))
+}
+
+pub fn config_change(_tx_input: TxInput, _tx_cache: TxCache) -> SystemSCResult {
+    Err(system_sc_error(ERR_SYSTEM_SC_OWNER_ONLY))
+}
+
+pub fn get_contract_config(_tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    let tx_result = TxResult {
+        result_values: vec![
+            VMAddress::zero().to_vec(),
+            top_encode_big_uint(&BigUint::from(BASE_ISSUING_COST)),
+            top_encode_u64(MIN_TOKEN_NAME_LENGTH),
+            top_encode_u64(MAX_TOKEN_NAME_LENGTH),
+        ],
+        ..Default::default()
+    };
+    Ok((tx_result, tx_cache.into_blockchain_updates()))
+}
@@ -0,0 +1,134 @@
+use num_bigint::BigUint;
+
+use crate::{
+    tx_mock::{TxCache, TxInput, TxResult},
+    types::{top_decode_u64, VMAddress, VMTokenType},
+};
+
+use super::{
+    check_num_args, load_token_info_as_owner, require, system_sc_error, SystemSCError,
+    SystemSCResult,
+};
+
+pub fn freeze(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    set_account_frozen(tx_input, tx_cache, true)
+}
+
+pub fn unfreeze(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    set_account_frozen(tx_input, tx_cache, false)
+}
+
+pub fn freeze_single_nft(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    set_nft_frozen(tx_input, tx_cache, true)
+}
+
+pub fn unfreeze_single_nft(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    set_nft_frozen(tx_input, tx_cache, false)
+}
+
+/// Wipes the fungible balance of a frozen account. The wiped amount is taken out of the supply.
+pub fn wipe(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 2)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let address = VMAddress::from_slice(tx_input.args[1].as_slice());
+
+    let mut token_info = load_token_info_as_owner(&tx_input, &tx_cache, token_identifier)?;
+    require(token_info.can_wipe, "cannot wipe")?;
+    let wiped_amount = wipe_instance(&tx_cache, &address, token_identifier, 0)?;
+
+    token_info.burnt_value += wiped_amount;
+    token_info.num_wiped += 1;
+    tx_cache.set_esdt_token_info(token_identifier, token_info);
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
+}
+
+/// Wipes a single frozen NFT instance. The wiped amount is taken out of the supply.
+pub fn wipe_single_nft(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 3)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let nonce = top_decode_u64(tx_input.args[1].as_slice());
+    let address = VMAddress::from_slice(tx_input.args[2].as_slice());
+
+    let mut token_info = load_token_info_as_owner(&tx_input, &tx_cache, token_identifier)?;
+    require(token_info.can_wipe, "cannot wipe")?;
+    require(
+        token_info.token_type != VMTokenType::Fungible,
+        "only non fungible tokens can be wiped per nonce",
+    )?;
+    let wiped_amount = wipe_instance(&tx_cache, &address, token_identifier, nonce)?;
+
+    token_info.burnt_value += wiped_amount;
+    token_info.num_wiped += 1;
+    tx_cache.set_esdt_token_info(token_identifier, token_info);
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
+}
+
+fn set_account_frozen(tx_input: TxInput, tx_cache: TxCache, frozen: bool) -> SystemSCResult {
+    check_num_args(&tx_input, 2)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let address = VMAddress::from_slice(tx_input.args[1].as_slice());
+
+    let token_info = load_token_info_as_owner(&tx_input, &tx_cache, token_identifier)?;
+    require(token_info.can_freeze, "cannot freeze")?;
+
+    tx_cache.with_account_mut(&address, |account| {
+        account
+            .esdt
+            .get_mut_by_identifier_or_insert(token_identifier)
+            .frozen = frozen;
+    });
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
+}
+
+fn set_nft_frozen(tx_input: TxInput, tx_cache: TxCache, frozen: bool) -> SystemSCResult {
+    check_num_args(&tx_input, 3)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let nonce = top_decode_u64(tx_input.args[1].as_slice());
+    let address = VMAddress::from_slice(tx_input.args[2].as_slice());
+
+    let token_info = load_token_info_as_owner(&tx_input, &tx_cache, token_identifier)?;
+    require(token_info.can_freeze, "cannot freeze")?;
+    require(
+        token_info.token_type != VMTokenType::Fungible,
+        "only non fungible tokens can be frozen per nonce",
+    )?;
+
+    tx_cache.with_account_mut(&address, |account| -> Result<(), SystemSCError> {
+        let instance = account
+            .esdt
+            .get_mut_by_identifier(token_identifier)
+            .and_then(|esdt_data| esdt_data.instances.get_mut_by_nonce(nonce))
+            .ok_or_else(|| system_sc_error("account does not hold the NFT"))?;
+        instance.frozen = frozen;
+        Ok(())
+    })?;
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
+}
+
+/// Zeroes the balance of a frozen token instance and returns the wiped amount.
+fn wipe_instance(
+    tx_cache: &TxCache,
+    address: &VMAddress,
+    token_identifier: &[u8],
+    nonce: u64,
+) -> Result<BigUint, SystemSCError> {
+    tx_cache.with_account_mut(address, |account| {
+        let esdt_data = account
+            .esdt
+            .get_mut_by_identifier(token_identifier)
+            .ok_or_else(|| system_sc_error("no balance to wipe"))?;
+        require(
+            esdt_data.is_frozen(nonce),
+            "cannot wipe because the account is not frozen for this esdt token",
+        )?;
+        let instance = esdt_data
+            .instances
+            .get_mut_by_nonce(nonce)
+            .ok_or_else(|| system_sc_error("no balance to wipe"))?;
+        Ok(core::mem::take(&mut instance.balance))
+    })
+}
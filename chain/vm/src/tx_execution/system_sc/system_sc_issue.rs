@@ -1,13 +1,16 @@
 use num_bigint::BigUint;
+use num_traits::Zero;
 
 use crate::{
     crypto_functions::keccak256,
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::{top_decode_u64, VMTokenType},
+    world_mock::{EsdtInstanceMetadata, EsdtTokenInfo},
 };
 
+use super::{check_num_args, load_token_info_as_owner, require, SystemSCResult};
+
 /// Issues a new fungible token.
-#[allow(unused_variables)]
 pub fn issue(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 4 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
@@ -18,11 +21,18 @@ pub fn issue(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdat
     let total_supply = BigUint::from_bytes_be(tx_input.args[2].clone().as_ref());
     let decimals = top_decode_u64(tx_input.args[3].clone().as_ref()) as u32;
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::Fungible)
+    let mut token_info = EsdtTokenInfo::new(
+        name,
+        ticker,
+        VMTokenType::Fungible,
+        tx_input.from.clone(),
+        decimals,
+    );
+    token_info.minted_value = total_supply;
+    register_and_set_roles(tx_input, tx_cache, token_info, 4)
 }
 
 /// Issues a new semi-fungible token.
-#[allow(unused_variables)]
 pub fn issue_semi_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 2 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
@@ -31,11 +41,17 @@ pub fn issue_semi_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, B
     let name = tx_input.args[0].clone();
     let ticker = tx_input.args[1].clone();
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::SemiFungible)
+    let token_info = EsdtTokenInfo::new(
+        name,
+        ticker,
+        VMTokenType::SemiFungible,
+        tx_input.from.clone(),
+        0,
+    );
+    register_and_set_roles(tx_input, tx_cache, token_info, 2)
 }
 
 /// Issues a new non-fungible token.
-#[allow(unused_variables)]
 pub fn issue_non_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 2 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
@@ -44,11 +60,37 @@ pub fn issue_non_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Bl
     let name = tx_input.args[0].clone();
     let ticker = tx_input.args[1].clone();
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::NonFungible)
+    let token_info = EsdtTokenInfo::new(
+        name,
+        ticker,
+        VMTokenType::NonFungible,
+        tx_input.from.clone(),
+        0,
+    );
+    register_and_set_roles(tx_input, tx_cache, token_info, 2)
+}
+
+/// Issues a new meta-ESDT token.
+pub fn register_meta_esdt(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() < 3 {
+        let tx_result = TxResult::from_vm_error("not enough arguments");
+        return (tx_result, BlockchainUpdate::empty());
+    }
+    let name = tx_input.args[0].clone();
+    let ticker = tx_input.args[1].clone();
+    let decimals = top_decode_u64(tx_input.args[2].clone().as_ref()) as u32;
+
+    let token_info = EsdtTokenInfo::new(
+        name,
+        ticker,
+        VMTokenType::Meta,
+        tx_input.from.clone(),
+        decimals,
+    );
+    register_and_set_roles(tx_input, tx_cache, token_info, 3)
 }
 
 // Issues a new token and sets all roles for its type.
-#[allow(unused_variables)]
 pub fn register_and_set_all_roles(
     tx_input: TxInput,
     tx_cache: TxCache,
@@ -63,32 +105,80 @@ pub fn register_and_set_all_roles(
     let token_type = VMTokenType::from_system_sc_arg(&tx_input.args[2]);
     let decimals = top_decode_u64(tx_input.args[3].clone().as_ref()) as u32;
 
-    register_and_set_roles(tx_input, tx_cache, ticker, token_type)
+    let token_info = EsdtTokenInfo::new(name, ticker, token_type, tx_input.from.clone(), decimals);
+    register_and_set_roles(tx_input, tx_cache, token_info, 4)
+}
+
+/// Converts an existing semi-fungible token into a meta-ESDT.
+pub fn change_sft_to_meta_esdt(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 2)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let decimals = top_decode_u64(tx_input.args[1].as_slice()) as u32;
+
+    let mut token_info = load_token_info_as_owner(&tx_input, &tx_cache, token_identifier)?;
+    require(
+        token_info.token_type == VMTokenType::SemiFungible,
+        "change can happen to semi fungible tokens only",
+    )?;
+    token_info.token_type = VMTokenType::Meta;
+    token_info.num_decimals = decimals;
+    tx_cache.set_esdt_token_info(token_identifier, token_info);
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
 }
 
+/// The issue arguments starting at `properties_start` are pairs of property names and values,
+/// e.g. `canFreeze@true`.
 fn register_and_set_roles(
     tx_input: TxInput,
     tx_cache: TxCache,
-    ticker: Vec<u8>,
-    token_type: VMTokenType,
+    mut token_info: EsdtTokenInfo,
+    properties_start: usize,
 ) -> (TxResult, BlockchainUpdate) {
+    for property in tx_input.args[properties_start..].chunks(2) {
+        let [name, value] = property else {
+            return (
+                TxResult::from_vm_error("odd number of token property arguments"),
+                BlockchainUpdate::empty(),
+            );
+        };
+        if !token_info.set_property(name, value) {
+            return (
+                TxResult::from_vm_error("invalid token property argument"),
+                BlockchainUpdate::empty(),
+            );
+        }
+    }
+
     let mut new_token_identifiers = tx_cache.get_new_token_identifiers();
 
     let token_identifier = if let Some((i, ti)) =
-        first_token_identifier_with_ticker(&new_token_identifiers, &ticker)
+        first_token_identifier_with_ticker(&new_token_identifiers, &token_info.ticker)
     {
         new_token_identifiers.remove(i);
         ti.into_bytes()
     } else {
-        generate_token_identifier_from_ticker(&tx_input, &tx_cache, &ticker)
+        generate_token_identifier_from_ticker(&tx_input, &tx_cache, &token_info.ticker)
     };
 
+    let token_type = token_info.token_type;
     tx_cache.with_account_mut(&tx_input.from, |account| {
         account
             .esdt
             .register_and_set_roles(&token_identifier, token_type);
     });
+    if !token_info.minted_value.is_zero() {
+        // the initial supply goes to the issuer
+        tx_cache.increase_esdt_balance(
+            &tx_input.from,
+            &token_identifier,
+            0,
+            &token_info.minted_value,
+            EsdtInstanceMetadata::default(),
+        );
+    }
     tx_cache.set_new_token_identifiers(new_token_identifiers);
+    tx_cache.set_esdt_token_info(&token_identifier, token_info);
 
     let tx_result = TxResult {
         result_values: vec![token_identifier],
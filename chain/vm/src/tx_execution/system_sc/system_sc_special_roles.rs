@@ -1,8 +1,14 @@
 use crate::{
+    display_util::address_bech32,
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
-    types::VMAddress,
+    types::{VMAddress, VMTokenType},
 };
 
+use super::{check_num_args, load_token_info_as_owner, require, system_sc_error, SystemSCResult};
+
+const NFT_CREATE_ROLE: &[u8] = b"ESDTRoleNFTCreate";
+const TRANSFER_ROLE: &[u8] = b"ESDTTransferRole";
+
 pub fn set_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 3 {
         return (
@@ -21,3 +27,205 @@ pub fn set_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Bloc
 
     (TxResult::empty(), tx_cache.into_blockchain_updates())
 }
+
+pub fn unset_special_role(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 3)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let address = VMAddress::from_slice(tx_input.args[1].as_slice());
+    load_token_info_as_owner(&tx_input, &tx_cache, token_identifier)?;
+
+    tx_cache.with_account_mut(&address, |account| {
+        for role in &tx_input.args[2..] {
+            account.esdt.unset_special_role(token_identifier, role);
+        }
+    });
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
+}
+
+/// Moves the NFT create role, together with the last created nonce, to another address.
+pub fn transfer_nft_create_role(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 3)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let old_creator = VMAddress::from_slice(tx_input.args[1].as_slice());
+    let new_creator = VMAddress::from_slice(tx_input.args[2].as_slice());
+
+    let token_info = load_token_info_as_owner(&tx_input, &tx_cache, token_identifier)?;
+    require(
+        token_info.token_type != VMTokenType::Fungible,
+        "invalid function call for fungible tokens",
+    )?;
+    require(
+        token_info.can_transfer_nft_create_role,
+        "NFT create role transfer is not allowed",
+    )?;
+
+    let last_nonce = tx_cache.with_account_mut(&old_creator, |account| {
+        if !account
+            .esdt
+            .has_special_role(token_identifier, NFT_CREATE_ROLE)
+        {
+            return Err(system_sc_error("address does not have the NFT create role"));
+        }
+        account
+            .esdt
+            .unset_special_role(token_identifier, NFT_CREATE_ROLE);
+        Ok(account
+            .esdt
+            .get_by_identifier(token_identifier)
+            .map(|esdt_data| esdt_data.last_nonce)
+            .unwrap_or_default())
+    })?;
+
+    tx_cache.with_account_mut(&new_creator, |account| {
+        let esdt_data = account
+            .esdt
+            .get_mut_by_identifier_or_insert(token_identifier);
+        esdt_data.last_nonce = esdt_data.last_nonce.max(last_nonce);
+        account
+            .esdt
+            .set_special_role(token_identifier, NFT_CREATE_ROLE);
+    });
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
+}
+
+/// Permanently disables NFT creation, by removing the NFT create role from all holders.
+pub fn stop_nft_create(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 1)?;
+    let token_identifier = tx_input.args[0].as_slice();
+
+    let mut token_info = load_token_info_as_owner(&tx_input, &tx_cache, token_identifier)?;
+    require(
+        token_info.token_type != VMTokenType::Fungible,
+        "invalid function call for fungible tokens",
+    )?;
+    require(
+        !token_info.nft_create_stopped,
+        "NFT create was already stopped",
+    )?;
+    token_info.nft_create_stopped = true;
+
+    for address in addresses_with_role(&tx_cache, token_identifier, NFT_CREATE_ROLE) {
+        tx_cache.with_account_mut(&address, |account| {
+            account
+                .esdt
+                .unset_special_role(token_identifier, NFT_CREATE_ROLE);
+        });
+    }
+    tx_cache.set_esdt_token_info(token_identifier, token_info);
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
+}
+
+/// Lists the addresses holding roles for a token, as `erd1...:Role1,Role2`.
+pub fn get_special_roles(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 1)?;
+    let token_identifier = tx_input.args[0].as_slice();
+
+    let result_values = addresses_and_roles(&tx_cache, token_identifier)
+        .into_iter()
+        .map(|(address, roles)| {
+            let role_names: Vec<String> = roles
+                .iter()
+                .map(|role| String::from_utf8_lossy(role).into_owned())
+                .collect();
+            format!("{}:{}", address_bech32(&address), role_names.join(",")).into_bytes()
+        })
+        .collect();
+
+    let tx_result = TxResult {
+        result_values,
+        ..Default::default()
+    };
+    Ok((tx_result, tx_cache.into_blockchain_updates()))
+}
+
+/// Same data as `getSpecialRoles`, flattened as raw address, followed by its roles.
+pub fn get_all_addresses_and_roles(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 1)?;
+    let token_identifier = tx_input.args[0].as_slice();
+
+    let mut result_values = Vec::new();
+    for (address, mut roles) in addresses_and_roles(&tx_cache, token_identifier) {
+        result_values.push(address.to_vec());
+        result_values.append(&mut roles);
+    }
+
+    let tx_result = TxResult {
+        result_values,
+        ..Default::default()
+    };
+    Ok((tx_result, tx_cache.into_blockchain_updates()))
+}
+
+pub fn set_burn_role_globally(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    set_burn_role_globally_flag(tx_input, tx_cache, true)
+}
+
+pub fn unset_burn_role_globally(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    set_burn_role_globally_flag(tx_input, tx_cache, false)
+}
+
+fn set_burn_role_globally_flag(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    value: bool,
+) -> SystemSCResult {
+    check_num_args(&tx_input, 1)?;
+    let token_identifier = tx_input.args[0].as_slice();
+
+    let mut token_info = load_token_info_as_owner(&tx_input, &tx_cache, token_identifier)?;
+    token_info.burn_role_globally = value;
+    tx_cache.set_esdt_token_info(token_identifier, token_info);
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
+}
+
+/// On the real chain it broadcasts the transfer role addresses to all shards.
+///
+/// The mock has a single shard, so it only performs the checks.
+pub fn send_all_transfer_role_addresses(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 1)?;
+    let token_identifier = tx_input.args[0].as_slice();
+
+    load_token_info_as_owner(&tx_input, &tx_cache, token_identifier)?;
+    require(
+        !addresses_with_role(&tx_cache, token_identifier, TRANSFER_ROLE).is_empty(),
+        "no address with transfer role",
+    )?;
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
+}
+
+/// All addresses in the blockchain state that have at least one role for the given token,
+/// sorted, to keep the output deterministic.
+fn addresses_and_roles(
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+) -> Vec<(VMAddress, Vec<Vec<u8>>)> {
+    let mut addresses: Vec<VMAddress> =
+        tx_cache.blockchain_ref().accounts.keys().cloned().collect();
+    addresses.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
+
+    addresses
+        .into_iter()
+        .filter_map(|address| {
+            let roles =
+                tx_cache.with_account(&address, |account| account.esdt.get_roles(token_identifier));
+            if roles.is_empty() {
+                None
+            } else {
+                Some((address, roles))
+            }
+        })
+        .collect()
+}
+
+fn addresses_with_role(tx_cache: &TxCache, token_identifier: &[u8], role: &[u8]) -> Vec<VMAddress> {
+    addresses_and_roles(tx_cache, token_identifier)
+        .into_iter()
+        .filter(|(_, roles)| roles.iter().any(|r| r.as_slice() == role))
+        .map(|(address, _)| address)
+        .collect()
+}
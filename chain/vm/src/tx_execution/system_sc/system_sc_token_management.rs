@@ -0,0 +1,166 @@
+use num_bigint::BigUint;
+
+use crate::{
+    tx_mock::{TxCache, TxInput, TxResult},
+    types::{VMAddress, VMTokenType},
+    world_mock::EsdtInstanceMetadata,
+};
+
+use super::{check_num_args, load_token_info_as_owner, require, system_sc_error, SystemSCResult};
+
+/// Mints more fungible tokens, to the caller or to the optional destination argument.
+pub fn mint(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 2)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let amount = BigUint::from_bytes_be(tx_input.args[1].as_slice());
+    let destination = if let Some(destination_arg) = tx_input.args.get(2) {
+        VMAddress::from_slice(destination_arg.as_slice())
+    } else {
+        tx_input.from.clone()
+    };
+
+    let mut token_info = load_token_info_as_owner(&tx_input, &tx_cache, token_identifier)?;
+    require(token_info.can_mint, "token is not mintable")?;
+    require(
+        token_info.token_type == VMTokenType::Fungible,
+        "mint is only allowed for fungible tokens",
+    )?;
+
+    tx_cache.increase_esdt_balance(
+        &destination,
+        token_identifier,
+        0,
+        &amount,
+        EsdtInstanceMetadata::default(),
+    );
+    token_info.minted_value += amount;
+    tx_cache.set_esdt_token_info(token_identifier, token_info);
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
+}
+
+/// Burns fungible tokens from the caller's balance.
+pub fn esdt_burn(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 2)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let amount = BigUint::from_bytes_be(tx_input.args[1].as_slice());
+
+    let mut token_info = tx_cache
+        .get_esdt_token_info(token_identifier)
+        .ok_or_else(|| system_sc_error("no ticker with given name"))?;
+    require(
+        token_info.can_burn || token_info.burn_role_globally,
+        "token is not burnable",
+    )?;
+
+    tx_cache
+        .subtract_esdt_balance(&tx_input.from, token_identifier, 0, &amount)
+        .map_err(|err| Box::new(TxResult::from_panic_obj(&err)))?;
+    token_info.burnt_value += amount;
+    tx_cache.set_esdt_token_info(token_identifier, token_info);
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
+}
+
+pub fn pause(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 1)?;
+    let token_identifier = tx_input.args[0].as_slice();
+
+    let mut token_info = load_token_info_as_owner(&tx_input, &tx_cache, token_identifier)?;
+    require(token_info.can_pause, "cannot pause/un-pause")?;
+    require(
+        !token_info.paused,
+        "cannot pause an already paused contract",
+    )?;
+    token_info.paused = true;
+    tx_cache.set_esdt_token_info(token_identifier, token_info);
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
+}
+
+pub fn unpause(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 1)?;
+    let token_identifier = tx_input.args[0].as_slice();
+
+    let mut token_info = load_token_info_as_owner(&tx_input, &tx_cache, token_identifier)?;
+    require(token_info.can_pause, "cannot pause/un-pause")?;
+    require(
+        token_info.paused,
+        "cannot unPause an already un-paused contract",
+    )?;
+    token_info.paused = false;
+    tx_cache.set_esdt_token_info(token_identifier, token_info);
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
+}
+
+pub fn transfer_ownership(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 2)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let new_owner = VMAddress::from_slice(tx_input.args[1].as_slice());
+
+    let mut token_info = load_token_info_as_owner(&tx_input, &tx_cache, token_identifier)?;
+    require(
+        token_info.can_change_owner,
+        "cannot change owner of the token",
+    )?;
+    token_info.owner = new_owner;
+    tx_cache.set_esdt_token_info(token_identifier, token_info);
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
+}
+
+/// Changes token properties, given as name/value pairs, e.g. `canFreeze@true`.
+pub fn control_changes(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 3)?;
+    let token_identifier = tx_input.args[0].as_slice();
+
+    let mut token_info = load_token_info_as_owner(&tx_input, &tx_cache, token_identifier)?;
+    require(token_info.can_upgrade, "token is not upgradable")?;
+    for property in tx_input.args[1..].chunks(2) {
+        let [name, value] = property else {
+            return Err(system_sc_error("odd number of token property arguments"));
+        };
+        require(
+            token_info.set_property(name, value),
+            "invalid token property argument",
+        )?;
+    }
+    tx_cache.set_esdt_token_info(token_identifier, token_info);
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
+}
+
+pub fn change_to_multi_shard_create(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 1)?;
+    let token_identifier = tx_input.args[0].as_slice();
+
+    let mut token_info = load_token_info_as_owner(&tx_input, &tx_cache, token_identifier)?;
+    require(
+        token_info.token_type != VMTokenType::Fungible,
+        "invalid function call for fungible tokens",
+    )?;
+    require(
+        !token_info.can_create_multi_shard,
+        "it is already multi shard create",
+    )?;
+    token_info.can_create_multi_shard = true;
+    tx_cache.set_esdt_token_info(token_identifier, token_info);
+
+    Ok((TxResult::empty(), tx_cache.into_blockchain_updates()))
+}
+
+pub fn get_token_properties(tx_input: TxInput, tx_cache: TxCache) -> SystemSCResult {
+    check_num_args(&tx_input, 1)?;
+    let token_identifier = tx_input.args[0].as_slice();
+
+    let token_info = tx_cache
+        .get_esdt_token_info(token_identifier)
+        .ok_or_else(|| system_sc_error("no ticker with given name"))?;
+
+    let tx_result = TxResult {
+        result_values: token_info.properties_as_results(),
+        ..Default::default()
+    };
+    Ok((tx_result, tx_cache.into_blockchain_updates()))
+}
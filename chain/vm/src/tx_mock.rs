@@ -10,6 +10,7 @@ mod tx_cache_source;
 mod tx_context;
 mod tx_context_ref;
 mod tx_context_stack;
+mod tx_hash;
 mod tx_input;
 mod tx_input_call_type;
 mod tx_input_function;
@@ -28,6 +29,7 @@ pub use tx_cache_source::*;
 pub use tx_context::*;
 pub use tx_context_ref::*;
 pub use tx_context_stack::*;
+pub use tx_hash::*;
 pub use tx_input::*;
 pub use tx_input_call_type::CallType;
 pub use tx_input_function::*;
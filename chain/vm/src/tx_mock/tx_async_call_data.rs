@@ -6,7 +6,7 @@ use crate::{
 
 use num_bigint::BigUint;
 
-use super::{CallType, CallbackPayments, Promise, TxFunctionName};
+use super::{derive_child_tx_hash, CallType, CallbackPayments, Promise, TxFunctionName};
 
 #[derive(Debug, Clone)]
 pub struct AsyncCallTxData {
@@ -15,7 +15,23 @@ pub struct AsyncCallTxData {
     pub call_value: BigUint,
     pub endpoint_name: TxFunctionName,
     pub arguments: Vec<Vec<u8>>,
+    pub gas_limit: u64,
     pub tx_hash: H256,
+    pub original_tx_hash: H256,
+    pub prev_tx_hash: H256,
+    pub original_caller: VMAddress,
+    pub relayer: Option<VMAddress>,
+}
+
+impl AsyncCallTxData {
+    /// Gives the call its own hash, as a smart contract result produced by the current transaction.
+    ///
+    /// Synchronous calls keep the hashes of their caller.
+    pub fn into_smart_contract_result(mut self, index: usize) -> Self {
+        self.prev_tx_hash = self.tx_hash.clone();
+        self.tx_hash = derive_child_tx_hash(&self.prev_tx_hash, &self.from, &self.to, index);
+        self
+    }
 }
 
 pub fn async_call_tx_input(async_call: &AsyncCallTxData, call_type: CallType) -> TxInput {
@@ -27,9 +43,13 @@ pub fn async_call_tx_input(async_call: &AsyncCallTxData, call_type: CallType) ->
         func_name: async_call.endpoint_name.clone(),
         args: async_call.arguments.clone(),
         call_type,
-        gas_limit: 1000,
+        gas_limit: async_call.gas_limit,
         gas_price: 0,
         tx_hash: async_call.tx_hash.clone(),
+        original_tx_hash: Some(async_call.original_tx_hash.clone()),
+        prev_tx_hash: Some(async_call.prev_tx_hash.clone()),
+        original_caller: Some(async_call.original_caller.clone()),
+        relayer: async_call.relayer.clone(),
         ..Default::default()
     }
 }
@@ -72,10 +92,20 @@ pub fn async_callback_tx_input(
         func_name: TxFunctionName::CALLBACK,
         args,
         call_type: CallType::AsyncCallback,
-        gas_limit: 1000,
+        gas_limit: async_data.gas_limit.saturating_sub(async_result.gas_used),
         gas_price: 0,
-        tx_hash: async_data.tx_hash.clone(),
+        // the callback comes after all the calls made by the callee
+        tx_hash: derive_child_tx_hash(
+            &async_data.tx_hash,
+            &async_data.to,
+            &async_data.from,
+            async_result.all_calls.len(),
+        ),
+        original_tx_hash: Some(async_data.original_tx_hash.clone()),
+        prev_tx_hash: Some(async_data.tx_hash.clone()),
         callback_payments,
+        original_caller: Some(async_data.original_caller.clone()),
+        relayer: async_data.relayer.clone(),
         ..Default::default()
     }
 }
@@ -117,17 +147,21 @@ pub fn async_promise_callback_tx_input(
     let mut callback_input =
         async_callback_tx_input(&promise.call, async_result, builtin_functions);
     callback_input.func_name = callback_name;
+    callback_input.gas_limit += promise.extra_gas_for_callback;
     callback_input.promise_callback_closure_data = Some(promise.callback_closure_data.clone());
     callback_input
 }
 
 pub fn merge_results(mut original: TxResult, mut new: TxResult) -> TxResult {
-    if original.result_status.is_success() {
+    let gas_used = original.gas_used + new.gas_used;
+    let mut merged = if original.result_status.is_success() {
         original.result_values.append(&mut new.result_values);
         original.result_logs.append(&mut new.result_logs);
         original.result_message = new.result_message;
         original
     } else {
         new
-    }
+    };
+    merged.gas_used = gas_used;
+    merged
 }
@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt,
     sync::{Arc, Mutex},
 };
@@ -9,7 +9,7 @@ use colored::Colorize;
 use crate::{
     display_util::address_hex,
     types::VMAddress,
-    world_mock::{AccountData, BlockchainState},
+    world_mock::{AccountData, BlockchainState, EsdtTokenInfo},
 };
 
 use super::{BlockchainUpdate, TxCacheSource};
@@ -18,6 +18,8 @@ pub struct TxCache {
     source_ref: Arc<dyn TxCacheSource>,
     pub(super) accounts: Mutex<HashMap<VMAddress, AccountData>>,
     pub(super) new_token_identifiers: Mutex<Option<Vec<String>>>,
+    pub(super) esdt_tokens: Mutex<HashMap<Vec<u8>, EsdtTokenInfo>>,
+    pub(super) deleted_accounts: Mutex<HashSet<VMAddress>>,
 }
 
 impl fmt::Debug for TxCache {
@@ -34,6 +36,8 @@ impl TxCache {
             source_ref,
             accounts: Mutex::new(HashMap::new()),
             new_token_identifiers: Mutex::new(None),
+            esdt_tokens: Mutex::new(HashMap::new()),
+            deleted_accounts: Mutex::new(HashSet::new()),
         }
     }
 
@@ -42,6 +46,10 @@ impl TxCache {
     }
 
     fn load_account_if_necessary(&self, address: &VMAddress) {
+        if self.deleted_accounts.lock().unwrap().contains(address) {
+            return;
+        }
+
         let mut accounts_mut = self.accounts.lock().unwrap();
         if !accounts_mut.contains_key(address) {
             if let Some(blockchain_account) = self.source_ref.load_account(address) {
@@ -85,13 +93,33 @@ impl TxCache {
         f(account)
     }
 
+    /// The code of a smart contract, `None` if the account is missing or is not a smart contract.
+    ///
+    /// Used when copying code, in deploy and upgrade from source.
+    pub fn contract_code(&self, address: &VMAddress) -> Option<Vec<u8>> {
+        self.with_account_or_else(address, |account| account.contract_path.clone(), || None)
+    }
+
     pub fn insert_account(&self, account_data: AccountData) {
+        self.deleted_accounts
+            .lock()
+            .unwrap()
+            .remove(&account_data.address);
         self.accounts
             .lock()
             .unwrap()
             .insert(account_data.address.clone(), account_data);
     }
 
+    /// Removes the account, it will no longer be found, neither in this cache, nor in the source.
+    pub fn delete_account(&self, address: &VMAddress) {
+        self.accounts.lock().unwrap().remove(address);
+        self.deleted_accounts
+            .lock()
+            .unwrap()
+            .insert(address.clone());
+    }
+
     pub fn increase_acount_nonce(&self, address: &VMAddress) {
         self.with_account_mut(address, |account| {
             account.nonce += 1;
@@ -127,14 +155,41 @@ impl TxCache {
         *self.new_token_identifiers.lock().unwrap() = Some(token_identifiers);
     }
 
+    /// Global token data, as kept by the ESDT system SC. Will provide a clone.
+    pub fn get_esdt_token_info(&self, token_identifier: &[u8]) -> Option<EsdtTokenInfo> {
+        if let Some(token_info) = self.esdt_tokens.lock().unwrap().get(token_identifier) {
+            return Some(token_info.clone());
+        }
+        self.source_ref.load_esdt_token_info(token_identifier)
+    }
+
+    pub fn set_esdt_token_info(&self, token_identifier: &[u8], token_info: EsdtTokenInfo) {
+        self.esdt_tokens
+            .lock()
+            .unwrap()
+            .insert(token_identifier.to_vec(), token_info);
+    }
+
+    pub fn is_esdt_paused(&self, token_identifier: &[u8]) -> bool {
+        self.get_esdt_token_info(token_identifier)
+            .map(|token_info| token_info.paused)
+            .unwrap_or_default()
+    }
+
     pub fn into_blockchain_updates(self) -> BlockchainUpdate {
         BlockchainUpdate {
             accounts: self.accounts.into_inner().unwrap(),
             new_token_identifiers: self.new_token_identifiers.into_inner().unwrap(),
+            esdt_tokens: self.esdt_tokens.into_inner().unwrap(),
+            deleted_accounts: self.deleted_accounts.into_inner().unwrap(),
         }
     }
 
     pub fn commit_updates(&self, updates: BlockchainUpdate) {
         self.accounts.lock().unwrap().extend(updates.accounts);
+        self.esdt_tokens.lock().unwrap().extend(updates.esdt_tokens);
+        for address in &updates.deleted_accounts {
+            self.delete_account(address);
+        }
     }
 }
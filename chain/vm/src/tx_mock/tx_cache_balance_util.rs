@@ -115,11 +115,38 @@ impl TxCache {
         }
 
         if !is_system_sc_address(from) && !is_system_sc_address(to) {
+            self.check_esdt_not_paused_or_frozen(from, esdt_token_identifier, nonce)?;
+            self.check_esdt_not_paused_or_frozen(to, esdt_token_identifier, nonce)?;
             let metadata = self.subtract_esdt_balance(from, esdt_token_identifier, nonce, value)?;
             self.increase_esdt_balance(to, esdt_token_identifier, nonce, value, metadata);
         }
         Ok(())
     }
+
+    /// Tokens cannot be moved while paused, or out of/into an account where they are frozen.
+    pub fn check_esdt_not_paused_or_frozen(
+        &self,
+        address: &VMAddress,
+        esdt_token_identifier: &[u8],
+        nonce: u64,
+    ) -> Result<(), TxPanic> {
+        if self.is_esdt_paused(esdt_token_identifier) {
+            return Err(TxPanic::vm_error("esdt token is paused"));
+        }
+
+        let frozen = self.with_account(address, |account| {
+            account
+                .esdt
+                .get_by_identifier(esdt_token_identifier)
+                .map(|esdt_data| esdt_data.is_frozen(nonce))
+                .unwrap_or_default()
+        });
+        if frozen {
+            return Err(TxPanic::vm_error("account is frozen for this esdt"));
+        }
+
+        Ok(())
+    }
 }
 
 fn err_insufficient_funds() -> TxPanic {
@@ -22,6 +22,7 @@ pub struct TxContext {
     pub back_transfers: Mutex<BackTransfers>,
     pub tx_result_cell: Mutex<TxResult>,
     pub b_rng: Mutex<BlockchainRng>,
+    pub gas_used: Mutex<u64>,
 }
 
 impl TxContext {
@@ -35,6 +36,7 @@ impl TxContext {
             back_transfers: Mutex::default(),
             tx_result_cell: Mutex::new(TxResult::empty()),
             b_rng,
+            gas_used: Mutex::new(0),
         }
     }
 
@@ -70,6 +72,7 @@ impl TxContext {
             back_transfers: Mutex::default(),
             tx_result_cell: Mutex::new(TxResult::empty()),
             b_rng,
+            gas_used: Mutex::new(0),
         }
     }
 
@@ -145,6 +148,31 @@ impl TxContext {
         self.b_rng.lock().unwrap()
     }
 
+    /// Charges gas, if the VM has a gas schedule, otherwise does nothing.
+    ///
+    /// Returns false if the gas limit was exceeded. In this case all the gas is considered used.
+    pub fn use_gas(&self, gas: u64) -> bool {
+        if self.vm_ref.gas_schedule.is_none() {
+            return true;
+        }
+
+        let gas_limit = self.input_ref().gas_limit;
+        let mut gas_used = self.gas_used.lock().unwrap();
+        let new_gas_used = gas_used.saturating_add(gas);
+        if new_gas_used > gas_limit {
+            *gas_used = gas_limit;
+            return false;
+        }
+        *gas_used = new_gas_used;
+        true
+    }
+
+    pub fn gas_left(&self) -> u64 {
+        self.input_ref()
+            .gas_limit
+            .saturating_sub(*self.gas_used.lock().unwrap())
+    }
+
     pub fn create_new_contract(
         &self,
         new_address: &VMAddress,
@@ -178,7 +206,8 @@ impl TxContext {
 
     pub fn into_results(self) -> (TxResult, BlockchainUpdate) {
         let tx_cache = Arc::try_unwrap(self.tx_cache).unwrap();
-        let tx_result = Mutex::into_inner(self.tx_result_cell).unwrap();
+        let mut tx_result = Mutex::into_inner(self.tx_result_cell).unwrap();
+        tx_result.gas_used = Mutex::into_inner(self.gas_used).unwrap();
         let blockchain_updates = tx_cache.into_blockchain_updates();
         (tx_result, blockchain_updates)
     }
@@ -192,6 +221,7 @@ impl std::fmt::Debug for TxContext {
             .field("managed_types", &self.managed_types)
             .field("tx_result_cell", &self.tx_result_cell)
             .field("b_rng", &self.b_rng)
+            .field("gas_used", &self.gas_used)
             .finish()
     }
 }
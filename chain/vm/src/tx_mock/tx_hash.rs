@@ -0,0 +1,73 @@
+use crate::{
+    crypto_functions::keccak256,
+    types::{VMAddress, H256},
+};
+
+use super::TxInput;
+
+/// Keccak256 over a list of fields, each prefixed by its length,
+/// so that no two different field lists produce the same preimage.
+pub(crate) fn hash_fields<'a, I>(fields: I) -> H256
+where
+    I: IntoIterator<Item = &'a [u8]>,
+{
+    let mut preimage = Vec::new();
+    for field in fields {
+        preimage.extend_from_slice(&(field.len() as u32).to_be_bytes());
+        preimage.extend_from_slice(field);
+    }
+    H256::from(keccak256(&preimage))
+}
+
+/// Hash of a transaction sent by a user.
+///
+/// Only depends on the transaction contents and on the sender nonce,
+/// so replaying the same steps always yields the same hashes.
+pub fn generate_user_tx_hash(tx_input: &TxInput, sender_nonce: u64) -> H256 {
+    let nonce_bytes = sender_nonce.to_be_bytes();
+    let egld_value_bytes = tx_input.egld_value.to_bytes_be();
+    let gas_limit_bytes = tx_input.gas_limit.to_be_bytes();
+    let gas_price_bytes = tx_input.gas_price.to_be_bytes();
+    let esdt_bytes: Vec<Vec<u8>> = tx_input
+        .esdt_values
+        .iter()
+        .flat_map(|esdt| {
+            [
+                esdt.token_identifier.clone(),
+                esdt.nonce.to_be_bytes().to_vec(),
+                esdt.value.to_bytes_be(),
+            ]
+        })
+        .collect();
+
+    let mut fields: Vec<&[u8]> = vec![
+        tx_input.from.as_bytes(),
+        &nonce_bytes,
+        tx_input.to.as_bytes(),
+        &egld_value_bytes,
+        &gas_limit_bytes,
+        &gas_price_bytes,
+        tx_input.func_name.as_str().as_bytes(),
+    ];
+    fields.extend(tx_input.args.iter().map(Vec::as_slice));
+    fields.extend(esdt_bytes.iter().map(Vec::as_slice));
+    hash_fields(fields)
+}
+
+/// Hash of a smart contract result (async call, callback, transfer & execute),
+/// derived from the hash of the transaction that produced it.
+///
+/// The index tells apart several results sent by the same contract, to the same recipient.
+pub fn derive_child_tx_hash(
+    parent_tx_hash: &H256,
+    from: &VMAddress,
+    to: &VMAddress,
+    index: usize,
+) -> H256 {
+    hash_fields([
+        parent_tx_hash.as_bytes(),
+        from.as_bytes(),
+        to.as_bytes(),
+        &(index as u64).to_be_bytes(),
+    ])
+}
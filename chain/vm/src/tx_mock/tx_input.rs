@@ -20,9 +20,33 @@ pub struct TxInput {
     pub call_type: CallType,
     pub gas_limit: u64,
     pub gas_price: u64,
+    /// Hash of the current transaction or smart contract result.
     pub tx_hash: H256,
+
+    /// Hash of the user transaction that started the call chain, if different from `tx_hash`.
+    pub original_tx_hash: Option<H256>,
+
+    /// Hash of the transaction or smart contract result that produced the current one, if any.
+    pub prev_tx_hash: Option<H256>,
+
     pub promise_callback_closure_data: Option<Vec<u8>>,
     pub callback_payments: CallbackPayments,
+
+    /// The sender of the transaction that started the call chain, if different from `from`.
+    pub original_caller: Option<VMAddress>,
+
+    /// Set for relayed transactions (v3). The relayer pays for the gas.
+    pub relayer: Option<VMAddress>,
+
+    /// Set for calls launched via execute on same context.
+    ///
+    /// The code of this account runs on the storage and balances of `to`.
+    pub code_address: Option<VMAddress>,
+
+    /// Set for calls launched via execute read-only, and for all calls nested in them.
+    ///
+    /// No state changes are allowed: storage writes, transfers and logs all fail.
+    pub readonly: bool,
 }
 
 impl Default for TxInput {
@@ -38,8 +62,14 @@ impl Default for TxInput {
             gas_limit: 0,
             gas_price: 0,
             tx_hash: H256::zero(),
+            original_tx_hash: None,
+            prev_tx_hash: None,
             promise_callback_closure_data: None,
             callback_payments: Default::default(),
+            original_caller: None,
+            relayer: None,
+            code_address: None,
+            readonly: false,
         }
     }
 }
@@ -61,6 +91,30 @@ impl TxInput {
         self.args.push(arg);
     }
 
+    pub fn original_caller(&self) -> &VMAddress {
+        self.original_caller.as_ref().unwrap_or(&self.from)
+    }
+
+    /// For user transactions, it is the transaction hash itself.
+    pub fn original_tx_hash(&self) -> &H256 {
+        self.original_tx_hash.as_ref().unwrap_or(&self.tx_hash)
+    }
+
+    /// Also the transaction hash itself, for user transactions.
+    pub fn prev_tx_hash(&self) -> &H256 {
+        self.prev_tx_hash.as_ref().unwrap_or(&self.tx_hash)
+    }
+
+    /// The account whose code gets executed. Only differs from `to` when executing on the same context.
+    pub fn code_address(&self) -> &VMAddress {
+        self.code_address.as_ref().unwrap_or(&self.to)
+    }
+
+    /// The account that pays for the gas: the relayer, if present, otherwise the sender.
+    pub fn gas_payer(&self) -> &VMAddress {
+        self.relayer.as_ref().unwrap_or(&self.from)
+    }
+
     pub fn func_name_from_arg_index(&self, arg_index: usize) -> TxFunctionName {
         if let Some(arg) = self.args.get(arg_index) {
             arg.into()
@@ -2,6 +2,7 @@
 pub enum CallType {
     DirectCall,
     ExecuteOnDestContext,
+    ExecuteOnSameContext,
     AsyncCall,
     AsyncCallback,
     TransferExecute,
@@ -18,6 +19,7 @@ impl CallType {
         match self {
             Self::DirectCall => "DirectCall",
             Self::ExecuteOnDestContext => "ExecuteOnDestContext",
+            Self::ExecuteOnSameContext => "ExecuteOnSameContext",
             Self::AsyncCall => "AsyncCall",
             Self::AsyncCallback => "AsyncCallback",
             Self::TransferExecute => "TransferAndExecute",
@@ -1,14 +1,21 @@
+mod big_float_gob;
 mod handle_map;
 mod tx_big_float;
 mod tx_big_int;
+mod tx_elliptic_curve;
 mod tx_managed_buffer;
 mod tx_managed_map;
 
+pub use big_float_gob::{
+    big_float_from_gob_bytes, big_float_to_gob_bytes, DecodedBigFloat, BIG_FLOAT_PRECISION,
+};
 pub use handle_map::HandleMap;
+
+use crate::{crypto_functions::EllipticCurve, types::RawHandle};
 use num_bigint::BigInt;
 pub use tx_big_int::big_int_to_i64;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 pub(crate) type ManagedBufferImpl = Vec<u8>;
 pub(crate) type ManagedMapImpl = HashMap<Vec<u8>, Vec<u8>>;
@@ -17,8 +24,11 @@ pub(crate) type ManagedMapImpl = HashMap<Vec<u8>, Vec<u8>>;
 pub struct TxManagedTypes {
     pub(crate) big_int_map: HandleMap<BigInt>,
     pub(crate) big_float_map: HandleMap<f64>,
+    /// Big floats decoded from a mantissa that is not normalized, see [`DecodedBigFloat`].
+    pub(crate) big_float_not_normalized: HashSet<RawHandle>,
     pub(crate) managed_buffer_map: HandleMap<ManagedBufferImpl>,
     pub(crate) managed_map_map: HandleMap<ManagedMapImpl>,
+    pub(crate) ec_map: HandleMap<EllipticCurve>,
 }
 
 impl TxManagedTypes {
@@ -26,8 +36,10 @@ impl TxManagedTypes {
         TxManagedTypes {
             big_int_map: HandleMap::new(),
             big_float_map: HandleMap::new(),
+            big_float_not_normalized: HashSet::new(),
             managed_buffer_map: HandleMap::new(),
             managed_map_map: HandleMap::new(),
+            ec_map: HandleMap::new(),
         }
     }
 }
@@ -0,0 +1,221 @@
+//! Serialization of big floats, compatible with the Go VM.
+//!
+//! The Go VM holds big floats as `big.Float` values with 53 bits of precision and serializes them
+//! with `big.Float.GobEncode`. The Rust VM holds them as `f64`, which has exactly the same
+//! precision, so all values in the `f64` range can be converted without loss.
+//!
+//! Format:
+//! - 1 byte: version, always 1;
+//! - 1 byte: rounding mode (3 bits), accuracy + 1 (2 bits), form (2 bits), sign (1 bit);
+//! - 4 bytes: precision, big endian;
+//! - only for finite non-zero values:
+//!     - 4 bytes: binary exponent, big endian, signed;
+//!     - mantissa words, big endian, normalized so that the most significant bit is set.
+//!
+//! The value of a finite number is `0.mantissa * 2^exponent`.
+//!
+//! The Go VM does not check that the mantissa is normalized when decoding,
+//! only the arithmetic operations on such values fail.
+
+use crate::vm_err_msg;
+
+const GOB_VERSION: u8 = 1;
+
+/// The only precision accepted by the Go VM, also the precision of `f64`.
+pub const BIG_FLOAT_PRECISION: u32 = 53;
+
+const FORM_ZERO: u8 = 0;
+const FORM_FINITE: u8 = 1;
+const FORM_INF: u8 = 2;
+
+/// `big.Accuracy` is serialized as `accuracy + 1`.
+/// The accuracy of the last operation is not tracked in the Rust VM, so it is always `Exact`.
+const ACCURACY_EXACT: u8 = 1;
+
+const HEADER_LEN: usize = 6;
+const FINITE_HEADER_LEN: usize = 10;
+
+/// Smallest exponent of a non-zero `f64`, subnormals included.
+const MIN_EXPONENT: i32 = -1073;
+
+/// Largest exponent of a finite `f64`.
+const MAX_EXPONENT: i32 = 1024;
+
+/// Serializes the same way the Go VM does, byte for byte.
+///
+/// The rounding mode is always `ToNearestEven`.
+pub fn big_float_to_gob_bytes(value: f64) -> Result<Vec<u8>, &'static str> {
+    if value.is_nan() {
+        return Err(vm_err_msg::NUMBER_IS_NOT_NORMAL);
+    }
+
+    let form = if value == 0.0 {
+        FORM_ZERO
+    } else if value.is_infinite() {
+        FORM_INF
+    } else {
+        FORM_FINITE
+    };
+    let flags = (ACCURACY_EXACT << 3) | (form << 1) | value.is_sign_negative() as u8;
+
+    let mut bytes = Vec::with_capacity(FINITE_HEADER_LEN + 8);
+    bytes.push(GOB_VERSION);
+    bytes.push(flags);
+    bytes.extend_from_slice(&BIG_FLOAT_PRECISION.to_be_bytes());
+
+    if form == FORM_FINITE {
+        let (mantissa, exponent) = normalized_parts(value);
+        bytes.extend_from_slice(&exponent.to_be_bytes());
+        bytes.extend_from_slice(&mantissa.to_be_bytes());
+    }
+
+    Ok(bytes)
+}
+
+/// Splits a finite non-zero value into a mantissa with the most significant bit set
+/// and an exponent, such that `|value| = mantissa * 2^(exponent - 64)`.
+fn normalized_parts(value: f64) -> (u64, i32) {
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    let (significand, exponent) = if biased_exponent == 0 {
+        // subnormal
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    };
+
+    let shift = significand.leading_zeros();
+    (significand << shift, exponent + 64 - shift as i32)
+}
+
+/// A big float, as deserialized from the Go VM format.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DecodedBigFloat {
+    pub value: f64,
+    /// False if the mantissa does not have its most significant bit set.
+    ///
+    /// The value is still well defined, but the Go VM refuses to do arithmetic on it.
+    pub normalized: bool,
+}
+
+impl From<f64> for DecodedBigFloat {
+    fn from(value: f64) -> Self {
+        DecodedBigFloat {
+            value,
+            normalized: true,
+        }
+    }
+}
+
+/// Deserializes the Go VM format.
+///
+/// Same as in the Go VM, the precision must be 53, infinity is not allowed,
+/// and an empty buffer is zero. Additionally, the exponent must be in the `f64` range.
+pub fn big_float_from_gob_bytes(bytes: &[u8]) -> Result<DecodedBigFloat, &'static str> {
+    if bytes.is_empty() {
+        return Ok(0.0.into());
+    }
+    if bytes.len() < HEADER_LEN {
+        return Err(vm_err_msg::BIG_FLOAT_DECODE_BUFFER_TOO_SMALL);
+    }
+    if bytes[0] != GOB_VERSION {
+        return Err(vm_err_msg::BIG_FLOAT_DECODE_VERSION_NOT_SUPPORTED);
+    }
+
+    let flags = bytes[1];
+    let form = (flags >> 1) & 3;
+    let negative = flags & 1 != 0;
+    let precision = u32::from_be_bytes(bytes[2..HEADER_LEN].try_into().unwrap());
+    if precision != BIG_FLOAT_PRECISION {
+        return Err(vm_err_msg::BIG_FLOAT_WRONG_PRECISION);
+    }
+
+    let (magnitude, normalized) = match form {
+        FORM_ZERO => (0.0, true),
+        FORM_FINITE => finite_magnitude(bytes)?,
+        _ => return Err(vm_err_msg::BIG_FLOAT_INFINITY),
+    };
+
+    Ok(DecodedBigFloat {
+        value: if negative { -magnitude } else { magnitude },
+        normalized,
+    })
+}
+
+fn finite_magnitude(bytes: &[u8]) -> Result<(f64, bool), &'static str> {
+    if bytes.len() < FINITE_HEADER_LEN {
+        return Err(vm_err_msg::BIG_FLOAT_DECODE_BUFFER_TOO_SMALL);
+    }
+    let exponent = i32::from_be_bytes(bytes[HEADER_LEN..FINITE_HEADER_LEN].try_into().unwrap());
+    if !(MIN_EXPONENT..=MAX_EXPONENT).contains(&exponent) {
+        return Err(vm_err_msg::EXPONENT_TOO_BIG_OR_TOO_SMALL);
+    }
+
+    // the Go VM reads the mantissa as a big endian integer, so a partial word is padded on the left
+    let mantissa_bytes = &bytes[FINITE_HEADER_LEN..];
+    let padding = (8 - mantissa_bytes.len() % 8) % 8;
+    let normalized = !mantissa_bytes.is_empty() && padding == 0 && mantissa_bytes[0] & 0x80 != 0;
+
+    let Some(leading_zeros) = leading_zero_bits(mantissa_bytes) else {
+        return Ok((0.0, normalized));
+    };
+    let exponent = exponent - (padding * 8 + leading_zeros) as i32;
+    if exponent < MIN_EXPONENT - 1 {
+        // below half of the smallest subnormal, rounds to 0
+        return Ok((0.0, normalized));
+    }
+
+    let mut mantissa = read_bits_u64(mantissa_bytes, leading_zeros);
+    if has_bits_after(mantissa_bytes, leading_zeros + 64) {
+        // sticky bit, it lies well below the 53 bits kept, so it only influences rounding
+        mantissa |= 1;
+    }
+
+    // rounds to nearest even, same as the Go VM when storing with precision 53
+    let fraction = mantissa as f64 / pow2(64);
+    let magnitude = mul_pow2(fraction, exponent);
+    if magnitude.is_infinite() {
+        // only happens when the mantissa rounds up at the largest exponent
+        return Err(vm_err_msg::EXPONENT_TOO_BIG_OR_TOO_SMALL);
+    }
+    Ok((magnitude, normalized))
+}
+
+fn bit_at(bytes: &[u8], index: usize) -> bool {
+    bytes
+        .get(index / 8)
+        .is_some_and(|byte| byte & (0x80 >> (index % 8)) != 0)
+}
+
+/// `None` if all bits are 0.
+fn leading_zero_bits(bytes: &[u8]) -> Option<usize> {
+    let first_non_zero = bytes.iter().position(|&b| b != 0)?;
+    Some(first_non_zero * 8 + bytes[first_non_zero].leading_zeros() as usize)
+}
+
+/// The 64 bits starting at the given bit index, padded with 0 on the right.
+fn read_bits_u64(bytes: &[u8], start: usize) -> u64 {
+    (0..64).fold(0u64, |acc, i| (acc << 1) | bit_at(bytes, start + i) as u64)
+}
+
+fn has_bits_after(bytes: &[u8], start: usize) -> bool {
+    (start..bytes.len() * 8).any(|index| bit_at(bytes, index))
+}
+
+/// Exact power of 2, for exponents in the normal `f64` range.
+fn pow2(exp: i32) -> f64 {
+    f64::from_bits(((exp + 1023) as u64) << 52)
+}
+
+/// Computes `x * 2^exp`, for `x` in `[0.5, 1]` and `exp >= -2044`, rounding at most once.
+fn mul_pow2(mut x: f64, mut exp: i32) -> f64 {
+    if exp < -1022 {
+        x *= pow2(exp + 1022);
+        exp = -1022;
+    } else if exp > 1023 {
+        x *= pow2(exp - 1023);
+        exp = 1023;
+    }
+    x * pow2(exp)
+}
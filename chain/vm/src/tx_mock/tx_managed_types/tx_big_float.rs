@@ -1,6 +1,6 @@
 use crate::types::RawHandle;
 
-use super::TxManagedTypes;
+use super::{big_float_from_gob_bytes, big_float_to_gob_bytes, TxManagedTypes};
 
 impl TxManagedTypes {
     pub fn bf_get_f64(&self, handle: RawHandle) -> f64 {
@@ -9,5 +9,37 @@ impl TxManagedTypes {
 
     pub fn bf_overwrite(&mut self, handle: RawHandle, value: f64) {
         self.big_float_map.insert(handle, value);
+        self.big_float_not_normalized.remove(&handle);
+    }
+
+    /// Always true, except for values decoded from a mantissa that is not normalized,
+    /// and for the values copied from them without any arithmetic.
+    pub fn bf_is_normalized(&self, handle: RawHandle) -> bool {
+        !self.big_float_not_normalized.contains(&handle)
+    }
+
+    pub fn bf_set_normalized(&mut self, handle: RawHandle, normalized: bool) {
+        if normalized {
+            self.big_float_not_normalized.remove(&handle);
+        } else {
+            self.big_float_not_normalized.insert(handle);
+        }
+    }
+
+    /// Serialized the same way as in the Go VM.
+    pub fn bf_get_gob_bytes(&self, handle: RawHandle) -> Result<Vec<u8>, &'static str> {
+        big_float_to_gob_bytes(self.bf_get_f64(handle))
+    }
+
+    /// Deserializes the Go VM format. Leaves the value unchanged on error.
+    pub fn bf_set_gob_bytes(
+        &mut self,
+        handle: RawHandle,
+        bytes: &[u8],
+    ) -> Result<(), &'static str> {
+        let decoded = big_float_from_gob_bytes(bytes)?;
+        self.bf_overwrite(handle, decoded.value);
+        self.bf_set_normalized(handle, decoded.normalized);
+        Ok(())
     }
 }
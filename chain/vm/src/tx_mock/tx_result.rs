@@ -2,6 +2,8 @@ use std::fmt;
 
 use multiversx_chain_core::types::ReturnCode;
 
+use crate::vm_err_msg;
+
 use super::{AsyncCallTxData, TxLog, TxPanic, TxResultCalls};
 
 #[derive(Clone, Debug)]
@@ -21,6 +23,11 @@ pub struct TxResult {
     ///
     /// Is never cleared of its contents.
     pub all_calls: Vec<AsyncCallTxData>,
+
+    /// Gas consumed, including the gas of all the calls launched from the tx.
+    ///
+    /// Always 0 when the VM has no gas schedule.
+    pub gas_used: u64,
 }
 
 impl Default for TxResult {
@@ -32,6 +39,7 @@ impl Default for TxResult {
             result_logs: Vec::new(),
             pending_calls: TxResultCalls::empty(),
             all_calls: Vec::new(),
+            gas_used: 0,
         }
     }
 }
@@ -80,6 +88,15 @@ impl TxResult {
         }
     }
 
+    pub fn from_out_of_gas(gas_limit: u64) -> Self {
+        TxResult {
+            result_status: ReturnCode::OutOfGas,
+            result_message: vm_err_msg::OUT_OF_GAS.to_string(),
+            gas_used: gas_limit,
+            ..Default::default()
+        }
+    }
+
     pub fn merge_after_sync_call(&mut self, sync_call_result: &TxResult) {
         self.result_values
             .extend_from_slice(sync_call_result.result_values.as_slice());
@@ -9,6 +9,28 @@ pub const EXPONENT_IS_POSITIVE: &str = "exponent must be negative";
 pub const NUMBER_IS_NOT_NORMAL: &str =
     "number is not normal. It is either infinite, NaN or subnormal";
 pub const CANNOT_COMPARE_VALUES: &str = "values are not comparable";
+pub const EXPONENT_TOO_BIG_OR_TOO_SMALL: &str = "exponent is either too small or too big";
+pub const BIG_FLOAT_WRONG_PRECISION: &str = "precision of the big float must be 53";
+pub const BIG_FLOAT_INFINITY: &str = "infinity operations are not allowed";
+pub const BIG_FLOAT_DECODE_BUFFER_TOO_SMALL: &str = "Float.GobDecode: buffer too small";
+pub const BIG_FLOAT_DECODE_VERSION_NOT_SUPPORTED: &str =
+    "Float.GobDecode: encoding version not supported";
+pub const BIG_FLOAT_ADD_NOT_PERMITTED: &str =
+    "this big Float operation is not permitted while doing float.Add";
+pub const BIG_FLOAT_SUB_NOT_PERMITTED: &str =
+    "this big Float operation is not permitted while doing float.Sub";
+pub const BIG_FLOAT_MUL_NOT_PERMITTED: &str =
+    "this big Float operation is not permitted while doing float.Mul";
+pub const BIG_FLOAT_QUO_NOT_PERMITTED: &str =
+    "this big Float operation is not permitted while doing float.Quo";
+pub const BIG_FLOAT_SQRT_NOT_PERMITTED: &str =
+    "this big Float operation is not permitted while doing float.Sqrt";
+
+pub const EC_CURVE_NOT_SUPPORTED: &str = "elliptic curve not supported";
+pub const EC_POINT_NOT_ON_CURVE: &str = "point is not on curve";
+pub const EC_BUFFER_LENGTH_NOT_CORRECT: &str = "length of buffer is not correct";
+
+pub const SIGNATURE_IS_INVALID: &str = "signature is invalid";
 
 pub const ERROR_SIGNALLED_BY_SMARTCONTRACT: &str = "error signalled by smartcontract";
 
@@ -16,3 +38,9 @@ pub const ERROR_NO_CALLBACK_CLOSURE: &str =
     "no callback for closure, cannot call callback directly";
 
 pub const PROMISES_TOKENIZE_FAILED: &str = "tokenize failed";
+
+pub const OUT_OF_GAS: &str = "out of gas";
+
+pub const CONTRACT_NOT_FOUND: &str = "contract not found";
+pub const CANNOT_WRITE_ON_READ_ONLY: &str = "cannot write on read only mode";
+pub const INVALID_CALL_ON_READ_ONLY_MODE: &str = "operation not permitted in read only mode";
@@ -2,7 +2,11 @@ use std::ffi::c_void;
 
 use multiversx_chain_vm_executor::{MemLength, MemPtr, VMHooks};
 
-use crate::mem_conv;
+use crate::{
+    crypto_functions::{self, BLS_PUBLIC_KEY_LEN, BLS_SIGNATURE_LEN},
+    mem_conv,
+    types::{VMAddress, VMCodeMetadata, H256},
+};
 
 use super::VMHooksHandler;
 
@@ -26,11 +30,82 @@ fn bool_to_i32(b: bool) -> i32 {
     }
 }
 
+/// Copies a hash into contract memory.
+///
+/// # Safety
+///
+/// The offset must point to at least 32 writable bytes.
+unsafe fn copy_hash_to_mem(offset: MemPtr, hash: &H256) {
+    mem_conv::with_bytes_mut(offset, H256::len_bytes() as MemLength, |dest| {
+        dest.copy_from_slice(hash.as_bytes());
+    });
+}
+
+/// Verifies a DER encoded secp256k1 signature, returns 0 if valid, -1 otherwise.
+///
+/// The signature length is not given, it is read from the DER header, same as in the Go VM.
+///
+/// # Safety
+///
+/// The offsets must point to the key, the message and a complete DER signature.
+unsafe fn verify_secp256k1_in_mem(
+    key_offset: MemPtr,
+    key_length: MemLength,
+    message_offset: MemPtr,
+    message_length: MemLength,
+    sig_offset: MemPtr,
+    hash_type: u8,
+) -> i32 {
+    let sig_length = mem_conv::with_bytes(sig_offset + 1, 1, |length| length[0] as MemLength + 2);
+    let sig_valid = mem_conv::with_bytes(key_offset, key_length, |key| {
+        mem_conv::with_bytes(message_offset, message_length, |message| {
+            mem_conv::with_bytes(sig_offset, sig_length, |sig| {
+                crypto_functions::verify_secp256k1(key, message, sig, hash_type)
+            })
+        })
+    });
+    if sig_valid {
+        0
+    } else {
+        -1
+    }
+}
+
+/// Reads the arguments of the legacy (non-managed) hooks:
+/// their lengths, as 4-byte little endian integers, followed by their concatenated contents.
+///
+/// # Safety
+///
+/// The offsets must point to the argument lengths and data, as provided by the contract.
+unsafe fn read_arguments(
+    num_arguments: i32,
+    arguments_length_offset: MemPtr,
+    data_offset: MemPtr,
+) -> Vec<Vec<u8>> {
+    mem_conv::with_bytes(
+        arguments_length_offset,
+        num_arguments as MemLength * 4,
+        |lengths| {
+            let mut data_ptr = data_offset;
+            lengths
+                .chunks_exact(4)
+                .map(|length_bytes| {
+                    let length = i32::from_le_bytes(length_bytes.try_into().unwrap()) as MemLength;
+                    let argument = mem_conv::with_bytes(data_ptr, length, <[u8]>::to_vec);
+                    data_ptr += length;
+                    argument
+                })
+                .collect()
+        },
+    )
+}
+
 #[allow(unused)]
 impl VMHooks for VMHooksDispatcher {
     fn set_vm_hooks_ptr(&mut self, _vm_hooks_ptr: *mut c_void) {}
 
     fn get_gas_left(&self) -> i64 {
+        self.handler.use_gas_for_hook("get_gas_left");
         self.handler.get_gas_left() as i64
     }
 
@@ -43,6 +118,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_shard_of_address(&self, address_offset: MemPtr) -> i32 {
+        self.handler.use_gas_for_hook("get_shard_of_address");
         unsafe {
             mem_conv::with_bytes(address_offset, 32, |address_bytes| {
                 self.handler.get_shard_of_address(address_bytes)
@@ -51,6 +127,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn is_smart_contract(&self, address_offset: MemPtr) -> i32 {
+        self.handler.use_gas_for_hook("is_smart_contract");
         unsafe {
             bool_to_i32(mem_conv::with_bytes(address_offset, 32, |address_bytes| {
                 self.handler.is_smart_contract(address_bytes)
@@ -59,6 +136,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn signal_error(&self, message_offset: MemPtr, message_length: MemLength) {
+        self.handler.use_gas_for_hook("signal_error");
         unsafe {
             mem_conv::with_bytes(message_offset, message_length, |message| {
                 self.handler.signal_error(message);
@@ -71,7 +149,14 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_block_hash(&self, nonce: i64, result_offset: MemPtr) -> i32 {
-        panic!("Unavailable: get_block_hash")
+        self.handler.use_gas_for_hook("get_block_hash");
+        // like the Go VM, leaves the result untouched for blocks that don't exist yet
+        if let Some(block_hash) = self.handler.block_hash(nonce as u64) {
+            unsafe {
+                copy_hash_to_mem(result_offset, &block_hash);
+            }
+        }
+        0
     }
 
     fn get_esdt_balance(
@@ -134,6 +219,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_esdt_local_roles(&self, token_id_handle: i32) -> i64 {
+        self.handler.use_gas_for_hook("get_esdt_local_roles");
         self.handler.get_esdt_local_roles_bits(token_id_handle) as i64
     }
 
@@ -267,7 +353,28 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) {
-        panic!("Unavailable: upgrade_from_source_contract");
+        self.handler
+            .use_gas_for_hook("upgrade_from_source_contract");
+        let to = unsafe { mem_conv::with_bytes(dest_offset, 32, VMAddress::from_slice) };
+        let egld_value =
+            unsafe { mem_conv::with_bytes(value_offset, 32, num_bigint::BigUint::from_bytes_be) };
+        let source_contract_address = unsafe {
+            mem_conv::with_bytes(source_contract_address_offset, 32, VMAddress::from_slice)
+        };
+        let code_metadata = unsafe {
+            mem_conv::with_bytes(code_metadata_offset, 2, |bytes| VMCodeMetadata::from(bytes))
+        };
+        let arguments =
+            unsafe { read_arguments(num_arguments, arguments_length_offset, data_offset) };
+
+        let source_contract_code = self.handler.source_contract_code(&source_contract_address);
+        self.handler.perform_upgrade_contract(
+            to,
+            egld_value,
+            source_contract_code,
+            code_metadata,
+            arguments,
+        );
     }
 
     fn delete_contract(
@@ -278,7 +385,12 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) {
-        panic!("Unavailable: delete_contract");
+        self.handler.use_gas_for_hook("delete_contract");
+        let to = unsafe { mem_conv::with_bytes(dest_offset, 32, VMAddress::from_slice) };
+        let arguments =
+            unsafe { read_arguments(num_arguments, arguments_length_offset, data_offset) };
+        self.handler
+            .perform_delete_contract(to, gas_limit as u64, arguments);
     }
 
     fn async_call(
@@ -304,6 +416,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_num_arguments(&self) -> i32 {
+        self.handler.use_gas_for_hook("get_num_arguments");
         self.handler.get_num_arguments()
     }
 
@@ -341,19 +454,39 @@ impl VMHooks for VMHooksDispatcher {
         key_length: MemLength,
         lock_timestamp: i64,
     ) -> i32 {
-        panic!("Unavailable: set_storage_lock")
+        self.handler.use_gas_for_hook("set_storage_lock");
+        unsafe {
+            mem_conv::with_bytes(key_offset, key_length, |key| {
+                self.handler.set_storage_lock(key, lock_timestamp)
+            })
+        }
     }
 
     fn get_storage_lock(&self, key_offset: MemPtr, key_length: MemLength) -> i64 {
-        panic!("Unavailable: get_storage_lock")
+        self.handler.use_gas_for_hook("get_storage_lock");
+        unsafe {
+            mem_conv::with_bytes(key_offset, key_length, |key| {
+                self.handler.get_storage_lock(key)
+            })
+        }
     }
 
     fn is_storage_locked(&self, key_offset: MemPtr, key_length: MemLength) -> i32 {
-        panic!("Unavailable: is_storage_locked")
+        self.handler.use_gas_for_hook("is_storage_locked");
+        unsafe {
+            mem_conv::with_bytes(key_offset, key_length, |key| {
+                self.handler.is_storage_locked(key)
+            })
+        }
     }
 
     fn clear_storage_lock(&self, key_offset: MemPtr, key_length: MemLength) -> i32 {
-        panic!("Unavailable: clear_storage_lock")
+        self.handler.use_gas_for_hook("clear_storage_lock");
+        unsafe {
+            mem_conv::with_bytes(key_offset, key_length, |key| {
+                self.handler.clear_storage_lock(key)
+            })
+        }
     }
 
     fn get_caller(&self, result_offset: MemPtr) {
@@ -361,6 +494,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn check_no_payment(&self) {
+        self.handler.use_gas_for_hook("check_no_payment");
         self.handler.check_not_payable();
     }
 
@@ -398,6 +532,7 @@ impl VMHooks for VMHooksDispatcher {
         token_id_offset: MemPtr,
         token_id_len: MemLength,
     ) -> i64 {
+        self.handler.use_gas_for_hook("get_current_esdt_nft_nonce");
         unsafe {
             mem_conv::with_bytes(address_offset, 32, |address_bytes| {
                 mem_conv::with_bytes(token_id_offset, token_id_len, |token_id_bytes| {
@@ -418,6 +553,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_num_esdt_transfers(&self) -> i32 {
+        self.handler.use_gas_for_hook("get_num_esdt_transfers");
         self.handler.esdt_num_transfers() as i32
     }
 
@@ -460,18 +596,22 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_block_timestamp(&self) -> i64 {
+        self.handler.use_gas_for_hook("get_block_timestamp");
         self.handler.get_block_timestamp() as i64
     }
 
     fn get_block_nonce(&self) -> i64 {
+        self.handler.use_gas_for_hook("get_block_nonce");
         self.handler.get_block_nonce() as i64
     }
 
     fn get_block_round(&self) -> i64 {
+        self.handler.use_gas_for_hook("get_block_round");
         self.handler.get_block_round() as i64
     }
 
     fn get_block_epoch(&self) -> i64 {
+        self.handler.use_gas_for_hook("get_block_epoch");
         self.handler.get_block_epoch() as i64
     }
 
@@ -480,22 +620,30 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_state_root_hash(&self, pointer: MemPtr) {
-        panic!("Unavailable: get_state_root_hash");
+        self.handler.use_gas_for_hook("get_state_root_hash");
+        let state_root_hash = self.handler.state_root_hash();
+        unsafe {
+            copy_hash_to_mem(pointer, &state_root_hash);
+        }
     }
 
     fn get_prev_block_timestamp(&self) -> i64 {
+        self.handler.use_gas_for_hook("get_prev_block_timestamp");
         self.handler.get_prev_block_timestamp() as i64
     }
 
     fn get_prev_block_nonce(&self) -> i64 {
+        self.handler.use_gas_for_hook("get_prev_block_nonce");
         self.handler.get_prev_block_nonce() as i64
     }
 
     fn get_prev_block_round(&self) -> i64 {
+        self.handler.use_gas_for_hook("get_prev_block_round");
         self.handler.get_prev_block_round() as i64
     }
 
     fn get_prev_block_epoch(&self) -> i64 {
+        self.handler.use_gas_for_hook("get_prev_block_epoch");
         self.handler.get_prev_block_epoch() as i64
     }
 
@@ -504,6 +652,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn finish(&self, pointer: MemPtr, length: MemLength) {
+        self.handler.use_gas_for_hook("finish");
         unsafe {
             mem_conv::with_bytes(pointer, length, |bytes| {
                 self.handler.finish_slice_u8(bytes);
@@ -578,7 +727,32 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: deploy_from_source_contract")
+        self.handler.use_gas_for_hook("deploy_from_source_contract");
+        let egld_value =
+            unsafe { mem_conv::with_bytes(value_offset, 32, num_bigint::BigUint::from_bytes_be) };
+        let source_contract_address = unsafe {
+            mem_conv::with_bytes(source_contract_address_offset, 32, VMAddress::from_slice)
+        };
+        let code_metadata = unsafe {
+            mem_conv::with_bytes(code_metadata_offset, 2, |bytes| VMCodeMetadata::from(bytes))
+        };
+        let arguments =
+            unsafe { read_arguments(num_arguments, arguments_length_offset, data_offset) };
+
+        let source_contract_code = self.handler.source_contract_code(&source_contract_address);
+        let (new_address, _) = self.handler.perform_deploy(
+            egld_value,
+            gas_limit as u64,
+            source_contract_code,
+            code_metadata,
+            arguments,
+        );
+        unsafe {
+            mem_conv::with_bytes_mut(result_address_offset, 32, |dest| {
+                dest.copy_from_slice(new_address.as_bytes());
+            });
+        }
+        0
     }
 
     fn get_num_return_data(&self) -> i32 {
@@ -594,58 +768,84 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn clean_return_data(&self) {
+        self.handler.use_gas_for_hook("clean_return_data");
         self.handler.clean_return_data();
     }
 
     fn delete_from_return_data(&self, result_id: i32) {
+        self.handler.use_gas_for_hook("delete_from_return_data");
         self.handler.delete_from_return_data(result_id as usize);
     }
 
     fn get_original_tx_hash(&self, data_offset: MemPtr) {
-        panic!("Unavailable: get_original_tx_hash");
+        self.handler.use_gas_for_hook("get_original_tx_hash");
+        let tx_hash = self.handler.input_ref().original_tx_hash().clone();
+        unsafe {
+            copy_hash_to_mem(data_offset, &tx_hash);
+        }
     }
 
     fn get_current_tx_hash(&self, data_offset: MemPtr) {
-        panic!("Unavailable: get_current_tx_hash");
+        self.handler.use_gas_for_hook("get_current_tx_hash");
+        let tx_hash = self.handler.input_ref().tx_hash.clone();
+        unsafe {
+            copy_hash_to_mem(data_offset, &tx_hash);
+        }
     }
 
     fn get_prev_tx_hash(&self, data_offset: MemPtr) {
-        panic!("Unavailable: get_prev_tx_hash");
+        self.handler.use_gas_for_hook("get_prev_tx_hash");
+        let tx_hash = self.handler.input_ref().prev_tx_hash().clone();
+        unsafe {
+            copy_hash_to_mem(data_offset, &tx_hash);
+        }
     }
 
     fn managed_sc_address(&self, destination_handle: i32) {
+        self.handler.use_gas_for_hook("managed_sc_address");
         self.handler.managed_sc_address(destination_handle);
     }
 
     fn managed_owner_address(&self, destination_handle: i32) {
+        self.handler.use_gas_for_hook("managed_owner_address");
         self.handler.managed_owner_address(destination_handle);
     }
 
     fn managed_caller(&self, destination_handle: i32) {
+        self.handler.use_gas_for_hook("managed_caller");
         self.handler.managed_caller(destination_handle);
     }
 
     fn managed_signal_error(&self, err_handle: i32) {
+        self.handler.use_gas_for_hook("managed_signal_error");
         self.handler.signal_error_from_buffer(err_handle);
     }
 
     fn managed_write_log(&self, topics_handle: i32, data_handle: i32) {
+        self.handler.use_gas_for_hook("managed_write_log");
         self.handler.managed_write_log(topics_handle, data_handle);
     }
 
     fn managed_get_original_tx_hash(&self, result_handle: i32) {
+        self.handler
+            .use_gas_for_hook("managed_get_original_tx_hash");
         self.handler.get_tx_hash(result_handle);
     }
 
     fn managed_get_state_root_hash(&self, result_handle: i32) {
-        panic!("Unavailable: managed_get_state_root_hash");
+        self.handler.use_gas_for_hook("managed_get_state_root_hash");
+        self.handler.get_state_root_hash(result_handle);
     }
 
     fn managed_get_block_random_seed(&self, result_handle: i32) {
+        self.handler
+            .use_gas_for_hook("managed_get_block_random_seed");
         self.handler.get_block_random_seed(result_handle);
     }
 
     fn managed_get_prev_block_random_seed(&self, result_handle: i32) {
+        self.handler
+            .use_gas_for_hook("managed_get_prev_block_random_seed");
         self.handler.get_prev_block_random_seed(result_handle);
     }
 
@@ -654,6 +854,8 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_get_multi_esdt_call_value(&self, multi_call_value_handle: i32) {
+        self.handler
+            .use_gas_for_hook("managed_get_multi_esdt_call_value");
         self.handler
             .load_all_esdt_transfers(multi_call_value_handle)
     }
@@ -682,6 +884,7 @@ impl VMHooks for VMHooksDispatcher {
         royalties_handle: i32,
         uris_handle: i32,
     ) {
+        self.handler.use_gas_for_hook("managed_get_esdt_token_data");
         self.handler.managed_get_esdt_token_data(
             address_handle,
             token_id_handle,
@@ -698,6 +901,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_get_back_transfers(&self, esdt_transfer_value_handle: i32, call_value_handle: i32) {
+        self.handler.use_gas_for_hook("managed_get_back_transfers");
         self.handler
             .managed_get_back_transfers(esdt_transfer_value_handle, call_value_handle);
     }
@@ -709,6 +913,7 @@ impl VMHooks for VMHooksDispatcher {
         function_handle: i32,
         arguments_handle: i32,
     ) {
+        self.handler.use_gas_for_hook("managed_async_call");
         self.handler
             .async_call_raw(dest_handle, value_handle, function_handle, arguments_handle)
     }
@@ -727,6 +932,7 @@ impl VMHooks for VMHooksDispatcher {
         extra_gas_for_callback: i64,
         callback_closure_handle: i32,
     ) -> i32 {
+        self.handler.use_gas_for_hook("managed_create_async_call");
         unsafe {
             mem_conv::with_bytes(success_offset, success_length, |success_callback| {
                 mem_conv::with_bytes(error_offset, error_length, |error_callback| {
@@ -748,6 +954,8 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_get_callback_closure(&self, callback_closure_handle: i32) {
+        self.handler
+            .use_gas_for_hook("managed_get_callback_closure");
         self.handler
             .load_callback_closure_buffer(callback_closure_handle)
     }
@@ -762,6 +970,8 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         _result_handle: i32,
     ) {
+        self.handler
+            .use_gas_for_hook("managed_upgrade_from_source_contract");
         self.handler.upgrade_from_source_contract(
             dest_handle,
             gas as u64,
@@ -782,6 +992,7 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         _result_handle: i32,
     ) {
+        self.handler.use_gas_for_hook("managed_upgrade_contract");
         self.handler.upgrade_contract(
             dest_handle,
             gas as u64,
@@ -793,7 +1004,9 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_delete_contract(&self, dest_handle: i32, gas_limit: i64, arguments_handle: i32) {
-        panic!("Unavailable: managed_delete_contract");
+        self.handler.use_gas_for_hook("managed_delete_contract");
+        self.handler
+            .delete_contract(dest_handle, gas_limit as u64, arguments_handle);
     }
 
     fn managed_deploy_from_source_contract(
@@ -806,6 +1019,8 @@ impl VMHooks for VMHooksDispatcher {
         result_address_handle: i32,
         result_handle: i32,
     ) -> i32 {
+        self.handler
+            .use_gas_for_hook("managed_deploy_from_source_contract");
         self.handler.deploy_from_source_contract(
             gas as u64,
             value_handle,
@@ -828,6 +1043,7 @@ impl VMHooks for VMHooksDispatcher {
         result_address_handle: i32,
         result_handle: i32,
     ) -> i32 {
+        self.handler.use_gas_for_hook("managed_create_contract");
         self.handler.deploy_contract(
             gas as u64,
             value_handle,
@@ -848,7 +1064,15 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_execute_read_only")
+        self.handler.use_gas_for_hook("managed_execute_read_only");
+        self.handler.execute_read_only_raw(
+            gas as u64,
+            address_handle,
+            function_handle,
+            arguments_handle,
+            result_handle,
+        );
+        0
     }
 
     fn managed_execute_on_same_context(
@@ -860,7 +1084,17 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_execute_on_same_context")
+        self.handler
+            .use_gas_for_hook("managed_execute_on_same_context");
+        self.handler.execute_on_same_context_raw(
+            gas as u64,
+            address_handle,
+            value_handle,
+            function_handle,
+            arguments_handle,
+            result_handle,
+        );
+        0
     }
 
     fn managed_execute_on_dest_context(
@@ -872,6 +1106,8 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         result_handle: i32,
     ) -> i32 {
+        self.handler
+            .use_gas_for_hook("managed_execute_on_dest_context");
         self.handler.execute_on_dest_context_raw(
             gas as u64,
             address_handle,
@@ -891,6 +1127,8 @@ impl VMHooks for VMHooksDispatcher {
         function_handle: i32,
         arguments_handle: i32,
     ) -> i32 {
+        self.handler
+            .use_gas_for_hook("managed_multi_transfer_esdt_nft_execute");
         self.handler.multi_transfer_esdt_nft_execute(
             dst_handle,
             token_transfers_handle,
@@ -909,6 +1147,8 @@ impl VMHooks for VMHooksDispatcher {
         function_handle: i32,
         arguments_handle: i32,
     ) -> i32 {
+        self.handler
+            .use_gas_for_hook("managed_transfer_value_execute");
         self.handler.transfer_value_execute(
             dst_handle,
             value_handle,
@@ -920,6 +1160,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_is_esdt_frozen(&self, address_handle: i32, token_id_handle: i32, nonce: i64) -> i32 {
+        self.handler.use_gas_for_hook("managed_is_esdt_frozen");
         bool_to_i32(
             self.handler
                 .check_esdt_frozen(address_handle, token_id_handle, nonce as u64),
@@ -927,23 +1168,29 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_is_esdt_limited_transfer(&self, _token_id_handle: i32) -> i32 {
+        self.handler
+            .use_gas_for_hook("managed_is_esdt_limited_transfer");
         bool_to_i32(false)
     }
 
-    fn managed_is_esdt_paused(&self, _token_id_handle: i32) -> i32 {
-        bool_to_i32(false)
+    fn managed_is_esdt_paused(&self, token_id_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("managed_is_esdt_paused");
+        bool_to_i32(self.handler.check_esdt_paused(token_id_handle))
     }
 
     fn managed_buffer_to_hex(&self, source_handle: i32, dest_handle: i32) {
+        self.handler.use_gas_for_hook("managed_buffer_to_hex");
         self.handler.mb_to_hex(source_handle, dest_handle);
     }
 
     fn managed_get_code_metadata(&self, address_handle: i32, response_handle: i32) {
+        self.handler.use_gas_for_hook("managed_get_code_metadata");
         self.handler
             .managed_get_code_metadata(address_handle, response_handle);
     }
 
     fn managed_is_builtin_function(&self, function_name_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("managed_is_builtin_function");
         bool_to_i32(
             self.handler
                 .managed_is_builtin_function(function_name_handle),
@@ -956,95 +1203,117 @@ impl VMHooks for VMHooksDispatcher {
         fractional_part: i32,
         exponent: i32,
     ) -> i32 {
+        self.handler.use_gas_for_hook("big_float_new_from_parts");
         self.handler
             .bf_from_parts(integral_part, fractional_part, exponent)
     }
 
     fn big_float_new_from_frac(&self, numerator: i64, denominator: i64) -> i32 {
+        self.handler.use_gas_for_hook("big_float_new_from_frac");
         self.handler.bf_from_frac(numerator, denominator)
     }
 
     fn big_float_new_from_sci(&self, significand: i64, exponent: i64) -> i32 {
+        self.handler.use_gas_for_hook("big_float_new_from_sci");
         self.handler.bf_from_sci(significand, exponent)
     }
 
     fn big_float_add(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.handler.use_gas_for_hook("big_float_add");
         self.handler
             .bf_add(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_sub(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.handler.use_gas_for_hook("big_float_sub");
         self.handler
             .bf_sub(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_mul(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.handler.use_gas_for_hook("big_float_mul");
         self.handler
             .bf_mul(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_div(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.handler.use_gas_for_hook("big_float_div");
         self.handler
             .bf_div(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_neg(&self, destination_handle: i32, op_handle: i32) {
+        self.handler.use_gas_for_hook("big_float_neg");
         self.handler.bf_neg(destination_handle, op_handle);
     }
 
     fn big_float_clone(&self, destination_handle: i32, op_handle: i32) {
+        self.handler.use_gas_for_hook("big_float_clone");
         self.handler.bf_clone(destination_handle, op_handle);
     }
 
     fn big_float_cmp(&self, op1_handle: i32, op2_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("big_float_cmp");
         self.handler.bf_cmp(op1_handle, op2_handle)
     }
 
     fn big_float_abs(&self, destination_handle: i32, op_handle: i32) {
+        self.handler.use_gas_for_hook("big_float_abs");
         self.handler.bf_abs(destination_handle, op_handle);
     }
 
     fn big_float_sign(&self, op_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("big_float_sign");
         self.handler.bf_sign(op_handle)
     }
 
     fn big_float_sqrt(&self, destination_handle: i32, op_handle: i32) {
+        self.handler.use_gas_for_hook("big_float_sqrt");
         self.handler.bf_sqrt(destination_handle, op_handle);
     }
 
     fn big_float_pow(&self, destination_handle: i32, op_handle: i32, exponent: i32) {
+        self.handler.use_gas_for_hook("big_float_pow");
         self.handler.bf_pow(destination_handle, op_handle, exponent);
     }
 
     fn big_float_floor(&self, dest_big_int_handle: i32, op_handle: i32) {
+        self.handler.use_gas_for_hook("big_float_floor");
         self.handler.bf_floor(dest_big_int_handle, op_handle);
     }
 
     fn big_float_ceil(&self, dest_big_int_handle: i32, op_handle: i32) {
+        self.handler.use_gas_for_hook("big_float_ceil");
         self.handler.bf_ceil(dest_big_int_handle, op_handle);
     }
 
     fn big_float_truncate(&self, dest_big_int_handle: i32, op_handle: i32) {
+        self.handler.use_gas_for_hook("big_float_truncate");
         self.handler.bf_trunc(dest_big_int_handle, op_handle);
     }
 
     fn big_float_set_int64(&self, destination_handle: i32, value: i64) {
+        self.handler.use_gas_for_hook("big_float_set_int64");
         self.handler.bf_set_i64(destination_handle, value);
     }
 
     fn big_float_is_int(&self, op_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("big_float_is_int");
         bool_to_i32(self.handler.bf_is_bi(op_handle))
     }
 
     fn big_float_set_big_int(&self, destination_handle: i32, big_int_handle: i32) {
+        self.handler.use_gas_for_hook("big_float_set_big_int");
         self.handler.bf_set_bi(destination_handle, big_int_handle);
     }
 
     fn big_float_get_const_pi(&self, destination_handle: i32) {
+        self.handler.use_gas_for_hook("big_float_get_const_pi");
         self.handler.bf_get_const_pi(destination_handle);
     }
 
     fn big_float_get_const_e(&self, destination_handle: i32) {
+        self.handler.use_gas_for_hook("big_float_get_const_e");
         self.handler.bf_get_const_e(destination_handle);
     }
 
@@ -1075,6 +1344,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_get_call_value(&self, destination_handle: i32) {
+        self.handler.use_gas_for_hook("big_int_get_call_value");
         self.handler.load_egld_value(destination_handle);
     }
 
@@ -1087,6 +1357,8 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_get_external_balance(&self, address_offset: MemPtr, result: i32) {
+        self.handler
+            .use_gas_for_hook("big_int_get_external_balance");
         unsafe {
             mem_conv::with_bytes(address_offset, 32, |address_bytes| {
                 self.handler.load_balance(address_bytes, result);
@@ -1102,6 +1374,8 @@ impl VMHooks for VMHooksDispatcher {
         nonce: i64,
         result_handle: i32,
     ) {
+        self.handler
+            .use_gas_for_hook("big_int_get_esdt_external_balance");
         unsafe {
             mem_conv::with_bytes(address_offset, 32, |address_bytes| {
                 mem_conv::with_bytes(token_id_offset, token_id_len, |token_id_bytes| {
@@ -1117,6 +1391,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_new(&self, small_value: i64) -> i32 {
+        self.handler.use_gas_for_hook("big_int_new");
         self.handler.bi_new(small_value)
     }
 
@@ -1142,6 +1417,7 @@ impl VMHooks for VMHooksDispatcher {
         byte_offset: MemPtr,
         byte_length: MemLength,
     ) {
+        self.handler.use_gas_for_hook("big_int_set_unsigned_bytes");
         unsafe {
             mem_conv::with_bytes_mut(byte_offset, byte_length, |bytes| {
                 self.handler
@@ -1156,6 +1432,7 @@ impl VMHooks for VMHooksDispatcher {
         byte_offset: MemPtr,
         byte_length: MemLength,
     ) {
+        self.handler.use_gas_for_hook("big_int_set_signed_bytes");
         unsafe {
             mem_conv::with_bytes_mut(byte_offset, byte_length, |bytes| {
                 self.handler.bi_set_signed_bytes(destination_handle, bytes);
@@ -1164,38 +1441,46 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_is_int64(&self, destination_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("big_int_is_int64");
         self.handler.bi_is_int64(destination_handle)
     }
 
     fn big_int_get_int64(&self, destination_handle: i32) -> i64 {
+        self.handler.use_gas_for_hook("big_int_get_int64");
         self.handler.bi_get_int64(destination_handle)
     }
 
     fn big_int_set_int64(&self, destination_handle: i32, value: i64) {
+        self.handler.use_gas_for_hook("big_int_set_int64");
         self.handler.bi_set_int64(destination_handle, value);
     }
 
     fn big_int_add(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.handler.use_gas_for_hook("big_int_add");
         self.handler
             .bi_add(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_sub(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.handler.use_gas_for_hook("big_int_sub");
         self.handler
             .bi_sub(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_mul(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.handler.use_gas_for_hook("big_int_mul");
         self.handler
             .bi_mul(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_tdiv(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.handler.use_gas_for_hook("big_int_tdiv");
         self.handler
             .bi_t_div(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_tmod(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.handler.use_gas_for_hook("big_int_tmod");
         self.handler
             .bi_t_mod(destination_handle, op1_handle, op2_handle);
     }
@@ -1209,31 +1494,38 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_sqrt(&self, destination_handle: i32, op_handle: i32) {
+        self.handler.use_gas_for_hook("big_int_sqrt");
         self.handler.bi_sqrt(destination_handle, op_handle);
     }
 
     fn big_int_pow(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.handler.use_gas_for_hook("big_int_pow");
         self.handler
             .bi_pow(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_log2(&self, op_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("big_int_log2");
         self.handler.bi_log2(op_handle)
     }
 
     fn big_int_abs(&self, destination_handle: i32, op_handle: i32) {
+        self.handler.use_gas_for_hook("big_int_abs");
         self.handler.bi_abs(destination_handle, op_handle);
     }
 
     fn big_int_neg(&self, destination_handle: i32, op_handle: i32) {
+        self.handler.use_gas_for_hook("big_int_neg");
         self.handler.bi_neg(destination_handle, op_handle);
     }
 
     fn big_int_sign(&self, op_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("big_int_sign");
         self.handler.bi_sign(op_handle)
     }
 
     fn big_int_cmp(&self, op1_handle: i32, op2_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("big_int_cmp");
         self.handler.bi_cmp(op1_handle, op2_handle)
     }
 
@@ -1242,48 +1534,58 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_and(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.handler.use_gas_for_hook("big_int_and");
         self.handler
             .bi_and(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_or(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.handler.use_gas_for_hook("big_int_or");
         self.handler
             .bi_or(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_xor(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.handler.use_gas_for_hook("big_int_xor");
         self.handler
             .bi_xor(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_shr(&self, destination_handle: i32, op_handle: i32, bits: i32) {
+        self.handler.use_gas_for_hook("big_int_shr");
         self.handler
             .bi_shr(destination_handle, op_handle, bits as usize);
     }
 
     fn big_int_shl(&self, destination_handle: i32, op_handle: i32, bits: i32) {
+        self.handler.use_gas_for_hook("big_int_shl");
         self.handler
             .bi_shl(destination_handle, op_handle, bits as usize);
     }
 
     fn big_int_finish_unsigned(&self, reference_handle: i32) {
+        self.handler.use_gas_for_hook("big_int_finish_unsigned");
         self.handler.finish_big_uint_raw(reference_handle);
     }
 
     fn big_int_finish_signed(&self, reference_handle: i32) {
+        self.handler.use_gas_for_hook("big_int_finish_signed");
         self.handler.finish_big_int_raw(reference_handle);
     }
 
     fn big_int_to_string(&self, big_int_handle: i32, destination_handle: i32) {
+        self.handler.use_gas_for_hook("big_int_to_string");
         self.handler
             .bi_to_string(big_int_handle, destination_handle);
     }
 
     fn mbuffer_new(&self) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_new");
         self.handler.mb_new_empty()
     }
 
     fn mbuffer_new_from_bytes(&self, data_offset: MemPtr, data_length: MemLength) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_new_from_bytes");
         unsafe {
             mem_conv::with_bytes_mut(data_offset, data_length, |bytes| {
                 self.handler.mb_new_from_bytes(bytes)
@@ -1292,10 +1594,12 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn mbuffer_get_length(&self, m_buffer_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_get_length");
         self.handler.mb_len(m_buffer_handle) as i32
     }
 
     fn mbuffer_get_bytes(&self, m_buffer_handle: i32, result_offset: MemPtr) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_get_bytes");
         unsafe {
             self.handler
                 .mb_copy_bytes(m_buffer_handle, result_offset as *mut u8) as i32
@@ -1309,6 +1613,7 @@ impl VMHooks for VMHooksDispatcher {
         slice_length: i32,
         result_offset: MemPtr,
     ) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_get_byte_slice");
         unsafe {
             mem_conv::with_bytes_mut(result_offset, slice_length as isize, |bytes| {
                 self.handler
@@ -1324,6 +1629,7 @@ impl VMHooks for VMHooksDispatcher {
         slice_length: i32,
         destination_handle: i32,
     ) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_copy_byte_slice");
         self.handler.mb_copy_slice(
             source_handle,
             starting_position as usize,
@@ -1333,6 +1639,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn mbuffer_eq(&self, m_buffer_handle1: i32, m_buffer_handle2: i32) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_eq");
         self.handler.mb_eq(m_buffer_handle1, m_buffer_handle2)
     }
 
@@ -1342,6 +1649,7 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         data_length: MemLength,
     ) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_set_bytes");
         unsafe {
             mem_conv::with_bytes(data_offset, data_length, |bytes| {
                 self.handler.mb_set(m_buffer_handle, bytes);
@@ -1357,6 +1665,7 @@ impl VMHooks for VMHooksDispatcher {
         data_length: MemLength,
         data_offset: MemPtr,
     ) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_set_byte_slice");
         unsafe {
             mem_conv::with_bytes(data_offset, data_length, |bytes| {
                 self.handler
@@ -1366,6 +1675,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn mbuffer_append(&self, accumulator_handle: i32, data_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_append");
         self.handler.mb_append(accumulator_handle, data_handle);
         0
     }
@@ -1376,6 +1686,7 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         data_length: MemLength,
     ) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_append_bytes");
         unsafe {
             mem_conv::with_bytes(data_offset, data_length, |bytes| {
                 self.handler.mb_append_bytes(accumulator_handle, bytes);
@@ -1385,44 +1696,57 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn mbuffer_to_big_int_unsigned(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_to_big_int_unsigned");
         self.handler
             .mb_to_big_int_unsigned(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_to_big_int_signed(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_to_big_int_signed");
         self.handler
             .mb_to_big_int_signed(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_from_big_int_unsigned(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
+        self.handler
+            .use_gas_for_hook("mbuffer_from_big_int_unsigned");
         self.handler
             .mb_from_big_int_unsigned(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_from_big_int_signed(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_from_big_int_signed");
         self.handler
             .mb_from_big_int_signed(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_to_big_float(&self, m_buffer_handle: i32, big_float_handle: i32) -> i32 {
-        panic!("Unavailable: mbuffer_to_big_float")
+        self.handler.use_gas_for_hook("mbuffer_to_big_float");
+        self.handler
+            .mb_to_big_float(m_buffer_handle, big_float_handle);
+        0
     }
 
     fn mbuffer_from_big_float(&self, m_buffer_handle: i32, big_float_handle: i32) -> i32 {
-        panic!("Unavailable: mbuffer_from_big_float")
+        self.handler.use_gas_for_hook("mbuffer_from_big_float");
+        self.handler
+            .mb_from_big_float(m_buffer_handle, big_float_handle);
+        0
     }
 
     fn mbuffer_storage_store(&self, key_handle: i32, source_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_storage_store");
         self.handler
             .storage_store_managed_buffer_raw(key_handle, source_handle);
         0
     }
 
     fn mbuffer_storage_load(&self, key_handle: i32, destination_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_storage_load");
         self.handler
             .storage_load_managed_buffer_raw(key_handle, destination_handle);
         0
@@ -1434,65 +1758,81 @@ impl VMHooks for VMHooksDispatcher {
         key_handle: i32,
         destination_handle: i32,
     ) {
+        self.handler
+            .use_gas_for_hook("mbuffer_storage_load_from_address");
         self.handler
             .storage_load_from_address(address_handle, key_handle, destination_handle);
     }
 
     fn mbuffer_get_argument(&self, id: i32, destination_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_get_argument");
         self.handler
             .load_argument_managed_buffer(id, destination_handle);
         0
     }
 
     fn mbuffer_finish(&self, source_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_finish");
         self.handler.finish_managed_buffer_raw(source_handle);
         0
     }
 
     fn mbuffer_set_random(&self, destination_handle: i32, length: i32) -> i32 {
+        self.handler.use_gas_for_hook("mbuffer_set_random");
         self.handler
             .mb_set_random(destination_handle, length as usize);
         0
     }
 
     fn managed_map_new(&self) -> i32 {
+        self.handler.use_gas_for_hook("managed_map_new");
         self.handler.mm_new()
     }
 
     fn managed_map_put(&self, map_handle: i32, key_handle: i32, value_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("managed_map_put");
         self.handler.mm_put(map_handle, key_handle, value_handle);
         0
     }
 
     fn managed_map_get(&self, map_handle: i32, key_handle: i32, out_value_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("managed_map_get");
         self.handler
             .mm_get(map_handle, key_handle, out_value_handle);
         0
     }
 
     fn managed_map_remove(&self, map_handle: i32, key_handle: i32, out_value_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("managed_map_remove");
         self.handler
             .mm_remove(map_handle, key_handle, out_value_handle);
         0
     }
 
     fn managed_map_contains(&self, map_handle: i32, key_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("managed_map_contains");
         bool_to_i32(self.handler.mm_contains(map_handle, key_handle))
     }
 
     fn small_int_get_unsigned_argument(&self, id: i32) -> i64 {
+        self.handler
+            .use_gas_for_hook("small_int_get_unsigned_argument");
         self.handler.get_argument_u64(id) as i64
     }
 
     fn small_int_get_signed_argument(&self, id: i32) -> i64 {
+        self.handler
+            .use_gas_for_hook("small_int_get_signed_argument");
         self.handler.get_argument_i64(id)
     }
 
     fn small_int_finish_unsigned(&self, value: i64) {
+        self.handler.use_gas_for_hook("small_int_finish_unsigned");
         self.handler.finish_u64(value as u64);
     }
 
     fn small_int_finish_signed(&self, value: i64) {
+        self.handler.use_gas_for_hook("small_int_finish_signed");
         self.handler.finish_i64(value);
     }
 
@@ -1543,6 +1883,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_sha256(&self, input_handle: i32, output_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("managed_sha256");
         self.handler.sha256_managed(output_handle, input_handle);
         0
     }
@@ -1552,16 +1893,26 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_keccak256(&self, input_handle: i32, output_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("managed_keccak256");
         self.handler.keccak256_managed(output_handle, input_handle);
         0
     }
 
     fn ripemd160(&self, data_offset: MemPtr, length: MemLength, result_offset: MemPtr) -> i32 {
-        panic!("Unavailable: ripemd160")
+        self.handler.use_gas_for_hook("ripemd160");
+        unsafe {
+            let result = mem_conv::with_bytes(data_offset, length, crypto_functions::ripemd160);
+            mem_conv::with_bytes_mut(result_offset, result.len() as MemLength, |dest| {
+                dest.copy_from_slice(&result);
+            });
+        }
+        0
     }
 
     fn managed_ripemd160(&self, input_handle: i32, output_handle: i32) -> i32 {
-        panic!("Unavailable: managed_ripemd160")
+        self.handler.use_gas_for_hook("managed_ripemd160");
+        self.handler.ripemd160_managed(output_handle, input_handle);
+        0
     }
 
     fn verify_bls(
@@ -1571,11 +1922,24 @@ impl VMHooks for VMHooksDispatcher {
         message_length: MemLength,
         sig_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: verify_bls")
+        self.handler.use_gas_for_hook("verify_bls");
+        unsafe {
+            mem_conv::with_bytes(key_offset, BLS_PUBLIC_KEY_LEN as MemLength, |key| {
+                mem_conv::with_bytes(message_offset, message_length, |message| {
+                    mem_conv::with_bytes(sig_offset, BLS_SIGNATURE_LEN as MemLength, |sig| {
+                        self.handler.verify_bls(key, message, sig);
+                    })
+                })
+            });
+        }
+        0
     }
 
     fn managed_verify_bls(&self, key_handle: i32, message_handle: i32, sig_handle: i32) -> i32 {
-        panic!("Unavailable: managed_verify_bls")
+        self.handler.use_gas_for_hook("managed_verify_bls");
+        self.handler
+            .verify_bls_managed(key_handle, message_handle, sig_handle);
+        0
     }
 
     fn verify_ed25519(
@@ -1589,6 +1953,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_verify_ed25519(&self, key_handle: i32, message_handle: i32, sig_handle: i32) -> i32 {
+        self.handler.use_gas_for_hook("managed_verify_ed25519");
         self.handler
             .verify_ed25519_managed(key_handle, message_handle, sig_handle);
         0
@@ -1603,7 +1968,17 @@ impl VMHooks for VMHooksDispatcher {
         sig_offset: MemPtr,
         hash_type: i32,
     ) -> i32 {
-        panic!("Unavailable: verify_custom_secp256k1")
+        self.handler.use_gas_for_hook("verify_custom_secp256k1");
+        unsafe {
+            verify_secp256k1_in_mem(
+                key_offset,
+                key_length,
+                message_offset,
+                message_length,
+                sig_offset,
+                hash_type as u8,
+            )
+        }
     }
 
     fn managed_verify_custom_secp256k1(
@@ -1613,7 +1988,19 @@ impl VMHooks for VMHooksDispatcher {
         sig_handle: i32,
         hash_type: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_verify_custom_secp256k1")
+        self.handler
+            .use_gas_for_hook("managed_verify_custom_secp256k1");
+        let sig_valid = self.handler.verify_custom_secp256k1_managed(
+            key_handle,
+            message_handle,
+            sig_handle,
+            hash_type as u8,
+        );
+        if sig_valid {
+            0
+        } else {
+            -1
+        }
     }
 
     fn verify_secp256k1(
@@ -1624,7 +2011,17 @@ impl VMHooks for VMHooksDispatcher {
         message_length: MemLength,
         sig_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: verify_secp256k1")
+        self.handler.use_gas_for_hook("verify_secp256k1");
+        unsafe {
+            verify_secp256k1_in_mem(
+                key_offset,
+                key_length,
+                message_offset,
+                message_length,
+                sig_offset,
+                crypto_functions::ECDSA_DOUBLE_SHA256,
+            )
+        }
     }
 
     fn managed_verify_secp256k1(
@@ -1633,7 +2030,15 @@ impl VMHooks for VMHooksDispatcher {
         message_handle: i32,
         sig_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_verify_secp256k1")
+        self.handler.use_gas_for_hook("managed_verify_secp256k1");
+        let sig_valid =
+            self.handler
+                .verify_secp256k1_managed(key_handle, message_handle, sig_handle);
+        if sig_valid {
+            0
+        } else {
+            -1
+        }
     }
 
     fn encode_secp256k1_der_signature(
@@ -1644,7 +2049,19 @@ impl VMHooks for VMHooksDispatcher {
         s_length: MemLength,
         sig_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: encode_secp256k1_der_signature")
+        self.handler
+            .use_gas_for_hook("encode_secp256k1_der_signature");
+        unsafe {
+            let signature = mem_conv::with_bytes(r_offset, r_length, |r| {
+                mem_conv::with_bytes(s_offset, s_length, |s| {
+                    crypto_functions::encode_secp256k1_der_signature(r, s)
+                })
+            });
+            mem_conv::with_bytes_mut(sig_offset, signature.len() as MemLength, |dest| {
+                dest.copy_from_slice(&signature);
+            });
+        }
+        0
     }
 
     fn managed_encode_secp256k1_der_signature(
@@ -1653,7 +2070,11 @@ impl VMHooks for VMHooksDispatcher {
         s_handle: i32,
         sig_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_encode_secp256k1_der_signature")
+        self.handler
+            .use_gas_for_hook("managed_encode_secp256k1_der_signature");
+        self.handler
+            .encode_secp256k1_der_signature_managed(r_handle, s_handle, sig_handle);
+        0
     }
 
     fn add_ec(
@@ -1666,7 +2087,16 @@ impl VMHooks for VMHooksDispatcher {
         snd_point_xhandle: i32,
         snd_point_yhandle: i32,
     ) {
-        panic!("Unavailable: add_ec");
+        self.handler.use_gas_for_hook("add_ec");
+        self.handler.ec_add(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            fst_point_xhandle,
+            fst_point_yhandle,
+            snd_point_xhandle,
+            snd_point_yhandle,
+        );
     }
 
     fn double_ec(
@@ -1677,11 +2107,22 @@ impl VMHooks for VMHooksDispatcher {
         point_xhandle: i32,
         point_yhandle: i32,
     ) {
-        panic!("Unavailable: double_ec");
+        self.handler.use_gas_for_hook("double_ec");
+        self.handler.ec_double(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            point_xhandle,
+            point_yhandle,
+        );
     }
 
     fn is_on_curve_ec(&self, ec_handle: i32, point_xhandle: i32, point_yhandle: i32) -> i32 {
-        panic!("Unavailable: is_on_curve_ec")
+        self.handler.use_gas_for_hook("is_on_curve_ec");
+        bool_to_i32(
+            self.handler
+                .ec_is_on_curve(ec_handle, point_xhandle, point_yhandle),
+        )
     }
 
     fn scalar_base_mult_ec(
@@ -1692,7 +2133,14 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: scalar_base_mult_ec")
+        self.handler.use_gas_for_hook("scalar_base_mult_ec");
+        unsafe {
+            mem_conv::with_bytes(data_offset, length, |data| {
+                self.handler
+                    .ec_scalar_base_mult(x_result_handle, y_result_handle, ec_handle, data);
+            });
+        }
+        0
     }
 
     fn managed_scalar_base_mult_ec(
@@ -1702,7 +2150,14 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_scalar_base_mult_ec")
+        self.handler.use_gas_for_hook("managed_scalar_base_mult_ec");
+        self.handler.ec_scalar_base_mult_managed(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            data_handle,
+        );
+        0
     }
 
     fn scalar_mult_ec(
@@ -1715,7 +2170,20 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: scalar_mult_ec")
+        self.handler.use_gas_for_hook("scalar_mult_ec");
+        unsafe {
+            mem_conv::with_bytes(data_offset, length, |data| {
+                self.handler.ec_scalar_mult(
+                    x_result_handle,
+                    y_result_handle,
+                    ec_handle,
+                    point_xhandle,
+                    point_yhandle,
+                    data,
+                );
+            });
+        }
+        0
     }
 
     fn managed_scalar_mult_ec(
@@ -1727,7 +2195,16 @@ impl VMHooks for VMHooksDispatcher {
         point_yhandle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_scalar_mult_ec")
+        self.handler.use_gas_for_hook("managed_scalar_mult_ec");
+        self.handler.ec_scalar_mult_managed(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            point_xhandle,
+            point_yhandle,
+            data_handle,
+        );
+        0
     }
 
     fn marshal_ec(
@@ -1737,7 +2214,16 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: marshal_ec")
+        self.handler.use_gas_for_hook("marshal_ec");
+        let result = self
+            .handler
+            .ec_marshal(ec_handle, x_pair_handle, y_pair_handle);
+        unsafe {
+            mem_conv::with_bytes_mut(result_offset, result.len() as MemLength, |dest| {
+                dest.copy_from_slice(&result);
+            });
+        }
+        result.len() as i32
     }
 
     fn managed_marshal_ec(
@@ -1747,7 +2233,10 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_marshal_ec")
+        self.handler.use_gas_for_hook("managed_marshal_ec");
+        self.handler
+            .ec_marshal_managed(ec_handle, x_pair_handle, y_pair_handle, result_handle)
+            as i32
     }
 
     fn marshal_compressed_ec(
@@ -1757,7 +2246,16 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: marshal_compressed_ec")
+        self.handler.use_gas_for_hook("marshal_compressed_ec");
+        let result = self
+            .handler
+            .ec_marshal_compressed(ec_handle, x_pair_handle, y_pair_handle);
+        unsafe {
+            mem_conv::with_bytes_mut(result_offset, result.len() as MemLength, |dest| {
+                dest.copy_from_slice(&result);
+            });
+        }
+        result.len() as i32
     }
 
     fn managed_marshal_compressed_ec(
@@ -1767,7 +2265,14 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_marshal_compressed_ec")
+        self.handler
+            .use_gas_for_hook("managed_marshal_compressed_ec");
+        self.handler.ec_marshal_compressed_managed(
+            ec_handle,
+            x_pair_handle,
+            y_pair_handle,
+            result_handle,
+        ) as i32
     }
 
     fn unmarshal_ec(
@@ -1778,7 +2283,14 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: unmarshal_ec")
+        self.handler.use_gas_for_hook("unmarshal_ec");
+        unsafe {
+            mem_conv::with_bytes(data_offset, length, |data| {
+                self.handler
+                    .ec_unmarshal(x_result_handle, y_result_handle, ec_handle, data);
+            });
+        }
+        0
     }
 
     fn managed_unmarshal_ec(
@@ -1788,7 +2300,10 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_unmarshal_ec")
+        self.handler.use_gas_for_hook("managed_unmarshal_ec");
+        self.handler
+            .ec_unmarshal_managed(x_result_handle, y_result_handle, ec_handle, data_handle);
+        0
     }
 
     fn unmarshal_compressed_ec(
@@ -1799,7 +2314,18 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: unmarshal_compressed_ec")
+        self.handler.use_gas_for_hook("unmarshal_compressed_ec");
+        unsafe {
+            mem_conv::with_bytes(data_offset, length, |data| {
+                self.handler.ec_unmarshal_compressed(
+                    x_result_handle,
+                    y_result_handle,
+                    ec_handle,
+                    data,
+                );
+            });
+        }
+        0
     }
 
     fn managed_unmarshal_compressed_ec(
@@ -1809,7 +2335,15 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_unmarshal_compressed_ec")
+        self.handler
+            .use_gas_for_hook("managed_unmarshal_compressed_ec");
+        self.handler.ec_unmarshal_compressed_managed(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            data_handle,
+        );
+        0
     }
 
     fn generate_key_ec(
@@ -1819,7 +2353,16 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: generate_key_ec")
+        self.handler.use_gas_for_hook("generate_key_ec");
+        let private_key =
+            self.handler
+                .ec_generate_key(x_pub_key_handle, y_pub_key_handle, ec_handle);
+        unsafe {
+            mem_conv::with_bytes_mut(result_offset, private_key.len() as MemLength, |dest| {
+                dest.copy_from_slice(&private_key);
+            });
+        }
+        0
     }
 
     fn managed_generate_key_ec(
@@ -1829,23 +2372,38 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_generate_key_ec")
+        self.handler.use_gas_for_hook("managed_generate_key_ec");
+        self.handler.ec_generate_key_managed(
+            x_pub_key_handle,
+            y_pub_key_handle,
+            ec_handle,
+            result_handle,
+        );
+        0
     }
 
     fn create_ec(&self, data_offset: MemPtr, data_length: MemLength) -> i32 {
-        panic!("Unavailable: create_ec")
+        self.handler.use_gas_for_hook("create_ec");
+        unsafe {
+            mem_conv::with_bytes(data_offset, data_length, |name| {
+                self.handler.ec_create_from_name(name)
+            })
+        }
     }
 
     fn managed_create_ec(&self, data_handle: i32) -> i32 {
-        panic!("Unavailable: managed_create_ec")
+        self.handler.use_gas_for_hook("managed_create_ec");
+        self.handler.ec_create_from_name_mb(data_handle)
     }
 
     fn get_curve_length_ec(&self, ec_handle: i32) -> i32 {
-        panic!("Unavailable: get_curve_length_ec")
+        self.handler.use_gas_for_hook("get_curve_length_ec");
+        self.handler.ec_curve_length(ec_handle) as i32
     }
 
     fn get_priv_key_byte_length_ec(&self, ec_handle: i32) -> i32 {
-        panic!("Unavailable: get_priv_key_byte_length_ec")
+        self.handler.use_gas_for_hook("get_priv_key_byte_length_ec");
+        self.handler.ec_private_key_byte_length(ec_handle) as i32
     }
 
     fn elliptic_curve_get_values(
@@ -1857,7 +2415,16 @@ impl VMHooks for VMHooksDispatcher {
         x_base_point_handle: i32,
         y_base_point_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: elliptic_curve_get_values")
+        self.handler.use_gas_for_hook("elliptic_curve_get_values");
+        self.handler.ec_get_values(
+            ec_handle,
+            field_order_handle,
+            base_point_order_handle,
+            eq_constant_handle,
+            x_base_point_handle,
+            y_base_point_handle,
+        );
+        0
     }
 
     fn is_reserved_function_name(&self, name_handle: i32) -> i32 {
@@ -1865,11 +2432,15 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_get_original_caller_addr(&self, destination_handle: i32) {
-        panic!("Unavailable: managed_get_original_caller_addr")
+        self.handler
+            .use_gas_for_hook("managed_get_original_caller_addr");
+        self.handler
+            .managed_get_original_caller_addr(destination_handle);
     }
 
     fn managed_get_relayer_addr(&self, destination_handle: i32) {
-        panic!("Unavailable: managed_get_relayer_addr")
+        self.handler.use_gas_for_hook("managed_get_relayer_addr");
+        self.handler.managed_get_relayer_addr(destination_handle);
     }
 
     fn managed_multi_transfer_esdt_nft_execute_by_user(
@@ -1890,7 +2461,10 @@ impl VMHooks for VMHooksDispatcher {
         message_handle: i32,
         sig_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_verify_secp256r1")
+        self.handler.use_gas_for_hook("managed_verify_secp256r1");
+        self.handler
+            .verify_secp256r1_managed(key_handle, message_handle, sig_handle);
+        0
     }
     fn managed_verify_blssignature_share(
         &self,
@@ -1898,7 +2472,11 @@ impl VMHooks for VMHooksDispatcher {
         message_handle: i32,
         sig_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_verify_blssignature_share")
+        self.handler
+            .use_gas_for_hook("managed_verify_blssignature_share");
+        self.handler
+            .verify_bls_signature_share_managed(key_handle, message_handle, sig_handle);
+        0
     }
     fn managed_verify_blsaggregated_signature(
         &self,
@@ -1906,6 +2484,13 @@ impl VMHooks for VMHooksDispatcher {
         message_handle: i32,
         sig_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_verify_blsaggregated_signature")
+        self.handler
+            .use_gas_for_hook("managed_verify_blsaggregated_signature");
+        self.handler.verify_bls_aggregated_signature_managed(
+            key_handle,
+            message_handle,
+            sig_handle,
+        );
+        0
     }
 }
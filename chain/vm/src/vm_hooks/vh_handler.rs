@@ -9,6 +9,7 @@ mod vh_managed_types;
 mod vh_send;
 mod vh_storage;
 
+pub(crate) use vh_blockchain::is_vm_builtin_function;
 pub use vh_blockchain::VMHooksBlockchain;
 pub use vh_call_value::VMHooksCallValue;
 pub use vh_crypto::VMHooksCrypto;
@@ -17,7 +18,8 @@ pub use vh_endpoint_finish::VMHooksEndpointFinish;
 pub use vh_error::{VMHooksError, VMHooksErrorManaged};
 pub use vh_log::VMHooksLog;
 pub use vh_managed_types::{
-    VMHooksBigFloat, VMHooksBigInt, VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes,
+    VMHooksBigFloat, VMHooksBigInt, VMHooksEllipticCurve, VMHooksManagedBuffer, VMHooksManagedMap,
+    VMHooksManagedTypes,
 };
 pub use vh_send::VMHooksSend;
 pub use vh_storage::{VMHooksStorageRead, VMHooksStorageWrite};
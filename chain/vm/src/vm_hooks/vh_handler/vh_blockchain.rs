@@ -9,7 +9,7 @@ use num_traits::Zero;
 
 // The Go VM doesn't do it, but if we change that, we can enable it easily here too via this constant.
 const ESDT_TOKEN_DATA_FUNC_RESETS_VALUES: bool = false;
-const VM_BUILTIN_FUNCTION_NAMES: [&str; 16] = [
+const VM_BUILTIN_FUNCTION_NAMES: [&str; 17] = [
     ESDT_LOCAL_MINT_FUNC_NAME,
     ESDT_LOCAL_BURN_FUNC_NAME,
     ESDT_MULTI_TRANSFER_FUNC_NAME,
@@ -26,8 +26,13 @@ const VM_BUILTIN_FUNCTION_NAMES: [&str; 16] = [
     MIGRATE_USERNAME_FUNC_NAME,
     DELETE_USERNAME_FUNC_NAME,
     UPGRADE_CONTRACT_FUNC_NAME,
+    DELETE_CONTRACT_FUNC_NAME,
 ];
 
+pub(crate) fn is_vm_builtin_function(func_name: &str) -> bool {
+    VM_BUILTIN_FUNCTION_NAMES.contains(&func_name)
+}
+
 pub trait VMHooksBlockchain: VMHooksHandlerSource {
     fn is_contract_address(&self, address_bytes: &[u8]) -> bool {
         let address = VMAddress::from_slice(address_bytes);
@@ -39,6 +44,22 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
             .mb_set(dest_handle, self.input_ref().from.to_vec());
     }
 
+    fn managed_get_original_caller_addr(&self, dest_handle: RawHandle) {
+        self.m_types_lock()
+            .mb_set(dest_handle, self.input_ref().original_caller().to_vec());
+    }
+
+    /// Empty buffer if the transaction is not relayed.
+    fn managed_get_relayer_addr(&self, dest_handle: RawHandle) {
+        let relayer = self
+            .input_ref()
+            .relayer
+            .as_ref()
+            .map(|relayer| relayer.to_vec())
+            .unwrap_or_default();
+        self.m_types_lock().mb_set(dest_handle, relayer);
+    }
+
     fn managed_sc_address(&self, dest_handle: RawHandle) {
         self.m_types_lock()
             .mb_set(dest_handle, self.current_address().to_vec());
@@ -73,11 +94,16 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
 
     fn get_tx_hash(&self, dest: RawHandle) {
         self.m_types_lock()
-            .mb_set(dest, self.input_ref().tx_hash.to_vec());
+            .mb_set(dest, self.input_ref().original_tx_hash().to_vec());
+    }
+
+    fn get_state_root_hash(&self, dest: RawHandle) {
+        self.m_types_lock()
+            .mb_set(dest, self.state_root_hash().to_vec());
     }
 
     fn get_gas_left(&self) -> u64 {
-        self.input_ref().gas_limit
+        self.gas_left()
     }
 
     fn get_block_timestamp(&self) -> u64 {
@@ -171,9 +197,8 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
     }
 
     fn managed_is_builtin_function(&self, function_name_handle: i32) -> bool {
-        VM_BUILTIN_FUNCTION_NAMES.contains(
-            &self
-                .m_types_lock()
+        is_vm_builtin_function(
+            self.m_types_lock()
                 .mb_to_function_name(function_name_handle)
                 .as_str(),
         )
@@ -248,19 +273,26 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
         &self,
         address_handle: RawHandle,
         token_id_handle: RawHandle,
-        _nonce: u64,
+        nonce: u64,
     ) -> bool {
         let address = VMAddress::from_slice(self.m_types_lock().mb_get(address_handle));
         let token_id_bytes = self.m_types_lock().mb_get(token_id_handle).to_vec();
         if let Some(account) = self.account_data(&address) {
             if let Some(esdt_data) = account.esdt.get_by_identifier(token_id_bytes.as_slice()) {
-                return esdt_data.frozen;
+                return esdt_data.is_frozen(nonce);
             }
         }
 
         false
     }
 
+    fn check_esdt_paused(&self, token_id_handle: RawHandle) -> bool {
+        let token_id_bytes = self.m_types_lock().mb_get(token_id_handle).to_vec();
+        self.esdt_token_info(token_id_bytes.as_slice())
+            .map(|token_info| token_info.paused)
+            .unwrap_or_default()
+    }
+
     fn get_esdt_local_roles_bits(&self, token_id_handle: RawHandle) -> u64 {
         let token_id_bytes = self.m_types_lock().mb_get(token_id_handle).to_vec();
         let account = self.current_account_data();
@@ -289,7 +321,7 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
     ) {
         let mut m_types = self.m_types_lock();
         m_types.bi_overwrite(value_handle, instance.balance.clone().into());
-        if esdt_data.frozen {
+        if esdt_data.is_frozen(instance.nonce) {
             m_types.mb_set(properties_handle, vec![1, 0]);
         } else {
             m_types.mb_set(properties_handle, vec![0, 0]);
@@ -1,4 +1,4 @@
-use crate::{crypto_functions, types::RawHandle, vm_hooks::VMHooksHandlerSource};
+use crate::{crypto_functions, types::RawHandle, vm_err_msg, vm_hooks::VMHooksHandlerSource};
 
 pub trait VMHooksCrypto: VMHooksHandlerSource {
     fn sha256_managed(&self, dest: RawHandle, data_handle: RawHandle) {
@@ -19,6 +19,13 @@ pub trait VMHooksCrypto: VMHooksHandlerSource {
         types.mb_set(dest, result_bytes[..].to_vec());
     }
 
+    fn ripemd160_managed(&self, dest: RawHandle, data_handle: RawHandle) {
+        let mut types = self.m_types_lock();
+        let data = types.mb_get(data_handle);
+        let result_bytes = crypto_functions::ripemd160(data);
+        types.mb_set(dest, result_bytes[..].to_vec());
+    }
+
     /// Should crash if the signature is invalid.
     fn verify_ed25519_managed(&self, key: RawHandle, message: RawHandle, signature: RawHandle) {
         let types = self.m_types_lock();
@@ -30,4 +37,112 @@ pub trait VMHooksCrypto: VMHooksHandlerSource {
             self.vm_error("invalid signature");
         }
     }
+
+    /// Uses double sha256 to hash the message, same as the Go VM.
+    fn verify_secp256k1_managed(
+        &self,
+        key: RawHandle,
+        message: RawHandle,
+        signature: RawHandle,
+    ) -> bool {
+        self.verify_custom_secp256k1_managed(
+            key,
+            message,
+            signature,
+            crypto_functions::ECDSA_DOUBLE_SHA256,
+        )
+    }
+
+    /// Does not crash if the signature is invalid, the result is returned instead.
+    fn verify_custom_secp256k1_managed(
+        &self,
+        key: RawHandle,
+        message: RawHandle,
+        signature: RawHandle,
+        hash_type: u8,
+    ) -> bool {
+        let types = self.m_types_lock();
+        let key = types.mb_get(key);
+        let message = types.mb_get(message);
+        let signature = types.mb_get(signature);
+        crypto_functions::verify_secp256k1(key, message, signature, hash_type)
+    }
+
+    fn encode_secp256k1_der_signature_managed(
+        &self,
+        r_handle: RawHandle,
+        s_handle: RawHandle,
+        dest: RawHandle,
+    ) {
+        let mut types = self.m_types_lock();
+        let r = types.mb_get(r_handle);
+        let s = types.mb_get(s_handle);
+        let signature = crypto_functions::encode_secp256k1_der_signature(r, s);
+        types.mb_set(dest, signature);
+    }
+
+    /// Should crash if the signature is invalid.
+    fn verify_secp256r1_managed(&self, key: RawHandle, message: RawHandle, signature: RawHandle) {
+        let types = self.m_types_lock();
+        let key = types.mb_get(key);
+        let message = types.mb_get(message);
+        let signature = types.mb_get(signature);
+        let sig_valid = crypto_functions::verify_secp256r1(key, message, signature);
+        if !sig_valid {
+            self.vm_error(vm_err_msg::SIGNATURE_IS_INVALID);
+        }
+    }
+
+    /// Should crash if the signature is invalid.
+    fn verify_bls(&self, key: &[u8], message: &[u8], signature: &[u8]) {
+        if !crypto_functions::verify_bls(key, message, signature) {
+            self.vm_error(vm_err_msg::SIGNATURE_IS_INVALID);
+        }
+    }
+
+    /// Should crash if the signature is invalid.
+    fn verify_bls_managed(&self, key: RawHandle, message: RawHandle, signature: RawHandle) {
+        let types = self.m_types_lock();
+        let key = types.mb_get(key);
+        let message = types.mb_get(message);
+        let signature = types.mb_get(signature);
+        self.verify_bls(key, message, signature);
+    }
+
+    /// Should crash if the signature is invalid.
+    fn verify_bls_signature_share_managed(
+        &self,
+        key: RawHandle,
+        message: RawHandle,
+        signature: RawHandle,
+    ) {
+        let types = self.m_types_lock();
+        let key = types.mb_get(key);
+        let message = types.mb_get(message);
+        let signature = types.mb_get(signature);
+        let sig_valid = crypto_functions::verify_bls_signature_share(key, message, signature);
+        if !sig_valid {
+            self.vm_error(vm_err_msg::SIGNATURE_IS_INVALID);
+        }
+    }
+
+    /// The keys are given as a `ManagedVec<ManagedBuffer>`.
+    ///
+    /// Should crash if the signature is invalid.
+    fn verify_bls_aggregated_signature_managed(
+        &self,
+        keys: RawHandle,
+        message: RawHandle,
+        signature: RawHandle,
+    ) {
+        let types = self.m_types_lock();
+        let keys = types.mb_get_vec_of_bytes(keys);
+        let message = types.mb_get(message);
+        let signature = types.mb_get(signature);
+        let sig_valid =
+            crypto_functions::verify_bls_aggregated_signature(&keys, message, signature);
+        if !sig_valid {
+            self.vm_error(vm_err_msg::SIGNATURE_IS_INVALID);
+        }
+    }
 }
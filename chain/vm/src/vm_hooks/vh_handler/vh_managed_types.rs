@@ -1,10 +1,12 @@
 mod vh_big_float;
 mod vh_big_int;
+mod vh_elliptic_curve;
 mod vh_managed_buffer;
 mod vh_managed_map;
 
 pub use vh_big_float::VMHooksBigFloat;
 pub use vh_big_int::VMHooksBigInt;
+pub use vh_elliptic_curve::VMHooksEllipticCurve;
 pub use vh_managed_buffer::VMHooksManagedBuffer;
 pub use vh_managed_map::VMHooksManagedMap;
 
@@ -18,7 +20,13 @@ use super::VMHooksError;
 ///
 /// It is also the trait that unifies all managed type functionality.
 pub trait VMHooksManagedTypes:
-    VMHooksBigInt + VMHooksManagedBuffer + VMHooksManagedMap + VMHooksBigFloat + VMHooksError + Debug
+    VMHooksBigInt
+    + VMHooksManagedBuffer
+    + VMHooksManagedMap
+    + VMHooksBigFloat
+    + VMHooksEllipticCurve
+    + VMHooksError
+    + Debug
 {
     fn mb_to_big_int_unsigned(&self, buffer_handle: RawHandle, bi_handle: RawHandle) {
         let bytes = self.m_types_lock().mb_to_bytes(buffer_handle);
@@ -42,6 +50,22 @@ pub trait VMHooksManagedTypes:
         self.m_types_lock().mb_set(buffer_handle, bi_bytes);
     }
 
+    fn mb_to_big_float(&self, buffer_handle: RawHandle, bf_handle: RawHandle) {
+        let bytes = self.m_types_lock().mb_to_bytes(buffer_handle);
+        let result = self.m_types_lock().bf_set_gob_bytes(bf_handle, &bytes);
+        if let Err(message) = result {
+            self.vm_error(message);
+        }
+    }
+
+    fn mb_from_big_float(&self, buffer_handle: RawHandle, bf_handle: RawHandle) {
+        let result = self.m_types_lock().bf_get_gob_bytes(bf_handle);
+        match result {
+            Ok(bytes) => self.m_types_lock().mb_set(buffer_handle, bytes),
+            Err(message) => self.vm_error(message),
+        }
+    }
+
     fn bi_to_string(&self, bi_handle: RawHandle, str_handle: RawHandle) {
         let bi = self.m_types_lock().bi_get(bi_handle);
         let s = bi.to_string();
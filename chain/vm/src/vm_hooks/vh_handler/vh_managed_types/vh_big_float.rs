@@ -12,8 +12,10 @@ use num_traits::{ToPrimitive, Zero};
 use std::convert::TryInto;
 
 macro_rules! binary_op_method {
-    ($method_name:ident, $rust_op_name:ident) => {
+    ($method_name:ident, $rust_op_name:ident, $not_permitted_err:expr) => {
         fn $method_name(&self, dest: RawHandle, x: RawHandle, y: RawHandle) {
+            self.bf_check_normalized(x, $not_permitted_err);
+            self.bf_check_normalized(y, $not_permitted_err);
             let bf_x = self.m_types_lock().bf_get_f64(x);
             let bf_y = self.m_types_lock().bf_get_f64(y);
             let result = bf_x.$rust_op_name(bf_y);
@@ -25,9 +27,12 @@ macro_rules! binary_op_method {
 macro_rules! unary_op_method {
     ($method_name:ident, $rust_op_name:ident) => {
         fn $method_name(&self, dest: RawHandle, x: RawHandle) {
-            let bf_x = self.m_types_lock().bf_get_f64(x);
+            let mut managed_types = self.m_types_lock();
+            let bf_x = managed_types.bf_get_f64(x);
+            let normalized = managed_types.bf_is_normalized(x);
             let result = bf_x.$rust_op_name();
-            self.m_types_lock().bf_overwrite(dest, result);
+            managed_types.bf_overwrite(dest, result);
+            managed_types.bf_set_normalized(dest, normalized);
         }
     };
 }
@@ -43,6 +48,14 @@ macro_rules! unary_op_method_big_int_handle {
 }
 
 pub trait VMHooksBigFloat: VMHooksHandlerSource + VMHooksError {
+    /// The Go VM accepts mantissas that are not normalized when decoding,
+    /// but fails when doing arithmetic on them.
+    fn bf_check_normalized(&self, x: RawHandle, not_permitted_err: &str) {
+        if !self.m_types_lock().bf_is_normalized(x) {
+            self.vm_error(not_permitted_err);
+        }
+    }
+
     fn bf_from_parts(&self, integral_part: i32, fractional_part: i32, exponent: i32) -> RawHandle {
         if exponent > 0 {
             self.vm_error(vm_err_msg::EXPONENT_IS_POSITIVE);
@@ -93,10 +106,10 @@ pub trait VMHooksBigFloat: VMHooksHandlerSource + VMHooksError {
         managed_types.big_float_map.insert_new_handle_raw(value)
     }
 
-    binary_op_method!(bf_add, add);
-    binary_op_method!(bf_sub, sub);
-    binary_op_method!(bf_mul, mul);
-    binary_op_method!(bf_div, div);
+    binary_op_method!(bf_add, add, vm_err_msg::BIG_FLOAT_ADD_NOT_PERMITTED);
+    binary_op_method!(bf_sub, sub, vm_err_msg::BIG_FLOAT_SUB_NOT_PERMITTED);
+    binary_op_method!(bf_mul, mul, vm_err_msg::BIG_FLOAT_MUL_NOT_PERMITTED);
+    binary_op_method!(bf_div, div, vm_err_msg::BIG_FLOAT_QUO_NOT_PERMITTED);
 
     unary_op_method!(bf_abs, abs);
     unary_op_method!(bf_neg, neg);
@@ -131,11 +144,15 @@ pub trait VMHooksBigFloat: VMHooksHandlerSource + VMHooksError {
     }
 
     fn bf_clone(&self, dest: RawHandle, x: RawHandle) {
-        let value = self.m_types_lock().bf_get_f64(x);
-        self.m_types_lock().bf_overwrite(dest, value);
+        let mut managed_types = self.m_types_lock();
+        let value = managed_types.bf_get_f64(x);
+        let normalized = managed_types.bf_is_normalized(x);
+        managed_types.bf_overwrite(dest, value);
+        managed_types.bf_set_normalized(dest, normalized);
     }
 
     fn bf_sqrt(&self, dest: RawHandle, x: RawHandle) {
+        self.bf_check_normalized(x, vm_err_msg::BIG_FLOAT_SQRT_NOT_PERMITTED);
         let bf_x = self.m_types_lock().bf_get_f64(x);
         if bf_x < 0f64 {
             self.vm_error(vm_err_msg::BAD_BOUNDS_LOWER);
@@ -145,6 +162,7 @@ pub trait VMHooksBigFloat: VMHooksHandlerSource + VMHooksError {
     }
 
     fn bf_pow(&self, dest: RawHandle, x: RawHandle, exp: i32) {
+        self.bf_check_normalized(x, vm_err_msg::BIG_FLOAT_MUL_NOT_PERMITTED);
         let value = self.m_types_lock().bf_get_f64(x);
         self.m_types_lock().bf_overwrite(dest, value.powi(exp));
     }
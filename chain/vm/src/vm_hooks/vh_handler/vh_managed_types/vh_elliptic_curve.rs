@@ -0,0 +1,318 @@
+use crate::{
+    crypto_functions::EllipticCurve,
+    types::RawHandle,
+    vm_err_msg,
+    vm_hooks::{VMHooksError, VMHooksHandlerSource},
+};
+use num_bigint::{BigInt, BigUint};
+
+/// Elliptic curve operations, backed by the NIST P-224, P-256, P-384 and P-521 curves.
+///
+/// Points are passed around as pairs of big int handles, same as in the Go VM.
+pub trait VMHooksEllipticCurve: VMHooksHandlerSource + VMHooksError {
+    fn ec_create_from_name(&self, name: &[u8]) -> RawHandle {
+        let Some(curve) = EllipticCurve::from_name(name) else {
+            self.vm_error(vm_err_msg::EC_CURVE_NOT_SUPPORTED);
+        };
+        self.m_types_lock().ec_new(curve)
+    }
+
+    fn ec_create_from_name_mb(&self, name_handle: RawHandle) -> RawHandle {
+        let name = self.m_types_lock().mb_to_bytes(name_handle);
+        self.ec_create_from_name(name.as_slice())
+    }
+
+    fn ec_get_values(
+        &self,
+        ec_handle: RawHandle,
+        field_order_handle: RawHandle,
+        base_point_order_handle: RawHandle,
+        eq_constant_handle: RawHandle,
+        x_base_point_handle: RawHandle,
+        y_base_point_handle: RawHandle,
+    ) {
+        let mut managed_types = self.m_types_lock();
+        let curve = managed_types.ec_get(ec_handle);
+        managed_types.bi_overwrite(field_order_handle, curve.field_order.into());
+        managed_types.bi_overwrite(base_point_order_handle, curve.base_point_order.into());
+        managed_types.bi_overwrite(eq_constant_handle, curve.eq_constant.into());
+        managed_types.bi_overwrite(x_base_point_handle, curve.x_base_point.into());
+        managed_types.bi_overwrite(y_base_point_handle, curve.y_base_point.into());
+    }
+
+    fn ec_curve_length(&self, ec_handle: RawHandle) -> u32 {
+        self.m_types_lock().ec_get(ec_handle).size_of_field
+    }
+
+    fn ec_private_key_byte_length(&self, ec_handle: RawHandle) -> u32 {
+        self.m_types_lock().ec_get(ec_handle).byte_length() as u32
+    }
+
+    fn ec_is_on_curve(
+        &self,
+        ec_handle: RawHandle,
+        x_handle: RawHandle,
+        y_handle: RawHandle,
+    ) -> bool {
+        let managed_types = self.m_types_lock();
+        let curve = managed_types.ec_get(ec_handle);
+        let x = managed_types.bi_get(x_handle).to_biguint();
+        let y = managed_types.bi_get(y_handle).to_biguint();
+        match (x, y) {
+            (Some(x), Some(y)) => curve.is_on_curve(&x, &y),
+            _ => false,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn ec_add(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        x_first_handle: RawHandle,
+        y_first_handle: RawHandle,
+        x_second_handle: RawHandle,
+        y_second_handle: RawHandle,
+    ) {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        let (x1, y1) = ec_get_point(self, &curve, x_first_handle, y_first_handle);
+        let (x2, y2) = ec_get_point(self, &curve, x_second_handle, y_second_handle);
+        let (x, y) = curve.add(&x1, &y1, &x2, &y2);
+        ec_set_point(self, x_result_handle, y_result_handle, x, y);
+    }
+
+    fn ec_double(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        x_handle: RawHandle,
+        y_handle: RawHandle,
+    ) {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        let (x, y) = ec_get_point(self, &curve, x_handle, y_handle);
+        let (x, y) = curve.double(&x, &y);
+        ec_set_point(self, x_result_handle, y_result_handle, x, y);
+    }
+
+    fn ec_scalar_mult(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        x_handle: RawHandle,
+        y_handle: RawHandle,
+        data: &[u8],
+    ) {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        let (x, y) = ec_get_point(self, &curve, x_handle, y_handle);
+        let (x, y) = curve.scalar_mult(&x, &y, data);
+        ec_set_point(self, x_result_handle, y_result_handle, x, y);
+    }
+
+    fn ec_scalar_mult_managed(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        x_handle: RawHandle,
+        y_handle: RawHandle,
+        data_handle: RawHandle,
+    ) {
+        let data = self.m_types_lock().mb_to_bytes(data_handle);
+        self.ec_scalar_mult(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            x_handle,
+            y_handle,
+            data.as_slice(),
+        );
+    }
+
+    fn ec_scalar_base_mult(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data: &[u8],
+    ) {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        let (x, y) = curve.scalar_base_mult(data);
+        ec_set_point(self, x_result_handle, y_result_handle, x, y);
+    }
+
+    fn ec_scalar_base_mult_managed(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data_handle: RawHandle,
+    ) {
+        let data = self.m_types_lock().mb_to_bytes(data_handle);
+        self.ec_scalar_base_mult(x_result_handle, y_result_handle, ec_handle, data.as_slice());
+    }
+
+    fn ec_marshal(
+        &self,
+        ec_handle: RawHandle,
+        x_handle: RawHandle,
+        y_handle: RawHandle,
+    ) -> Vec<u8> {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        let (x, y) = ec_get_point(self, &curve, x_handle, y_handle);
+        curve.marshal(&x, &y)
+    }
+
+    fn ec_marshal_managed(
+        &self,
+        ec_handle: RawHandle,
+        x_handle: RawHandle,
+        y_handle: RawHandle,
+        result_handle: RawHandle,
+    ) -> usize {
+        let bytes = self.ec_marshal(ec_handle, x_handle, y_handle);
+        let len = bytes.len();
+        self.m_types_lock().mb_set(result_handle, bytes);
+        len
+    }
+
+    fn ec_marshal_compressed(
+        &self,
+        ec_handle: RawHandle,
+        x_handle: RawHandle,
+        y_handle: RawHandle,
+    ) -> Vec<u8> {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        let (x, y) = ec_get_point(self, &curve, x_handle, y_handle);
+        curve.marshal_compressed(&x, &y)
+    }
+
+    fn ec_marshal_compressed_managed(
+        &self,
+        ec_handle: RawHandle,
+        x_handle: RawHandle,
+        y_handle: RawHandle,
+        result_handle: RawHandle,
+    ) -> usize {
+        let bytes = self.ec_marshal_compressed(ec_handle, x_handle, y_handle);
+        let len = bytes.len();
+        self.m_types_lock().mb_set(result_handle, bytes);
+        len
+    }
+
+    fn ec_unmarshal(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data: &[u8],
+    ) {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        if data.len() != 1 + 2 * curve.byte_length() {
+            self.vm_error(vm_err_msg::EC_BUFFER_LENGTH_NOT_CORRECT);
+        }
+        let Some((x, y)) = curve.unmarshal(data) else {
+            self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE);
+        };
+        ec_set_point(self, x_result_handle, y_result_handle, x, y);
+    }
+
+    fn ec_unmarshal_managed(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data_handle: RawHandle,
+    ) {
+        let data = self.m_types_lock().mb_to_bytes(data_handle);
+        self.ec_unmarshal(x_result_handle, y_result_handle, ec_handle, data.as_slice());
+    }
+
+    fn ec_unmarshal_compressed(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data: &[u8],
+    ) {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        if data.len() != 1 + curve.byte_length() {
+            self.vm_error(vm_err_msg::EC_BUFFER_LENGTH_NOT_CORRECT);
+        }
+        let Some((x, y)) = curve.unmarshal_compressed(data) else {
+            self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE);
+        };
+        ec_set_point(self, x_result_handle, y_result_handle, x, y);
+    }
+
+    fn ec_unmarshal_compressed_managed(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data_handle: RawHandle,
+    ) {
+        let data = self.m_types_lock().mb_to_bytes(data_handle);
+        self.ec_unmarshal_compressed(x_result_handle, y_result_handle, ec_handle, data.as_slice());
+    }
+
+    /// Generates a key pair using the blockchain random number generator.
+    ///
+    /// Writes the public key to the given big int handles and returns the private key.
+    fn ec_generate_key(
+        &self,
+        x_pub_key_handle: RawHandle,
+        y_pub_key_handle: RawHandle,
+        ec_handle: RawHandle,
+    ) -> Vec<u8> {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        let (private_key, x, y) = curve.generate_key(|length| self.random_next_bytes(length));
+        ec_set_point(self, x_pub_key_handle, y_pub_key_handle, x, y);
+        private_key
+    }
+
+    fn ec_generate_key_managed(
+        &self,
+        x_pub_key_handle: RawHandle,
+        y_pub_key_handle: RawHandle,
+        ec_handle: RawHandle,
+        result_handle: RawHandle,
+    ) {
+        let private_key = self.ec_generate_key(x_pub_key_handle, y_pub_key_handle, ec_handle);
+        self.m_types_lock().mb_set(result_handle, private_key);
+    }
+}
+
+/// Loads a point from a pair of big int handles, signalling an error if it is not on the curve.
+fn ec_get_point<H: VMHooksEllipticCurve + ?Sized>(
+    handler: &H,
+    curve: &EllipticCurve,
+    x_handle: RawHandle,
+    y_handle: RawHandle,
+) -> (BigUint, BigUint) {
+    let (x, y) = {
+        let managed_types = handler.m_types_lock();
+        (
+            managed_types.bi_get(x_handle).to_biguint(),
+            managed_types.bi_get(y_handle).to_biguint(),
+        )
+    };
+    match (x, y) {
+        (Some(x), Some(y)) if curve.is_on_curve(&x, &y) => (x, y),
+        _ => handler.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE),
+    }
+}
+
+fn ec_set_point<H: VMHooksEllipticCurve + ?Sized>(
+    handler: &H,
+    x_handle: RawHandle,
+    y_handle: RawHandle,
+    x: BigUint,
+    y: BigUint,
+) {
+    let mut managed_types = handler.m_types_lock();
+    managed_types.bi_overwrite(x_handle, BigInt::from(x));
+    managed_types.bi_overwrite(y_handle, BigInt::from(y));
+}
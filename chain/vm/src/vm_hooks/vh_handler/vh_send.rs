@@ -1,7 +1,7 @@
 use crate::{
     chain_core::builtin_func_names::{
-        ESDT_MULTI_TRANSFER_FUNC_NAME, ESDT_NFT_TRANSFER_FUNC_NAME, ESDT_TRANSFER_FUNC_NAME,
-        UPGRADE_CONTRACT_FUNC_NAME,
+        DELETE_CONTRACT_FUNC_NAME, ESDT_MULTI_TRANSFER_FUNC_NAME, ESDT_NFT_TRANSFER_FUNC_NAME,
+        ESDT_TRANSFER_FUNC_NAME, UPGRADE_CONTRACT_FUNC_NAME,
     },
     tx_mock::{AsyncCallTxData, Promise, TxFunctionName, TxTokenTransfer},
     types::{top_encode_big_uint, top_encode_u64, RawHandle, VMAddress, VMCodeMetadata},
@@ -22,12 +22,18 @@ fn append_endpoint_name_and_args(
 }
 
 pub trait VMHooksSend: VMHooksHandlerSource {
+    /// Code to be copied when deploying or upgrading from source.
+    fn source_contract_code(&self, source_contract_address: &VMAddress) -> Vec<u8> {
+        self.account_code(source_contract_address)
+            .unwrap_or_else(|| self.vm_error(vm_err_msg::CONTRACT_NOT_FOUND))
+    }
+
     fn perform_transfer_execute_esdt(
         &self,
         to: VMAddress,
         token: Vec<u8>,
         amount: num_bigint::BigUint,
-        _gas_limit: u64,
+        gas_limit: u64,
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) {
@@ -37,6 +43,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         self.perform_transfer_execute(
             to,
             num_bigint::BigUint::zero(),
+            gas_limit,
             ESDT_TRANSFER_FUNC_NAME.into(),
             args,
         );
@@ -49,7 +56,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         token: Vec<u8>,
         nonce: u64,
         amount: num_bigint::BigUint,
-        _gas_limit: u64,
+        gas_limit: u64,
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) {
@@ -67,6 +74,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         self.perform_transfer_execute(
             contract_address,
             num_bigint::BigUint::zero(),
+            gas_limit,
             ESDT_NFT_TRANSFER_FUNC_NAME.into(),
             args,
         );
@@ -76,7 +84,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         &self,
         to: VMAddress,
         payments: Vec<TxTokenTransfer>,
-        _gas_limit: u64,
+        gas_limit: u64,
         endpoint_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) {
@@ -98,6 +106,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         self.perform_transfer_execute(
             contract_address,
             num_bigint::BigUint::zero(),
+            gas_limit,
             ESDT_MULTI_TRANSFER_FUNC_NAME.into(),
             args,
         );
@@ -116,11 +125,24 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         self.perform_async_call(to, egld_value, UPGRADE_CONTRACT_FUNC_NAME.into(), arguments)
     }
 
+    /// Deletes a child contract of the currently executing contract, synchronously.
+    ///
+    /// The arguments are passed on to the `DeleteContract` builtin function.
+    fn perform_delete_contract(&self, to: VMAddress, gas: u64, args: Vec<Vec<u8>>) {
+        self.perform_execute_on_dest_context(
+            to,
+            num_bigint::BigUint::zero(),
+            gas,
+            DELETE_CONTRACT_FUNC_NAME.into(),
+            args,
+        );
+    }
+
     fn transfer_value_execute(
         &self,
         to_handle: RawHandle,
         amount_handle: RawHandle,
-        _gas_limit: u64,
+        gas_limit: u64,
         endpoint_name_handle: RawHandle,
         arg_buffer_handle: RawHandle,
     ) -> Result<(), &'static [u8]> {
@@ -131,7 +153,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
             .mb_to_function_name(endpoint_name_handle);
         let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
 
-        self.perform_transfer_execute(recipient, egld_value, endpoint_name, arg_buffer);
+        self.perform_transfer_execute(recipient, egld_value, gas_limit, endpoint_name, arg_buffer);
 
         Ok(())
     }
@@ -206,10 +228,12 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         arg_buffer_handle: RawHandle,
         success_callback: &[u8],
         error_callback: &[u8],
-        _gas: u64,
-        _extra_gas_for_callback: u64,
+        gas: u64,
+        extra_gas_for_callback: u64,
         callback_closure_handle: RawHandle,
     ) {
+        self.check_not_readonly();
+
         let contract_address = self.current_address().clone();
         let to = self.m_types_lock().mb_to_address(to_handle);
         let egld_value = self.m_types_lock().bu_get(egld_value_handle);
@@ -231,25 +255,30 @@ pub trait VMHooksSend: VMHooksHandlerSource {
             call_value: egld_value,
             endpoint_name,
             arguments: arg_buffer,
+            gas_limit: gas.min(self.gas_left()),
             tx_hash,
+            original_tx_hash: self.input_ref().original_tx_hash().clone(),
+            prev_tx_hash: self.input_ref().prev_tx_hash().clone(),
+            original_caller: self.input_ref().original_caller().clone(),
+            relayer: self.input_ref().relayer.clone(),
         };
 
-        let promise = Promise {
+        let mut tx_result = self.result_lock();
+        let call = call.into_smart_contract_result(tx_result.all_calls.len());
+        tx_result.all_calls.push(call.clone());
+        tx_result.pending_calls.promises.push(Promise {
             call,
             success_callback: success_callback.into(),
             error_callback: error_callback.into(),
             callback_closure_data,
-        };
-
-        let mut tx_result = self.result_lock();
-        tx_result.all_calls.push(promise.call.clone());
-        tx_result.pending_calls.promises.push(promise);
+            extra_gas_for_callback,
+        });
     }
 
     #[allow(clippy::too_many_arguments)]
     fn deploy_contract(
         &self,
-        _gas: u64,
+        gas: u64,
         egld_value_handle: RawHandle,
         code_handle: RawHandle,
         code_metadata_handle: RawHandle,
@@ -265,7 +294,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
 
         let (new_address, result) =
-            self.perform_deploy(egld_value, code, code_metadata, arg_buffer);
+            self.perform_deploy(egld_value, gas, code, code_metadata, arg_buffer);
 
         self.m_types_lock()
             .mb_set(new_address_handle, new_address.to_vec());
@@ -276,7 +305,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
     #[allow(clippy::too_many_arguments)]
     fn deploy_from_source_contract(
         &self,
-        _gas: u64,
+        gas: u64,
         egld_value_handle: RawHandle,
         source_contract_address_handle: RawHandle,
         code_metadata_handle: RawHandle,
@@ -288,14 +317,19 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         let source_contract_address = self
             .m_types_lock()
             .mb_to_address(source_contract_address_handle);
-        let source_contract_code = self.account_code(&source_contract_address);
+        let source_contract_code = self.source_contract_code(&source_contract_address);
         let code_metadata = self
             .m_types_lock()
             .mb_to_code_metadata(code_metadata_handle);
         let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
 
-        let (new_address, result) =
-            self.perform_deploy(egld_value, source_contract_code, code_metadata, arg_buffer);
+        let (new_address, result) = self.perform_deploy(
+            egld_value,
+            gas,
+            source_contract_code,
+            code_metadata,
+            arg_buffer,
+        );
 
         self.m_types_lock()
             .mb_set(new_address_handle, new_address.to_vec());
@@ -317,7 +351,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         let source_contract_address = self
             .m_types_lock()
             .mb_to_address(source_contract_address_handle);
-        let source_contract_code = self.account_code(&source_contract_address);
+        let source_contract_code = self.source_contract_code(&source_contract_address);
         let code_metadata = self
             .m_types_lock()
             .mb_to_code_metadata(code_metadata_handle);
@@ -352,9 +386,43 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         self.perform_upgrade_contract(to, egld_value, code, code_metadata, arg_buffer)
     }
 
+    fn delete_contract(
+        &self,
+        sc_address_handle: RawHandle,
+        gas: u64,
+        arg_buffer_handle: RawHandle,
+    ) {
+        let to = self.m_types_lock().mb_to_address(sc_address_handle);
+        let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
+        self.perform_delete_contract(to, gas, arg_buffer);
+    }
+
     fn execute_on_dest_context_raw(
         &self,
-        _gas: u64,
+        gas: u64,
+        to_handle: RawHandle,
+        egld_value_handle: RawHandle,
+        endpoint_name_handle: RawHandle,
+        arg_buffer_handle: RawHandle,
+        result_handle: RawHandle,
+    ) {
+        let to = self.m_types_lock().mb_to_address(to_handle);
+        let egld_value = self.m_types_lock().bu_get(egld_value_handle);
+        let endpoint_name = self
+            .m_types_lock()
+            .mb_to_function_name(endpoint_name_handle);
+        let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
+
+        let result =
+            self.perform_execute_on_dest_context(to, egld_value, gas, endpoint_name, arg_buffer);
+
+        self.m_types_lock()
+            .mb_set_vec_of_bytes(result_handle, result);
+    }
+
+    fn execute_on_same_context_raw(
+        &self,
+        gas: u64,
         to_handle: RawHandle,
         egld_value_handle: RawHandle,
         endpoint_name_handle: RawHandle,
@@ -369,7 +437,27 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
 
         let result =
-            self.perform_execute_on_dest_context(to, egld_value, endpoint_name, arg_buffer);
+            self.perform_execute_on_same_context(to, egld_value, gas, endpoint_name, arg_buffer);
+
+        self.m_types_lock()
+            .mb_set_vec_of_bytes(result_handle, result);
+    }
+
+    fn execute_read_only_raw(
+        &self,
+        gas: u64,
+        to_handle: RawHandle,
+        endpoint_name_handle: RawHandle,
+        arg_buffer_handle: RawHandle,
+        result_handle: RawHandle,
+    ) {
+        let to = self.m_types_lock().mb_to_address(to_handle);
+        let endpoint_name = self
+            .m_types_lock()
+            .mb_to_function_name(endpoint_name_handle);
+        let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
+
+        let result = self.perform_execute_read_only(to, gas, endpoint_name, arg_buffer);
 
         self.m_types_lock()
             .mb_set_vec_of_bytes(result_handle, result);
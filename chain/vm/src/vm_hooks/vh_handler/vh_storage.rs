@@ -1,10 +1,33 @@
 use crate::{
     types::{RawHandle, VMAddress},
     vm_hooks::VMHooksHandlerSource,
+    world_mock::reserved::STORAGE_TIME_LOCK_PREFIX,
 };
 
 use super::VMHooksManagedTypes;
 
+/// Storage status codes returned by the Go VM when writing to storage.
+const STORAGE_UNCHANGED: i32 = 0;
+const STORAGE_MODIFIED: i32 = 1;
+const STORAGE_ADDED: i32 = 2;
+const STORAGE_DELETED: i32 = 3;
+
+fn storage_lock_key(key: &[u8]) -> Vec<u8> {
+    [STORAGE_TIME_LOCK_PREFIX, key].concat()
+}
+
+fn storage_status(old_value: &[u8], new_value: &[u8]) -> i32 {
+    if old_value == new_value {
+        STORAGE_UNCHANGED
+    } else if old_value.is_empty() {
+        STORAGE_ADDED
+    } else if new_value.is_empty() {
+        STORAGE_DELETED
+    } else {
+        STORAGE_MODIFIED
+    }
+}
+
 pub trait VMHooksStorageRead: VMHooksHandlerSource {
     fn storage_load_managed_buffer_raw(&self, key_handle: RawHandle, dest: RawHandle) {
         let value = self.storage_read(self.m_types_lock().mb_get(key_handle));
@@ -21,6 +44,31 @@ pub trait VMHooksStorageRead: VMHooksHandlerSource {
         let value = self.storage_read_any_address(&address, self.m_types_lock().mb_get(key_handle));
         self.m_types_lock().mb_set(dest, value);
     }
+
+    /// The timestamp until which the key is locked, 0 if there is no lock.
+    ///
+    /// Same as the Go VM, only the lowest 64 bits of the stored value are considered.
+    fn get_storage_lock(&self, key: &[u8]) -> i64 {
+        let value = self.storage_read(&storage_lock_key(key));
+        value
+            .iter()
+            .fold(0u64, |acc, &byte| (acc << 8) | byte as u64) as i64
+    }
+
+    /// 1 if the lock expires after the current block timestamp, 0 otherwise, -1 if the lock is invalid.
+    fn is_storage_locked(&self, key: &[u8]) -> i32 {
+        let lock_timestamp = self.get_storage_lock(key);
+        if lock_timestamp < 0 {
+            return -1;
+        }
+
+        let block_timestamp = self.get_current_block_info().block_timestamp;
+        if lock_timestamp as u64 <= block_timestamp {
+            0
+        } else {
+            1
+        }
+    }
 }
 
 pub trait VMHooksStorageWrite: VMHooksHandlerSource + VMHooksManagedTypes {
@@ -30,4 +78,23 @@ pub trait VMHooksStorageWrite: VMHooksHandlerSource + VMHooksManagedTypes {
         let value_bytes = types.mb_get(value_handle);
         self.storage_write(key_bytes, value_bytes);
     }
+
+    /// Locks the key until the given timestamp. Returns the storage status, as the Go VM does.
+    ///
+    /// The timestamp is stored as a big-endian unsigned number, without leading zeroes.
+    fn set_storage_lock(&self, key: &[u8], lock_timestamp: i64) -> i32 {
+        let lock_key = storage_lock_key(key);
+        let magnitude = lock_timestamp.unsigned_abs().to_be_bytes();
+        let leading_zeros = magnitude.iter().take_while(|&&byte| byte == 0).count();
+        let new_value = &magnitude[leading_zeros..];
+
+        let old_value = self.storage_read(&lock_key);
+        self.storage_write_reserved(&lock_key, new_value);
+        storage_status(&old_value, new_value)
+    }
+
+    fn clear_storage_lock(&self, key: &[u8]) -> i32 {
+        self.set_storage_lock(key, 0);
+        1
+    }
 }
@@ -2,22 +2,26 @@ use std::sync::{Arc, MutexGuard};
 
 use multiversx_chain_core::types::ReturnCode;
 use multiversx_chain_vm_executor::BreakpointValue;
+use num_bigint::BigUint;
+use num_traits::Zero;
 
 use crate::{
+    schedule::GasSchedule,
     tx_execution::execute_current_tx_context_input,
     tx_mock::{
         async_call_tx_input, AsyncCallTxData, BackTransfers, BlockchainUpdate, CallType, TxCache,
-        TxContext, TxFunctionName, TxInput, TxManagedTypes, TxPanic, TxResult,
+        TxContext, TxFunctionName, TxInput, TxLog, TxManagedTypes, TxPanic, TxResult,
     },
-    types::{VMAddress, VMCodeMetadata},
+    types::{VMAddress, VMCodeMetadata, H256},
     vm_err_msg,
     vm_hooks::{
-        VMHooksBigFloat, VMHooksBigInt, VMHooksBlockchain, VMHooksCallValue, VMHooksCrypto,
-        VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError, VMHooksErrorManaged,
-        VMHooksHandler, VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
+        is_vm_builtin_function, VMHooksBigFloat, VMHooksBigInt, VMHooksBlockchain,
+        VMHooksCallValue, VMHooksCrypto, VMHooksEllipticCurve, VMHooksEndpointArgument,
+        VMHooksEndpointFinish, VMHooksError, VMHooksErrorManaged, VMHooksHandler,
+        VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
         VMHooksManagedTypes, VMHooksSend, VMHooksStorageRead, VMHooksStorageWrite,
     },
-    world_mock::{reserved::STORAGE_RESERVED_PREFIX, AccountData, BlockInfo},
+    world_mock::{reserved::STORAGE_RESERVED_PREFIX, AccountData, BlockInfo, EsdtTokenInfo},
 };
 
 /// A simple wrapper around a managed type container RefCell.
@@ -50,6 +54,20 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         self.0.input_ref()
     }
 
+    fn gas_schedule(&self) -> Option<&GasSchedule> {
+        self.0.vm_ref.gas_schedule.as_ref()
+    }
+
+    fn use_gas(&self, gas: u64) {
+        if !self.0.use_gas(gas) {
+            self.halt_with_error(ReturnCode::OutOfGas, vm_err_msg::OUT_OF_GAS);
+        }
+    }
+
+    fn gas_left(&self) -> u64 {
+        self.0.gas_left()
+    }
+
     fn random_next_bytes(&self, length: usize) -> Vec<u8> {
         self.0.rng_lock().next_bytes(length)
     }
@@ -66,12 +84,24 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
 
     fn storage_write(&self, key: &[u8], value: &[u8]) {
         self.check_reserved_key(key);
+        self.storage_write_reserved(key, value);
+    }
+
+    fn storage_write_reserved(&self, key: &[u8], value: &[u8]) {
+        if self.input_ref().readonly {
+            self.vm_error(vm_err_msg::CANNOT_WRITE_ON_READ_ONLY);
+        }
 
         self.0.with_contract_account_mut(|account| {
             account.storage.insert(key.to_vec(), value.to_vec());
         });
     }
 
+    fn push_tx_log(&self, tx_log: TxLog) {
+        self.check_not_readonly();
+        self.0.result_lock().result_logs.push(tx_log);
+    }
+
     fn get_previous_block_info(&self) -> &BlockInfo {
         &self.0.blockchain_ref().previous_block_info
     }
@@ -80,6 +110,14 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         &self.0.blockchain_ref().current_block_info
     }
 
+    fn state_root_hash(&self) -> H256 {
+        self.0.blockchain_ref().state_root_hash()
+    }
+
+    fn block_hash(&self, block_nonce: u64) -> Option<H256> {
+        self.0.blockchain_ref().block_hash(block_nonce)
+    }
+
     fn back_transfers_lock(&self) -> MutexGuard<BackTransfers> {
         self.0.back_transfers_lock()
     }
@@ -89,11 +127,14 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             .with_account_or_else(address, |account| Some(account.clone()), || None)
     }
 
-    fn account_code(&self, address: &VMAddress) -> Vec<u8> {
+    fn account_code(&self, address: &VMAddress) -> Option<Vec<u8>> {
+        self.0.blockchain_cache().contract_code(address)
+    }
+
+    fn esdt_token_info(&self, token_identifier: &[u8]) -> Option<EsdtTokenInfo> {
         self.0
             .blockchain_cache()
-            .with_account(address, |account| account.contract_path.clone())
-            .unwrap_or_else(|| panic!("Account is not a smart contract, it has no code"))
+            .get_esdt_token_info(token_identifier)
     }
 
     fn perform_async_call(
@@ -103,9 +144,15 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) -> ! {
-        let async_call_data = self.create_async_call_data(to, egld_value, func_name, arguments);
+        self.check_not_readonly();
+
+        // the async call gets all the remaining gas
+        let gas_limit = self.gas_left();
         // the cell is no longer needed, since we end in a panic
         let mut tx_result = self.result_lock();
+        let async_call_data = self
+            .create_async_call_data(to, egld_value, gas_limit, func_name, arguments)
+            .into_smart_contract_result(tx_result.all_calls.len());
         tx_result.all_calls.push(async_call_data.clone());
         tx_result.pending_calls.async_call = Some(async_call_data);
         drop(tx_result); // this avoid to poison the mutex
@@ -116,33 +163,93 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         &self,
         to: VMAddress,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>> {
-        let async_call_data = self.create_async_call_data(to, egld_value, func_name, arguments);
-        let tx_input = async_call_tx_input(&async_call_data, CallType::ExecuteOnDestContext);
+        if !egld_value.is_zero() || is_vm_builtin_function(func_name.as_str()) {
+            self.check_not_readonly();
+        }
+
+        let gas_limit = self.forwarded_gas(gas_limit);
+        let async_call_data =
+            self.create_async_call_data(to, egld_value, gas_limit, func_name, arguments);
+        let tx_input = self.sync_call_tx_input(&async_call_data, CallType::ExecuteOnDestContext);
         let tx_cache = TxCache::new(self.0.blockchain_cache_arc());
         let (tx_result, blockchain_updates) = self.0.vm_ref.execute_builtin_function_or_default(
             tx_input,
             tx_cache,
             execute_current_tx_context_input,
         );
+        self.sync_call_result(tx_result, blockchain_updates)
+    }
 
-        if tx_result.result_status.is_success() {
-            self.sync_call_post_processing(tx_result, blockchain_updates)
-        } else {
-            // also kill current execution
-            self.halt_with_error(tx_result.result_status, &tx_result.result_message)
+    fn perform_execute_on_same_context(
+        &self,
+        to: VMAddress,
+        egld_value: num_bigint::BigUint,
+        gas_limit: u64,
+        func_name: TxFunctionName,
+        arguments: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        if !egld_value.is_zero() {
+            self.check_not_readonly();
         }
+
+        // the call stays on the current account, only the code comes from the target
+        let contract_address = self.current_address().clone();
+        let gas_limit = self.forwarded_gas(gas_limit);
+        let async_call_data = self.create_async_call_data(
+            contract_address,
+            egld_value,
+            gas_limit,
+            func_name,
+            arguments,
+        );
+        let tx_input = self.sync_call_tx_input(&async_call_data, CallType::ExecuteOnSameContext);
+        let tx_cache = TxCache::new(self.0.blockchain_cache_arc());
+        let (tx_result, blockchain_updates) = self.0.vm_ref.execute_on_same_context(
+            tx_input,
+            to,
+            tx_cache,
+            execute_current_tx_context_input,
+        );
+        self.sync_call_result(tx_result, blockchain_updates)
+    }
+
+    fn perform_execute_read_only(
+        &self,
+        to: VMAddress,
+        gas_limit: u64,
+        func_name: TxFunctionName,
+        arguments: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        if is_vm_builtin_function(func_name.as_str()) {
+            self.vm_error(vm_err_msg::INVALID_CALL_ON_READ_ONLY_MODE);
+        }
+
+        let gas_limit = self.forwarded_gas(gas_limit);
+        let async_call_data =
+            self.create_async_call_data(to, BigUint::zero(), gas_limit, func_name, arguments);
+        let tx_input = self.sync_call_tx_input(&async_call_data, CallType::ExecuteOnDestContext);
+        let tx_cache = TxCache::new(self.0.blockchain_cache_arc());
+        let (tx_result, blockchain_updates) =
+            self.0
+                .vm_ref
+                .execute_read_only(tx_input, tx_cache, execute_current_tx_context_input);
+        self.sync_call_result(tx_result, blockchain_updates)
     }
 
     fn perform_deploy(
         &self,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         contract_code: Vec<u8>,
         code_metadata: VMCodeMetadata,
         args: Vec<Vec<u8>>,
     ) -> (VMAddress, Vec<Vec<u8>>) {
+        self.check_not_readonly();
+
         let contract_address = self.current_address();
         let tx_hash = self.tx_hash();
         let tx_input = TxInput {
@@ -152,9 +259,13 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             esdt_values: Vec::new(),
             func_name: TxFunctionName::EMPTY,
             args,
-            gas_limit: 1000,
+            gas_limit: self.forwarded_gas(gas_limit),
             gas_price: 0,
             tx_hash,
+            original_tx_hash: Some(self.0.input_ref().original_tx_hash().clone()),
+            prev_tx_hash: Some(self.0.input_ref().prev_tx_hash().clone()),
+            original_caller: Some(self.0.input_ref().original_caller().clone()),
+            relayer: self.0.input_ref().relayer.clone(),
             ..Default::default()
         };
 
@@ -167,6 +278,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             tx_cache,
             execute_current_tx_context_input,
         );
+        self.use_gas(tx_result.gas_used);
 
         match tx_result.result_status {
             ReturnCode::Success => (
@@ -182,10 +294,17 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         &self,
         to: VMAddress,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) {
-        let async_call_data = self.create_async_call_data(to, egld_value, func_name, arguments);
+        self.check_not_readonly();
+
+        let gas_limit = self.forwarded_gas(gas_limit);
+        let scr_index = self.0.result_lock().all_calls.len();
+        let async_call_data = self
+            .create_async_call_data(to, egld_value, gas_limit, func_name, arguments)
+            .into_smart_contract_result(scr_index);
         let mut tx_input = async_call_tx_input(&async_call_data, CallType::TransferExecute);
         if self.is_back_transfer(&tx_input) {
             tx_input.call_type = CallType::BackTransfer;
@@ -197,6 +316,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             tx_cache,
             execute_current_tx_context_input,
         );
+        self.use_gas(tx_result.gas_used);
 
         match tx_result.result_status {
             ReturnCode::Success => {
@@ -215,6 +335,7 @@ impl DebugApiVMHooksHandler {
         &self,
         to: VMAddress,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) -> AsyncCallTxData {
@@ -226,7 +347,43 @@ impl DebugApiVMHooksHandler {
             call_value: egld_value,
             endpoint_name: func_name,
             arguments,
+            gas_limit,
             tx_hash,
+            original_tx_hash: self.0.input_ref().original_tx_hash().clone(),
+            prev_tx_hash: self.0.input_ref().prev_tx_hash().clone(),
+            original_caller: self.0.input_ref().original_caller().clone(),
+            relayer: self.0.input_ref().relayer.clone(),
+        }
+    }
+
+    /// Input for synchronous calls. Read-only mode is inherited from the caller.
+    fn sync_call_tx_input(
+        &self,
+        async_call_data: &AsyncCallTxData,
+        call_type: CallType,
+    ) -> TxInput {
+        let mut tx_input = async_call_tx_input(async_call_data, call_type);
+        tx_input.readonly = self.input_ref().readonly;
+        tx_input
+    }
+
+    /// Sync calls cannot receive more gas than what the caller has left.
+    fn forwarded_gas(&self, gas_limit: u64) -> u64 {
+        gas_limit.min(self.gas_left())
+    }
+
+    fn sync_call_result(
+        &self,
+        tx_result: TxResult,
+        blockchain_updates: BlockchainUpdate,
+    ) -> Vec<Vec<u8>> {
+        self.use_gas(tx_result.gas_used);
+
+        if tx_result.result_status.is_success() {
+            self.sync_call_post_processing(tx_result, blockchain_updates)
+        } else {
+            // also kill current execution
+            self.halt_with_error(tx_result.result_status, &tx_result.result_message)
         }
     }
 
@@ -269,6 +426,7 @@ impl VMHooksBigInt for DebugApiVMHooksHandler {}
 impl VMHooksManagedBuffer for DebugApiVMHooksHandler {}
 impl VMHooksManagedMap for DebugApiVMHooksHandler {}
 impl VMHooksBigFloat for DebugApiVMHooksHandler {}
+impl VMHooksEllipticCurve for DebugApiVMHooksHandler {}
 impl VMHooksManagedTypes for DebugApiVMHooksHandler {}
 
 impl VMHooksCallValue for DebugApiVMHooksHandler {}
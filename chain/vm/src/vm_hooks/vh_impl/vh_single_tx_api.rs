@@ -7,14 +7,15 @@ use multiversx_chain_core::types::ReturnCode;
 
 use crate::{
     tx_mock::{BackTransfers, TxFunctionName, TxInput, TxManagedTypes, TxResult},
-    types::{VMAddress, VMCodeMetadata},
+    types::{VMAddress, VMCodeMetadata, H256},
     vm_hooks::{
         VMHooksBigFloat, VMHooksBigInt, VMHooksBlockchain, VMHooksCallValue, VMHooksCrypto,
-        VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError, VMHooksErrorManaged,
-        VMHooksHandler, VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
-        VMHooksManagedTypes, VMHooksSend, VMHooksStorageRead, VMHooksStorageWrite,
+        VMHooksEllipticCurve, VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError,
+        VMHooksErrorManaged, VMHooksHandler, VMHooksHandlerSource, VMHooksLog,
+        VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes, VMHooksSend,
+        VMHooksStorageRead, VMHooksStorageWrite,
     },
-    world_mock::{AccountData, BlockInfo},
+    world_mock::{AccountData, BlockInfo, EsdtTokenInfo},
 };
 
 #[derive(Default, Debug)]
@@ -95,6 +96,14 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
         &self.0.current_block_info
     }
 
+    fn state_root_hash(&self) -> H256 {
+        panic!("cannot access the state root hash in the SingleTxApi")
+    }
+
+    fn block_hash(&self, _block_nonce: u64) -> Option<H256> {
+        panic!("cannot access the block hashes in the SingleTxApi")
+    }
+
     fn back_transfers_lock(&self) -> MutexGuard<BackTransfers> {
         panic!("cannot access back transfers in the SingleTxApi")
     }
@@ -103,8 +112,12 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
         Some(self.0.with_account_mut(address, |account| account.clone()))
     }
 
-    fn account_code(&self, _address: &VMAddress) -> Vec<u8> {
-        vec![]
+    fn account_code(&self, _address: &VMAddress) -> Option<Vec<u8>> {
+        None
+    }
+
+    fn esdt_token_info(&self, _token_identifier: &[u8]) -> Option<EsdtTokenInfo> {
+        None
     }
 
     fn perform_async_call(
@@ -121,6 +134,28 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
         &self,
         _to: VMAddress,
         _egld_value: num_bigint::BigUint,
+        _gas_limit: u64,
+        _func_name: TxFunctionName,
+        _args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        panic!("cannot launch contract calls in the SingleTxApi")
+    }
+
+    fn perform_execute_on_same_context(
+        &self,
+        _to: VMAddress,
+        _egld_value: num_bigint::BigUint,
+        _gas_limit: u64,
+        _func_name: TxFunctionName,
+        _args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        panic!("cannot launch contract calls in the SingleTxApi")
+    }
+
+    fn perform_execute_read_only(
+        &self,
+        _to: VMAddress,
+        _gas_limit: u64,
         _func_name: TxFunctionName,
         _args: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>> {
@@ -130,6 +165,7 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
     fn perform_deploy(
         &self,
         _egld_value: num_bigint::BigUint,
+        _gas_limit: u64,
         _contract_code: Vec<u8>,
         _code_metadata: VMCodeMetadata,
         _args: Vec<Vec<u8>>,
@@ -141,6 +177,7 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
         &self,
         _to: VMAddress,
         _egld_value: num_bigint::BigUint,
+        _gas_limit: u64,
         _func_name: TxFunctionName,
         _arguments: Vec<Vec<u8>>,
     ) {
@@ -152,6 +189,7 @@ impl VMHooksBigInt for SingleTxApiVMHooksHandler {}
 impl VMHooksManagedBuffer for SingleTxApiVMHooksHandler {}
 impl VMHooksManagedMap for SingleTxApiVMHooksHandler {}
 impl VMHooksBigFloat for SingleTxApiVMHooksHandler {}
+impl VMHooksEllipticCurve for SingleTxApiVMHooksHandler {}
 impl VMHooksManagedTypes for SingleTxApiVMHooksHandler {}
 
 impl VMHooksCallValue for SingleTxApiVMHooksHandler {}
@@ -4,14 +4,15 @@ use multiversx_chain_core::types::ReturnCode;
 
 use crate::{
     tx_mock::{BackTransfers, TxFunctionName, TxInput, TxLog, TxManagedTypes, TxResult},
-    types::{VMAddress, VMCodeMetadata},
+    types::{VMAddress, VMCodeMetadata, H256},
     vm_hooks::{
         VMHooksBigFloat, VMHooksBigInt, VMHooksBlockchain, VMHooksCallValue, VMHooksCrypto,
-        VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError, VMHooksErrorManaged,
-        VMHooksHandler, VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
-        VMHooksManagedTypes, VMHooksSend, VMHooksStorageRead, VMHooksStorageWrite,
+        VMHooksEllipticCurve, VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError,
+        VMHooksErrorManaged, VMHooksHandler, VMHooksHandlerSource, VMHooksLog,
+        VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes, VMHooksSend,
+        VMHooksStorageRead, VMHooksStorageWrite,
     },
-    world_mock::{AccountData, BlockInfo},
+    world_mock::{AccountData, BlockInfo, EsdtTokenInfo},
 };
 
 /// A simple wrapper around a managed type container Mutex.
@@ -70,6 +71,14 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
         panic!("cannot access the block info in the StaticApi")
     }
 
+    fn state_root_hash(&self) -> H256 {
+        panic!("cannot access the state root hash in the StaticApi")
+    }
+
+    fn block_hash(&self, _block_nonce: u64) -> Option<H256> {
+        panic!("cannot access the block hashes in the StaticApi")
+    }
+
     fn back_transfers_lock(&self) -> MutexGuard<BackTransfers> {
         panic!("cannot access the back transfers in the StaticApi")
     }
@@ -78,10 +87,14 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
         panic!("cannot access account data in the StaticApi")
     }
 
-    fn account_code(&self, _address: &VMAddress) -> Vec<u8> {
+    fn account_code(&self, _address: &VMAddress) -> Option<Vec<u8>> {
         panic!("cannot access account data in the StaticApi")
     }
 
+    fn esdt_token_info(&self, _token_identifier: &[u8]) -> Option<EsdtTokenInfo> {
+        panic!("cannot access token data in the StaticApi")
+    }
+
     fn perform_async_call(
         &self,
         _to: VMAddress,
@@ -96,6 +109,28 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
         &self,
         _to: VMAddress,
         _egld_value: num_bigint::BigUint,
+        _gas_limit: u64,
+        _func_name: TxFunctionName,
+        _args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        panic!("cannot launch contract calls in the StaticApi")
+    }
+
+    fn perform_execute_on_same_context(
+        &self,
+        _to: VMAddress,
+        _egld_value: num_bigint::BigUint,
+        _gas_limit: u64,
+        _func_name: TxFunctionName,
+        _args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        panic!("cannot launch contract calls in the StaticApi")
+    }
+
+    fn perform_execute_read_only(
+        &self,
+        _to: VMAddress,
+        _gas_limit: u64,
         _func_name: TxFunctionName,
         _args: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>> {
@@ -105,6 +140,7 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
     fn perform_deploy(
         &self,
         _egld_value: num_bigint::BigUint,
+        _gas_limit: u64,
         _contract_code: Vec<u8>,
         _code_metadata: VMCodeMetadata,
         _args: Vec<Vec<u8>>,
@@ -116,6 +152,7 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
         &self,
         _to: VMAddress,
         _egld_value: num_bigint::BigUint,
+        _gas_limit: u64,
         _func_name: TxFunctionName,
         _arguments: Vec<Vec<u8>>,
     ) {
@@ -127,6 +164,7 @@ impl VMHooksBigInt for StaticApiVMHooksHandler {}
 impl VMHooksManagedBuffer for StaticApiVMHooksHandler {}
 impl VMHooksManagedMap for StaticApiVMHooksHandler {}
 impl VMHooksBigFloat for StaticApiVMHooksHandler {}
+impl VMHooksEllipticCurve for StaticApiVMHooksHandler {}
 impl VMHooksManagedTypes for StaticApiVMHooksHandler {}
 
 impl VMHooksCallValue for StaticApiVMHooksHandler {}
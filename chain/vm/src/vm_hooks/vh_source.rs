@@ -3,9 +3,11 @@ use std::{fmt::Debug, sync::MutexGuard};
 use multiversx_chain_core::types::ReturnCode;
 
 use crate::{
+    schedule::GasSchedule,
     tx_mock::{BackTransfers, TxFunctionName, TxInput, TxLog, TxManagedTypes, TxResult},
     types::{VMAddress, VMCodeMetadata, H256},
-    world_mock::{AccountData, BlockInfo},
+    vm_err_msg,
+    world_mock::{AccountData, BlockInfo, EsdtTokenInfo},
 };
 
 /// Abstracts away the borrowing of a managed types structure.
@@ -20,6 +22,32 @@ pub trait VMHooksHandlerSource: Debug {
 
     fn input_ref(&self) -> &TxInput;
 
+    /// Gas is only metered if a gas schedule is configured.
+    fn gas_schedule(&self) -> Option<&GasSchedule> {
+        None
+    }
+
+    /// Charges gas, halts execution with an "out of gas" error if the gas limit is exceeded.
+    fn use_gas(&self, _gas: u64) {}
+
+    fn gas_left(&self) -> u64 {
+        self.input_ref().gas_limit
+    }
+
+    /// Charges the cost of a VM hook, as configured in the gas schedule.
+    fn use_gas_for_hook(&self, hook_name: &str) {
+        if let Some(gas_schedule) = self.gas_schedule() {
+            self.use_gas(gas_schedule.hook_cost(hook_name));
+        }
+    }
+
+    /// Halts execution if the current call is read-only, i.e. it is not allowed to change the state.
+    fn check_not_readonly(&self) {
+        if self.input_ref().readonly {
+            self.vm_error(vm_err_msg::INVALID_CALL_ON_READ_ONLY_MODE);
+        }
+    }
+
     fn current_address(&self) -> &VMAddress {
         &self.input_ref().to
     }
@@ -45,10 +73,23 @@ pub trait VMHooksHandlerSource: Debug {
 
     fn storage_write(&self, key: &[u8], value: &[u8]);
 
+    /// Writes under a key reserved for the protocol, e.g. storage locks.
+    ///
+    /// Unlike `storage_write`, it does not reject reserved keys.
+    fn storage_write_reserved(&self, key: &[u8], value: &[u8]) {
+        self.storage_write(key, value);
+    }
+
     fn get_previous_block_info(&self) -> &BlockInfo;
 
     fn get_current_block_info(&self) -> &BlockInfo;
 
+    /// Root hash of the state at the beginning of the transaction.
+    fn state_root_hash(&self) -> H256;
+
+    /// `None` for blocks that have not been produced yet.
+    fn block_hash(&self, block_nonce: u64) -> Option<H256>;
+
     fn back_transfers_lock(&self) -> MutexGuard<BackTransfers>;
 
     /// For ownership reasons, needs to return a clone.
@@ -64,7 +105,11 @@ pub trait VMHooksHandlerSource: Debug {
             .expect("missing current account")
     }
 
-    fn account_code(&self, address: &VMAddress) -> Vec<u8>;
+    /// The code of a smart contract, `None` if the account is missing or is not a smart contract.
+    fn account_code(&self, address: &VMAddress) -> Option<Vec<u8>>;
+
+    /// Global token data, as kept by the ESDT system SC. Will provide a clone.
+    fn esdt_token_info(&self, token_identifier: &[u8]) -> Option<EsdtTokenInfo>;
 
     fn perform_async_call(
         &self,
@@ -78,6 +123,26 @@ pub trait VMHooksHandlerSource: Debug {
         &self,
         to: VMAddress,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
+        func_name: TxFunctionName,
+        args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>>;
+
+    /// Runs the code of `to`, but on the storage and balances of the current contract.
+    fn perform_execute_on_same_context(
+        &self,
+        to: VMAddress,
+        egld_value: num_bigint::BigUint,
+        gas_limit: u64,
+        func_name: TxFunctionName,
+        args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>>;
+
+    /// Calls `to` synchronously, without allowing it to change the state.
+    fn perform_execute_read_only(
+        &self,
+        to: VMAddress,
+        gas_limit: u64,
         func_name: TxFunctionName,
         args: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>>;
@@ -85,6 +150,7 @@ pub trait VMHooksHandlerSource: Debug {
     fn perform_deploy(
         &self,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         contract_code: Vec<u8>,
         code_metadata: VMCodeMetadata,
         args: Vec<Vec<u8>>,
@@ -94,6 +160,7 @@ pub trait VMHooksHandlerSource: Debug {
         &self,
         to: VMAddress,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     );
@@ -3,11 +3,13 @@ mod block_info;
 mod blockchain_mock;
 mod blockchain_mock_account_util;
 mod blockchain_state;
+mod blockchain_state_hash;
 mod esdt_data;
 mod esdt_instance;
 mod esdt_instance_metadata;
 mod esdt_instances;
 mod esdt_roles;
+mod esdt_token_info;
 mod failing_executor;
 pub mod reserved;
 
@@ -20,4 +22,5 @@ pub use esdt_instance::*;
 pub use esdt_instance_metadata::*;
 pub use esdt_instances::*;
 pub use esdt_roles::*;
+pub use esdt_token_info::EsdtTokenInfo;
 pub use failing_executor::FailingExecutor;
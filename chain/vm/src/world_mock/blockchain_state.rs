@@ -4,7 +4,7 @@ use std::{collections::HashMap, fmt::Debug};
 
 use crate::{tx_mock::BlockchainUpdate, types::VMAddress};
 
-use super::{reserved::STORAGE_REWARD_KEY, AccountData, BlockInfo};
+use super::{reserved::STORAGE_REWARD_KEY, AccountData, AccountEsdt, BlockInfo, EsdtTokenInfo};
 
 #[derive(Default, Clone)]
 pub struct BlockchainState {
@@ -13,6 +13,7 @@ pub struct BlockchainState {
     pub previous_block_info: BlockInfo,
     pub current_block_info: BlockInfo,
     pub new_token_identifiers: Vec<String>,
+    pub esdt_tokens: HashMap<Vec<u8>, EsdtTokenInfo>,
 }
 
 impl BlockchainState {
@@ -80,6 +81,21 @@ impl BlockchainState {
     pub fn update_new_token_identifiers(&mut self, token_identifiers: Vec<String>) {
         self.new_token_identifiers = token_identifiers;
     }
+
+    /// Derives the global token data from the account, for tokens that were not issued.
+    pub fn register_account_esdt_tokens(&mut self, owner: &VMAddress, account_esdt: &AccountEsdt) {
+        for (token_identifier, esdt_data) in account_esdt.iter() {
+            self.esdt_tokens
+                .entry(token_identifier.clone())
+                .or_insert_with(|| {
+                    EsdtTokenInfo::from_account_esdt(token_identifier, esdt_data, owner.clone())
+                });
+        }
+    }
+
+    pub fn update_esdt_tokens(&mut self, esdt_tokens: HashMap<Vec<u8>, EsdtTokenInfo>) {
+        self.esdt_tokens.extend(esdt_tokens);
+    }
 }
 
 impl Debug for BlockchainState {
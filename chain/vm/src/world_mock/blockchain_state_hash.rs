@@ -0,0 +1,104 @@
+use crate::{crypto_functions::keccak256, tx_mock::hash_fields, types::H256};
+
+use super::{AccountData, BlockchainState};
+
+impl BlockchainState {
+    /// A simple Merkle root over all accounts, sorted by address.
+    ///
+    /// It is not the trie root computed by the protocol,
+    /// but it changes whenever any account changes, and only then.
+    pub fn state_root_hash(&self) -> H256 {
+        let mut accounts: Vec<&AccountData> = self.accounts.values().collect();
+        accounts.sort_by(|a, b| a.address.as_bytes().cmp(b.address.as_bytes()));
+
+        let leaves = accounts.into_iter().map(account_hash).collect();
+        merkle_root(leaves)
+    }
+
+    /// Hash of a past or current block, derived from its nonce.
+    ///
+    /// There is no hash for blocks that have not been produced yet.
+    pub fn block_hash(&self, block_nonce: u64) -> Option<H256> {
+        if block_nonce > self.current_block_info.block_nonce {
+            return None;
+        }
+
+        Some(hash_fields([
+            b"block".as_slice(),
+            &block_nonce.to_be_bytes(),
+        ]))
+    }
+}
+
+fn account_hash(account: &AccountData) -> H256 {
+    let nonce_bytes = account.nonce.to_be_bytes();
+    let balance_bytes = account.egld_balance.to_bytes_be();
+    let developer_rewards_bytes = account.developer_rewards.to_bytes_be();
+    let code_metadata_bytes = account.code_metadata.to_byte_array();
+    let owner_bytes = account
+        .contract_owner
+        .as_ref()
+        .map(|owner| owner.as_bytes())
+        .unwrap_or_default();
+
+    let mut fields: Vec<Vec<u8>> = Vec::new();
+
+    let mut esdt: Vec<_> = account.esdt.iter().collect();
+    esdt.sort_by_key(|(k, _)| *k);
+    for (token_identifier, esdt_data) in esdt {
+        fields.push(token_identifier.clone());
+        fields.push(esdt_data.last_nonce.to_be_bytes().to_vec());
+        fields.push(vec![esdt_data.frozen as u8]);
+        fields.extend(esdt_data.roles.get());
+        for (nonce, instance) in esdt_data.instances.get_instances() {
+            fields.push(nonce.to_be_bytes().to_vec());
+            fields.push(instance.balance.to_bytes_be());
+            fields.push(instance.metadata.attributes.clone());
+            fields.push(vec![instance.frozen as u8]);
+        }
+    }
+
+    let mut storage: Vec<_> = account.storage.iter().collect();
+    storage.sort();
+    for (key, value) in storage {
+        fields.push(key.clone());
+        fields.push(value.clone());
+    }
+
+    hash_fields(
+        [
+            account.address.as_bytes(),
+            &nonce_bytes,
+            &balance_bytes,
+            account.username.as_slice(),
+            account.contract_path.as_deref().unwrap_or_default(),
+            &code_metadata_bytes,
+            owner_bytes,
+            &developer_rewards_bytes,
+        ]
+        .into_iter()
+        .chain(fields.iter().map(Vec::as_slice)),
+    )
+}
+
+/// Hashes nodes pairwise, level by level. The last node on a level is paired with itself if left alone.
+fn merkle_root(mut level: Vec<H256>) -> H256 {
+    if level.is_empty() {
+        return H256::zero();
+    }
+
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| {
+                let left = &pair[0];
+                let right = pair.get(1).unwrap_or(left);
+                let mut preimage = left.to_vec();
+                preimage.extend_from_slice(right.as_bytes());
+                H256::from(keccak256(&preimage))
+            })
+            .collect();
+    }
+
+    level.pop().unwrap()
+}
@@ -28,6 +28,16 @@ impl EsdtData {
     pub fn get_roles(&self) -> Vec<Vec<u8>> {
         self.roles.get()
     }
+
+    /// Frozen either for the whole account, or only for the given NFT nonce.
+    pub fn is_frozen(&self, nonce: u64) -> bool {
+        self.frozen
+            || self
+                .instances
+                .get_by_nonce(nonce)
+                .map(|instance| instance.frozen)
+                .unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -70,6 +80,11 @@ impl AccountEsdt {
         self.0.get_mut(identifier)
     }
 
+    /// Creates an empty entry for the token if it is missing.
+    pub fn get_mut_by_identifier_or_insert(&mut self, identifier: &[u8]) -> &mut EsdtData {
+        self.0.entry(identifier.to_vec()).or_default()
+    }
+
     pub fn new_from_raw_map(hash: HashMap<Vec<u8>, EsdtData>) -> Self {
         AccountEsdt(hash)
     }
@@ -161,6 +176,20 @@ impl AccountEsdt {
         }
     }
 
+    pub fn unset_special_role(&mut self, token_identifier: &[u8], role: &[u8]) {
+        if let Some(esdt_data) = self.get_mut_by_identifier(token_identifier) {
+            let mut roles = esdt_data.roles.get();
+            roles.retain(|existing_role| existing_role.as_slice() != role);
+            esdt_data.roles = EsdtRoles::new(roles);
+        }
+    }
+
+    pub fn has_special_role(&self, token_identifier: &[u8], role: &[u8]) -> bool {
+        self.get_by_identifier(token_identifier)
+            .map(|esdt_data| esdt_data.roles.get().iter().any(|r| r.as_slice() == role))
+            .unwrap_or_default()
+    }
+
     pub fn register_and_set_roles(&mut self, token_identifier: &[u8], token_type: VMTokenType) {
         self.issue_token(token_identifier);
         self.set_roles(
@@ -0,0 +1,191 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::types::{VMAddress, VMTokenType};
+
+use super::EsdtData;
+
+/// Global token data, as kept by the ESDT system smart contract.
+///
+/// Account-level data (balances, roles, frozen flags) lives in each account's `EsdtData`.
+#[derive(Clone, Debug)]
+pub struct EsdtTokenInfo {
+    pub name: Vec<u8>,
+    pub ticker: Vec<u8>,
+    pub token_type: VMTokenType,
+    pub owner: VMAddress,
+    pub num_decimals: u32,
+    pub minted_value: BigUint,
+    pub burnt_value: BigUint,
+    pub paused: bool,
+    pub can_upgrade: bool,
+    pub can_mint: bool,
+    pub can_burn: bool,
+    pub can_change_owner: bool,
+    pub can_pause: bool,
+    pub can_freeze: bool,
+    pub can_wipe: bool,
+    pub can_add_special_roles: bool,
+    pub can_transfer_nft_create_role: bool,
+    pub can_create_multi_shard: bool,
+    pub nft_create_stopped: bool,
+    pub burn_role_globally: bool,
+    pub num_wiped: u64,
+}
+
+impl EsdtTokenInfo {
+    /// Properties of a freshly issued token, before applying the issue arguments.
+    pub fn new(
+        name: Vec<u8>,
+        ticker: Vec<u8>,
+        token_type: VMTokenType,
+        owner: VMAddress,
+        num_decimals: u32,
+    ) -> Self {
+        EsdtTokenInfo {
+            name,
+            ticker,
+            token_type,
+            owner,
+            num_decimals,
+            minted_value: BigUint::zero(),
+            burnt_value: BigUint::zero(),
+            paused: false,
+            can_upgrade: true,
+            can_mint: false,
+            can_burn: false,
+            can_change_owner: false,
+            can_pause: false,
+            can_freeze: false,
+            can_wipe: false,
+            can_add_special_roles: true,
+            can_transfer_nft_create_role: false,
+            can_create_multi_shard: false,
+            nft_create_stopped: false,
+            burn_role_globally: false,
+            num_wiped: 0,
+        }
+    }
+
+    /// Token data for a token that was never issued, but only appears in account state,
+    /// e.g. from a scenario `setState` step.
+    ///
+    /// The name and ticker come from the identifier and the type is guessed from the account data.
+    /// All properties are enabled, so that the owner can manage the token (freeze, pause, wipe, etc.).
+    pub fn from_account_esdt(
+        token_identifier: &[u8],
+        esdt_data: &EsdtData,
+        owner: VMAddress,
+    ) -> Self {
+        let ticker = token_identifier
+            .split(|&c| c == b'-')
+            .next()
+            .unwrap_or_default()
+            .to_vec();
+        let mut token_info = EsdtTokenInfo::new(
+            ticker.clone(),
+            ticker,
+            guess_token_type(esdt_data),
+            owner,
+            0,
+        );
+        token_info.can_mint = true;
+        token_info.can_burn = true;
+        token_info.can_change_owner = true;
+        token_info.can_pause = true;
+        token_info.can_freeze = true;
+        token_info.can_wipe = true;
+        token_info.can_transfer_nft_create_role = true;
+        token_info
+    }
+
+    /// Sets a property from its system SC argument name, e.g. `canFreeze`.
+    ///
+    /// Returns false if the property name or value is not recognized.
+    pub fn set_property(&mut self, name: &[u8], value: &[u8]) -> bool {
+        let value = match value {
+            b"true" => true,
+            b"false" => false,
+            _ => return false,
+        };
+        let property = match name {
+            b"canUpgrade" => &mut self.can_upgrade,
+            b"canMint" => &mut self.can_mint,
+            b"canBurn" => &mut self.can_burn,
+            b"canChangeOwner" => &mut self.can_change_owner,
+            b"canPause" => &mut self.can_pause,
+            b"canFreeze" => &mut self.can_freeze,
+            b"canWipe" => &mut self.can_wipe,
+            b"canAddSpecialRoles" => &mut self.can_add_special_roles,
+            b"canTransferNFTCreateRole" => &mut self.can_transfer_nft_create_role,
+            b"canCreateMultiShard" => &mut self.can_create_multi_shard,
+            _ => return false,
+        };
+        *property = value;
+        true
+    }
+
+    /// Token type name, as returned by `getTokenProperties`.
+    pub fn token_type_name(&self) -> &'static str {
+        match self.token_type {
+            VMTokenType::Fungible => "FungibleESDT",
+            VMTokenType::SemiFungible => "SemiFungibleESDT",
+            VMTokenType::Meta => "MetaESDT",
+            VMTokenType::NonFungible => "NonFungibleESDT",
+        }
+    }
+
+    /// Mirrors the output of the `getTokenProperties` system SC view.
+    pub fn properties_as_results(&self) -> Vec<Vec<u8>> {
+        vec![
+            self.name.clone(),
+            self.token_type_name().as_bytes().to_vec(),
+            self.owner.to_vec(),
+            self.minted_value.to_string().into_bytes(),
+            self.burnt_value.to_string().into_bytes(),
+            format!("NumDecimals-{}", self.num_decimals).into_bytes(),
+            bool_property("IsPaused", self.paused),
+            bool_property("CanUpgrade", self.can_upgrade),
+            bool_property("CanMint", self.can_mint),
+            bool_property("CanBurn", self.can_burn),
+            bool_property("CanChangeOwner", self.can_change_owner),
+            bool_property("CanPause", self.can_pause),
+            bool_property("CanFreeze", self.can_freeze),
+            bool_property("CanWipe", self.can_wipe),
+            bool_property("CanAddSpecialRoles", self.can_add_special_roles),
+            bool_property(
+                "CanTransferNFTCreateRole",
+                self.can_transfer_nft_create_role,
+            ),
+            bool_property("NFTCreateStopped", self.nft_create_stopped),
+            format!("NumWiped-{}", self.num_wiped).into_bytes(),
+        ]
+    }
+}
+
+fn bool_property(name: &str, value: bool) -> Vec<u8> {
+    format!("{name}-{value}").into_bytes()
+}
+
+fn guess_token_type(esdt_data: &EsdtData) -> VMTokenType {
+    let roles = esdt_data.get_roles();
+    let has_role = |role: &[u8]| roles.iter().any(|r| r.as_slice() == role);
+    let nft_instances = esdt_data
+        .instances
+        .get_instances()
+        .values()
+        .filter(|instance| instance.nonce > 0);
+
+    let mut is_nft = has_role(b"ESDTRoleNFTCreate");
+    let mut is_sft = has_role(b"ESDTRoleNFTAddQuantity");
+    for instance in nft_instances {
+        is_nft = true;
+        is_sft |= instance.balance > BigUint::from(1u32);
+    }
+
+    match (is_nft, is_sft) {
+        (_, true) => VMTokenType::SemiFungible,
+        (true, false) => VMTokenType::NonFungible,
+        _ => VMTokenType::Fungible,
+    }
+}
@@ -1,3 +1,6 @@
 pub const STORAGE_RESERVED_PREFIX: &[u8] = b"ELROND";
 
 pub const STORAGE_REWARD_KEY: &[u8] = b"ELRONDreward";
+
+/// Prepended to the key of a storage lock, as in the Go VM.
+pub const STORAGE_TIME_LOCK_PREFIX: &[u8] = b"ELRONDtimelock";
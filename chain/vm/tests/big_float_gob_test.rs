@@ -0,0 +1,148 @@
+use multiversx_chain_vm::{
+    tx_mock::{big_float_from_gob_bytes, big_float_to_gob_bytes, DecodedBigFloat},
+    vm_err_msg,
+};
+
+/// Values and their encodings, as produced by `big.Float.GobEncode` in the Go VM.
+const GO_VECTORS: &[(f64, &str)] = &[
+    (0.0, "010800000035"),
+    (-0.0, "010900000035"),
+    (1.0, "010a00000035000000018000000000000000"),
+    (0.5, "010a00000035000000008000000000000000"),
+    (-3853625.0, "010b0000003500000016eb34e40000000000"),
+    (
+        core::f64::consts::PI,
+        "010a0000003500000002c90fdaa22168c000",
+    ),
+    (0.1, "010a00000035fffffffdccccccccccccd000"),
+    (f64::MAX, "010a0000003500000400fffffffffffff800"),
+    (f64::MIN_POSITIVE, "010a00000035fffffc038000000000000000"),
+    (
+        // smallest subnormal
+        5e-324,
+        "010a00000035fffffbcf8000000000000000",
+    ),
+];
+
+#[test]
+fn big_float_gob_encode_test() {
+    for &(value, expected) in GO_VECTORS {
+        let encoded = big_float_to_gob_bytes(value).unwrap();
+        assert_eq!(hex::encode(encoded), expected, "encoding {value:e}");
+    }
+}
+
+#[test]
+fn big_float_gob_decode_test() {
+    for &(expected, encoded) in GO_VECTORS {
+        let decoded = big_float_from_gob_bytes(&hex::decode(encoded).unwrap()).unwrap();
+        assert!(decoded.normalized, "decoding {encoded}");
+        assert_eq!(
+            decoded.value.to_bits(),
+            expected.to_bits(),
+            "decoding {encoded}"
+        );
+    }
+}
+
+#[test]
+fn big_float_gob_decode_empty_test() {
+    assert_eq!(big_float_from_gob_bytes(&[]), Ok(0.0.into()));
+}
+
+#[test]
+fn big_float_gob_decode_rounding_test() {
+    // mantissa has more than 53 significant bits, the Go VM rounds it to nearest even
+    let decoded =
+        big_float_from_gob_bytes(&hex::decode("010a0000003500000034ded4316c407a6b64").unwrap())
+            .unwrap()
+            .value;
+    assert_eq!(decoded, 0xded4316c407a6800u64 as f64 / 4096.0);
+
+    // exactly half way, rounds to even (down)
+    let decoded =
+        big_float_from_gob_bytes(&hex::decode("010a00000035000000018000000000000400").unwrap())
+            .unwrap()
+            .value;
+    assert_eq!(decoded, 1.0);
+
+    // half way plus some bits in a further word, rounds up
+    let decoded = big_float_from_gob_bytes(
+        &hex::decode("010a000000350000000180000000000004000000000000000001").unwrap(),
+    )
+    .unwrap()
+    .value;
+    assert_eq!(decoded, 1.0 + f64::EPSILON);
+}
+
+#[test]
+fn big_float_gob_decode_not_normalized_test() {
+    let cases = [
+        ("010a0000003500000001", 0.0),
+        (
+            "010a000000350000003a001f1c1a96fe0e2d",
+            0x1f1c1a96fe0e2du64 as f64 / 64.0,
+        ),
+        // partial word, padded on the left
+        ("010b0000003500000032c000", -3.0),
+        // exactly half way after the 53 significant bits, rounds to even (up)
+        (
+            "010a000000350000000c00100000000000018000000000000000",
+            1.0 + 2.0 * f64::EPSILON,
+        ),
+    ];
+    for (encoded, expected) in cases {
+        assert_eq!(
+            big_float_from_gob_bytes(&hex::decode(encoded).unwrap()),
+            Ok(DecodedBigFloat {
+                value: expected,
+                normalized: false,
+            }),
+            "decoding {encoded}"
+        );
+    }
+}
+
+#[test]
+fn big_float_gob_decode_errors_test() {
+    let cases = [
+        ("0108000000", vm_err_msg::BIG_FLOAT_DECODE_BUFFER_TOO_SMALL),
+        (
+            "020a00000035",
+            vm_err_msg::BIG_FLOAT_DECODE_VERSION_NOT_SUPPORTED,
+        ),
+        (
+            "010a00000036000000018000000000000000",
+            vm_err_msg::BIG_FLOAT_WRONG_PRECISION,
+        ),
+        ("010c00000035", vm_err_msg::BIG_FLOAT_INFINITY),
+        (
+            "010a00000035000100139c9920182fea6800",
+            vm_err_msg::EXPONENT_TOO_BIG_OR_TOO_SMALL,
+        ),
+        (
+            "010a00000035fffffbce8000000000000000",
+            vm_err_msg::EXPONENT_TOO_BIG_OR_TOO_SMALL,
+        ),
+        (
+            // rounds up to 2^1024
+            "010a0000003500000400fffffffffffffc00",
+            vm_err_msg::EXPONENT_TOO_BIG_OR_TOO_SMALL,
+        ),
+    ];
+    for (encoded, expected_error) in cases {
+        assert_eq!(
+            big_float_from_gob_bytes(&hex::decode(encoded).unwrap()),
+            Err(expected_error),
+            "decoding {encoded}"
+        );
+    }
+}
+
+#[test]
+fn big_float_gob_encode_nan_test() {
+    assert_eq!(
+        big_float_to_gob_bytes(f64::NAN),
+        Err(vm_err_msg::NUMBER_IS_NOT_NORMAL)
+    );
+}
@@ -0,0 +1,39 @@
+use multiversx_chain_vm::schedule::GasSchedule;
+
+const GAS_SCHEDULE_V8_TOML: &str = include_str!("gasScheduleV8.toml");
+
+/// All hooks that the dispatcher charges gas for.
+fn dispatcher_hook_names() -> Vec<&'static str> {
+    const PREFIX: &str = "use_gas_for_hook(\"";
+    include_str!("../src/vm_hooks/vh_dispatcher.rs")
+        .split(PREFIX)
+        .skip(1)
+        .map(|rest| &rest[..rest.find('"').unwrap()])
+        .collect()
+}
+
+#[test]
+fn gas_schedule_v8_all_hooks_charged_test() {
+    let gas_schedule = GasSchedule::from_toml_str(GAS_SCHEDULE_V8_TOML).unwrap();
+    let hook_names = dispatcher_hook_names();
+    assert!(hook_names.len() > 100);
+    for hook_name in hook_names {
+        assert!(
+            gas_schedule.hook_cost(hook_name) > 0,
+            "VM hook `{hook_name}` is free in gasScheduleV8"
+        );
+    }
+}
+
+#[test]
+fn gas_schedule_v8_managed_hooks_test() {
+    let gas_schedule = GasSchedule::from_toml_str(GAS_SCHEDULE_V8_TOML).unwrap();
+    assert_eq!(gas_schedule.hook_cost("managed_caller"), 1000);
+    assert_eq!(gas_schedule.hook_cost("managed_sc_address"), 1000);
+    assert_eq!(gas_schedule.hook_cost("managed_write_log"), 3750);
+    assert_eq!(gas_schedule.hook_cost("managed_create_contract"), 300000);
+    assert_eq!(gas_schedule.hook_cost("managed_sha256"), 1000000);
+    assert_eq!(gas_schedule.hook_cost("mbuffer_storage_store"), 75000);
+    assert_eq!(gas_schedule.hook_cost("big_int_add"), 2000);
+    assert_eq!(gas_schedule.builtin_function_cost("ESDTTransfer"), 200000);
+}
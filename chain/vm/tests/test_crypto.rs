@@ -42,3 +42,215 @@ fn test_verify_ed25519_invalid_args() {
     let success = crypto_functions::verify_ed25519(&pub_bytes, &msg_bytes, &sig_bytes);
     assert!(!success);
 }
+
+#[test]
+fn test_elliptic_curve_base_point() {
+    for name in ["p224", "p256", "p384", "p521"] {
+        let curve = crypto_functions::EllipticCurve::from_name(name.as_bytes()).unwrap();
+        assert!(curve.is_on_curve(&curve.x_base_point, &curve.y_base_point));
+
+        let order_bytes = curve.base_point_order.to_bytes_be();
+        let (x, y) = curve.scalar_base_mult(&(curve.base_point_order.clone() - 1u32).to_bytes_be());
+        assert!(curve.is_on_curve(&x, &y));
+        assert_eq!(x, curve.x_base_point);
+        assert_eq!(
+            curve.add(&x, &y, &curve.x_base_point, &curve.y_base_point),
+            (0u32.into(), 0u32.into())
+        );
+        assert_eq!(
+            curve.scalar_base_mult(&order_bytes),
+            (0u32.into(), 0u32.into())
+        );
+    }
+    assert!(crypto_functions::EllipticCurve::from_name(b"p22424").is_none());
+}
+
+#[test]
+fn test_elliptic_curve_p256_double() {
+    let curve = crypto_functions::EllipticCurve::p256();
+    let (x, y) = curve.double(&curve.x_base_point, &curve.y_base_point);
+    let expected_x: Vec<u8> =
+        FromHex::from_hex("7cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978")
+            .unwrap();
+    let expected_y: Vec<u8> =
+        FromHex::from_hex("07775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1")
+            .unwrap();
+    assert_eq!(x.to_bytes_be(), expected_x);
+    assert_eq!(y.to_bytes_be(), expected_y);
+    assert_eq!(curve.scalar_base_mult(&[2]), (x, y));
+}
+
+#[test]
+fn test_elliptic_curve_marshal() {
+    for name in ["p224", "p256", "p384", "p521"] {
+        let curve = crypto_functions::EllipticCurve::from_name(name.as_bytes()).unwrap();
+        let (x, y) = curve.scalar_base_mult(&[0x12, 0x34, 0x56, 0x78]);
+
+        let marshalled = curve.marshal(&x, &y);
+        assert_eq!(marshalled.len(), 1 + 2 * curve.byte_length());
+        assert_eq!(curve.unmarshal(&marshalled), Some((x.clone(), y.clone())));
+
+        let compressed = curve.marshal_compressed(&x, &y);
+        assert_eq!(compressed.len(), 1 + curve.byte_length());
+        assert_eq!(curve.unmarshal_compressed(&compressed), Some((x, y)));
+    }
+}
+
+#[test]
+fn test_elliptic_curve_unmarshal_invalid() {
+    let curve = crypto_functions::EllipticCurve::p256();
+    let mut not_on_curve = vec![0u8; 65];
+    not_on_curve[0] = 4;
+    assert_eq!(curve.unmarshal(&not_on_curve), None);
+    assert_eq!(curve.unmarshal(&[4, 0, 0]), None);
+    assert_eq!(curve.unmarshal_compressed(&[4, 0, 0]), None);
+}
+
+#[test]
+fn test_ripemd160() {
+    let result = crypto_functions::ripemd160(&[1, 2, 3]);
+    let expected: Vec<u8> = FromHex::from_hex("79f901da2609f020adadbf2e5f68a16c8c3f7d57").unwrap();
+    assert_eq!(&result[..], &expected[..]);
+}
+
+#[test]
+fn test_verify_secp256k1_double_sha256() {
+    let public_key: &[u8] = b"02a673638cb9587cb68ea08dbef685c6f2d2a751a8b3c6f2a7e9a4999e6e4bfaf5";
+    let signature: &[u8] = b"3045022100fab29dbcb623e7e96f44665f312b89ffdb9e4d2e2f53353236fbbcf57b2f49bf02203d6f9c63df8be43121042970a549a2d3baeca093fd37b726ddcc0924638409f5";
+
+    let pub_bytes: Vec<u8> = FromHex::from_hex(public_key).unwrap();
+    let sig_bytes: Vec<u8> = FromHex::from_hex(signature).unwrap();
+
+    let success = crypto_functions::verify_secp256k1(
+        &pub_bytes,
+        b"message to sign",
+        &sig_bytes,
+        crypto_functions::ECDSA_DOUBLE_SHA256,
+    );
+    assert!(success);
+
+    let success = crypto_functions::verify_secp256k1(
+        &pub_bytes,
+        b"message to sign",
+        &sig_bytes,
+        crypto_functions::ECDSA_SHA256,
+    );
+    assert!(!success);
+}
+
+#[test]
+fn test_encode_secp256k1_der_signature() {
+    let r: Vec<u8> =
+        FromHex::from_hex("90f27b8b488db00b00606796d2987f6a5f59ae62ea05effe84fef5b8b0e54998")
+            .unwrap();
+    let s: Vec<u8> =
+        FromHex::from_hex("4a691139ad57a3f0b906637673aa2f63d1f55cb1a69199d4009eea23ceaddc93")
+            .unwrap();
+    let expected: Vec<u8> = FromHex::from_hex("304502210090f27b8b488db00b00606796d2987f6a5f59ae62ea05effe84fef5b8b0e5499802204a691139ad57a3f0b906637673aa2f63d1f55cb1a69199d4009eea23ceaddc93").unwrap();
+
+    let signature = crypto_functions::encode_secp256k1_der_signature(&r, &s);
+    assert_eq!(signature, expected);
+}
+
+#[test]
+fn test_verify_secp256r1() {
+    let public_key: &[u8] = b"02bc52274edebbef8878eacc4d1e0ed4fb213e5b0737389701ae8d59c403325720";
+    let message: &[u8] = b"bf9facf48b2219db73b50c7ff59ceef2ada56632c71afc555d6bb4072d7634d1d9353acd53517ffb9a06935a89a6454fcaa40c69becf9f8029a271fd252ea55307d00d6e97a30719d48d6b7f993af24e9c54381cba02a113238eaee9d741cababeb21aaf";
+    let signature: &[u8] = b"c7877497444274267a4ea6f42deefde23a12e44f1ec1b437018e5c0e2834ce376dec1b81ebeacf5fbc6882e69af7cafad47bbb96cfb09e8d77d12afff7543052";
+    let bad_signature: &[u8] = b"00877497444274267a4ea6f42deefde23a12e44f1ec1b437018e5c0e2834ce376dec1b81ebeacf5fbc6882e69af7cafad47bbb96cfb09e8d77d12afff7543052";
+
+    let pub_bytes: Vec<u8> = FromHex::from_hex(public_key).unwrap();
+    let msg_bytes: Vec<u8> = FromHex::from_hex(message).unwrap();
+    let sig_bytes: Vec<u8> = FromHex::from_hex(signature).unwrap();
+    let bad_sig_bytes: Vec<u8> = FromHex::from_hex(bad_signature).unwrap();
+
+    assert!(crypto_functions::verify_secp256r1(
+        &pub_bytes, &msg_bytes, &sig_bytes
+    ));
+    assert!(!crypto_functions::verify_secp256r1(
+        &pub_bytes,
+        &msg_bytes,
+        &bad_sig_bytes
+    ));
+}
+
+#[test]
+fn test_verify_bls() {
+    let public_key: &[u8] = b"b5823f6e564251cc03ce7bad3da83e72576e92795d3500bba1acb30ec9a94dce87bb8aa794d67b2d61d15c33f28f6c0c23ba1dfcbf21e8f8b46286ff871afabac925303ddcaddce6254fcff6d3155797db40b3d3b5865e8fc0bd770b3d79b381";
+    let signature: &[u8] = b"af32a2ddf341c08d1eb7232f05dc34e4454155e676b58c40fddf9a036562ac2c01533d2d557cb49d73aa9d7a89744696";
+    let bad_signature: &[u8] = b"0032a2ddf341c08d1eb7232f05dc34e4454155e676b58c40fddf9a036562ac2c01533d2d557cb49d73aa9d7a89744696";
+
+    let pub_bytes: Vec<u8> = FromHex::from_hex(public_key).unwrap();
+    let sig_bytes: Vec<u8> = FromHex::from_hex(signature).unwrap();
+    let bad_sig_bytes: Vec<u8> = FromHex::from_hex(bad_signature).unwrap();
+
+    assert!(crypto_functions::verify_bls(
+        &pub_bytes,
+        b"message to be signed",
+        &sig_bytes
+    ));
+    assert!(!crypto_functions::verify_bls(
+        &pub_bytes,
+        b"message to be signed",
+        &bad_sig_bytes
+    ));
+    assert!(!crypto_functions::verify_bls(
+        &pub_bytes,
+        b"another message",
+        &sig_bytes
+    ));
+    assert!(!crypto_functions::verify_bls(
+        &pub_bytes[1..],
+        b"message to be signed",
+        &sig_bytes
+    ));
+}
+
+#[test]
+fn test_verify_bls_signature_share() {
+    let public_key: &[u8] = b"3e886a4c6e109a151f4105aee65a5192d150ef1fa68d3cd76964a0b086006dbe4324c989deb0e4416c6d6706db1b1910eb2732f08842fb4886067b9ed191109ac2188d76002d2e11da80a3f0ea89fee6b59c834cc478a6bd49cb8a193b1abb16";
+    let message: &[u8] = b"e96bd0f36b70c5ccc0c4396343bd7d8255b8a526c55fa1e218511fafe6539b8e";
+    let signature: &[u8] = b"04725db195e37aa237cdbbda76270d4a229b6e7a3651104dc58c4349c0388e8546976fe54a04240530b99064e434c90f";
+
+    let pub_bytes: Vec<u8> = FromHex::from_hex(public_key).unwrap();
+    let msg_bytes: Vec<u8> = FromHex::from_hex(message).unwrap();
+    let sig_bytes: Vec<u8> = FromHex::from_hex(signature).unwrap();
+
+    assert!(crypto_functions::verify_bls_signature_share(
+        &pub_bytes, &msg_bytes, &sig_bytes
+    ));
+}
+
+#[test]
+fn test_verify_bls_aggregated_signature() {
+    let public_keys: [&[u8]; 5] = [
+        b"95f1d96b582f35294eb7dd4589b158e108e1d94cd0dd71ef16140e9b37126ec52dac6f57397f1e041acd7bb77df1d214f9f894e3b7fbf7abeaabc9fab8ff5c2ef05f9841322f301fdb900ac59479c334ac76a2d4ff992cd49bc9b530c25ee293",
+        b"97aa2862418eb4ea74fddcb511eef9b771e07ff901e7e6abb35847a4bb81e58f189fc9bce4186c6129014fb43002300e959702ef4b9d0c32ebe4d795457095d65b5414efb36edbb8dc66d84a445a92472d4a31cedd4700d5ebb885eb11d3430b",
+        b"37b73265936a2aaafe652a4dd451a1851c2dbbb32208604787479b31033e2a354615562ea2a5488f9134843362477a139050a0e798dd5ce0f01b35b8d473454ae99633aadde9237f84c87eb366144cca4de3d2cc6acc35e522a3294bf1186800",
+        b"f46c10d114dcd3019dd4bcd4152fda678c56144eb177c67a6411213b86e206e4e56a9aad1eab0313b13031fda046d715ec4a02612b083dfae0d82a23b643e1a89756c0df3d65c27e87a9c1289628d1a8404f0668a3d87c7451ba1c78fc452693",
+        b"aa0a97917df9240c537c89e873d7baa5ce1796e8fedfb23cf682b80fa19b8baae35af3754f9b8149985cb2a1fbda0f02c2942d2c99d9af556c9a5e90b8170e6a96379a45dd69351abfb814a16b5665abb7ddb8b096ee9f273de81845cda9728a",
+    ];
+    let signature: &[u8] = b"ae12858363e8caa5b398d3febdd7bc01bc2fae1fef8f486ff4d84a5f3342f2d38085904eb10b73c0879a45d23585ce8f";
+
+    let pub_bytes: Vec<Vec<u8>> = public_keys
+        .iter()
+        .map(|key| FromHex::from_hex(key).unwrap())
+        .collect();
+    let sig_bytes: Vec<u8> = FromHex::from_hex(signature).unwrap();
+
+    assert!(crypto_functions::verify_bls_aggregated_signature(
+        &pub_bytes,
+        b"message0",
+        &sig_bytes
+    ));
+    assert!(!crypto_functions::verify_bls_aggregated_signature(
+        &pub_bytes[1..],
+        b"message0",
+        &sig_bytes
+    ));
+    assert!(!crypto_functions::verify_bls_aggregated_signature(
+        &[],
+        b"message0",
+        &sig_bytes
+    ));
+}
@@ -0,0 +1,119 @@
+use multiversx_chain_vm::{
+    tx_execution::BuiltinFunctionContainer,
+    tx_mock::{
+        async_call_tx_input, async_callback_tx_input, generate_user_tx_hash, AsyncCallTxData,
+        CallType, TxFunctionName, TxInput, TxResult,
+    },
+    types::{VMAddress, H256},
+    world_mock::{AccountData, BlockchainState},
+};
+
+fn address(name: &[u8]) -> VMAddress {
+    let mut bytes = [b'_'; 32];
+    bytes[..name.len()].copy_from_slice(name);
+    VMAddress::from(bytes)
+}
+
+fn user_tx_input() -> TxInput {
+    TxInput {
+        from: address(b"user"),
+        to: address(b"sc"),
+        func_name: TxFunctionName::from("endpoint"),
+        args: vec![b"arg".to_vec()],
+        gas_limit: 5_000_000,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn user_tx_hash_test() {
+    let tx_hash = generate_user_tx_hash(&user_tx_input(), 1);
+    assert_eq!(tx_hash, generate_user_tx_hash(&user_tx_input(), 1));
+    assert_ne!(tx_hash, generate_user_tx_hash(&user_tx_input(), 2));
+
+    let mut other_tx_input = user_tx_input();
+    other_tx_input.args = vec![b"ar".to_vec(), b"g".to_vec()];
+    assert_ne!(tx_hash, generate_user_tx_hash(&other_tx_input, 1));
+
+    let mut tx_input = user_tx_input();
+    tx_input.tx_hash = tx_hash.clone();
+    assert_eq!(tx_input.original_tx_hash(), &tx_hash);
+    assert_eq!(tx_input.prev_tx_hash(), &tx_hash);
+}
+
+#[test]
+fn async_call_tx_hash_test() {
+    let original_tx_hash = generate_user_tx_hash(&user_tx_input(), 1);
+    let async_call = AsyncCallTxData {
+        from: address(b"sc"),
+        to: address(b"other-sc"),
+        call_value: 0u32.into(),
+        endpoint_name: TxFunctionName::from("callee"),
+        arguments: Vec::new(),
+        gas_limit: 1_000_000,
+        tx_hash: original_tx_hash.clone(),
+        original_tx_hash: original_tx_hash.clone(),
+        prev_tx_hash: original_tx_hash.clone(),
+        original_caller: address(b"user"),
+        relayer: None,
+    };
+
+    let first_scr = async_call.clone().into_smart_contract_result(0);
+    let second_scr = async_call.into_smart_contract_result(1);
+    assert_ne!(first_scr.tx_hash, second_scr.tx_hash);
+
+    let async_input = async_call_tx_input(&first_scr, CallType::AsyncCall);
+    assert_ne!(async_input.tx_hash, original_tx_hash);
+    assert_eq!(async_input.original_tx_hash(), &original_tx_hash);
+    assert_eq!(async_input.prev_tx_hash(), &original_tx_hash);
+
+    let callback_input =
+        async_callback_tx_input(&first_scr, &TxResult::empty(), &BuiltinFunctionContainer);
+    assert_ne!(callback_input.tx_hash, original_tx_hash);
+    assert_ne!(callback_input.tx_hash, async_input.tx_hash);
+    assert_eq!(callback_input.original_tx_hash(), &original_tx_hash);
+    assert_eq!(callback_input.prev_tx_hash(), &async_input.tx_hash);
+}
+
+#[test]
+fn state_root_hash_test() {
+    let mut state = BlockchainState::default();
+    let empty_root = state.state_root_hash();
+    assert_eq!(empty_root, H256::zero());
+
+    for name in [b"a".as_slice(), b"b", b"c"] {
+        let account = AccountData::new_empty(address(name));
+        state.accounts.insert(account.address.clone(), account);
+    }
+    let root = state.state_root_hash();
+    assert_ne!(root, empty_root);
+    assert_eq!(root, state.clone().state_root_hash());
+
+    state
+        .accounts
+        .get_mut(&address(b"c"))
+        .unwrap()
+        .storage
+        .insert(b"key".to_vec(), b"value".to_vec());
+    let changed_root = state.state_root_hash();
+    assert_ne!(changed_root, root);
+
+    state
+        .accounts
+        .get_mut(&address(b"c"))
+        .unwrap()
+        .storage
+        .clear();
+    assert_eq!(state.state_root_hash(), root);
+}
+
+#[test]
+fn block_hash_test() {
+    let mut state = BlockchainState::default();
+    state.current_block_info.block_nonce = 10;
+
+    let block_hash = state.block_hash(5).unwrap();
+    assert_eq!(state.block_hash(5), Some(block_hash.clone()));
+    assert_ne!(state.block_hash(10), Some(block_hash));
+    assert_eq!(state.block_hash(11), None);
+}
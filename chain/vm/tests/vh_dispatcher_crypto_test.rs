@@ -0,0 +1,113 @@
+use hex::FromHex;
+use multiversx_chain_vm::{
+    crypto_functions,
+    executor::{MemLength, MemPtr, VMHooks},
+    vm_hooks::{StaticApiVMHooksHandler, VMHooksDispatcher},
+};
+
+const SECP256K1_PUBLIC_KEY: &str =
+    "02a673638cb9587cb68ea08dbef685c6f2d2a751a8b3c6f2a7e9a4999e6e4bfaf5";
+const SECP256K1_SIGNATURE_R: &str =
+    "fab29dbcb623e7e96f44665f312b89ffdb9e4d2e2f53353236fbbcf57b2f49bf";
+const SECP256K1_SIGNATURE_S: &str =
+    "3d6f9c63df8be43121042970a549a2d3baeca093fd37b726ddcc0924638409f5";
+const SECP256K1_SIGNATURE: &str = "3045022100fab29dbcb623e7e96f44665f312b89ffdb9e4d2e2f53353236fbbcf57b2f49bf02203d6f9c63df8be43121042970a549a2d3baeca093fd37b726ddcc0924638409f5";
+const SECP256K1_MESSAGE: &[u8] = b"message to sign";
+
+/// The legacy hooks work with contract memory, here simply the memory of the test.
+fn dispatcher() -> VMHooksDispatcher {
+    VMHooksDispatcher::new(Box::new(StaticApiVMHooksHandler::default()))
+}
+
+fn ptr(bytes: &[u8]) -> MemPtr {
+    bytes.as_ptr() as MemPtr
+}
+
+fn len(bytes: &[u8]) -> MemLength {
+    bytes.len() as MemLength
+}
+
+fn from_hex(hex: &str) -> Vec<u8> {
+    FromHex::from_hex(hex).unwrap()
+}
+
+#[test]
+fn vh_ripemd160_test() {
+    let data = [1u8, 2, 3];
+    let mut result = [0u8; 20];
+    let status = dispatcher().ripemd160(ptr(&data), len(&data), result.as_mut_ptr() as MemPtr);
+    assert_eq!(status, 0);
+    assert_eq!(result, crypto_functions::ripemd160(&data));
+}
+
+#[test]
+fn vh_verify_secp256k1_test() {
+    let key = from_hex(SECP256K1_PUBLIC_KEY);
+    let signature = from_hex(SECP256K1_SIGNATURE);
+    let vh = dispatcher();
+
+    let status = vh.verify_secp256k1(
+        ptr(&key),
+        len(&key),
+        ptr(SECP256K1_MESSAGE),
+        len(SECP256K1_MESSAGE),
+        ptr(&signature),
+    );
+    assert_eq!(status, 0);
+
+    let status = vh.verify_secp256k1(
+        ptr(&key),
+        len(&key),
+        ptr(b"other message"),
+        len(b"other message"),
+        ptr(&signature),
+    );
+    assert_eq!(status, -1);
+}
+
+#[test]
+fn vh_verify_custom_secp256k1_test() {
+    let key = from_hex(SECP256K1_PUBLIC_KEY);
+    // trailing bytes after the DER signature are ignored, the length comes from the DER header
+    let mut signature = from_hex(SECP256K1_SIGNATURE);
+    signature.extend_from_slice(&[0xff; 4]);
+    let vh = dispatcher();
+
+    let status = vh.verify_custom_secp256k1(
+        ptr(&key),
+        len(&key),
+        ptr(SECP256K1_MESSAGE),
+        len(SECP256K1_MESSAGE),
+        ptr(&signature),
+        crypto_functions::ECDSA_DOUBLE_SHA256 as i32,
+    );
+    assert_eq!(status, 0);
+
+    let status = vh.verify_custom_secp256k1(
+        ptr(&key),
+        len(&key),
+        ptr(SECP256K1_MESSAGE),
+        len(SECP256K1_MESSAGE),
+        ptr(&signature),
+        crypto_functions::ECDSA_SHA256 as i32,
+    );
+    assert_eq!(status, -1);
+}
+
+#[test]
+fn vh_encode_secp256k1_der_signature_test() {
+    let r = from_hex(SECP256K1_SIGNATURE_R);
+    let s = from_hex(SECP256K1_SIGNATURE_S);
+    let expected = from_hex(SECP256K1_SIGNATURE);
+    let mut signature = vec![0u8; expected.len()];
+
+    let status = dispatcher().encode_secp256k1_der_signature(
+        ptr(&r),
+        len(&r),
+        ptr(&s),
+        len(&s),
+        signature.as_mut_ptr() as MemPtr,
+    );
+    assert_eq!(status, 0);
+    assert_eq!(signature, expected);
+}
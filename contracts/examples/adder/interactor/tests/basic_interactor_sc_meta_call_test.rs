@@ -0,0 +1,123 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use adder::adder_proxy;
+use multiversx_sc_meta::{cli::ContractEndpointArgs, cmd::contract_call::EndpointRequest};
+use multiversx_sc_snippets::{
+    imports::*, multiversx_sc::contract_base::ContractAbiProvider,
+    multiversx_sc_scenario::meta::abi_json::abi_to_json_dummy_environment,
+};
+use serde_json::json;
+
+const ADDER_CODE_PATH: MxscPath = MxscPath::new("../../multisig/test-contracts/adder.mxsc.json");
+
+/// The `sc-meta` commands only get the ABI JSON file, same as for a contract on a real network.
+///
+/// Each test writes its own file, since tests run in parallel.
+fn write_adder_abi(file_name: &str) -> PathBuf {
+    let abi_json = abi_to_json_dummy_environment(&adder::AbiProvider::abi());
+    let path = std::env::temp_dir().join(file_name);
+    fs::write(&path, abi_json).unwrap();
+    path
+}
+
+fn endpoint_args(abi: &Path, address: &str, endpoint: &str, args: &str) -> ContractEndpointArgs {
+    ContractEndpointArgs {
+        gateway: String::new(),
+        abi: abi.to_path_buf(),
+        address: address.to_string(),
+        endpoint: endpoint.to_string(),
+        args: args.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn sc_meta_query_and_call_test() {
+    let mut interactor = VMInteractor::new_vm().await;
+    interactor.set_current_dir_from_workspace("contracts/examples/adder/interactor");
+    interactor.register_contract(ADDER_CODE_PATH, adder::ContractBuilder);
+    let owner = interactor.register_wallet(test_wallets::heidi()).await;
+    let adder_address = interactor
+        .tx()
+        .from(&owner)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u64)
+        .code(ADDER_CODE_PATH)
+        .returns(ReturnsNewBech32Address)
+        .run()
+        .await;
+    let abi = write_adder_abi("adder_sc_meta_query_and_call_test.abi.json");
+
+    let get_sum = EndpointRequest::new(&endpoint_args(
+        &abi,
+        adder_address.to_bech32_str(),
+        "getSum",
+        "[]",
+    ))
+    .unwrap();
+    assert_eq!(get_sum.query(&mut interactor).await, Ok(vec![json!("5")]));
+
+    let add = EndpointRequest::new(&endpoint_args(
+        &abi,
+        adder_address.to_bech32_str(),
+        "add",
+        r#"["7"]"#,
+    ))
+    .unwrap();
+    let results = add
+        .call(
+            &mut interactor,
+            &owner,
+            6_000_000,
+            EgldOrMultiEsdtPayment::Egld(BigUint::zero()),
+        )
+        .await;
+    assert_eq!(results, Ok(vec![]));
+    assert_eq!(get_sum.query(&mut interactor).await, Ok(vec![json!("12")]));
+
+    let too_many_args = EndpointRequest::new(&endpoint_args(
+        &abi,
+        adder_address.to_bech32_str(),
+        "getSum",
+        "[1]",
+    ));
+    assert!(too_many_args.is_err());
+    let error = get_sum
+        .call(
+            &mut interactor,
+            &owner,
+            6_000_000,
+            EgldOrMultiEsdtPayment::Egld(BigUint::from(1u32)),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error,
+        "`getSum` failed: function does not accept EGLD payment"
+    );
+}
+
+#[test]
+fn sc_meta_invalid_address_test() {
+    let abi = write_adder_abi("adder_sc_meta_invalid_address_test.abi.json");
+    let args = endpoint_args(&abi, "erd1invalid", "getSum", "[]");
+    let Err(error) = EndpointRequest::new(&args) else {
+        panic!("expected invalid address error");
+    };
+    assert!(error.starts_with("invalid contract address: "));
+
+    // valid bech32, but not a MultiversX address
+    let args = endpoint_args(
+        &abi,
+        "tst1qqqqqqqqqqqqqpgqfzydqmdw7m2vazsp6u5p95yxz76t2p9rd8ssr080ak",
+        "getSum",
+        "[]",
+    );
+    let Err(error) = EndpointRequest::new(&args) else {
+        panic!("expected invalid address HRP error");
+    };
+    assert!(error.contains("HRP `tst`"));
+}
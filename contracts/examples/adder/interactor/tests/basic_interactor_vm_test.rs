@@ -0,0 +1,299 @@
+use adder::adder_proxy;
+use multiversx_sc_snippets::{
+    hex,
+    imports::*,
+    multiversx_sc_scenario::multiversx_chain_vm::world_mock::{AccountData, EsdtInstanceMetadata},
+    sdk::{
+        data::{sdk_address::SdkAddress, transaction::Transaction},
+        gateway::{
+            GatewayAsyncService, GetAccountEsdtRolesRequest, GetAccountEsdtTokensRequest,
+            NetworkConfigRequest, NetworkStatusRequest, SendTxRequest,
+        },
+    },
+};
+
+const TOKEN_ID: &str = "ADDER-123456";
+const NFT_ID: &str = "ADDERNFT-123456";
+const ADDER_CODE_PATH: MxscPath = MxscPath::new("../../multisig/test-contracts/adder.mxsc.json");
+
+async fn new_vm_interactor() -> VMInteractor {
+    let mut interactor = VMInteractor::new_vm().await;
+    interactor.set_current_dir_from_workspace("contracts/examples/adder/interactor");
+    interactor.register_contract(ADDER_CODE_PATH, adder::ContractBuilder);
+    interactor
+}
+
+async fn deploy(interactor: &mut VMInteractor, owner: &Bech32Address) -> Bech32Address {
+    interactor
+        .tx()
+        .from(owner)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u64)
+        .code(ADDER_CODE_PATH)
+        .returns(ReturnsNewBech32Address)
+        .run()
+        .await
+}
+
+async fn get_sum(interactor: &mut VMInteractor, adder_address: &Bech32Address) -> RustBigUint {
+    interactor
+        .query()
+        .to(adder_address)
+        .typed(adder_proxy::AdderProxy)
+        .sum()
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .await
+}
+
+#[tokio::test]
+async fn vm_deploy_add_query_test() {
+    let mut interactor = new_vm_interactor().await;
+    let owner: Bech32Address = interactor
+        .register_wallet(test_wallets::heidi())
+        .await
+        .into();
+    let user: Bech32Address = interactor
+        .register_wallet(test_wallets::ivan())
+        .await
+        .into();
+
+    let adder_address = deploy(&mut interactor, &owner).await;
+    assert_eq!(get_sum(&mut interactor, &adder_address).await, 5u32.into());
+
+    interactor
+        .tx()
+        .from(&user)
+        .to(&adder_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .add(7u32)
+        .run()
+        .await;
+    assert_eq!(get_sum(&mut interactor, &adder_address).await, 12u32.into());
+
+    let account = interactor.get_account(&adder_address.to_address()).await;
+    assert_eq!(account.owner_address, Some(owner.to_bech32_string()));
+    let storage = interactor
+        .get_account_storage(&adder_address.to_address())
+        .await;
+    assert_eq!(storage.get(&hex::encode("sum")), Some(&hex::encode([12u8])));
+}
+
+#[tokio::test]
+async fn vm_failed_tx_test() {
+    let mut interactor = new_vm_interactor().await;
+    let owner: Bech32Address = interactor
+        .register_wallet(test_wallets::heidi())
+        .await
+        .into();
+    let adder_address = deploy(&mut interactor, &owner).await;
+
+    let error = interactor
+        .tx()
+        .from(&owner)
+        .to(&adder_address)
+        .gas(6_000_000)
+        .raw_call("missingEndpoint")
+        .returns(ReturnsHandledOrError::new())
+        .run()
+        .await
+        .unwrap_err();
+    assert_eq!(error.message, "invalid function (not found)");
+
+    // the failed transaction still increased the sender nonce
+    assert_eq!(get_sum(&mut interactor, &adder_address).await, 5u32.into());
+    interactor
+        .tx()
+        .from(&owner)
+        .to(&adder_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .add(1u32)
+        .run()
+        .await;
+    assert_eq!(get_sum(&mut interactor, &adder_address).await, 6u32.into());
+}
+
+#[tokio::test]
+async fn vm_send_multiple_test() {
+    let mut interactor = new_vm_interactor().await;
+    let owner: Bech32Address = interactor
+        .register_wallet(test_wallets::heidi())
+        .await
+        .into();
+    let adder_address = deploy(&mut interactor, &owner).await;
+
+    let mut buffer = interactor.homogenous_call_buffer();
+    for value in 1u32..=3 {
+        buffer.push_tx(|tx| {
+            tx.from(&owner)
+                .to(&adder_address)
+                .gas(6_000_000)
+                .typed(adder_proxy::AdderProxy)
+                .add(value)
+                .returns(ReturnsStatus)
+        });
+    }
+    let statuses = buffer.run().await;
+    assert_eq!(statuses, vec![0, 0, 0]);
+
+    assert_eq!(get_sum(&mut interactor, &adder_address).await, 11u32.into());
+}
+
+#[tokio::test]
+async fn vm_invalid_signature_test() {
+    let mut interactor = new_vm_interactor().await;
+    let owner = interactor.register_wallet(test_wallets::heidi()).await;
+    let relayer = interactor.register_wallet(test_wallets::ivan()).await;
+    let network_config = interactor
+        .proxy
+        .request(NetworkConfigRequest)
+        .await
+        .unwrap();
+
+    let mut tx = Transaction {
+        nonce: 0,
+        value: "1".to_string(),
+        receiver: SdkAddress(relayer.clone()),
+        sender: SdkAddress(owner),
+        gas_price: network_config.min_gas_price,
+        gas_limit: network_config.min_gas_limit,
+        data: None,
+        signature: None,
+        chain_id: network_config.chain_id,
+        version: 2,
+        options: 0,
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
+    };
+    let send_error = |tx: &Transaction| {
+        interactor
+            .proxy
+            .vm_request(SendTxRequest(tx))
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert!(send_error(&tx).contains("invalid signature"));
+
+    // signed, then tampered with
+    tx.signature = Some(hex::encode(test_wallets::heidi().sign_tx(&tx)));
+    tx.value = "2".to_string();
+    assert!(send_error(&tx).contains("invalid signature"));
+
+    // the relayer must co-sign the same payload
+    tx.relayer = Some(SdkAddress(relayer));
+    tx.signature = Some(hex::encode(test_wallets::heidi().sign_tx(&tx)));
+    tx.relayer_signature = Some(hex::encode(test_wallets::heidi().sign_tx(&tx)));
+    assert!(send_error(&tx).contains("invalid relayer signature"));
+
+    tx.relayer_signature = Some(hex::encode(test_wallets::ivan().sign_tx_as_relayer(&tx)));
+    interactor.proxy.vm_request(SendTxRequest(&tx)).unwrap();
+}
+
+#[tokio::test]
+async fn vm_esdt_balances_and_roles_test() {
+    let mut interactor = new_vm_interactor().await;
+    let owner: Bech32Address = interactor
+        .register_wallet(test_wallets::heidi())
+        .await
+        .into();
+    let user: Bech32Address = interactor
+        .register_wallet(test_wallets::ivan())
+        .await
+        .into();
+
+    interactor.proxy.with_vm_runner(|runner| {
+        let account = runner
+            .blockchain_mock
+            .state
+            .accounts
+            .entry(owner.to_address())
+            .or_insert_with(|| AccountData::new_empty(owner.to_address()));
+        account.esdt.set_esdt_balance(
+            TOKEN_ID.as_bytes().to_vec(),
+            0,
+            &1_000u32.into(),
+            EsdtInstanceMetadata::default(),
+        );
+        account.esdt.set_esdt_balance(
+            NFT_ID.as_bytes().to_vec(),
+            10,
+            &1u32.into(),
+            EsdtInstanceMetadata::default(),
+        );
+        account.esdt.set_roles(
+            TOKEN_ID.as_bytes().to_vec(),
+            vec![b"ESDTRoleLocalMint".to_vec(), b"ESDTRoleLocalBurn".to_vec()],
+        );
+    });
+
+    interactor
+        .tx()
+        .from(&owner)
+        .to(&user)
+        .gas(1_000_000)
+        .single_esdt(&TOKEN_ID.into(), 0, &300u32.into())
+        .run()
+        .await;
+
+    let owner_esdts = interactor
+        .proxy
+        .request(GetAccountEsdtTokensRequest::new(&owner.to_address()))
+        .await
+        .unwrap();
+    assert_eq!(owner_esdts.len(), 2);
+    assert_eq!(owner_esdts[TOKEN_ID].token_identifier, TOKEN_ID);
+    assert_eq!(owner_esdts[TOKEN_ID].balance, "700");
+    let nft_key = format!("{NFT_ID}-0a");
+    assert_eq!(owner_esdts[&nft_key].token_identifier, nft_key);
+    assert_eq!(owner_esdts[&nft_key].balance, "1");
+
+    let user_esdts = interactor
+        .proxy
+        .request(GetAccountEsdtTokensRequest::new(&user.to_address()))
+        .await
+        .unwrap();
+    assert_eq!(user_esdts.len(), 1);
+    assert_eq!(user_esdts[TOKEN_ID].balance, "300");
+
+    let owner_roles = interactor
+        .proxy
+        .request(GetAccountEsdtRolesRequest::new(&owner.to_address()))
+        .await
+        .unwrap();
+    assert_eq!(owner_roles.len(), 1);
+    assert_eq!(
+        owner_roles[TOKEN_ID],
+        vec![
+            "ESDTRoleLocalMint".to_string(),
+            "ESDTRoleLocalBurn".to_string()
+        ]
+    );
+    let user_roles = interactor
+        .proxy
+        .request(GetAccountEsdtRolesRequest::new(&user.to_address()))
+        .await
+        .unwrap();
+    assert!(user_roles.is_empty());
+}
+
+#[tokio::test]
+async fn vm_generate_blocks_test() {
+    let interactor = new_vm_interactor().await;
+
+    interactor.generate_blocks_until_epoch(2).await.unwrap();
+    interactor.generate_blocks(3).await.unwrap();
+
+    let network_status = interactor
+        .proxy
+        .request(NetworkStatusRequest::default())
+        .await
+        .unwrap();
+    assert_eq!(network_status.epoch_number, 2);
+    assert_eq!(network_status.nonce, 43);
+}
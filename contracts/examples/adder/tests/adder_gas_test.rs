@@ -0,0 +1,81 @@
+use multiversx_sc_scenario::{imports::*, multiversx_chain_vm::schedule::GasSchedule};
+
+use adder::*;
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const ADDER_ADDRESS: TestSCAddress = TestSCAddress::new("adder");
+const CODE_PATH: MxscPath = MxscPath::new("output/adder.mxsc.json");
+
+const GAS_SCHEDULE_TOML: &str = r#"
+[BigIntAPICost]
+    BigIntAdd = 2000
+
+[ManagedBufferAPICost]
+    MBufferStorageStore = 75000
+"#;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+    blockchain.register_contract(CODE_PATH, adder::ContractBuilder);
+    blockchain.gas_schedule(GasSchedule::from_toml_str(GAS_SCHEDULE_TOML).unwrap());
+    blockchain
+}
+
+#[test]
+fn adder_gas_used() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u32)
+        .code(CODE_PATH)
+        .new_address(ADDER_ADDRESS)
+        .run();
+
+    let gas_used = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ADDER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .add(1u32)
+        .returns(ReturnsGasUsed)
+        .run();
+
+    assert!(gas_used >= 2000 + 75000);
+}
+
+#[test]
+fn adder_out_of_gas() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u32)
+        .code(CODE_PATH)
+        .new_address(ADDER_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ADDER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .add(1u32)
+        .gas(50_000)
+        .returns(ExpectError(5, "out of gas"))
+        .run();
+
+    world
+        .check_account(ADDER_ADDRESS)
+        .check_storage("str:sum", "5");
+}
@@ -50,7 +50,7 @@ impl<M: ManagedTypeApi> Action<M> {
 
 /// Not used internally, just to retrieve results via endpoint.
 #[type_abi]
-#[derive(TopEncode)]
+#[derive(TopEncode, TopDecode)]
 pub struct ActionFullInfo<M: ManagedTypeApi> {
     pub action_id: usize,
     pub action_data: Action<M>,
@@ -406,7 +406,7 @@ where
 }
 
 #[type_abi]
-#[derive(TopEncode)]
+#[derive(TopEncode, TopDecode)]
 pub struct ActionFullInfo<Api>
 where
     Api: ManagedTypeApi,
@@ -464,3 +464,309 @@ pub enum UserRole {
     Proposer,
     BoardMember,
 }
+
+pub struct AsyncCallSuccessEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub results: MultiValueEncoded<Api, ManagedBuffer<Api>>,
+}
+
+#[rustfmt::skip]
+impl<Api> TypedEvent for AsyncCallSuccessEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    const IDENTIFIER: &'static str = "asyncCallSuccess";
+
+    fn decode_event(log: &mut EventLogInput) -> Result<Self, DecodeError> {
+        Ok(AsyncCallSuccessEvent {
+            results: log.next_topic()?,
+        })
+    }
+
+    fn encode_event(&self, log: &mut EventLogOutput) -> Result<(), EncodeError> {
+        log.push_topic(&self.results)?;
+        Ok(())
+    }
+}
+
+pub struct AsyncCallErrorEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub err_code: u32,
+    pub err_message: ManagedBuffer<Api>,
+}
+
+#[rustfmt::skip]
+impl<Api> TypedEvent for AsyncCallErrorEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    const IDENTIFIER: &'static str = "asyncCallError";
+
+    fn decode_event(log: &mut EventLogInput) -> Result<Self, DecodeError> {
+        Ok(AsyncCallErrorEvent {
+            err_code: log.next_topic()?,
+            err_message: log.next_topic()?,
+        })
+    }
+
+    fn encode_event(&self, log: &mut EventLogOutput) -> Result<(), EncodeError> {
+        log.push_topic(&self.err_code)?;
+        log.push_topic(&self.err_message)?;
+        Ok(())
+    }
+}
+
+pub struct StartPerformActionEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub data: ActionFullInfo<Api>,
+}
+
+#[rustfmt::skip]
+impl<Api> TypedEvent for StartPerformActionEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    const IDENTIFIER: &'static str = "startPerformAction";
+
+    fn decode_event(log: &mut EventLogInput) -> Result<Self, DecodeError> {
+        Ok(StartPerformActionEvent {
+            data: log.data()?,
+        })
+    }
+
+    fn encode_event(&self, log: &mut EventLogOutput) -> Result<(), EncodeError> {
+        log.set_data(&self.data)?;
+        Ok(())
+    }
+}
+
+pub struct PerformChangeUserEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub action_id: usize,
+    pub changed_user: ManagedAddress<Api>,
+    pub old_role: UserRole,
+    pub new_role: UserRole,
+}
+
+#[rustfmt::skip]
+impl<Api> TypedEvent for PerformChangeUserEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    const IDENTIFIER: &'static str = "performChangeUser";
+
+    fn decode_event(log: &mut EventLogInput) -> Result<Self, DecodeError> {
+        Ok(PerformChangeUserEvent {
+            action_id: log.next_topic()?,
+            changed_user: log.next_topic()?,
+            old_role: log.next_topic()?,
+            new_role: log.next_topic()?,
+        })
+    }
+
+    fn encode_event(&self, log: &mut EventLogOutput) -> Result<(), EncodeError> {
+        log.push_topic(&self.action_id)?;
+        log.push_topic(&self.changed_user)?;
+        log.push_topic(&self.old_role)?;
+        log.push_topic(&self.new_role)?;
+        Ok(())
+    }
+}
+
+pub struct PerformChangeQuorumEvent {
+    pub action_id: usize,
+    pub new_quorum: usize,
+}
+
+#[rustfmt::skip]
+impl TypedEvent for PerformChangeQuorumEvent {
+    const IDENTIFIER: &'static str = "performChangeQuorum";
+
+    fn decode_event(log: &mut EventLogInput) -> Result<Self, DecodeError> {
+        Ok(PerformChangeQuorumEvent {
+            action_id: log.next_topic()?,
+            new_quorum: log.next_topic()?,
+        })
+    }
+
+    fn encode_event(&self, log: &mut EventLogOutput) -> Result<(), EncodeError> {
+        log.push_topic(&self.action_id)?;
+        log.push_topic(&self.new_quorum)?;
+        Ok(())
+    }
+}
+
+pub struct PerformAsyncCallEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub action_id: usize,
+    pub to: ManagedAddress<Api>,
+    pub egld_value: BigUint<Api>,
+    pub gas: u64,
+    pub endpoint: ManagedBuffer<Api>,
+    pub arguments: MultiValueManagedVec<Api, ManagedBuffer<Api>>,
+}
+
+#[rustfmt::skip]
+impl<Api> TypedEvent for PerformAsyncCallEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    const IDENTIFIER: &'static str = "performAsyncCall";
+
+    fn decode_event(log: &mut EventLogInput) -> Result<Self, DecodeError> {
+        Ok(PerformAsyncCallEvent {
+            action_id: log.next_topic()?,
+            to: log.next_topic()?,
+            egld_value: log.next_topic()?,
+            gas: log.next_topic()?,
+            endpoint: log.next_topic()?,
+            arguments: log.next_topic()?,
+        })
+    }
+
+    fn encode_event(&self, log: &mut EventLogOutput) -> Result<(), EncodeError> {
+        log.push_topic(&self.action_id)?;
+        log.push_topic(&self.to)?;
+        log.push_topic(&self.egld_value)?;
+        log.push_topic(&self.gas)?;
+        log.push_topic(&self.endpoint)?;
+        log.push_topic(&self.arguments)?;
+        Ok(())
+    }
+}
+
+pub struct PerformTransferExecuteEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub action_id: usize,
+    pub to: ManagedAddress<Api>,
+    pub egld_value: BigUint<Api>,
+    pub gas: u64,
+    pub endpoint: ManagedBuffer<Api>,
+    pub arguments: MultiValueManagedVec<Api, ManagedBuffer<Api>>,
+}
+
+#[rustfmt::skip]
+impl<Api> TypedEvent for PerformTransferExecuteEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    const IDENTIFIER: &'static str = "performTransferExecute";
+
+    fn decode_event(log: &mut EventLogInput) -> Result<Self, DecodeError> {
+        Ok(PerformTransferExecuteEvent {
+            action_id: log.next_topic()?,
+            to: log.next_topic()?,
+            egld_value: log.next_topic()?,
+            gas: log.next_topic()?,
+            endpoint: log.next_topic()?,
+            arguments: log.next_topic()?,
+        })
+    }
+
+    fn encode_event(&self, log: &mut EventLogOutput) -> Result<(), EncodeError> {
+        log.push_topic(&self.action_id)?;
+        log.push_topic(&self.to)?;
+        log.push_topic(&self.egld_value)?;
+        log.push_topic(&self.gas)?;
+        log.push_topic(&self.endpoint)?;
+        log.push_topic(&self.arguments)?;
+        Ok(())
+    }
+}
+
+pub struct PerformDeployFromSourceEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub action_id: usize,
+    pub egld_value: BigUint<Api>,
+    pub source_address: ManagedAddress<Api>,
+    pub code_metadata: CodeMetadata,
+    pub gas: u64,
+    pub arguments: MultiValueManagedVec<Api, ManagedBuffer<Api>>,
+}
+
+#[rustfmt::skip]
+impl<Api> TypedEvent for PerformDeployFromSourceEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    const IDENTIFIER: &'static str = "performDeployFromSource";
+
+    fn decode_event(log: &mut EventLogInput) -> Result<Self, DecodeError> {
+        Ok(PerformDeployFromSourceEvent {
+            action_id: log.next_topic()?,
+            egld_value: log.next_topic()?,
+            source_address: log.next_topic()?,
+            code_metadata: log.next_topic()?,
+            gas: log.next_topic()?,
+            arguments: log.next_topic()?,
+        })
+    }
+
+    fn encode_event(&self, log: &mut EventLogOutput) -> Result<(), EncodeError> {
+        log.push_topic(&self.action_id)?;
+        log.push_topic(&self.egld_value)?;
+        log.push_topic(&self.source_address)?;
+        log.push_topic(&self.code_metadata)?;
+        log.push_topic(&self.gas)?;
+        log.push_topic(&self.arguments)?;
+        Ok(())
+    }
+}
+
+pub struct PerformUpgradeFromSourceEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub action_id: usize,
+    pub target_address: ManagedAddress<Api>,
+    pub egld_value: BigUint<Api>,
+    pub source_address: ManagedAddress<Api>,
+    pub code_metadata: CodeMetadata,
+    pub gas: u64,
+    pub arguments: MultiValueManagedVec<Api, ManagedBuffer<Api>>,
+}
+
+#[rustfmt::skip]
+impl<Api> TypedEvent for PerformUpgradeFromSourceEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    const IDENTIFIER: &'static str = "performUpgradeFromSource";
+
+    fn decode_event(log: &mut EventLogInput) -> Result<Self, DecodeError> {
+        Ok(PerformUpgradeFromSourceEvent {
+            action_id: log.next_topic()?,
+            target_address: log.next_topic()?,
+            egld_value: log.next_topic()?,
+            source_address: log.next_topic()?,
+            code_metadata: log.next_topic()?,
+            gas: log.next_topic()?,
+            arguments: log.next_topic()?,
+        })
+    }
+
+    fn encode_event(&self, log: &mut EventLogOutput) -> Result<(), EncodeError> {
+        log.push_topic(&self.action_id)?;
+        log.push_topic(&self.target_address)?;
+        log.push_topic(&self.egld_value)?;
+        log.push_topic(&self.source_address)?;
+        log.push_topic(&self.code_metadata)?;
+        log.push_topic(&self.gas)?;
+        log.push_topic(&self.arguments)?;
+        Ok(())
+    }
+}
@@ -160,7 +160,7 @@ where
 }
 
 #[type_abi]
-#[derive(TopEncode)]
+#[derive(TopEncode, TopDecode)]
 pub struct ActionFullInfo<Api>
 where
     Api: ManagedTypeApi,
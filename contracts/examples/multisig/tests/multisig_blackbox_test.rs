@@ -258,7 +258,25 @@ fn test_add_board_member() {
 
     let action_id = state.propose_add_board_member(new_board_member_expr);
     state.sign(action_id);
-    state.perform(action_id);
+    let events = state
+        .world
+        .tx()
+        .from(BOARD_MEMBER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .perform_action_endpoint(action_id)
+        .returns(ReturnsEvents::<
+            multisig_proxy::PerformChangeUserEvent<StaticApi>,
+        >::new())
+        .run();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].action_id, action_id);
+    assert_eq!(
+        events[0].changed_user,
+        new_board_member_expr.to_managed_address()
+    );
+    assert_eq!(events[0].old_role, multisig_proxy::UserRole::None);
+    assert_eq!(events[0].new_role, multisig_proxy::UserRole::BoardMember);
 
     let expected_value = MultiValueVec::from(vec![BOARD_MEMBER_ADDRESS, new_board_member_expr]);
 
@@ -409,7 +427,18 @@ fn test_change_quorum() {
     // change quorum to 2
     let action_id = state.propose_change_quorum(new_quorum);
     state.sign(action_id);
-    state.perform(action_id);
+    state
+        .world
+        .tx()
+        .from(BOARD_MEMBER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .perform_action_endpoint(action_id)
+        .expect_event(multisig_proxy::PerformChangeQuorumEvent {
+            action_id,
+            new_quorum,
+        })
+        .run();
 }
 
 #[test]
@@ -0,0 +1,119 @@
+use multiversx_sc_scenario::imports::*;
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const FACTORY_ADDRESS: TestSCAddress = TestSCAddress::new("factory");
+const FACTORY_PATH: MxscPath = MxscPath::new("output/order-book-factory.mxsc.json");
+const PAIR_TEMPLATE_ADDRESS: TestSCAddress = TestSCAddress::new("pair-template");
+const PAIR_ADDRESS: TestSCAddress = TestSCAddress::new("pair");
+const PAIR_PATH: MxscPath = MxscPath::new("../pair/output/order-book-pair.mxsc.json");
+
+const FIRST_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("FIRST-123456");
+const SECOND_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("SECOND-123456");
+
+fn token_id_pair(
+    first_token_id: TestTokenIdentifier,
+    second_token_id: TestTokenIdentifier,
+) -> (TokenIdentifier<StaticApi>, TokenIdentifier<StaticApi>) {
+    (
+        first_token_id.to_token_identifier(),
+        second_token_id.to_token_identifier(),
+    )
+}
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/examples/order-book/factory");
+    blockchain.register_contract(FACTORY_PATH, order_book_factory::ContractBuilder);
+    blockchain.register_contract(PAIR_PATH, order_book_pair::ContractBuilder);
+
+    blockchain.account(OWNER_ADDRESS).nonce(1);
+
+    blockchain
+}
+
+fn deploy_pair_template(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .raw_deploy()
+        .code(PAIR_PATH)
+        .argument(&TestTokenIdentifier::new("TEMPLATE-111111"))
+        .argument(&TestTokenIdentifier::new("TEMPLATE-222222"))
+        .new_address(PAIR_TEMPLATE_ADDRESS)
+        .run();
+}
+
+fn deploy_factory<A>(world: &mut ScenarioWorld, pair_template_address: A)
+where
+    A: TopEncode,
+{
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .raw_deploy()
+        .code(FACTORY_PATH)
+        .argument(&pair_template_address)
+        .new_address(FACTORY_ADDRESS)
+        .run();
+}
+
+#[test]
+fn factory_create_pair_test() {
+    let mut world = world();
+    deploy_pair_template(&mut world);
+    deploy_factory(&mut world, PAIR_TEMPLATE_ADDRESS);
+
+    world.new_address(FACTORY_ADDRESS, 0, PAIR_ADDRESS);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(FACTORY_ADDRESS)
+        .raw_call("createPair")
+        .argument(&token_id_pair(FIRST_TOKEN_ID, SECOND_TOKEN_ID))
+        .original_result::<ManagedAddress<StaticApi>>()
+        .returns(ExpectValue(PAIR_ADDRESS))
+        .run();
+
+    // the code is copied from the template, init runs with the new arguments
+    world.check_account(PAIR_ADDRESS).code(PAIR_PATH);
+    world
+        .query()
+        .to(PAIR_ADDRESS)
+        .raw_call("getFirstTokenId")
+        .original_result::<TokenIdentifier<StaticApi>>()
+        .returns(ExpectValue(FIRST_TOKEN_ID))
+        .run();
+    world
+        .query()
+        .to(PAIR_ADDRESS)
+        .raw_call("getSecondTokenId")
+        .original_result::<TokenIdentifier<StaticApi>>()
+        .returns(ExpectValue(SECOND_TOKEN_ID))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(FACTORY_ADDRESS)
+        .raw_call("createPair")
+        .argument(&token_id_pair(SECOND_TOKEN_ID, FIRST_TOKEN_ID))
+        .returns(ExpectError(4, "Already has pair"))
+        .run();
+}
+
+#[test]
+fn factory_create_pair_source_not_contract_test() {
+    let mut world = world();
+    deploy_factory(&mut world, OWNER_ADDRESS);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(FACTORY_ADDRESS)
+        .raw_call("createPair")
+        .argument(&token_id_pair(FIRST_TOKEN_ID, SECOND_TOKEN_ID))
+        .returns(ExpectError(10, "contract not found"))
+        .run();
+}
@@ -33,3 +33,26 @@ pub enum ExplicitDiscriminantMixed {
     Five,
     Struct { a: u8, b: u16 } = 1,
 }
+
+/// An enum with explicit codec discriminants, which also work for variants with fields
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub enum CodecDiscriminant {
+    #[codec(discriminant = 2)]
+    Two,
+    Three(u8),
+    #[codec(discriminant = 0)]
+    Zero {
+        a: u16,
+    },
+}
+
+/// An enum with descending codec discriminants, the second one is not the implicit value
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub enum CodecDiscriminantDescending {
+    #[codec(discriminant = 2)]
+    Two,
+    #[codec(discriminant = 1)]
+    One,
+}
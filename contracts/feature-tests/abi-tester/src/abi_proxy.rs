@@ -569,8 +569,11 @@ pub struct OnlyShowsUpAsNested10 {}
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
 pub enum ExplicitDiscriminant {
     Zero,
+    #[codec(discriminant = 30)]
     Thirty,
+    #[codec(discriminant = 12)]
     Twelve,
+    #[codec(discriminant = 50)]
     Fifty,
     FiftyOne,
 }
@@ -580,15 +583,48 @@ pub enum ExplicitDiscriminant {
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
 pub enum ExplicitDiscriminantMixed {
     Zero,
+    #[codec(discriminant = 3)]
     Unit,
     Tuple(u16),
     Five,
+    #[codec(discriminant = 1)]
     Struct {
         a: u8,
         b: u16,
     },
 }
 
+#[rustfmt::skip]
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub enum CodecDiscriminant {
+    #[codec(discriminant = 2)]
+    Two,
+    Three(u8),
+    #[codec(discriminant = 0)]
+    Zero {
+        a: u16,
+    },
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub enum CodecDiscriminantDescending {
+    #[codec(discriminant = 2)]
+    Two,
+    #[codec(discriminant = 1)]
+    One,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct AbiCodecAttributes {
+    pub value: u32,
+    pub custom: u8,
+    #[codec(default)]
+    pub added_later: u8,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode)]
 pub struct ManagedDecimalWrapper<Api>
@@ -59,3 +59,53 @@ pub struct AbiWithManagedBufferReadToEnd<M: ManagedTypeApi> {
     pub gas: u64,
     pub flush: ManagedBufferReadToEnd<M>,
 }
+
+/// Tests that the codec field attributes are reflected in the ABI.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct AbiCodecAttributes {
+    pub value: u32,
+
+    /// Not serialized, so it does not appear in the ABI.
+    #[codec(skip)]
+    #[allow(dead_code)]
+    pub skipped: u64,
+
+    /// Shows up in the ABI as the encoded type.
+    #[codec(with = "crate::abi_test_type::u16_as_u8", encoded_as = "u8")]
+    pub custom: u16,
+
+    /// Can be missing when decoding older data.
+    #[codec(default)]
+    pub added_later: u8,
+}
+
+/// Custom encoding for a field in [`AbiCodecAttributes`].
+pub mod u16_as_u8 {
+    use multiversx_sc::codec::{
+        DecodeErrorHandler, EncodeError, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
+        NestedEncode, NestedEncodeOutput,
+    };
+
+    pub fn dep_encode_or_handle_err<O, H>(
+        value: &u16,
+        dest: &mut O,
+        h: H,
+    ) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        let byte = u8::try_from(*value)
+            .map_err(|_| h.handle_error(EncodeError::from("value too large")))?;
+        byte.dep_encode_or_handle_err(dest, h)
+    }
+
+    pub fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<u16, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        u8::dep_decode_or_handle_err(input, h).map(u16::from)
+    }
+}
@@ -25,6 +25,9 @@ use only_nested::*;
 #[esdt_attribute("OnlyInEsdt", OnlyShowsUpInEsdtAttr)]
 #[esdt_attribute("ExplicitDiscriminant", ExplicitDiscriminant)]
 #[esdt_attribute("ExplicitDiscriminantMixed", ExplicitDiscriminantMixed)]
+#[esdt_attribute("CodecDiscriminant", CodecDiscriminant)]
+#[esdt_attribute("CodecDiscriminantDescending", CodecDiscriminantDescending)]
+#[esdt_attribute("CodecAttributes", AbiCodecAttributes)]
 #[esdt_attribute("ManagedDecimalVar", ManagedDecimal<Self::Api, NumDecimals>)]
 #[esdt_attribute("ManagedDecimalConst", ManagedDecimalWrapper<Self::Api>)]
 pub trait AbiTester {
@@ -119,6 +119,39 @@ where
             .original_result()
     }
 
+    pub fn get_original_caller(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_original_caller")
+            .original_result()
+    }
+
+    /// Calls `get_original_caller` on another contract, 
+    /// which should still see the sender of the transaction, not this contract. 
+    pub fn get_original_caller_nested<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        to: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_original_caller_nested")
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn get_relayer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_relayer")
+            .original_result()
+    }
+
     /// This tests how is generated type name in proxy 
     pub fn echo_managed_option<
         Arg0: ProxyArg<ManagedOption<Env::Api, BigUint<Env::Api>>>,
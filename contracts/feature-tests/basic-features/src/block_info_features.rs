@@ -52,4 +52,9 @@ pub trait BlockInfoFeatures {
     fn get_prev_block_random_seed(&self) -> ManagedByteArray<Self::Api, 48> {
         self.blockchain().get_prev_block_random_seed()
     }
+
+    #[view]
+    fn get_block_hash(&self, nonce: u64) -> ManagedByteArray<Self::Api, 32> {
+        self.blockchain().get_block_hash(nonce)
+    }
 }
@@ -8,6 +8,28 @@ pub trait BlockchainApiFeatures {
         self.blockchain().get_caller()
     }
 
+    #[endpoint]
+    fn get_original_caller(&self) -> ManagedAddress {
+        self.blockchain().get_original_caller()
+    }
+
+    /// Calls `get_original_caller` on another contract,
+    /// which should still see the sender of the transaction, not this contract.
+    #[endpoint]
+    fn get_original_caller_nested(&self, to: ManagedAddress) -> ManagedAddress {
+        self.tx()
+            .to(&to)
+            .typed(crate::basic_features_proxy::BasicFeaturesProxy)
+            .get_original_caller()
+            .returns(ReturnsResult)
+            .sync_call()
+    }
+
+    #[endpoint]
+    fn get_relayer(&self) -> OptionalValue<ManagedAddress> {
+        self.blockchain().get_relayer().into()
+    }
+
     #[endpoint]
     fn get_owner_address(&self) -> ManagedAddress {
         self.blockchain().get_owner_address()
@@ -33,6 +55,16 @@ pub trait BlockchainApiFeatures {
         self.blockchain().get_tx_hash()
     }
 
+    #[endpoint]
+    fn get_current_tx_hash(&self) -> ManagedByteArray<Self::Api, 32> {
+        self.blockchain().get_current_tx_hash()
+    }
+
+    #[endpoint]
+    fn get_prev_tx_hash(&self) -> ManagedByteArray<Self::Api, 32> {
+        self.blockchain().get_prev_tx_hash()
+    }
+
     #[endpoint]
     fn get_gas_left(&self) -> u64 {
         self.blockchain().get_gas_left()
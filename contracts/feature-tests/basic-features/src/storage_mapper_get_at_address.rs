@@ -82,6 +82,54 @@ pub trait StorageMapperGetAtAddress {
             .get_by_index(index)
     }
 
+    #[endpoint]
+    fn avl_tree_len_at_address(&self) -> usize {
+        let address = self.contract_address().get();
+        self.avl_tree_mapper_from_address(address).len()
+    }
+
+    #[endpoint]
+    fn avl_tree_contains_at_address(&self, item: u32) -> bool {
+        let address = self.contract_address().get();
+        self.avl_tree_mapper_from_address(address).contains(&item)
+    }
+
+    #[endpoint]
+    fn avl_tree_select_at_address(&self, index: usize) -> u32 {
+        let address = self.contract_address().get();
+        self.avl_tree_mapper_from_address(address)
+            .select(index)
+            .unwrap()
+    }
+
+    #[endpoint]
+    fn avl_tree_range_at_address(&self, start: u32, end: u32) -> ManagedVec<u32> {
+        let address = self.contract_address().get();
+        self.avl_tree_mapper_from_address(address)
+            .range(start..end)
+            .collect()
+    }
+
+    #[endpoint]
+    fn priority_queue_len_at_address(&self) -> usize {
+        let address = self.contract_address().get();
+        self.priority_queue_mapper_from_address(address).len()
+    }
+
+    #[endpoint]
+    fn priority_queue_peek_at_address(&self) -> Option<u32> {
+        let address = self.contract_address().get();
+        self.priority_queue_mapper_from_address(address).peek()
+    }
+
+    #[endpoint]
+    fn priority_queue_items_at_address(&self) -> ManagedVec<u32> {
+        let address = self.contract_address().get();
+        self.priority_queue_mapper_from_address(address)
+            .iter()
+            .collect()
+    }
+
     /// Storage to be called. For testing, this contract is deployed twice,
     /// and this module acts both as caller and receiver
     #[storage_mapper("set_mapper")]
@@ -108,6 +156,24 @@ pub trait StorageMapperGetAtAddress {
         address: ManagedAddress,
     ) -> UnorderedSetMapper<u32, ManagedAddress>;
 
+    #[storage_mapper("avl_tree_mapper")]
+    fn avl_tree_mapper(&self) -> AvlTreeMapper<u32>;
+
+    #[storage_mapper_from_address("avl_tree_mapper")]
+    fn avl_tree_mapper_from_address(
+        &self,
+        address: ManagedAddress,
+    ) -> AvlTreeMapper<u32, ManagedAddress>;
+
+    #[storage_mapper("priority_queue_mapper")]
+    fn priority_queue_mapper(&self) -> PriorityQueueMapper<u32>;
+
+    #[storage_mapper_from_address("priority_queue_mapper")]
+    fn priority_queue_mapper_from_address(
+        &self,
+        address: ManagedAddress,
+    ) -> PriorityQueueMapper<u32, ManagedAddress>;
+
     #[endpoint]
     fn fill_set_mapper(&self, value: u32) {
         for item in 1u32..=value {
@@ -130,6 +196,20 @@ pub trait StorageMapperGetAtAddress {
         }
     }
 
+    #[endpoint]
+    fn fill_avl_tree_mapper(&self, value: u32) {
+        for item in 1u32..=value {
+            self.avl_tree_mapper().insert(item);
+        }
+    }
+
+    #[endpoint]
+    fn fill_priority_queue_mapper(&self, value: u32) {
+        for item in (1u32..=value).rev() {
+            self.priority_queue_mapper().push(item);
+        }
+    }
+
     #[storage_mapper_from_address("single_value_mapper_with_key")]
     fn single_value_from_address_with_keys(
         &self,
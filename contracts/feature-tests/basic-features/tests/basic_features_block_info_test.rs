@@ -0,0 +1,165 @@
+use std::time::Duration;
+
+use basic_features::block_info_features::BlockInfoFeatures;
+use multiversx_sc_scenario::imports::*;
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const BASIC_FEATURES_ADDRESS: TestSCAddress = TestSCAddress::new("basic-features");
+const BASIC_FEATURES_PATH: MxscPath = MxscPath::new("output/basic-features.mxsc.json");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+    blockchain.register_contract(BASIC_FEATURES_PATH, basic_features::ContractBuilder);
+
+    blockchain.account(OWNER_ADDRESS).nonce(1);
+    blockchain
+        .account(BASIC_FEATURES_ADDRESS)
+        .nonce(1)
+        .code(BASIC_FEATURES_PATH);
+
+    blockchain
+}
+
+#[derive(Debug, PartialEq)]
+struct BlockSnapshot {
+    timestamp: u64,
+    nonce: u64,
+    round: u64,
+    epoch: u64,
+}
+
+const fn block(timestamp: u64, nonce: u64, round: u64, epoch: u64) -> BlockSnapshot {
+    BlockSnapshot {
+        timestamp,
+        nonce,
+        round,
+        epoch,
+    }
+}
+
+/// The current and the previous block.
+fn block_info(world: &mut ScenarioWorld) -> (BlockSnapshot, BlockSnapshot) {
+    let mut result = None;
+    world
+        .query()
+        .to(BASIC_FEATURES_ADDRESS)
+        .whitebox(basic_features::contract_obj, |sc| {
+            result = Some((
+                block(
+                    sc.get_block_timestamp(),
+                    sc.get_block_nonce(),
+                    sc.get_block_round(),
+                    sc.get_block_epoch(),
+                ),
+                block(
+                    sc.get_prev_block_timestamp(),
+                    sc.get_prev_block_nonce(),
+                    sc.get_prev_block_round(),
+                    sc.get_prev_block_epoch(),
+                ),
+            ));
+        });
+    result.unwrap()
+}
+
+fn random_seeds(world: &mut ScenarioWorld) -> (Vec<u8>, Vec<u8>) {
+    let mut result = Default::default();
+    world
+        .query()
+        .to(BASIC_FEATURES_ADDRESS)
+        .whitebox(basic_features::contract_obj, |sc| {
+            result = (
+                sc.get_block_random_seed().to_byte_array().to_vec(),
+                sc.get_prev_block_random_seed().to_byte_array().to_vec(),
+            );
+        });
+    result
+}
+
+#[test]
+fn advance_blocks_test() {
+    let mut world = world();
+    world
+        .current_block()
+        .block_timestamp(1_000)
+        .block_nonce(10)
+        .block_round(12)
+        .block_epoch(1);
+
+    world.advance_blocks(3);
+    assert_eq!(
+        block_info(&mut world),
+        (block(1_018, 13, 15, 1), block(1_012, 12, 14, 1))
+    );
+
+    let (seed, prev_seed) = random_seeds(&mut world);
+    assert_ne!(seed, prev_seed);
+
+    // rotates the previous block
+    world.advance_blocks(1);
+    assert_eq!(
+        block_info(&mut world),
+        (block(1_024, 14, 16, 1), block(1_018, 13, 15, 1))
+    );
+    assert_eq!(random_seeds(&mut world).1, seed);
+
+    world.advance_blocks(0);
+    assert_eq!(
+        block_info(&mut world),
+        (block(1_024, 14, 16, 1), block(1_018, 13, 15, 1))
+    );
+}
+
+#[test]
+fn advance_time_test() {
+    let mut world = world();
+    world.round_duration(Duration::from_secs(4));
+
+    world.advance_time(Duration::from_secs(40));
+    assert_eq!(
+        block_info(&mut world),
+        (block(40, 10, 10, 0), block(36, 9, 9, 0))
+    );
+
+    // rounded up to a whole number of rounds
+    world.advance_time(Duration::from_millis(4_001));
+    assert_eq!(
+        block_info(&mut world),
+        (block(48, 12, 12, 0), block(44, 11, 11, 0))
+    );
+}
+
+#[test]
+fn advance_to_epoch_test() {
+    let mut world = world();
+    world.rounds_per_epoch(100);
+
+    world.advance_blocks(150);
+    assert_eq!(block_info(&mut world).0, block(900, 150, 150, 1));
+
+    world.advance_to_epoch(3);
+    assert_eq!(
+        block_info(&mut world),
+        (block(1_800, 300, 300, 3), block(1_794, 299, 299, 2))
+    );
+
+    // already reached
+    world.advance_to_epoch(2);
+    assert_eq!(block_info(&mut world).0, block(1_800, 300, 300, 3));
+}
+
+#[test]
+fn advance_blocks_trace_test() {
+    let mut world = world();
+    world.start_trace();
+    world.advance_blocks(2);
+
+    let trace_path = std::env::temp_dir().join("basic_features_advance_blocks.scen.json");
+    world.write_scenario_trace(&trace_path);
+    let trace = std::fs::read_to_string(&trace_path).unwrap();
+    assert!(trace.contains("advance 2 block(s)"));
+    assert!(trace.contains(r#""blockNonce": "2""#));
+    assert!(trace.contains(r#""blockNonce": "1""#));
+}
@@ -0,0 +1,192 @@
+use imports::{MxscPath, ReturnsResult, TestAddress, TestSCAddress};
+use multiversx_sc_scenario::{
+    imports,
+    scenario::tx_to_step::TxToStep,
+    scenario_model::{ScCallStep, ScDeployStep, SetStateStep, TransferStep, U64Value},
+    ScenarioTxRun, ScenarioWorld,
+};
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const RELAYER_ADDRESS: TestAddress = TestAddress::new("relayer");
+const RECEIVER_ADDRESS: TestAddress = TestAddress::new("receiver");
+const BASIC_FEATURES_ADDRESS: TestSCAddress = TestSCAddress::new("basic-features");
+const OTHER_BASIC_FEATURES_ADDRESS: TestSCAddress = TestSCAddress::new("other-basic-features");
+const NEW_BASIC_FEATURES_ADDRESS: TestSCAddress = TestSCAddress::new("new-basic-features");
+const BASIC_FEATURES_PATH: MxscPath = MxscPath::new("output/basic-features.mxsc.json");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+    blockchain.register_contract(BASIC_FEATURES_PATH, basic_features::ContractBuilder);
+    blockchain
+}
+
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    let basic_features_code = world.code_expression(BASIC_FEATURES_PATH.eval_to_expr().as_str());
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(1_000);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(2_000_000);
+    world.account(RECEIVER_ADDRESS).nonce(1);
+    world
+        .account(BASIC_FEATURES_ADDRESS)
+        .nonce(1)
+        .code(basic_features_code.clone());
+    world
+        .account(OTHER_BASIC_FEATURES_ADDRESS)
+        .nonce(1)
+        .code(basic_features_code);
+
+    world
+}
+
+#[test]
+fn relayed_tx_get_relayer_test() {
+    let mut world = setup();
+
+    let relayer = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BASIC_FEATURES_ADDRESS)
+        .relayer(RELAYER_ADDRESS)
+        .typed(basic_features::basic_features_proxy::BasicFeaturesProxy)
+        .get_relayer()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(
+        relayer.into_option(),
+        Some(RELAYER_ADDRESS.to_managed_address())
+    );
+
+    let relayer = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BASIC_FEATURES_ADDRESS)
+        .typed(basic_features::basic_features_proxy::BasicFeaturesProxy)
+        .get_relayer()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(relayer.into_option(), None);
+}
+
+#[test]
+fn relayed_tx_get_original_caller_test() {
+    let mut world = setup();
+
+    let original_caller = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BASIC_FEATURES_ADDRESS)
+        .relayer(RELAYER_ADDRESS)
+        .typed(basic_features::basic_features_proxy::BasicFeaturesProxy)
+        .get_original_caller()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(original_caller, OWNER_ADDRESS.to_managed_address());
+
+    // the nested call still sees the sender as original caller, not the calling contract
+    let original_caller = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BASIC_FEATURES_ADDRESS)
+        .relayer(RELAYER_ADDRESS)
+        .typed(basic_features::basic_features_proxy::BasicFeaturesProxy)
+        .get_original_caller_nested(OTHER_BASIC_FEATURES_ADDRESS)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(original_caller, OWNER_ADDRESS.to_managed_address());
+}
+
+#[test]
+fn relayed_tx_gas_paid_by_relayer_test() {
+    let mut world = setup();
+
+    let mut step = ScCallStep::new()
+        .from(OWNER_ADDRESS)
+        .to(BASIC_FEATURES_ADDRESS)
+        .relayer(RELAYER_ADDRESS)
+        .function("get_relayer")
+        .gas_limit(1_000_000u64);
+    step.tx.gas_price = U64Value::from(1u64);
+    world.sc_call(step);
+
+    world.check_account(OWNER_ADDRESS).balance(1_000);
+    world.check_account(RELAYER_ADDRESS).balance(1_000_000);
+}
+
+#[test]
+fn relayed_transfer_gas_paid_by_relayer_test() {
+    let mut world = setup();
+
+    let mut step = TransferStep::new()
+        .from(OWNER_ADDRESS)
+        .to(RECEIVER_ADDRESS)
+        .relayer(RELAYER_ADDRESS)
+        .egld_value(100u64);
+    step.tx.gas_price = U64Value::from(1u64);
+    world.transfer_step(step);
+
+    world.check_account(OWNER_ADDRESS).balance(900);
+    world.check_account(RECEIVER_ADDRESS).balance(100);
+    world
+        .check_account(RELAYER_ADDRESS)
+        .balance(2_000_000 - 50_000);
+}
+
+#[test]
+fn relayed_deploy_gas_paid_by_relayer_test() {
+    let mut world = setup();
+    let basic_features_code = world.code_expression(BASIC_FEATURES_PATH.eval_to_expr().as_str());
+
+    world.set_state_step(SetStateStep::new().new_address(
+        OWNER_ADDRESS,
+        1,
+        NEW_BASIC_FEATURES_ADDRESS,
+    ));
+    let mut step = ScDeployStep::new()
+        .from(OWNER_ADDRESS)
+        .relayer(RELAYER_ADDRESS)
+        .code(basic_features_code)
+        .gas_limit(1_000_000u64);
+    step.tx.gas_price = U64Value::from(1u64);
+    world.sc_deploy(step);
+
+    world.check_account(OWNER_ADDRESS).balance(1_000);
+    world.check_account(RELAYER_ADDRESS).balance(1_000_000);
+}
+
+#[test]
+fn relayed_transfer_and_deploy_trace_test() {
+    let mut world = setup();
+    world.start_trace();
+
+    // plain transfers only run through steps in the scenario world
+    let transfer_step = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(RECEIVER_ADDRESS)
+        .relayer(RELAYER_ADDRESS)
+        .egld(100)
+        .tx_to_step()
+        .step;
+    world.transfer_step(transfer_step);
+
+    world.new_address(OWNER_ADDRESS, 2, NEW_BASIC_FEATURES_ADDRESS);
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .relayer(RELAYER_ADDRESS)
+        .typed(basic_features::basic_features_proxy::BasicFeaturesProxy)
+        .init()
+        .code(BASIC_FEATURES_PATH)
+        .run();
+
+    let trace_path = std::env::temp_dir().join("basic_features_relayed_tx.scen.json");
+    world.write_scenario_trace(&trace_path);
+    let trace = std::fs::read_to_string(&trace_path).unwrap();
+    let compact_trace: String = trace.split_whitespace().collect();
+    assert!(compact_trace.contains(r#""step":"transfer""#));
+    assert!(compact_trace.contains(r#""step":"scDeploy""#));
+    assert_eq!(compact_trace.matches(r#""relayer":"#).count(), 2);
+}
@@ -409,6 +409,16 @@ fn storage_mapper_get_at_address_go() {
     world().run("scenarios/storage_mapper_get_at_address.scen.json");
 }
 
+#[test]
+fn storage_mapper_get_at_address_avl_tree_go() {
+    world().run("scenarios/storage_mapper_get_at_address_avl_tree.scen.json");
+}
+
+#[test]
+fn storage_mapper_get_at_address_priority_queue_go() {
+    world().run("scenarios/storage_mapper_get_at_address_priority_queue.scen.json");
+}
+
 #[test]
 fn storage_mapper_get_at_address_extra_key_go() {
     world().run("scenarios/storage_mapper_get_at_address_extra_key.scen.json");
@@ -76,7 +76,6 @@ fn count_ones_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_elliptic_curves_rs() {
     world().run("scenarios/crypto_elliptic_curves.scen.json");
 }
@@ -87,7 +86,6 @@ fn crypto_keccak_256_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_ripemd_160_rs() {
     world().run("scenarios/crypto_ripemd160.scen.json");
 }
@@ -98,19 +96,16 @@ fn crypto_sha_256_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_verify_bls_rs() {
     world().run("scenarios/crypto_verify_bls.scen.json");
 }
 
 #[test]
-#[ignore]
 fn crypto_verify_bls_aggregated_signature_rs() {
     world().run("scenarios/crypto_verify_bls_aggregated_signature.scen.json");
 }
 
 #[test]
-#[ignore]
 fn crypto_verify_bls_share_rs() {
     world().run("scenarios/crypto_verify_bls_share.scen.json");
 }
@@ -121,13 +116,11 @@ fn crypto_verify_ed_25519_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_verify_secp_256_k_1_rs() {
     world().run("scenarios/crypto_verify_secp256k1.scen.json");
 }
 
 #[test]
-#[ignore]
 fn crypto_verify_secp_256_r_1_rs() {
     world().run("scenarios/crypto_verify_secp256r1.scen.json");
 }
@@ -431,6 +424,16 @@ fn storage_mapper_get_at_address_rs() {
     world().run("scenarios/storage_mapper_get_at_address.scen.json");
 }
 
+#[test]
+fn storage_mapper_get_at_address_avl_tree_rs() {
+    world().run("scenarios/storage_mapper_get_at_address_avl_tree.scen.json");
+}
+
+#[test]
+fn storage_mapper_get_at_address_priority_queue_rs() {
+    world().run("scenarios/storage_mapper_get_at_address_priority_queue.scen.json");
+}
+
 #[test]
 fn storage_mapper_get_at_address_extra_key_rs() {
     world().run("scenarios/storage_mapper_get_at_address_extra_key.scen.json");
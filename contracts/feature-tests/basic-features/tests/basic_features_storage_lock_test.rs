@@ -0,0 +1,115 @@
+use multiversx_sc::storage::StorageKey;
+use multiversx_sc_scenario::imports::*;
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const BASIC_FEATURES_ADDRESS: TestSCAddress = TestSCAddress::new("basic-features");
+const BASIC_FEATURES_PATH: MxscPath = MxscPath::new("output/basic-features.mxsc.json");
+
+const PARAM_KEY: &str = "param";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+    blockchain.register_contract(BASIC_FEATURES_PATH, basic_features::ContractBuilder);
+
+    blockchain.account(OWNER_ADDRESS).nonce(1);
+    blockchain
+        .account(BASIC_FEATURES_ADDRESS)
+        .nonce(1)
+        .code(BASIC_FEATURES_PATH);
+    blockchain.current_block().block_timestamp(1_000);
+
+    blockchain
+}
+
+fn set_lock(world: &mut ScenarioWorld, unlock_timestamp: u64) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BASIC_FEATURES_ADDRESS)
+        .whitebox(basic_features::contract_obj, |sc| {
+            sc.blockchain()
+                .set_storage_lock(&StorageKey::from(PARAM_KEY), unlock_timestamp);
+        });
+}
+
+fn clear_lock(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BASIC_FEATURES_ADDRESS)
+        .whitebox(basic_features::contract_obj, |sc| {
+            sc.blockchain()
+                .clear_storage_lock(&StorageKey::from(PARAM_KEY));
+        });
+}
+
+/// (lock timestamp, is locked)
+fn lock_status(world: &mut ScenarioWorld) -> (u64, bool) {
+    let mut result = Default::default();
+    world
+        .query()
+        .to(BASIC_FEATURES_ADDRESS)
+        .whitebox(basic_features::contract_obj, |sc| {
+            let key = StorageKey::from(PARAM_KEY);
+            result = (
+                sc.blockchain().get_storage_lock(&key),
+                sc.blockchain().is_storage_locked(&key),
+            );
+        });
+    result
+}
+
+#[test]
+fn storage_lock_test() {
+    let mut world = world();
+    assert_eq!(lock_status(&mut world), (0, false));
+
+    set_lock(&mut world, 1_060);
+    assert_eq!(lock_status(&mut world), (1_060, true));
+
+    // the lock is kept under a reserved key
+    world
+        .check_account(BASIC_FEATURES_ADDRESS)
+        .check_storage("str:ELRONDtimelockparam", "1060");
+
+    world.advance_time(std::time::Duration::from_secs(54));
+    assert_eq!(lock_status(&mut world), (1_060, true));
+
+    // expires at exactly the lock timestamp
+    world.advance_blocks(1);
+    assert_eq!(lock_status(&mut world), (1_060, false));
+}
+
+#[test]
+fn storage_lock_overwrite_and_clear_test() {
+    let mut world = world();
+
+    set_lock(&mut world, 2_000);
+    set_lock(&mut world, 500);
+    assert_eq!(lock_status(&mut world), (500, false));
+
+    set_lock(&mut world, 2_000);
+    clear_lock(&mut world);
+    assert_eq!(lock_status(&mut world), (0, false));
+}
+
+#[test]
+fn storage_lock_does_not_affect_value_test() {
+    let mut world = world();
+
+    set_lock(&mut world, 2_000);
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BASIC_FEATURES_ADDRESS)
+        .whitebox(basic_features::contract_obj, |sc| {
+            sc.storage_raw().write(PARAM_KEY, &5u32);
+        });
+
+    world
+        .check_account(BASIC_FEATURES_ADDRESS)
+        .check_storage("str:param", "5")
+        .check_storage("str:ELRONDtimelockparam", "2000");
+}
@@ -0,0 +1,127 @@
+use basic_features::{
+    block_info_features::BlockInfoFeatures, blockchain_api_features::BlockchainApiFeatures,
+};
+use multiversx_sc_scenario::imports::*;
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const BASIC_FEATURES_ADDRESS: TestSCAddress = TestSCAddress::new("basic-features");
+const BASIC_FEATURES_PATH: MxscPath = MxscPath::new("output/basic-features.mxsc.json");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+    blockchain.register_contract(BASIC_FEATURES_PATH, basic_features::ContractBuilder);
+
+    blockchain.account(OWNER_ADDRESS).nonce(1);
+    blockchain
+        .account(BASIC_FEATURES_ADDRESS)
+        .nonce(1)
+        .code(BASIC_FEATURES_PATH);
+
+    blockchain
+}
+
+/// (original, current, previous), as seen by the contract.
+fn tx_hashes(world: &mut ScenarioWorld) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+    let mut result = Default::default();
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BASIC_FEATURES_ADDRESS)
+        .whitebox(basic_features::contract_obj, |sc| {
+            result = (
+                sc.get_tx_hash().to_byte_array().to_vec(),
+                sc.get_current_tx_hash().to_byte_array().to_vec(),
+                sc.get_prev_tx_hash().to_byte_array().to_vec(),
+            );
+        });
+    result
+}
+
+fn state_root_hash(world: &mut ScenarioWorld) -> Vec<u8> {
+    let mut result = Vec::new();
+    world
+        .query()
+        .to(BASIC_FEATURES_ADDRESS)
+        .whitebox(basic_features::contract_obj, |sc| {
+            result = sc.get_state_root_hash().to_byte_array().to_vec();
+        });
+    result
+}
+
+fn block_hash(world: &mut ScenarioWorld, nonce: u64) -> Vec<u8> {
+    let mut result = Vec::new();
+    world
+        .query()
+        .to(BASIC_FEATURES_ADDRESS)
+        .whitebox(basic_features::contract_obj, |sc| {
+            result = sc.get_block_hash(nonce).to_byte_array().to_vec();
+        });
+    result
+}
+
+#[test]
+fn tx_hash_deterministic_test() {
+    let mut world_1 = world();
+    let mut world_2 = world();
+
+    let (original, current, prev) = tx_hashes(&mut world_1);
+    assert_ne!(original, vec![0u8; 32]);
+    assert_eq!(original, current);
+    assert_eq!(original, prev);
+
+    // replaying the same steps yields the same hashes
+    assert_eq!(tx_hashes(&mut world_2).0, original);
+
+    // the sender nonce changed
+    assert_ne!(tx_hashes(&mut world_1).0, original);
+}
+
+#[test]
+fn tx_hash_response_test() {
+    let mut world = world();
+
+    let (tx_hash, result) = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BASIC_FEATURES_ADDRESS)
+        .raw_call("get_tx_hash")
+        .returns(ReturnsTxHash)
+        .returns(ReturnsRawResult)
+        .run();
+
+    assert_eq!(
+        tx_hash.as_bytes(),
+        result.get(0).to_boxed_bytes().as_slice()
+    );
+}
+
+#[test]
+fn state_root_hash_test() {
+    let mut world_1 = world();
+    let mut world_2 = world();
+
+    let root = state_root_hash(&mut world_1);
+    assert_ne!(root, vec![0u8; 32]);
+    assert_eq!(root, state_root_hash(&mut world_1));
+    assert_eq!(root, state_root_hash(&mut world_2));
+
+    // increases the owner nonce
+    tx_hashes(&mut world_1);
+    assert_ne!(state_root_hash(&mut world_1), root);
+}
+
+#[test]
+fn block_hash_test() {
+    let mut world = world();
+    world.current_block().block_nonce(10);
+
+    let block_hash_5 = block_hash(&mut world, 5);
+    assert_ne!(block_hash_5, vec![0u8; 32]);
+    assert_eq!(block_hash(&mut world, 5), block_hash_5);
+    assert_ne!(block_hash(&mut world, 10), block_hash_5);
+
+    // not produced yet
+    assert_eq!(block_hash(&mut world, 11), vec![0u8; 32]);
+}
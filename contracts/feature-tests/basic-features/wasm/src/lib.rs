@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                          414
+// Endpoints:                          429
 // Async Callback:                       1
-// Total number of exported functions: 416
+// Total number of exported functions: 431
 
 #![no_std]
 
@@ -119,12 +119,18 @@ multiversx_sc_wasm_adapter::endpoints! {
         get_prev_block_round => get_prev_block_round
         get_prev_block_epoch => get_prev_block_epoch
         get_prev_block_random_seed => get_prev_block_random_seed
+        get_block_hash => get_block_hash
         get_caller => get_caller
+        get_original_caller => get_original_caller
+        get_original_caller_nested => get_original_caller_nested
+        get_relayer => get_relayer
         get_owner_address => get_owner_address
         get_shard_of_address => get_shard_of_address
         is_smart_contract => is_smart_contract
         get_state_root_hash => get_state_root_hash
         get_tx_hash => get_tx_hash
+        get_current_tx_hash => get_current_tx_hash
+        get_prev_tx_hash => get_prev_tx_hash
         get_gas_left => get_gas_left
         get_cumulated_validator_rewards => get_cumulated_validator_rewards
         get_code_metadata => get_code_metadata
@@ -415,9 +421,18 @@ multiversx_sc_wasm_adapter::endpoints! {
         values_at_address => values_at_address
         contains_unordered_at_address => contains_unordered_at_address
         get_by_index => get_by_index
+        avl_tree_len_at_address => avl_tree_len_at_address
+        avl_tree_contains_at_address => avl_tree_contains_at_address
+        avl_tree_select_at_address => avl_tree_select_at_address
+        avl_tree_range_at_address => avl_tree_range_at_address
+        priority_queue_len_at_address => priority_queue_len_at_address
+        priority_queue_peek_at_address => priority_queue_peek_at_address
+        priority_queue_items_at_address => priority_queue_items_at_address
         fill_set_mapper => fill_set_mapper
         fill_map_mapper => fill_map_mapper
         fill_unordered_set_mapper => fill_unordered_set_mapper
+        fill_avl_tree_mapper => fill_avl_tree_mapper
+        fill_priority_queue_mapper => fill_priority_queue_mapper
         get_value_from_address_with_keys => get_value_from_address_with_keys
         address_to_id_mapper_get_id_from_address => address_to_id_mapper_get_id_from_address
         managed_decimal_addition => managed_decimal_addition
@@ -38,7 +38,6 @@ fn big_float_new_from_int_rs() {
 }
 
 #[test]
-#[ignore]
 fn big_float_new_from_managed_buffer_rs() {
     world().run("scenarios/big_float_new_from_managed_buffer.scen.json");
 }
@@ -54,7 +53,6 @@ fn big_float_new_from_sci_rs() {
 }
 
 #[test]
-#[ignore]
 fn big_float_operator_checks_rs() {
     world().run("scenarios/big_float_operator_checks.scen.json");
 }
@@ -72,4 +72,42 @@ pub trait ForwarderRawDeployUpgrade {
             .gas(self.blockchain().get_gas_left())
             .upgrade_async_call_and_exit();
     }
+
+    /// Same as `deploy_from_source`, but goes through the legacy VM hook.
+    #[endpoint]
+    fn deploy_from_source_legacy(
+        &self,
+        source_contract_address: ManagedAddress,
+        code_metadata: CodeMetadata,
+        args: MultiValueEncoded<ManagedBuffer>,
+    ) -> ManagedAddress {
+        let new_address = self.send_raw().deploy_from_source_contract_legacy(
+            self.blockchain().get_gas_left(),
+            &BigUint::zero(),
+            &source_contract_address,
+            code_metadata,
+            &args.to_arg_buffer(),
+        );
+        self.send_raw().clean_return_data();
+        new_address
+    }
+
+    /// Same as `call_upgrade_from_source`, but goes through the legacy VM hook.
+    #[endpoint]
+    fn call_upgrade_from_source_legacy(
+        &self,
+        sc_address: ManagedAddress,
+        source_contract_address: ManagedAddress,
+        code_metadata: CodeMetadata,
+        args: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        self.send_raw().upgrade_from_source_contract_legacy(
+            &sc_address,
+            self.blockchain().get_gas_left(),
+            &BigUint::zero(),
+            &source_contract_address,
+            code_metadata,
+            &args.to_arg_buffer(),
+        );
+    }
 }
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           25
+// Endpoints:                           27
 // Async Callback:                       1
-// Total number of exported functions:  27
+// Total number of exported functions:  29
 
 #![no_std]
 
@@ -43,6 +43,8 @@ multiversx_sc_wasm_adapter::endpoints! {
         deploy_from_source => deploy_from_source
         call_upgrade => call_upgrade
         call_upgrade_from_source => call_upgrade_from_source
+        deploy_from_source_legacy => deploy_from_source_legacy
+        call_upgrade_from_source_legacy => call_upgrade_from_source_legacy
     )
 }
 
@@ -507,6 +507,22 @@ where
             .original_result()
     }
 
+    pub fn delete_child_contract<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        child_sc_address: Arg0,
+        beneficiary: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("deleteChildContract")
+            .argument(&child_sc_address)
+            .argument(&beneficiary)
+            .original_result()
+    }
+
     pub fn deploy_contract<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
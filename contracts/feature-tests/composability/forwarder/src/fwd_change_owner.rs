@@ -17,6 +17,17 @@ pub trait ChangeOwnerModule {
         self.get_owner_of_vault_contract(child_sc_address)
     }
 
+    #[endpoint(deleteChildContract)]
+    fn delete_child_contract(&self, child_sc_address: ManagedAddress, beneficiary: ManagedAddress) {
+        let mut arg_buffer = ManagedArgBuffer::new();
+        arg_buffer.push_arg(&beneficiary);
+        self.send_raw().delete_contract(
+            &child_sc_address,
+            self.blockchain().get_gas_left(),
+            &arg_buffer,
+        );
+    }
+
     fn get_owner_of_vault_contract(&self, address: ManagedAddress) -> ManagedAddress {
         self.tx()
             .to(&address)
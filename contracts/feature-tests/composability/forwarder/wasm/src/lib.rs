@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           78
+// Endpoints:                           79
 // Async Callback:                       1
-// Total number of exported functions:  80
+// Total number of exported functions:  81
 
 #![no_std]
 
@@ -49,6 +49,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         forward_transf_exec_reject_funds_multi_transfer => forward_transf_exec_reject_funds_multi_transfer
         transf_exec_multi_reject_funds => transf_exec_multi_reject_funds
         changeOwnerAddress => change_owner
+        deleteChildContract => delete_child_contract
         deploy_contract => deploy_contract
         deploy_two_contracts => deploy_two_contracts
         deploy_vault_from_source => deploy_vault_from_source
@@ -59,6 +59,11 @@ fn forw_raw_contract_deploy_go() {
     world().run("scenarios/forw_raw_contract_deploy.scen.json");
 }
 
+#[test]
+fn forw_raw_contract_legacy_from_source_go() {
+    world().run("scenarios/forw_raw_contract_legacy_from_source.scen.json");
+}
+
 #[test]
 fn forw_raw_contract_upgrade_go() {
     world().run("scenarios/forw_raw_contract_upgrade.scen.json");
@@ -107,6 +107,11 @@ fn forw_raw_contract_deploy_rs() {
     world().run("scenarios/forw_raw_contract_deploy.scen.json");
 }
 
+#[test]
+fn forw_raw_contract_legacy_from_source_rs() {
+    world().run("scenarios/forw_raw_contract_legacy_from_source.scen.json");
+}
+
 #[test]
 fn forw_raw_contract_upgrade_rs() {
     world().run("scenarios/forw_raw_contract_upgrade.scen.json");
@@ -166,19 +171,16 @@ fn forw_raw_sync_egld_rs() {
 }
 
 #[test]
-#[ignore]
 fn forw_raw_sync_readonly_rs() {
     world().run("scenarios/forw_raw_sync_readonly.scen.json");
 }
 
 #[test]
-#[ignore]
 fn forw_raw_sync_same_context_rs() {
     world().run("scenarios/forw_raw_sync_same_context.scen.json");
 }
 
 #[test]
-#[ignore]
 fn forw_raw_sync_same_context_egld_rs() {
     world().run("scenarios/forw_raw_sync_same_context_egld.scen.json");
 }
@@ -0,0 +1,178 @@
+use forwarder::forwarder_proxy;
+
+use multiversx_sc_scenario::imports::*;
+
+const USER_ADDRESS: TestAddress = TestAddress::new("user");
+const BENEFICIARY_ADDRESS: TestAddress = TestAddress::new("beneficiary");
+const FORWARDER_ADDRESS: TestSCAddress = TestSCAddress::new("forwarder");
+const FORWARDER_PATH: MxscPath = MxscPath::new("forwarder/output/forwarder.mxsc.json");
+const VAULT_ADDRESS: TestSCAddress = TestSCAddress::new("vault");
+const VAULT_PATH: MxscPath = MxscPath::new("vault/output/vault.mxsc.json");
+
+const TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("TOKEN-123456");
+const NFT_ID: TestTokenIdentifier = TestTokenIdentifier::new("NFT-123456");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = world_without_vault();
+    blockchain
+        .account(VAULT_ADDRESS)
+        .nonce(1)
+        .balance(1_000)
+        .code(VAULT_PATH)
+        .owner(USER_ADDRESS);
+
+    blockchain
+}
+
+fn world_without_vault() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+    blockchain.register_contract(FORWARDER_PATH, forwarder::ContractBuilder);
+    blockchain.register_contract(VAULT_PATH, vault::ContractBuilder);
+
+    blockchain.account(USER_ADDRESS).nonce(1);
+    blockchain
+        .account(BENEFICIARY_ADDRESS)
+        .nonce(1)
+        .balance(100);
+    blockchain
+        .account(FORWARDER_ADDRESS)
+        .nonce(1)
+        .code(FORWARDER_PATH)
+        .owner(USER_ADDRESS);
+
+    blockchain
+}
+
+#[test]
+fn delete_contract_by_owner_test() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(VAULT_ADDRESS)
+        .typed(UserBuiltinProxy)
+        .delete_contract(&BENEFICIARY_ADDRESS.to_managed_address())
+        .run();
+
+    world.check_account_deleted(VAULT_ADDRESS);
+    world.check_account(BENEFICIARY_ADDRESS).balance(1_100);
+}
+
+#[test]
+#[should_panic(expected = "Expected account not found")]
+fn deleted_contract_regular_check_test() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(VAULT_ADDRESS)
+        .typed(UserBuiltinProxy)
+        .delete_contract(&BENEFICIARY_ADDRESS.to_managed_address())
+        .run();
+
+    // only `check_account_deleted` accepts a missing account
+    world.check_account(VAULT_ADDRESS).balance(0);
+}
+
+#[test]
+fn delete_child_contract_test() {
+    let mut world = world_without_vault();
+    world
+        .account(VAULT_ADDRESS)
+        .nonce(1)
+        .balance(1_000)
+        .code(VAULT_PATH)
+        .owner(FORWARDER_ADDRESS);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(FORWARDER_ADDRESS)
+        .typed(forwarder_proxy::ForwarderProxy)
+        .delete_child_contract(VAULT_ADDRESS, BENEFICIARY_ADDRESS)
+        .run();
+
+    world.check_account_deleted(VAULT_ADDRESS);
+    world.check_account(BENEFICIARY_ADDRESS).balance(1_100);
+}
+
+#[test]
+fn delete_contract_not_owner_test() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(BENEFICIARY_ADDRESS)
+        .to(VAULT_ADDRESS)
+        .typed(UserBuiltinProxy)
+        .delete_contract(&BENEFICIARY_ADDRESS.to_managed_address())
+        .returns(ExpectError(10, "operation in account not permitted"))
+        .run();
+
+    // the forwarder is not the owner either
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(FORWARDER_ADDRESS)
+        .typed(forwarder_proxy::ForwarderProxy)
+        .delete_child_contract(VAULT_ADDRESS, BENEFICIARY_ADDRESS)
+        .returns(ExpectError(10, "operation in account not permitted"))
+        .run();
+
+    world.check_account(VAULT_ADDRESS).balance(1_000);
+}
+
+#[test]
+fn delete_contract_holding_esdt_test() {
+    let mut world = world_without_vault();
+    world
+        .account(VAULT_ADDRESS)
+        .nonce(1)
+        .balance(1_000)
+        .esdt_balance(TOKEN_ID, 5)
+        .esdt_nft_balance(NFT_ID, 3, 1, ())
+        .code(VAULT_PATH)
+        .owner(USER_ADDRESS);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(VAULT_ADDRESS)
+        .typed(UserBuiltinProxy)
+        .delete_contract(&BENEFICIARY_ADDRESS.to_managed_address())
+        .run();
+
+    world.check_account_deleted(VAULT_ADDRESS);
+    world
+        .check_account(BENEFICIARY_ADDRESS)
+        .balance(1_100)
+        .esdt_balance(TOKEN_ID, 5)
+        .esdt_nft_balance_and_attributes(NFT_ID, 3, 1, "");
+}
+
+#[test]
+fn delete_contract_trace_test() {
+    let mut world = world();
+    world.start_trace();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(VAULT_ADDRESS)
+        .typed(UserBuiltinProxy)
+        .delete_contract(&BENEFICIARY_ADDRESS.to_managed_address())
+        .run();
+    world.check_account_deleted(VAULT_ADDRESS);
+
+    let trace_path = std::env::temp_dir().join("forwarder_delete_contract.scen.json");
+    world.write_scenario_trace(&trace_path);
+    let trace = std::fs::read_to_string(&trace_path).unwrap();
+    let compact_trace: String = trace.split_whitespace().collect();
+    assert!(compact_trace.contains(r#""function":"DeleteContract""#));
+    // the deletion check has no scenario syntax, so it is not part of the trace
+    assert!(!compact_trace.contains("checkState"));
+}
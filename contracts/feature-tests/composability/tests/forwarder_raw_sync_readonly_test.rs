@@ -0,0 +1,126 @@
+use multiversx_sc_scenario::imports::*;
+
+const USER_ADDRESS: TestAddress = TestAddress::new("user");
+const FORWARDER_ADDRESS: TestSCAddress = TestSCAddress::new("forwarder");
+const FORWARDER_PATH: MxscPath = MxscPath::new("forwarder-raw/output/forwarder-raw.mxsc.json");
+const VAULT_ADDRESS: TestSCAddress = TestSCAddress::new("vault");
+const VAULT_PATH: MxscPath = MxscPath::new("vault/output/vault.mxsc.json");
+
+const CANNOT_WRITE_ON_READ_ONLY: &str = "cannot write on read only mode";
+const INVALID_CALL_ON_READ_ONLY_MODE: &str = "operation not permitted in read only mode";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+    blockchain.register_contract(FORWARDER_PATH, forwarder_raw::ContractBuilder);
+    blockchain.register_contract(VAULT_PATH, vault::ContractBuilder);
+
+    blockchain.account(USER_ADDRESS).nonce(1).balance(1_000);
+    blockchain
+        .account(FORWARDER_ADDRESS)
+        .nonce(1)
+        .balance(1_000)
+        .code(FORWARDER_PATH);
+    blockchain
+        .account(VAULT_ADDRESS)
+        .nonce(1)
+        .balance(1_000)
+        .code(VAULT_PATH);
+
+    blockchain
+}
+
+fn call_readonly(world: &mut ScenarioWorld, args: &[&[u8]], expected_error: &str) {
+    let mut arg_buffer = ManagedArgBuffer::<StaticApi>::new();
+    arg_buffer.push_arg(VAULT_ADDRESS.to_address());
+    for arg in args {
+        arg_buffer.push_arg(*arg);
+    }
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(FORWARDER_ADDRESS)
+        .raw_call("call_execute_on_dest_context_readonly")
+        .arguments_raw(arg_buffer)
+        .returns(ExpectError(10, expected_error))
+        .run();
+}
+
+#[test]
+fn readonly_storage_write_test() {
+    let mut world = world();
+
+    call_readonly(
+        &mut world,
+        &[b"echo_arguments", b"1"],
+        CANNOT_WRITE_ON_READ_ONLY,
+    );
+
+    world
+        .check_account(VAULT_ADDRESS)
+        .check_storage("str:call_counts|nested:str:echo_arguments", "");
+}
+
+#[test]
+fn readonly_log_test() {
+    let mut world = world();
+
+    call_readonly(
+        &mut world,
+        &[b"accept_funds"],
+        INVALID_CALL_ON_READ_ONLY_MODE,
+    );
+}
+
+#[test]
+fn readonly_builtin_function_test() {
+    let mut world = world();
+
+    let user_address = USER_ADDRESS.to_address();
+    call_readonly(
+        &mut world,
+        &[b"ChangeOwnerAddress", user_address.as_bytes()],
+        INVALID_CALL_ON_READ_ONLY_MODE,
+    );
+}
+
+#[test]
+fn readonly_propagates_to_nested_calls_test() {
+    let mut world = world();
+
+    // the forwarder calls itself in read-only mode, then forwards the call to the vault
+    let mut arg_buffer = ManagedArgBuffer::<StaticApi>::new();
+    arg_buffer.push_arg(FORWARDER_ADDRESS.to_address());
+    arg_buffer.push_arg(b"call_execute_on_dest_context");
+    arg_buffer.push_arg(VAULT_ADDRESS.to_address());
+    arg_buffer.push_arg(b"echo_arguments");
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(FORWARDER_ADDRESS)
+        .raw_call("call_execute_on_dest_context_readonly")
+        .arguments_raw(arg_buffer)
+        .returns(ExpectError(10, CANNOT_WRITE_ON_READ_ONLY))
+        .run();
+}
+
+#[test]
+fn readonly_view_test() {
+    let mut world = world();
+
+    let mut arg_buffer = ManagedArgBuffer::<StaticApi>::new();
+    arg_buffer.push_arg(VAULT_ADDRESS.to_address());
+    arg_buffer.push_arg(b"echo_arguments_without_storage");
+    arg_buffer.push_arg(b"1");
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(FORWARDER_ADDRESS)
+        .raw_call("call_execute_on_dest_context_readonly")
+        .arguments_raw(arg_buffer)
+        .run();
+}
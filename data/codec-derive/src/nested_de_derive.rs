@@ -1,22 +1,55 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::util::*;
+use crate::{codec_attr::FieldCodecAttributes, util::*};
 
 pub fn dep_decode_snippet(
     _index: usize,
     field: &syn::Field,
     input_value: &proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
-    let ty = &field.ty;
+    let value = field_decode_expr(field, input_value);
     if let Some(ident) = &field.ident {
         quote! {
-            #ident: <#ty as codec::NestedDecode>::dep_decode_or_handle_err(#input_value, __h__)?
+            #ident: #value
+        }
+    } else {
+        value
+    }
+}
+
+fn field_decode_expr(
+    field: &syn::Field,
+    input_value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let codec_attributes = FieldCodecAttributes::parse(field);
+    if codec_attributes.skip {
+        return quote! {
+            core::default::Default::default()
+        };
+    }
+
+    let ty = &field.ty;
+    let decode_expr = if let Some(module) = &codec_attributes.with {
+        quote! {
+            #module::dep_decode_or_handle_err(#input_value, __h__)?
         }
     } else {
         quote! {
             <#ty as codec::NestedDecode>::dep_decode_or_handle_err(#input_value, __h__)?
         }
+    };
+
+    if codec_attributes.default {
+        quote! {
+            if codec::NestedDecodeInput::is_depleted(#input_value) {
+                core::default::Default::default()
+            } else {
+                #decode_expr
+            }
+        }
+    } else {
+        decode_expr
     }
 }
 
@@ -48,6 +81,8 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
+            validate_fields(&data_struct.fields);
+
             let field_dep_decode_snippets =
                 fields_decl_syntax(&data_struct.fields, |index, field| {
                     dep_decode_snippet(index, field, &quote! {input})
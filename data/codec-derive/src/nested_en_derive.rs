@@ -1,10 +1,24 @@
-use crate::util::*;
+use crate::{codec_attr::FieldCodecAttributes, util::*};
 use proc_macro::TokenStream;
 use quote::quote;
 
-pub fn dep_encode_snippet(value: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-    quote! {
-        codec::NestedEncode::dep_encode_or_handle_err(&#value, __dest__, __h__)?;
+pub fn dep_encode_snippet(
+    field: &syn::Field,
+    value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let codec_attributes = FieldCodecAttributes::parse(field);
+    if codec_attributes.skip {
+        return quote! {};
+    }
+
+    if let Some(module) = &codec_attributes.with {
+        quote! {
+            #module::dep_encode_or_handle_err(&#value, __dest__, __h__)?;
+        }
+    } else {
+        quote! {
+            codec::NestedEncode::dep_encode_or_handle_err(&#value, __dest__, __h__)?;
+        }
     }
 }
 
@@ -21,9 +35,9 @@ fn variant_dep_encode_snippets(
             let variant_discriminant = get_discriminant(variant_index, variant, &mut previous_disc);
             let variant_ident = &variant.ident;
             let local_var_declarations =
-                fields_decl_syntax(&variant.fields, local_variable_for_field);
+                fields_decl_syntax(&variant.fields, local_variable_pattern_for_field);
             let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-                dep_encode_snippet(&local_variable_for_field(index, field))
+                dep_encode_snippet(field, &local_variable_for_field(index, field))
             });
             quote! {
                 #name::#variant_ident #local_var_declarations => {
@@ -40,8 +54,10 @@ pub fn nested_encode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
+            validate_fields(&data_struct.fields);
+
             let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
-                dep_encode_snippet(&self_field_expr(index, field))
+                dep_encode_snippet(field, &self_field_expr(index, field))
             });
             quote! {
                 impl #impl_generics codec::NestedEncode for #name #ty_generics #where_clause {
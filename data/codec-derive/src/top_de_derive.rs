@@ -23,21 +23,19 @@ fn fieldless_enum_match_arm_result_ok(
 }
 
 /// Generates a default-value deserializer snippet automatically.
-/// Currently only does so for enums whose first variant is fieldless.
+/// Currently only does so for enums whose variant with discriminant 0 is fieldless
+/// (normally the first variant).
 /// Not called for TopDecodeOrDefault, since that one already provides an explicit default.
 fn auto_default(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let name = &ast.ident;
     if let syn::Data::Enum(data_enum) = &ast.data {
-        assert!(
-            !data_enum.variants.is_empty(),
-            "cannot deserialize enums without variants"
-        );
-        let first_variant = &data_enum.variants[0];
-        if first_variant.fields.is_empty() {
-            let first_variant_ident = &first_variant.ident;
+        let default_variant = zero_discriminant_variant(data_enum)
+            .unwrap_or_else(|| panic!("cannot deserialize enums without variants"));
+        if default_variant.fields.is_empty() {
+            let default_variant_ident = &default_variant.ident;
             let auto_default = quote! {
                 if top_input.byte_len() == 0 {
-                    return core::result::Result::Ok(#name::#first_variant_ident);
+                    return core::result::Result::Ok(#name::#default_variant_ident);
                 }
             };
             return auto_default;
@@ -53,6 +51,8 @@ fn top_decode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let name = &ast.ident;
     match &ast.data {
         syn::Data::Struct(data_struct) => {
+            validate_fields(&data_struct.fields);
+
             let field_dep_decode_snippets =
                 fields_decl_syntax(&data_struct.fields, |index, field| {
                     dep_decode_snippet(index, field, &quote! {&mut nested_buffer})
@@ -1,6 +1,8 @@
 use quote::quote;
 use syn::{punctuated::Punctuated, token::Comma, Variant};
 
+use crate::codec_attr::{variant_codec_discriminant, FieldCodecAttributes};
+
 pub struct ExplicitDiscriminant {
     pub variant_index: usize,
     pub value: u8,
@@ -40,6 +42,26 @@ pub fn local_variable_for_field(index: usize, field: &syn::Field) -> proc_macro2
     }
 }
 
+/// Same as `local_variable_for_field`, but ignores skipped fields, since they never get encoded.
+pub fn local_variable_pattern_for_field(
+    index: usize,
+    field: &syn::Field,
+) -> proc_macro2::TokenStream {
+    if !FieldCodecAttributes::parse(field).skip {
+        return local_variable_for_field(index, field);
+    }
+
+    if let Some(ident) = &field.ident {
+        quote! {
+            #ident: _
+        }
+    } else {
+        quote! {
+            _
+        }
+    }
+}
+
 pub fn fields_snippets<F>(fields: &syn::Fields, field_mapper: F) -> Vec<proc_macro2::TokenStream>
 where
     F: Fn(usize, &syn::Field) -> proc_macro2::TokenStream,
@@ -92,11 +114,57 @@ where
     }
 }
 
+/// Fields marked `#[codec(default)]` can be missing from the input,
+/// so only other such fields (or skipped fields) are allowed to follow them.
+pub fn validate_fields(fields: &syn::Fields) {
+    let mut default_field_found = false;
+    for field in fields.iter() {
+        let codec_attributes = FieldCodecAttributes::parse(field);
+        if codec_attributes.default {
+            default_field_found = true;
+        } else if !codec_attributes.skip {
+            assert!(
+                !default_field_found,
+                "fields marked #[codec(default)] can only be followed by other #[codec(default)] or #[codec(skip)] fields"
+            );
+        }
+    }
+}
+
 pub fn validate_enum_variants(variants: &Punctuated<Variant, Comma>) {
     assert!(
         variants.len() <= 256,
         "enums with more than 256 variants not supported"
     );
+
+    let mut previous_disc: Vec<ExplicitDiscriminant> = Vec::new();
+    let mut discriminants: Vec<u8> = Vec::new();
+    for (variant_index, variant) in variants.iter().enumerate() {
+        let discriminant = get_discriminant_value(variant_index, variant, &mut previous_disc);
+        assert!(
+            !discriminants.contains(&discriminant),
+            "duplicate discriminant {discriminant} for variant {}",
+            variant.ident
+        );
+        discriminants.push(discriminant);
+        validate_fields(&variant.fields);
+    }
+}
+
+/// The variant that top-decodes from empty input, i.e. the one with discriminant 0.
+///
+/// Falls back to the first variant, if none of them has discriminant 0.
+pub fn zero_discriminant_variant(data_enum: &syn::DataEnum) -> Option<&syn::Variant> {
+    let mut previous_disc: Vec<ExplicitDiscriminant> = Vec::new();
+    data_enum
+        .variants
+        .iter()
+        .enumerate()
+        .find(|(variant_index, variant)| {
+            get_discriminant_value(*variant_index, variant, &mut previous_disc) == 0
+        })
+        .map(|(_, variant)| variant)
+        .or_else(|| data_enum.variants.first())
 }
 
 pub fn get_discriminant(
@@ -104,27 +172,47 @@ pub fn get_discriminant(
     variant: &syn::Variant,
     previous_disc: &mut Vec<ExplicitDiscriminant>,
 ) -> proc_macro2::TokenStream {
-    // if it has explicit discriminant
+    let value = get_discriminant_value(variant_index, variant, previous_disc);
+    quote! { #value}
+}
+
+/// The `#[codec(discriminant = N)]` attribute takes precedence over the Rust discriminant.
+fn explicit_discriminant(variant: &syn::Variant) -> Option<u8> {
+    if let Some(value) = variant_codec_discriminant(variant) {
+        return Some(value);
+    }
+
     if let Some((_, syn::Expr::Lit(expr))) = &variant.discriminant {
-        let lit = match &expr.lit {
+        return match &expr.lit {
             syn::Lit::Int(val) => {
-                let value = val.base10_parse().unwrap_or_else(|_| {
+                Some(val.base10_parse().unwrap_or_else(|_| {
                     panic!("Can not unwrap int value from explicit discriminant")
-                });
-                previous_disc.push(ExplicitDiscriminant {
-                    variant_index,
-                    value,
-                });
-                value
+                }))
             },
             _ => panic!("Only integer values as discriminants"), // theoretically covered by the compiler
         };
-        return quote! { #lit};
+    }
+
+    None
+}
+
+pub fn get_discriminant_value(
+    variant_index: usize,
+    variant: &syn::Variant,
+    previous_disc: &mut Vec<ExplicitDiscriminant>,
+) -> u8 {
+    // if it has explicit discriminant
+    if let Some(value) = explicit_discriminant(variant) {
+        previous_disc.push(ExplicitDiscriminant {
+            variant_index,
+            value,
+        });
+        return value;
     }
 
     // if no explicit discriminant, check previous discriminants
     // get previous explicit + 1 if there has been any explicit before
-    let next_value = match previous_disc.last() {
+    match previous_disc.last() {
         //there are previous explicit discriminants
         Some(ExplicitDiscriminant {
             variant_index: prev_index,
@@ -137,7 +225,5 @@ pub fn get_discriminant(
 
         // vec is empty, return index
         None => variant_index as u8,
-    };
-
-    quote! { #next_value}
+    }
 }
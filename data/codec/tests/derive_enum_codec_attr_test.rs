@@ -0,0 +1,97 @@
+use multiversx_sc_codec as codec;
+use multiversx_sc_codec_derive::*;
+
+use codec::test_util::{check_dep_encode_decode, check_top_decode, check_top_encode_decode};
+
+// to test, run the following command in the crate folder:
+// cargo expand --test derive_enum_codec_attr_test > enum_expanded.rs
+
+/// Variants got reordered and a new one was added in front,
+/// but the encoding stays the same, thanks to the explicit discriminants.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+enum Status {
+    #[codec(discriminant = 3)]
+    Paused {
+        until: u64,
+    },
+    #[codec(discriminant = 0)]
+    Inactive,
+    Active(u8),
+    #[codec(discriminant = 2)]
+    Finished,
+}
+
+#[test]
+fn enum_codec_discriminant_test() {
+    check_top_encode_decode(Status::Inactive, &[]);
+    check_dep_encode_decode(Status::Inactive, &[0]);
+    assert_eq!(Status::Inactive, check_top_decode(&[0]));
+
+    // follows the previous explicit discriminant
+    check_top_encode_decode(Status::Active(5), &[1, 5]);
+    check_dep_encode_decode(Status::Active(5), &[1, 5]);
+
+    check_top_encode_decode(Status::Finished, &[2]);
+    check_dep_encode_decode(Status::Finished, &[2]);
+
+    check_top_encode_decode(Status::Paused { until: 7 }, &[3, 0, 0, 0, 0, 0, 0, 0, 7]);
+    check_dep_encode_decode(Status::Paused { until: 7 }, &[3, 0, 0, 0, 0, 0, 0, 0, 7]);
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Debug)]
+enum FieldlessWithDiscriminants {
+    #[codec(discriminant = 5)]
+    Five,
+    Six,
+    #[codec(discriminant = 0)]
+    Zero,
+}
+
+#[test]
+fn fieldless_enum_codec_discriminant_test() {
+    check_top_encode_decode(FieldlessWithDiscriminants::Five, &[5]);
+    check_top_encode_decode(FieldlessWithDiscriminants::Six, &[6]);
+    check_dep_encode_decode(FieldlessWithDiscriminants::Six, &[6]);
+
+    // the variant with discriminant 0 decodes from empty input, even if it is not the first
+    check_top_encode_decode(FieldlessWithDiscriminants::Zero, &[]);
+    check_dep_encode_decode(FieldlessWithDiscriminants::Zero, &[0]);
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Debug)]
+enum EnumWithFieldAttributes {
+    Nothing,
+    Named {
+        int: u16,
+        #[codec(skip)]
+        cached: u32,
+        #[codec(default)]
+        extra: u8,
+    },
+    Unnamed(u16, #[codec(skip)] u32),
+}
+
+#[test]
+fn enum_field_codec_attributes_test() {
+    let named = EnumWithFieldAttributes::Named {
+        int: 0x42,
+        cached: 0,
+        extra: 3,
+    };
+    check_top_encode_decode(named.clone(), &[1, 0, 0x42, 3]);
+    check_dep_encode_decode(named, &[1, 0, 0x42, 3]);
+
+    // missing trailing field
+    assert_eq!(
+        check_top_decode::<EnumWithFieldAttributes>(&[1, 0, 0x42]),
+        EnumWithFieldAttributes::Named {
+            int: 0x42,
+            cached: 0,
+            extra: 0,
+        }
+    );
+
+    let unnamed = EnumWithFieldAttributes::Unnamed(0x42, 0);
+    check_top_encode_decode(unnamed.clone(), &[2, 0, 0x42]);
+    check_dep_encode_decode(unnamed, &[2, 0, 0x42]);
+}
@@ -0,0 +1,150 @@
+use multiversx_sc_codec as codec;
+use multiversx_sc_codec_derive::*;
+
+use codec::test_util::{check_dep_encode_decode, check_top_decode, check_top_encode_decode};
+
+// to test, run the following command in the crate folder:
+// cargo expand --test derive_struct_codec_attr_test > expanded.rs
+
+/// The first version of the struct, as it might have been saved to storage.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct StructV1 {
+    pub int: u16,
+    pub seq: Vec<u8>,
+}
+
+/// Same struct, after 2 fields got added.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct StructV2 {
+    pub int: u16,
+    pub seq: Vec<u8>,
+    #[codec(default)]
+    pub another_byte: u8,
+    #[codec(default)]
+    pub uint_32: u32,
+}
+
+#[test]
+fn struct_default_fields_present_test() {
+    let s = StructV2 {
+        int: 0x42,
+        seq: vec![1, 2],
+        another_byte: 3,
+        uint_32: 0x12345,
+    };
+
+    #[rustfmt::skip]
+    let bytes = &[
+        /* int */ 0, 0x42,
+        /* seq length */ 0, 0, 0, 2,
+        /* seq contents */ 1, 2,
+        /* another_byte */ 3,
+        /* uint_32 */ 0x00, 0x01, 0x23, 0x45,
+    ];
+
+    check_top_encode_decode(s.clone(), bytes);
+    check_dep_encode_decode(s, bytes);
+}
+
+#[test]
+fn struct_default_fields_missing_test() {
+    let v1 = StructV1 {
+        int: 0x42,
+        seq: vec![1, 2],
+    };
+    let v1_bytes = codec::top_encode_to_vec_u8_or_panic(&v1);
+
+    let expected = StructV2 {
+        int: 0x42,
+        seq: vec![1, 2],
+        another_byte: 0,
+        uint_32: 0,
+    };
+    assert_eq!(check_top_decode::<StructV2>(&v1_bytes), expected);
+
+    // only the last field missing
+    let mut partial_bytes = v1_bytes.clone();
+    partial_bytes.push(7);
+    assert_eq!(
+        check_top_decode::<StructV2>(&partial_bytes),
+        StructV2 {
+            another_byte: 7,
+            ..expected
+        }
+    );
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct StructWithSkip {
+    pub int: u16,
+    #[codec(skip)]
+    pub cached_value: u64,
+    pub another_byte: u8,
+}
+
+#[test]
+fn struct_skip_field_test() {
+    let s = StructWithSkip {
+        int: 0x42,
+        cached_value: 0,
+        another_byte: 3,
+    };
+    check_top_encode_decode(s.clone(), &[0, 0x42, 3]);
+    check_dep_encode_decode(s, &[0, 0x42, 3]);
+
+    // skipped field is not encoded, so it decodes as default
+    let s_with_cache = StructWithSkip {
+        int: 0x42,
+        cached_value: 1234,
+        another_byte: 3,
+    };
+    assert_eq!(
+        codec::top_encode_to_vec_u8_or_panic(&s_with_cache),
+        &[0, 0x42, 3]
+    );
+}
+
+/// Encodes a `u32` as a single byte. Values above 255 are errors.
+mod u32_as_u8 {
+    use multiversx_sc_codec::{
+        DecodeErrorHandler, EncodeError, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
+        NestedEncode, NestedEncodeOutput,
+    };
+
+    pub fn dep_encode_or_handle_err<O, H>(
+        value: &u32,
+        dest: &mut O,
+        h: H,
+    ) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        let byte = u8::try_from(*value)
+            .map_err(|_| h.handle_error(EncodeError::from("value too large")))?;
+        byte.dep_encode_or_handle_err(dest, h)
+    }
+
+    pub fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<u32, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        u8::dep_decode_or_handle_err(input, h).map(u32::from)
+    }
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct StructWithCustomCodec(u16, #[codec(with = "u32_as_u8", encoded_as = "u8")] u32, u8);
+
+#[test]
+fn struct_custom_codec_field_test() {
+    let s = StructWithCustomCodec(0x42, 5, 3);
+    check_top_encode_decode(s.clone(), &[0, 0x42, 5, 3]);
+    check_dep_encode_decode(s, &[0, 0x42, 5, 3]);
+
+    assert_eq!(
+        codec::top_encode_to_vec_u8(&StructWithCustomCodec(0x42, 300, 3)),
+        Err(codec::EncodeError::from("value too large"))
+    );
+}
@@ -3,6 +3,7 @@ mod contract_abi;
 mod endpoint_abi;
 mod esdt_attribute_abi;
 mod event_abi;
+mod storage_abi;
 mod type_abi;
 mod type_abi_from;
 mod type_abi_impl_basic;
@@ -19,6 +20,7 @@ pub use contract_abi::*;
 pub use endpoint_abi::*;
 pub use esdt_attribute_abi::EsdtAttributeAbi;
 pub use event_abi::*;
+pub use storage_abi::*;
 pub use type_abi::*;
 pub use type_abi_from::*;
 pub use type_description::*;
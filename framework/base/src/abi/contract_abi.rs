@@ -14,6 +14,7 @@ pub struct ContractAbi {
     pub endpoints: Vec<EndpointAbi>,
     pub promise_callbacks: Vec<EndpointAbi>,
     pub events: Vec<EventAbi>,
+    pub storage: Vec<StorageAbi>,
     pub esdt_attributes: Vec<EsdtAttributeAbi>,
     pub has_callback: bool,
     pub type_descriptions: TypeDescriptionContainerImpl,
@@ -31,6 +32,7 @@ impl ContractAbi {
             endpoints: Vec::new(),
             promise_callbacks: Vec::new(),
             events: Vec::new(),
+            storage: Vec::new(),
             esdt_attributes: Vec::new(),
             has_callback,
             type_descriptions: TypeDescriptionContainerImpl::new(),
@@ -44,6 +46,9 @@ impl ContractAbi {
         self.upgrade_constructors
             .extend_from_slice(other.upgrade_constructors.as_slice());
         self.events.extend_from_slice(other.events.as_slice());
+        for storage_abi in other.storage {
+            self.add_storage(storage_abi);
+        }
         self.promise_callbacks
             .extend_from_slice(other.promise_callbacks.as_slice());
         self.has_callback |= other.has_callback;
@@ -58,6 +63,18 @@ impl ContractAbi {
         T::provide_type_descriptions(&mut self.type_descriptions);
     }
 
+    /// Getters, setters and mappers of the same key describe the same storage entry,
+    /// so they are only listed once. The first one to be exposed as a view provides the view name.
+    pub fn add_storage(&mut self, storage_abi: StorageAbi) {
+        if let Some(existing) = self.storage.iter_mut().find(|s| s.key == storage_abi.key) {
+            if existing.view.is_none() {
+                existing.view = storage_abi.view;
+            }
+        } else {
+            self.storage.push(storage_abi);
+        }
+    }
+
     /// Contract main crate name.
     pub fn get_crate_name(&self) -> &str {
         self.build_info.contract_crate.name
@@ -8,6 +8,7 @@ use alloc::{
 pub struct EventInputAbi {
     pub arg_name: String,
     pub type_name: TypeName,
+    pub type_name_rust: TypeName,
     pub indexed: bool,
 }
 
@@ -15,15 +16,17 @@ pub struct EventInputAbi {
 pub struct EventAbi {
     pub docs: Vec<String>,
     pub identifier: String,
+    pub rust_method_name: String,
     pub inputs: Vec<EventInputAbi>,
 }
 
 impl EventAbi {
     /// Used in code generation.
-    pub fn new(docs: &[&str], identifier: &str) -> Self {
+    pub fn new(docs: &[&str], identifier: &str, rust_method_name: &str) -> Self {
         EventAbi {
             docs: docs.iter().map(|s| s.to_string()).collect(),
             identifier: identifier.to_string(),
+            rust_method_name: rust_method_name.to_string(),
             inputs: Vec::new(),
         }
     }
@@ -33,6 +36,7 @@ impl EventAbi {
         self.inputs.push(EventInputAbi {
             arg_name: arg_name.to_string(),
             type_name: T::type_name(),
+            type_name_rust: T::type_name_rust(),
             indexed,
         });
     }
@@ -0,0 +1,109 @@
+use super::*;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::marker::PhantomData;
+
+/// Mapper kind reported for `#[storage_get]` and `#[storage_set]` methods,
+/// since they share the storage layout of a single value mapper.
+pub const STORAGE_SINGLE_VALUE_MAPPER: &str = "SingleValueMapper";
+
+/// Type name reported for stored types that do not implement `TypeAbi`.
+///
+/// Deliberately not the Rust type name, which depends on the compiler and on the crate layout.
+pub const STORAGE_UNKNOWN_TYPE: &str = "unknown";
+
+/// Describes a storage key of the contract, together with the types stored under it.
+#[derive(Clone, Debug)]
+pub struct StorageAbi {
+    pub docs: Vec<String>,
+    pub key: String,
+    pub rust_method_name: String,
+    pub mapper: String,
+    pub view: Option<String>,
+    pub key_args: Vec<InputAbi>,
+    pub value_types: Vec<TypeNames>,
+}
+
+impl StorageAbi {
+    /// Used in code generation.
+    pub fn new(docs: &[&str], key: &str, rust_method_name: &str, mapper: &str) -> Self {
+        StorageAbi {
+            docs: docs.iter().map(|s| s.to_string()).collect(),
+            key: key.to_string(),
+            rust_method_name: rust_method_name.to_string(),
+            mapper: mapper.to_string(),
+            view: None,
+            key_args: Vec::new(),
+            value_types: Vec::new(),
+        }
+    }
+
+    /// Used in code generation.
+    pub fn with_view(mut self, view: &str) -> Self {
+        self.view = Some(view.to_string());
+        self
+    }
+
+    /// Used in code generation.
+    pub fn add_key_arg(&mut self, arg_name: &str, type_names: TypeNames) {
+        self.key_args.push(InputAbi {
+            arg_name: arg_name.to_string(),
+            type_names,
+            multi_arg: false,
+        });
+    }
+
+    /// Used in code generation.
+    pub fn add_value_type(&mut self, type_names: TypeNames) {
+        self.value_types.push(type_names);
+    }
+}
+
+/// Stored types are not required to implement `TypeAbi`.
+///
+/// The probe resolves to the `TypeAbi` names and descriptions when available,
+/// and falls back to [`STORAGE_UNKNOWN_TYPE`] otherwise.
+/// It relies on method resolution preferring [`StorageTypeAbi`] over [`StorageTypeAbiFallback`],
+/// so it must be called as `(&StorageTypeProbe::<T>::new()).storage_type_names(...)`.
+pub struct StorageTypeProbe<T: ?Sized>(PhantomData<T>);
+
+impl<T: ?Sized> StorageTypeProbe<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        StorageTypeProbe(PhantomData)
+    }
+}
+
+pub trait StorageTypeAbi {
+    fn storage_type_names(&self, type_descriptions: &mut TypeDescriptionContainerImpl)
+        -> TypeNames;
+}
+
+impl<T: TypeAbi> StorageTypeAbi for StorageTypeProbe<T> {
+    fn storage_type_names(
+        &self,
+        type_descriptions: &mut TypeDescriptionContainerImpl,
+    ) -> TypeNames {
+        T::provide_type_descriptions(type_descriptions);
+        T::type_names()
+    }
+}
+
+pub trait StorageTypeAbiFallback {
+    fn storage_type_names(&self, type_descriptions: &mut TypeDescriptionContainerImpl)
+        -> TypeNames;
+}
+
+impl<T: ?Sized> StorageTypeAbiFallback for &StorageTypeProbe<T> {
+    fn storage_type_names(
+        &self,
+        _type_descriptions: &mut TypeDescriptionContainerImpl,
+    ) -> TypeNames {
+        TypeNames {
+            abi: STORAGE_UNKNOWN_TYPE.to_string(),
+            rust: STORAGE_UNKNOWN_TYPE.to_string(),
+        }
+    }
+}
@@ -92,6 +92,10 @@ pub struct StructFieldDescription {
     pub docs: Vec<String>,
     pub name: String,
     pub field_type: TypeNames,
+
+    /// Field marked `#[codec(default)]`: it can be missing at the end of the encoded value,
+    /// in which case it gets decoded as the default value.
+    pub default_if_missing: bool,
 }
 
 impl StructFieldDescription {
@@ -101,8 +105,15 @@ impl StructFieldDescription {
             docs: docs.iter().map(|s| s.to_string()).collect(),
             name: name.to_string(),
             field_type,
+            default_if_missing: false,
         }
     }
+
+    /// Used in code generation, for fields marked `#[codec(default)]`.
+    pub fn with_default_if_missing(mut self) -> Self {
+        self.default_if_missing = true;
+        self
+    }
 }
 
 /// An explicit enum is an enum that gets serialized by name instead of discriminant.
@@ -37,6 +37,12 @@ pub trait BlockchainApiImpl: ManagedTypeApiImpl {
 
     fn load_owner_address_managed(&self, dest: Self::ManagedBufferHandle);
 
+    /// Loads the sender of the transaction that started the current call chain.
+    fn load_original_caller_managed(&self, dest: Self::ManagedBufferHandle);
+
+    /// Loads the relayer of the current transaction, or an empty buffer if it was not relayed.
+    fn load_relayer_managed(&self, dest: Self::ManagedBufferHandle);
+
     fn get_shard_of_address_legacy(&self, address: &Address) -> u32;
 
     fn get_shard_of_address(&self, address_handle: Self::ManagedBufferHandle) -> u32 {
@@ -69,6 +75,18 @@ pub trait BlockchainApiImpl: ManagedTypeApiImpl {
         self.mb_overwrite(dest, self.get_tx_hash_legacy().as_bytes());
     }
 
+    fn get_current_tx_hash_legacy(&self) -> H256;
+
+    fn load_current_tx_hash_managed(&self, dest: Self::ManagedBufferHandle) {
+        self.mb_overwrite(dest, self.get_current_tx_hash_legacy().as_bytes());
+    }
+
+    fn get_prev_tx_hash_legacy(&self) -> H256;
+
+    fn load_prev_tx_hash_managed(&self, dest: Self::ManagedBufferHandle) {
+        self.mb_overwrite(dest, self.get_prev_tx_hash_legacy().as_bytes());
+    }
+
     fn get_gas_left(&self) -> u64;
 
     fn get_block_timestamp(&self) -> u64;
@@ -95,6 +113,12 @@ pub trait BlockchainApiImpl: ManagedTypeApiImpl {
         self.mb_overwrite(dest, self.get_prev_block_random_seed_legacy().as_slice());
     }
 
+    fn get_block_hash_legacy(&self, nonce: u64) -> H256;
+
+    fn load_block_hash_managed(&self, nonce: u64, dest: Self::ManagedBufferHandle) {
+        self.mb_overwrite(dest, self.get_block_hash_legacy(nonce).as_bytes());
+    }
+
     fn get_current_esdt_nft_nonce(
         &self,
         address_handle: Self::ManagedBufferHandle,
@@ -154,4 +178,15 @@ pub trait BlockchainApiImpl: ManagedTypeApiImpl {
     );
 
     fn managed_is_builtin_function(&self, function_name_handle: Self::ManagedBufferHandle) -> bool;
+
+    /// Locks the storage key until the given timestamp.
+    fn set_storage_lock(&self, key: &[u8], lock_timestamp: u64);
+
+    /// The timestamp until which the storage key is locked, 0 if it was never locked.
+    fn get_storage_lock(&self, key: &[u8]) -> u64;
+
+    /// True if the lock timestamp is after the current block timestamp.
+    fn is_storage_locked(&self, key: &[u8]) -> bool;
+
+    fn clear_storage_lock(&self, key: &[u8]);
 }
@@ -1,3 +1,5 @@
+use crate::types::heap::ArgBuffer;
+
 use super::{BlockchainApi, HandleTypeInfo, ManagedTypeApi, ManagedTypeApiImpl, RawHandle};
 
 pub trait SendApi: ManagedTypeApi + BlockchainApi {
@@ -99,6 +101,30 @@ pub trait SendApiImpl: ManagedTypeApiImpl {
         arg_buffer_handle: RawHandle,
     );
 
+    /// Same as `deploy_from_source_contract`, but through the legacy VM hook,
+    /// which reads its inputs from contract memory instead of managed handles.
+    fn deploy_from_source_contract_legacy(
+        &self,
+        gas: u64,
+        egld_value: &[u8; 32],
+        source_contract_address: &[u8; 32],
+        code_metadata: &[u8; 2],
+        arg_buffer: &ArgBuffer,
+        new_address: &mut [u8; 32],
+    );
+
+    /// Same as `upgrade_from_source_contract`, but through the legacy VM hook,
+    /// which reads its inputs from contract memory instead of managed handles.
+    fn upgrade_from_source_contract_legacy(
+        &self,
+        sc_address: &[u8; 32],
+        gas: u64,
+        egld_value: &[u8; 32],
+        source_contract_address: &[u8; 32],
+        code_metadata: &[u8; 2],
+        arg_buffer: &ArgBuffer,
+    );
+
     /// Upgrades a child contract of the currently executing contract.
     /// The upgrade is synchronous, and the current transaction will fail if the upgrade fails.
     /// The child contract's new init function will be called with the provided arguments
@@ -112,6 +138,10 @@ pub trait SendApiImpl: ManagedTypeApiImpl {
         arg_buffer_handle: RawHandle,
     );
 
+    /// Deletes a child contract of the currently executing contract.
+    /// The deletion is synchronous, and the current transaction will fail if it fails.
+    fn delete_contract(&self, sc_address_handle: RawHandle, gas: u64, arg_buffer_handle: RawHandle);
+
     /// Same shard, in-line execution of another contract.
     fn execute_on_dest_context_raw(
         &self,
@@ -23,6 +23,15 @@ impl BlockchainApiImpl for UncallableApi {
     fn load_owner_address_managed(&self, _dest: Self::ManagedBufferHandle) {
         unreachable!()
     }
+
+    fn load_original_caller_managed(&self, _dest: Self::ManagedBufferHandle) {
+        unreachable!()
+    }
+
+    fn load_relayer_managed(&self, _dest: Self::ManagedBufferHandle) {
+        unreachable!()
+    }
+
     fn get_shard_of_address_legacy(&self, _address: &Address) -> u32 {
         unreachable!()
     }
@@ -47,6 +56,14 @@ impl BlockchainApiImpl for UncallableApi {
         unreachable!()
     }
 
+    fn get_current_tx_hash_legacy(&self) -> H256 {
+        unreachable!()
+    }
+
+    fn get_prev_tx_hash_legacy(&self) -> H256 {
+        unreachable!()
+    }
+
     fn get_gas_left(&self) -> u64 {
         unreachable!()
     }
@@ -91,6 +108,10 @@ impl BlockchainApiImpl for UncallableApi {
         unreachable!()
     }
 
+    fn get_block_hash_legacy(&self, _nonce: u64) -> H256 {
+        unreachable!()
+    }
+
     fn get_current_esdt_nft_nonce(
         &self,
         _address_handle: Self::ManagedBufferHandle,
@@ -172,4 +193,20 @@ impl BlockchainApiImpl for UncallableApi {
     ) -> bool {
         unreachable!()
     }
+
+    fn set_storage_lock(&self, _key: &[u8], _lock_timestamp: u64) {
+        unreachable!()
+    }
+
+    fn get_storage_lock(&self, _key: &[u8]) -> u64 {
+        unreachable!()
+    }
+
+    fn is_storage_locked(&self, _key: &[u8]) -> bool {
+        unreachable!()
+    }
+
+    fn clear_storage_lock(&self, _key: &[u8]) {
+        unreachable!()
+    }
 }
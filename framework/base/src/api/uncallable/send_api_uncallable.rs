@@ -1,4 +1,7 @@
-use crate::api::{RawHandle, SendApi, SendApiImpl};
+use crate::{
+    api::{RawHandle, SendApi, SendApiImpl},
+    types::heap::ArgBuffer,
+};
 
 use super::UncallableApi;
 
@@ -96,6 +99,30 @@ impl SendApiImpl for UncallableApi {
         unreachable!()
     }
 
+    fn deploy_from_source_contract_legacy(
+        &self,
+        _gas: u64,
+        _egld_value: &[u8; 32],
+        _source_contract_address: &[u8; 32],
+        _code_metadata: &[u8; 2],
+        _arg_buffer: &ArgBuffer,
+        _new_address: &mut [u8; 32],
+    ) {
+        unreachable!()
+    }
+
+    fn upgrade_from_source_contract_legacy(
+        &self,
+        _sc_address: &[u8; 32],
+        _gas: u64,
+        _egld_value: &[u8; 32],
+        _source_contract_address: &[u8; 32],
+        _code_metadata: &[u8; 2],
+        _arg_buffer: &ArgBuffer,
+    ) {
+        unreachable!()
+    }
+
     fn upgrade_contract(
         &self,
         _sc_address: RawHandle,
@@ -108,6 +135,15 @@ impl SendApiImpl for UncallableApi {
         unreachable!()
     }
 
+    fn delete_contract(
+        &self,
+        _sc_address_handle: RawHandle,
+        _gas: u64,
+        _arg_buffer_handle: RawHandle,
+    ) {
+        unreachable!()
+    }
+
     fn execute_on_dest_context_raw(
         &self,
         _gas: u64,
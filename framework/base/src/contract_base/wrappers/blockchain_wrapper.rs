@@ -8,7 +8,7 @@ use crate::{
     },
     codec::TopDecode,
     err_msg::{ONLY_OWNER_CALLER, ONLY_USER_ACCOUNT_CALLER},
-    storage::{self},
+    storage::{self, StorageKey},
     types::{
         BackTransfers, BigUint, CodeMetadata, EgldOrEsdtTokenIdentifier, EsdtLocalRoleFlags,
         EsdtTokenData, EsdtTokenType, ManagedAddress, ManagedBuffer, ManagedByteArray,
@@ -56,6 +56,29 @@ where
         }
     }
 
+    /// The sender of the transaction that started the current call chain.
+    ///
+    /// Unlike `get_caller`, it does not change across cross-contract calls.
+    #[inline]
+    pub fn get_original_caller(&self) -> ManagedAddress<A> {
+        unsafe {
+            let result = ManagedAddress::new_uninit();
+            A::blockchain_api_impl().load_original_caller_managed(result.get_handle());
+            result
+        }
+    }
+
+    /// The relayer of the current transaction, if it is a relayed transaction (v3).
+    pub fn get_relayer(&self) -> Option<ManagedAddress<A>> {
+        let relayer = ManagedBuffer::<A>::new();
+        A::blockchain_api_impl().load_relayer_managed(relayer.get_handle());
+        if relayer.is_empty() {
+            None
+        } else {
+            ManagedAddress::try_from(relayer).ok()
+        }
+    }
+
     #[deprecated(since = "0.41.0", note = "Please use method `get_sc_address` instead.")]
     #[cfg(feature = "alloc")]
     #[inline]
@@ -209,6 +232,29 @@ where
         }
     }
 
+    /// Hash of the transaction or smart contract result currently being executed.
+    ///
+    /// Unlike `get_tx_hash`, which always gives the hash of the original transaction,
+    /// it is different in async calls and callbacks.
+    #[inline]
+    pub fn get_current_tx_hash(&self) -> ManagedByteArray<A, 32> {
+        unsafe {
+            let result = ManagedByteArray::new_uninit();
+            A::blockchain_api_impl().load_current_tx_hash_managed(result.get_handle());
+            result
+        }
+    }
+
+    /// Hash of the transaction or smart contract result that produced the current one.
+    #[inline]
+    pub fn get_prev_tx_hash(&self) -> ManagedByteArray<A, 32> {
+        unsafe {
+            let result = ManagedByteArray::new_uninit();
+            A::blockchain_api_impl().load_prev_tx_hash_managed(result.get_handle());
+            result
+        }
+    }
+
     #[inline]
     pub fn get_gas_left(&self) -> u64 {
         A::blockchain_api_impl().get_gas_left()
@@ -292,6 +338,16 @@ where
         }
     }
 
+    /// Hash of the block with the given nonce. All zeros for blocks not yet produced.
+    #[inline]
+    pub fn get_block_hash(&self, nonce: u64) -> ManagedByteArray<A, 32> {
+        unsafe {
+            let result = ManagedByteArray::new_uninit();
+            A::blockchain_api_impl().load_block_hash_managed(nonce, result.get_handle());
+            result
+        }
+    }
+
     #[inline]
     pub fn get_current_esdt_nft_nonce(
         &self,
@@ -451,6 +507,42 @@ where
     }
 }
 
+/// Storage locks.
+///
+/// A storage lock is a timestamp attached to a storage key, kept by the protocol under a reserved key.
+/// It does not prevent writing to the key, the contract decides what the lock means,
+/// e.g. that a governance parameter cannot be changed before a certain time.
+impl<A> BlockchainWrapper<A>
+where
+    A: BlockchainApi + ManagedTypeApi + ErrorApi + 'static,
+{
+    /// Locks the storage key until the given block timestamp, overwriting any previous lock.
+    pub fn set_storage_lock(&self, key: &StorageKey<A>, unlock_timestamp: u64) {
+        key.buffer.with_buffer_contents(|key_bytes| {
+            A::blockchain_api_impl().set_storage_lock(key_bytes, unlock_timestamp)
+        })
+    }
+
+    /// The block timestamp until which the storage key is locked, 0 if it is not locked.
+    pub fn get_storage_lock(&self, key: &StorageKey<A>) -> u64 {
+        key.buffer
+            .with_buffer_contents(|key_bytes| A::blockchain_api_impl().get_storage_lock(key_bytes))
+    }
+
+    /// True if the lock expires after the current block timestamp.
+    pub fn is_storage_locked(&self, key: &StorageKey<A>) -> bool {
+        key.buffer
+            .with_buffer_contents(|key_bytes| A::blockchain_api_impl().is_storage_locked(key_bytes))
+    }
+
+    /// Removes the lock from the storage key.
+    pub fn clear_storage_lock(&self, key: &StorageKey<A>) {
+        key.buffer.with_buffer_contents(|key_bytes| {
+            A::blockchain_api_impl().clear_storage_lock(key_bytes)
+        })
+    }
+}
+
 impl<A> BlockchainWrapper<A>
 where
     A: BlockchainApi + StorageReadApi + ManagedTypeApi + ErrorApi,
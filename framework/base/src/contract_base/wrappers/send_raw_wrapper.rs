@@ -6,8 +6,8 @@ use crate::{
         HandleConstraints, ManagedBufferApiImpl, RawHandle, SendApiImpl, StaticVarApiImpl,
     },
     types::{
-        BigUint, CodeMetadata, EgldOrEsdtTokenPayment, EsdtTokenPayment, ManagedAddress,
-        ManagedArgBuffer, ManagedBuffer, ManagedType, ManagedVec, TokenIdentifier,
+        heap::ArgBuffer, BigUint, CodeMetadata, EgldOrEsdtTokenPayment, EsdtTokenPayment,
+        ManagedAddress, ManagedArgBuffer, ManagedBuffer, ManagedType, ManagedVec, TokenIdentifier,
     },
 };
 
@@ -281,6 +281,49 @@ where
         )
     }
 
+    /// Same as `deploy_from_source_contract`, but through the legacy VM hook,
+    /// which receives its inputs as contract memory instead of managed handles.
+    pub fn deploy_from_source_contract_legacy(
+        &self,
+        gas: u64,
+        egld_value: &BigUint<A>,
+        source_contract_address: &ManagedAddress<A>,
+        code_metadata: CodeMetadata,
+        arg_buffer: &ManagedArgBuffer<A>,
+    ) -> ManagedAddress<A> {
+        let mut new_address = [0u8; 32];
+        A::send_api_impl().deploy_from_source_contract_legacy(
+            gas,
+            &egld_value_to_legacy_bytes(egld_value),
+            &source_contract_address.to_byte_array(),
+            &code_metadata.to_byte_array(),
+            &ArgBuffer::from(arg_buffer),
+            &mut new_address,
+        );
+        ManagedAddress::new_from_bytes(&new_address)
+    }
+
+    /// Same as `upgrade_from_source_contract`, but through the legacy VM hook,
+    /// which receives its inputs as contract memory instead of managed handles.
+    pub fn upgrade_from_source_contract_legacy(
+        &self,
+        sc_address: &ManagedAddress<A>,
+        gas: u64,
+        egld_value: &BigUint<A>,
+        source_contract_address: &ManagedAddress<A>,
+        code_metadata: CodeMetadata,
+        arg_buffer: &ManagedArgBuffer<A>,
+    ) {
+        A::send_api_impl().upgrade_from_source_contract_legacy(
+            &sc_address.to_byte_array(),
+            gas,
+            &egld_value_to_legacy_bytes(egld_value),
+            &source_contract_address.to_byte_array(),
+            &code_metadata.to_byte_array(),
+            &ArgBuffer::from(arg_buffer),
+        )
+    }
+
     /// Upgrades a child contract of the currently executing contract.
     /// The upgrade is synchronous, and the current transaction will fail if the upgrade fails.
     /// The child contract's new init function will be called with the provided arguments
@@ -305,6 +348,23 @@ where
         )
     }
 
+    /// Deletes a child contract of the currently executing contract.
+    /// The deletion is synchronous, and the current transaction will fail if it fails.
+    /// The arguments are passed on to the `DeleteContract` builtin function,
+    /// which expects the beneficiary of the remaining EGLD and ESDT balances.
+    pub fn delete_contract(
+        &self,
+        sc_address: &ManagedAddress<A>,
+        gas: u64,
+        arg_buffer: &ManagedArgBuffer<A>,
+    ) {
+        A::send_api_impl().delete_contract(
+            sc_address.get_handle().get_raw_handle(),
+            gas,
+            arg_buffer.get_handle().get_raw_handle(),
+        )
+    }
+
     /// Same shard, in-line execution of another contract.
     pub fn execute_on_dest_context_raw(
         &self,
@@ -396,3 +456,11 @@ where
         A::send_api_impl().clean_return_data()
     }
 }
+
+/// The legacy VM hooks read EGLD values as 32 bytes, big endian.
+fn egld_value_to_legacy_bytes<A: CallTypeApi>(egld_value: &BigUint<A>) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    let bytes = egld_value.to_bytes_be();
+    result[32 - bytes.len()..].copy_from_slice(bytes.as_slice());
+    result
+}
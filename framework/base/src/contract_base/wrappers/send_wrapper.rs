@@ -394,6 +394,29 @@ where
             .change_owner_address(new_owner)
     }
 
+    /// Creates a call to the `DeleteContract` builtin function.
+    ///
+    /// The remaining EGLD and ESDT balances go to the beneficiary.
+    #[allow(clippy::type_complexity)]
+    pub fn delete_contract(
+        &self,
+        child_sc_address: ManagedAddress<A>,
+        beneficiary: &ManagedAddress<A>,
+    ) -> Tx<
+        TxScEnv<A>,
+        (),
+        ManagedAddress<A>,
+        NotPayable,
+        (),
+        FunctionCall<A>,
+        OriginalResultMarker<()>,
+    > {
+        Tx::new_tx_from_sc()
+            .to(child_sc_address)
+            .typed(system_proxy::UserBuiltinProxy)
+            .delete_contract(beneficiary)
+    }
+
     /// Allows synchronously calling a local function by name. Execution is resumed afterwards.
     /// You should never have to call this function directly.
     /// Use the other specific methods instead.
@@ -41,5 +41,10 @@ pub mod derive_imports;
 
 /// Conveniently groups all imports required for generated proxies.
 pub mod proxy_imports {
-    pub use super::{derive_imports::*, imports::*};
+    pub use super::{
+        codec::EncodeError,
+        derive_imports::*,
+        imports::*,
+        log_util::{EventLogInput, EventLogOutput, TypedEvent},
+    };
 }
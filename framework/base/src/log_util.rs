@@ -1,3 +1,7 @@
+mod typed_event;
+
+pub use typed_event::*;
+
 use unwrap_infallible::UnwrapInfallible;
 
 use crate::codec::{TopEncode, TopEncodeMulti};
@@ -0,0 +1,63 @@
+use alloc::vec::Vec;
+
+use crate::codec::{
+    DecodeError, EncodeError, TopDecode, TopDecodeMulti, TopEncode, TopEncodeMulti,
+};
+
+/// Implemented by the event structs generated in contract proxies.
+///
+/// Allows decoding event logs into typed values outside of contracts, in tests and interactors,
+/// as well as encoding them back, to check against actual logs.
+pub trait TypedEvent: Sized {
+    /// The event identifier, always found in the first log topic.
+    const IDENTIFIER: &'static str;
+
+    fn decode_event(log: &mut EventLogInput) -> Result<Self, DecodeError>;
+
+    fn encode_event(&self, log: &mut EventLogOutput) -> Result<(), EncodeError>;
+}
+
+/// The raw contents of an event log, with the identifier topic already removed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventLogInput {
+    pub topics: Vec<Vec<u8>>,
+    pub data: Vec<u8>,
+}
+
+impl EventLogInput {
+    pub fn new(topics: Vec<Vec<u8>>, data: Vec<u8>) -> Self {
+        EventLogInput { topics, data }
+    }
+
+    /// Decodes the next indexed argument. Multi-values can span several topics.
+    pub fn next_topic<T: TopDecodeMulti>(&mut self) -> Result<T, DecodeError> {
+        T::multi_decode(&mut self.topics)
+    }
+
+    /// Decodes the non-indexed argument.
+    pub fn data<T: TopDecode>(&self) -> Result<T, DecodeError> {
+        T::top_decode(self.data.as_slice())
+    }
+}
+
+/// Accumulates the raw contents of an event log, without the identifier topic.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventLogOutput {
+    pub topics: Vec<Vec<u8>>,
+    pub data: Vec<u8>,
+}
+
+impl EventLogOutput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push_topic<T: TopEncodeMulti>(&mut self, topic: &T) -> Result<(), EncodeError> {
+        topic.multi_encode(&mut self.topics)
+    }
+
+    pub fn set_data<T: TopEncode>(&mut self, data: &T) -> Result<(), EncodeError> {
+        self.data.clear();
+        data.top_encode(&mut self.data)
+    }
+}
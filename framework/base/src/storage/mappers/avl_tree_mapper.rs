@@ -0,0 +1,805 @@
+use core::{
+    cmp::Ordering,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds, RangeFull},
+};
+
+use super::{
+    set_mapper::{CurrentStorage, StorageAddress},
+    StorageClearable, StorageMapper, StorageMapperFromAddress,
+};
+use crate::{
+    abi::{TypeAbi, TypeAbiFrom, TypeDescriptionContainer, TypeName},
+    api::StorageMapperApi,
+    codec::{
+        self,
+        derive::{TopDecode, TopDecodeOrDefault, TopEncode, TopEncodeOrDefault},
+        multi_encode_iter_or_handle_err, DecodeDefault, EncodeDefault, EncodeErrorHandler,
+        NestedDecode, NestedEncode, TopEncode, TopEncodeMulti, TopEncodeMultiOutput,
+    },
+    storage::{storage_set, StorageKey},
+    types::{ManagedAddress, ManagedType, ManagedVec, MultiValueEncoded},
+};
+
+const NULL_ENTRY: u32 = 0;
+const INFO_IDENTIFIER: &[u8] = b".info";
+const NODE_IDENTIFIER: &[u8] = b".node";
+
+#[derive(TopEncodeOrDefault, TopDecodeOrDefault, PartialEq, Eq, Clone, Copy)]
+pub struct AvlTreeMapperInfo {
+    pub len: u32,
+    pub root: u32,
+    pub last_id: u32,
+}
+
+impl EncodeDefault for AvlTreeMapperInfo {
+    fn is_default(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl DecodeDefault for AvlTreeMapperInfo {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            root: NULL_ENTRY,
+            last_id: 0,
+        }
+    }
+}
+
+impl AvlTreeMapperInfo {
+    pub fn generate_new_node_id(&mut self) -> u32 {
+        self.last_id += 1;
+        self.last_id
+    }
+}
+
+/// A tree node. The value is stored in the same entry as the links,
+/// so each step of a search costs a single storage read.
+#[derive(TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct AvlTreeNode<T: NestedEncode + NestedDecode> {
+    pub left: u32,
+    pub right: u32,
+    /// Height of the subtree rooted in this node, a leaf has height 1.
+    pub height: u8,
+    /// Number of nodes in the subtree rooted in this node, used for `rank` and `select`.
+    pub size: u32,
+    pub value: T,
+}
+
+impl<T: NestedEncode + NestedDecode> AvlTreeNode<T> {
+    fn new_leaf(value: T) -> Self {
+        AvlTreeNode {
+            left: NULL_ENTRY,
+            right: NULL_ENTRY,
+            height: 1,
+            size: 1,
+            value,
+        }
+    }
+
+    fn subtree_info(&self) -> SubtreeInfo {
+        SubtreeInfo {
+            height: self.height,
+            size: self.size,
+        }
+    }
+
+    fn update_subtree_info(&mut self, left: SubtreeInfo, right: SubtreeInfo) {
+        let subtree_info = SubtreeInfo::with_children(left, right);
+        self.height = subtree_info.height;
+        self.size = subtree_info.size;
+    }
+}
+
+/// Height and size of a subtree. Both are zero for the empty subtree.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+struct SubtreeInfo {
+    height: u8,
+    size: u32,
+}
+
+impl SubtreeInfo {
+    fn with_children(left: SubtreeInfo, right: SubtreeInfo) -> Self {
+        SubtreeInfo {
+            height: core::cmp::max(left.height, right.height) + 1,
+            size: left.size + right.size + 1,
+        }
+    }
+
+    fn balance_factor(left: SubtreeInfo, right: SubtreeInfo) -> i16 {
+        left.height as i16 - right.height as i16
+    }
+}
+
+/// An ordered set, organized as an AVL tree.
+///
+/// Unlike the `OrderedBinaryTreeMapper`, the tree is kept balanced on every insert and remove,
+/// so all operations, including `rank` and `select`, take *O*(log n) storage reads and writes,
+/// regardless of the order in which the elements are inserted.
+///
+/// Each node also keeps the size of its subtree, which allows accessing elements by their position in the ordering.
+pub struct AvlTreeMapper<SA, T, A = CurrentStorage>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + Ord + 'static,
+{
+    _phantom_api: PhantomData<SA>,
+    address: A,
+    base_key: StorageKey<SA>,
+    _phantom_item: PhantomData<T>,
+}
+
+impl<SA, T> StorageMapper<SA> for AvlTreeMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Ord,
+{
+    fn new(base_key: StorageKey<SA>) -> Self {
+        AvlTreeMapper {
+            _phantom_api: PhantomData,
+            address: CurrentStorage,
+            base_key,
+            _phantom_item: PhantomData,
+        }
+    }
+}
+
+impl<SA, T> StorageMapperFromAddress<SA> for AvlTreeMapper<SA, T, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Ord,
+{
+    fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        AvlTreeMapper {
+            _phantom_api: PhantomData,
+            address,
+            base_key,
+            _phantom_item: PhantomData,
+        }
+    }
+}
+
+impl<SA, T> StorageClearable for AvlTreeMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Ord,
+{
+    fn clear(&mut self) {
+        let info = self.get_info();
+        self.clear_subtree(info.root);
+        self.set_info(AvlTreeMapperInfo::default());
+    }
+}
+
+impl<SA, T> AvlTreeMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Ord,
+{
+    fn set_info(&mut self, value: AvlTreeMapperInfo) {
+        storage_set(self.build_name_key(INFO_IDENTIFIER).as_ref(), &value);
+    }
+
+    fn set_node(&mut self, node_id: u32, node: &AvlTreeNode<T>) {
+        storage_set(
+            self.build_node_id_named_key(NODE_IDENTIFIER, node_id)
+                .as_ref(),
+            node,
+        );
+    }
+
+    fn clear_node(&mut self, node_id: u32) {
+        storage_set(
+            self.build_node_id_named_key(NODE_IDENTIFIER, node_id)
+                .as_ref(),
+            &codec::Empty,
+        );
+    }
+
+    fn clear_subtree(&mut self, node_id: u32) {
+        if node_id == NULL_ENTRY {
+            return;
+        }
+        let node = self.get_node(node_id);
+        self.clear_subtree(node.left);
+        self.clear_subtree(node.right);
+        self.clear_node(node_id);
+    }
+
+    /// Adds a value to the tree.
+    ///
+    /// Returns `false` if the value was already present, in which case nothing changes.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn insert(&mut self, value: T) -> bool {
+        let mut info = self.get_info();
+        match self.insert_into_subtree(info.root, value, &mut info) {
+            Some(new_root) => {
+                info.root = new_root;
+                info.len += 1;
+                self.set_info(info);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Returns the new root of the subtree, or `None` if the value is already present.
+    fn insert_into_subtree(
+        &mut self,
+        node_id: u32,
+        value: T,
+        info: &mut AvlTreeMapperInfo,
+    ) -> Option<u32> {
+        if node_id == NULL_ENTRY {
+            let new_node_id = info.generate_new_node_id();
+            self.set_node(new_node_id, &AvlTreeNode::new_leaf(value));
+            return Some(new_node_id);
+        }
+
+        let mut node = self.get_node(node_id);
+        match value.cmp(&node.value) {
+            Ordering::Equal => return None,
+            Ordering::Less => node.left = self.insert_into_subtree(node.left, value, info)?,
+            Ordering::Greater => node.right = self.insert_into_subtree(node.right, value, info)?,
+        }
+        Some(self.rebalance(node_id, node))
+    }
+
+    /// Removes a value from the tree.
+    ///
+    /// Returns `false` if the value was not present.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn remove(&mut self, value: &T) -> bool {
+        let mut info = self.get_info();
+        match self.remove_from_subtree(info.root, value) {
+            Some((new_root, _)) => {
+                info.root = new_root;
+                info.len -= 1;
+                self.set_info(info);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Removes and returns the smallest value, or `None` if the tree is empty.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn pop_min(&mut self) -> Option<T> {
+        self.pop_extreme(Side::Left)
+    }
+
+    /// Removes and returns the largest value, or `None` if the tree is empty.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn pop_max(&mut self) -> Option<T> {
+        self.pop_extreme(Side::Right)
+    }
+
+    fn pop_extreme(&mut self, side: Side) -> Option<T> {
+        let mut info = self.get_info();
+        if info.root == NULL_ENTRY {
+            return None;
+        }
+
+        let (new_root, detached_id, detached_node) = self.detach_extreme(info.root, side);
+        self.clear_node(detached_id);
+        info.root = new_root;
+        info.len -= 1;
+        self.set_info(info);
+        Some(detached_node.value)
+    }
+
+    /// Returns the new root of the subtree and the removed value, or `None` if the value is missing.
+    fn remove_from_subtree(&mut self, node_id: u32, value: &T) -> Option<(u32, T)> {
+        if node_id == NULL_ENTRY {
+            return None;
+        }
+
+        let mut node = self.get_node(node_id);
+        match value.cmp(&node.value) {
+            Ordering::Equal => Some(self.remove_node(node_id, node)),
+            Ordering::Less => {
+                let (new_left, removed) = self.remove_from_subtree(node.left, value)?;
+                node.left = new_left;
+                Some((self.rebalance(node_id, node), removed))
+            },
+            Ordering::Greater => {
+                let (new_right, removed) = self.remove_from_subtree(node.right, value)?;
+                node.right = new_right;
+                Some((self.rebalance(node_id, node), removed))
+            },
+        }
+    }
+
+    /// Removes the node from its subtree and returns the new root of the subtree.
+    ///
+    /// A node with two children is replaced by its successor, which keeps its own node id.
+    fn remove_node(&mut self, node_id: u32, node: AvlTreeNode<T>) -> (u32, T) {
+        self.clear_node(node_id);
+        if node.left == NULL_ENTRY {
+            return (node.right, node.value);
+        }
+        if node.right == NULL_ENTRY {
+            return (node.left, node.value);
+        }
+
+        let (new_right, successor_id, mut successor) = self.detach_extreme(node.right, Side::Left);
+        successor.left = node.left;
+        successor.right = new_right;
+        (self.rebalance(successor_id, successor), node.value)
+    }
+
+    /// Unlinks the leftmost (or rightmost) node of a subtree, without clearing it.
+    ///
+    /// Returns the new root of the subtree, together with the id and contents of the unlinked node.
+    fn detach_extreme(&mut self, node_id: u32, side: Side) -> (u32, u32, AvlTreeNode<T>) {
+        let mut node = self.get_node(node_id);
+        match side {
+            Side::Left => {
+                if node.left == NULL_ENTRY {
+                    return (node.right, node_id, node);
+                }
+                let (new_left, detached_id, detached) = self.detach_extreme(node.left, side);
+                node.left = new_left;
+                (self.rebalance(node_id, node), detached_id, detached)
+            },
+            Side::Right => {
+                if node.right == NULL_ENTRY {
+                    return (node.left, node_id, node);
+                }
+                let (new_right, detached_id, detached) = self.detach_extreme(node.right, side);
+                node.right = new_right;
+                (self.rebalance(node_id, node), detached_id, detached)
+            },
+        }
+    }
+
+    /// Restores the AVL property for a node whose children are balanced,
+    /// but whose subtree heights might differ by 2.
+    ///
+    /// Saves the changed nodes and returns the new root of the subtree.
+    fn rebalance(&mut self, node_id: u32, mut node: AvlTreeNode<T>) -> u32 {
+        let left = self.get_subtree_info(node.left);
+        let right = self.get_subtree_info(node.right);
+        let balance_factor = SubtreeInfo::balance_factor(left, right);
+
+        if balance_factor > 1 {
+            let left_node = self.get_node(node.left);
+            if self.get_subtree_info(left_node.left).height
+                < self.get_subtree_info(left_node.right).height
+            {
+                node.left = self.rotate_left(node.left, left_node);
+            }
+            return self.rotate_right(node_id, node);
+        }
+
+        if balance_factor < -1 {
+            let right_node = self.get_node(node.right);
+            if self.get_subtree_info(right_node.right).height
+                < self.get_subtree_info(right_node.left).height
+            {
+                node.right = self.rotate_right(node.right, right_node);
+            }
+            return self.rotate_left(node_id, node);
+        }
+
+        node.update_subtree_info(left, right);
+        self.set_node(node_id, &node);
+        node_id
+    }
+
+    /// The left child becomes the root of the subtree.
+    fn rotate_right(&mut self, node_id: u32, mut node: AvlTreeNode<T>) -> u32 {
+        let pivot_id = node.left;
+        let mut pivot = self.get_node(pivot_id);
+
+        node.left = pivot.right;
+        node.update_subtree_info(
+            self.get_subtree_info(node.left),
+            self.get_subtree_info(node.right),
+        );
+        self.set_node(node_id, &node);
+
+        pivot.right = node_id;
+        pivot.update_subtree_info(self.get_subtree_info(pivot.left), node.subtree_info());
+        self.set_node(pivot_id, &pivot);
+        pivot_id
+    }
+
+    /// The right child becomes the root of the subtree.
+    fn rotate_left(&mut self, node_id: u32, mut node: AvlTreeNode<T>) -> u32 {
+        let pivot_id = node.right;
+        let mut pivot = self.get_node(pivot_id);
+
+        node.right = pivot.left;
+        node.update_subtree_info(
+            self.get_subtree_info(node.left),
+            self.get_subtree_info(node.right),
+        );
+        self.set_node(node_id, &node);
+
+        pivot.left = node_id;
+        pivot.update_subtree_info(node.subtree_info(), self.get_subtree_info(pivot.right));
+        self.set_node(pivot_id, &pivot);
+        pivot_id
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Side {
+    Left,
+    Right,
+}
+
+impl<SA, A, T> AvlTreeMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + Ord + 'static,
+{
+    fn build_node_id_named_key(&self, name: &[u8], node_id: u32) -> StorageKey<SA> {
+        let mut named_key = self.base_key.clone();
+        named_key.append_bytes(name);
+        named_key.append_item(&node_id);
+        named_key
+    }
+
+    fn build_name_key(&self, name: &[u8]) -> StorageKey<SA> {
+        let mut name_key = self.base_key.clone();
+        name_key.append_bytes(name);
+        name_key
+    }
+
+    fn get_info(&self) -> AvlTreeMapperInfo {
+        self.address
+            .address_storage_get(self.build_name_key(INFO_IDENTIFIER).as_ref())
+    }
+
+    fn get_node(&self, node_id: u32) -> AvlTreeNode<T> {
+        self.address.address_storage_get(
+            self.build_node_id_named_key(NODE_IDENTIFIER, node_id)
+                .as_ref(),
+        )
+    }
+
+    fn get_subtree_info(&self, node_id: u32) -> SubtreeInfo {
+        if node_id == NULL_ENTRY {
+            return SubtreeInfo::default();
+        }
+        self.get_node(node_id).subtree_info()
+    }
+
+    /// Returns `true` if the tree is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn is_empty(&self) -> bool {
+        self.get_info().len == 0
+    }
+
+    /// Returns the number of elements in the tree.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn len(&self) -> usize {
+        self.get_info().len as usize
+    }
+
+    /// The number of levels in the tree, 0 if it is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn height(&self) -> usize {
+        self.get_subtree_info(self.get_info().root).height as usize
+    }
+
+    /// Returns `true` if the tree contains the value.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn contains(&self, value: &T) -> bool {
+        let mut node_id = self.get_info().root;
+        while node_id != NULL_ENTRY {
+            let node = self.get_node(node_id);
+            node_id = match value.cmp(&node.value) {
+                Ordering::Equal => return true,
+                Ordering::Less => node.left,
+                Ordering::Greater => node.right,
+            };
+        }
+        false
+    }
+
+    /// The smallest value, or `None` if the tree is empty.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn first(&self) -> Option<T> {
+        self.extreme_value(Side::Left)
+    }
+
+    /// The largest value, or `None` if the tree is empty.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn last(&self) -> Option<T> {
+        self.extreme_value(Side::Right)
+    }
+
+    fn extreme_value(&self, side: Side) -> Option<T> {
+        let mut node_id = self.get_info().root;
+        if node_id == NULL_ENTRY {
+            return None;
+        }
+        loop {
+            let node = self.get_node(node_id);
+            let next_id = match side {
+                Side::Left => node.left,
+                Side::Right => node.right,
+            };
+            if next_id == NULL_ENTRY {
+                return Some(node.value);
+            }
+            node_id = next_id;
+        }
+    }
+
+    /// The number of elements strictly smaller than the value.
+    /// The value itself does not need to be in the tree.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn rank(&self, value: &T) -> usize {
+        let mut rank = 0usize;
+        let mut node_id = self.get_info().root;
+        while node_id != NULL_ENTRY {
+            let node = self.get_node(node_id);
+            if node.value < *value {
+                rank += self.get_subtree_info(node.left).size as usize + 1;
+                node_id = node.right;
+            } else {
+                node_id = node.left;
+            }
+        }
+        rank
+    }
+
+    /// The element at the given position in the ordering, starting from 0,
+    /// or `None` if the index is out of range.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn select(&self, index: usize) -> Option<T> {
+        let info = self.get_info();
+        if index >= info.len as usize {
+            return None;
+        }
+
+        let mut index = index as u32;
+        let mut node_id = info.root;
+        loop {
+            let node = self.get_node(node_id);
+            let left_size = self.get_subtree_info(node.left).size;
+            match index.cmp(&left_size) {
+                Ordering::Less => node_id = node.left,
+                Ordering::Equal => return Some(node.value),
+                Ordering::Greater => {
+                    index -= left_size + 1;
+                    node_id = node.right;
+                },
+            }
+        }
+    }
+
+    /// Iterates over all elements, in ascending order.
+    pub fn iter(&self) -> Iter<SA, A, T, RangeFull> {
+        Iter::new(self, ..)
+    }
+
+    /// Iterates over the elements in the range, in ascending order.
+    ///
+    /// Finding the start of the range takes *O*(log n) time,
+    /// after which each element is yielded in amortized constant time.
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> Iter<SA, A, T, R> {
+        Iter::new(self, range)
+    }
+
+    /// Checks that the elements are ordered, that the tree is balanced,
+    /// and that the heights and sizes stored in the nodes, as well as the length, are correct.
+    /// Used for unit testing.
+    ///
+    /// This operation should compute in *O*(n) time.
+    pub fn check_internal_consistency(&self) -> bool {
+        let info = self.get_info();
+        match self.check_subtree(info.root, Bound::Unbounded, Bound::Unbounded) {
+            Some(subtree_info) => subtree_info.size == info.len,
+            None => false,
+        }
+    }
+
+    /// Returns the height and size of the subtree if it is valid,
+    /// with all values strictly between the given bounds.
+    fn check_subtree(
+        &self,
+        node_id: u32,
+        lower: Bound<&T>,
+        upper: Bound<&T>,
+    ) -> Option<SubtreeInfo> {
+        if node_id == NULL_ENTRY {
+            return Some(SubtreeInfo::default());
+        }
+        let node = self.get_node(node_id);
+        if let Bound::Excluded(lower) = lower {
+            if node.value <= *lower {
+                return None;
+            }
+        }
+        if let Bound::Excluded(upper) = upper {
+            if node.value >= *upper {
+                return None;
+            }
+        }
+
+        let left = self.check_subtree(node.left, lower, Bound::Excluded(&node.value))?;
+        let right = self.check_subtree(node.right, Bound::Excluded(&node.value), upper)?;
+        if SubtreeInfo::balance_factor(left, right).abs() > 1 {
+            return None;
+        }
+
+        let expected = SubtreeInfo::with_children(left, right);
+        if node.subtree_info() != expected {
+            return None;
+        }
+        Some(expected)
+    }
+}
+
+impl<'a, SA, A, T> IntoIterator for &'a AvlTreeMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + Ord + 'static,
+{
+    type Item = T;
+
+    type IntoIter = Iter<'a, SA, A, T, RangeFull>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An in-order iterator over the elements of an `AvlTreeMapper`.
+///
+/// This `struct` is created by [`AvlTreeMapper::iter()`] and [`AvlTreeMapper::range()`].
+/// The path to the current node is kept in a managed vec, so no heap allocation is needed.
+pub struct Iter<'a, SA, A, T, R>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + Ord + 'static,
+    R: RangeBounds<T>,
+{
+    tree: &'a AvlTreeMapper<SA, T, A>,
+    range: R,
+    /// Nodes whose value and right subtree still need to be visited, the next one on top.
+    stack: ManagedVec<SA, u32>,
+}
+
+impl<'a, SA, A, T, R> Iter<'a, SA, A, T, R>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + Ord + 'static,
+    R: RangeBounds<T>,
+{
+    fn new(tree: &'a AvlTreeMapper<SA, T, A>, range: R) -> Self {
+        let mut stack = ManagedVec::new();
+
+        // only the nodes on the path to the start of the range are relevant
+        let mut node_id = tree.get_info().root;
+        while node_id != NULL_ENTRY {
+            let node = tree.get_node(node_id);
+            let after_start = match range.start_bound() {
+                Bound::Included(start) => node.value >= *start,
+                Bound::Excluded(start) => node.value > *start,
+                Bound::Unbounded => true,
+            };
+            if after_start {
+                stack.push(node_id);
+                node_id = node.left;
+            } else {
+                node_id = node.right;
+            }
+        }
+
+        Iter { tree, range, stack }
+    }
+
+    fn push_left_branch(&mut self, mut node_id: u32) {
+        while node_id != NULL_ENTRY {
+            self.stack.push(node_id);
+            node_id = self.tree.get_node(node_id).left;
+        }
+    }
+}
+
+impl<SA, A, T, R> Iterator for Iter<'_, SA, A, T, R>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + Ord + 'static,
+    R: RangeBounds<T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let stack_len = self.stack.len();
+        if stack_len == 0 {
+            return None;
+        }
+
+        let node_id = self.stack.take(stack_len - 1);
+        let node = self.tree.get_node(node_id);
+        // everything on the stack is past the start of the range, so only the end is relevant
+        if !self.range.contains(&node.value) {
+            self.stack.clear();
+            return None;
+        }
+
+        self.push_left_branch(node.right);
+        Some(node.value)
+    }
+}
+
+/// Behaves like a MultiResultVec when an endpoint result.
+impl<SA, T> TopEncodeMulti for AvlTreeMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + NestedEncode + NestedDecode + Ord,
+{
+    fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        multi_encode_iter_or_handle_err(self.iter(), output, h)
+    }
+}
+
+impl<SA, T> TypeAbiFrom<AvlTreeMapper<SA, T, CurrentStorage>> for MultiValueEncoded<SA, T>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Ord,
+{
+}
+
+impl<SA, T> TypeAbiFrom<Self> for AvlTreeMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Ord,
+{
+}
+
+/// Behaves like a MultiResultVec when an endpoint result.
+impl<SA, T> TypeAbi for AvlTreeMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Ord + TypeAbi,
+{
+    type Unmanaged = Self;
+
+    fn type_name() -> TypeName {
+        crate::abi::type_name_variadic::<T>()
+    }
+
+    fn type_name_rust() -> TypeName {
+        crate::abi::type_name_multi_value_encoded::<T>()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+
+    fn is_variadic() -> bool {
+        true
+    }
+}
@@ -56,6 +56,9 @@ where
     }
 }
 
+/// A binary search tree, without any balancing.
+///
+/// Inserting elements in sorted order degrades it to a list, use the `AvlTreeMapper` when that can happen.
 pub struct OrderedBinaryTreeMapper<SA, T, A = CurrentStorage>
 where
     SA: StorageMapperApi,
@@ -0,0 +1,271 @@
+use core::marker::PhantomData;
+
+pub use super::vec_mapper::Iter;
+use super::{
+    set_mapper::{CurrentStorage, StorageAddress},
+    StorageClearable, StorageMapper, StorageMapperFromAddress, VecMapper,
+};
+use crate::{
+    abi::{TypeAbi, TypeAbiFrom, TypeDescriptionContainer, TypeName},
+    api::StorageMapperApi,
+    codec::{
+        multi_encode_iter_or_handle_err, EncodeErrorHandler, TopDecode, TopEncode, TopEncodeMulti,
+        TopEncodeMultiOutput,
+    },
+    storage::StorageKey,
+    types::{ManagedAddress, MultiValueEncoded},
+};
+
+/// A priority queue, organized as a binary min-heap.
+///
+/// The smallest item is always at the front: `peek` takes one storage read,
+/// while `push` and `pop` take *O*(log n) storage reads and writes.
+/// For items ordered by a deadline or timestamp, the front is the earliest one.
+///
+/// The items are kept in a `VecMapper` under the same base key, with the heap at indexes 1..=len,
+/// so the root of the heap is at index 1 and the children of index `i` are at `2 * i` and `2 * i + 1`.
+pub struct PriorityQueueMapper<SA, T, A = CurrentStorage>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: TopEncode + TopDecode + Ord + 'static,
+{
+    _phantom_api: PhantomData<SA>,
+    vec_mapper: VecMapper<SA, T, A>,
+}
+
+impl<SA, T> StorageMapper<SA> for PriorityQueueMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + Ord,
+{
+    fn new(base_key: StorageKey<SA>) -> Self {
+        PriorityQueueMapper {
+            _phantom_api: PhantomData,
+            vec_mapper: VecMapper::new(base_key),
+        }
+    }
+}
+
+impl<SA, T> StorageMapperFromAddress<SA> for PriorityQueueMapper<SA, T, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + Ord,
+{
+    fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        PriorityQueueMapper {
+            _phantom_api: PhantomData,
+            vec_mapper: VecMapper::new_from_address(address, base_key),
+        }
+    }
+}
+
+impl<SA, T> StorageClearable for PriorityQueueMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + Ord,
+{
+    fn clear(&mut self) {
+        self.vec_mapper.clear();
+    }
+}
+
+impl<SA, T, A> PriorityQueueMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: TopEncode + TopDecode + Ord,
+{
+    /// Number of items in the queue.
+    pub fn len(&self) -> usize {
+        self.vec_mapper.len()
+    }
+
+    /// True if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.vec_mapper.is_empty()
+    }
+
+    /// Provides a copy of the smallest item, or `None` if the queue is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn peek(&self) -> Option<T> {
+        if self.vec_mapper.is_empty() {
+            return None;
+        }
+        Some(self.vec_mapper.get_unchecked(1))
+    }
+
+    /// Iterates over the items in heap order, which is only sorted in so far as each item
+    /// comes after its parent. Use `pop` to get the items in sorted order.
+    pub fn iter(&self) -> Iter<SA, T, A> {
+        self.vec_mapper.iter()
+    }
+
+    /// Checks that no item is smaller than its parent.
+    /// Used for unit testing.
+    ///
+    /// This operation should compute in *O*(n) time.
+    pub fn check_internal_consistency(&self) -> bool {
+        let len = self.vec_mapper.len();
+        for index in 2..=len {
+            if self.vec_mapper.get_unchecked(index) < self.vec_mapper.get_unchecked(index / 2) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<SA, T> PriorityQueueMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + Ord,
+{
+    /// Adds an item to the queue.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn push(&mut self, item: T) {
+        // the new item only gets written once its final position is known,
+        // the larger parents on the way up are moved one level down,
+        // the first of them into the newly appended slot
+        let new_index = self.vec_mapper.len() + 1;
+        let mut index = new_index;
+        while index > 1 {
+            let parent_index = index / 2;
+            let parent = self.vec_mapper.get_unchecked(parent_index);
+            if parent <= item {
+                break;
+            }
+            self.write_at(index, new_index, &parent);
+            index = parent_index;
+        }
+        self.write_at(index, new_index, &item);
+    }
+
+    /// Removes the smallest item and returns it, or `None` if the queue is empty.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn pop(&mut self) -> Option<T> {
+        let last = self.vec_mapper.pop()?;
+        let len = self.vec_mapper.len();
+        if len == 0 {
+            return Some(last);
+        }
+
+        let smallest = self.vec_mapper.get_unchecked(1);
+
+        // the last item fills the gap left at the root, moving down to its place
+        let mut index = 1;
+        loop {
+            let left_index = index * 2;
+            if left_index > len {
+                break;
+            }
+
+            let mut child_index = left_index;
+            let mut child = self.vec_mapper.get_unchecked(left_index);
+            let right_index = left_index + 1;
+            if right_index <= len {
+                let right = self.vec_mapper.get_unchecked(right_index);
+                if right < child {
+                    child_index = right_index;
+                    child = right;
+                }
+            }
+
+            if last <= child {
+                break;
+            }
+            self.vec_mapper.set_unchecked(index, &child);
+            index = child_index;
+        }
+        self.vec_mapper.set_unchecked(index, &last);
+
+        Some(smallest)
+    }
+
+    /// Deletes all items from storage.
+    /// Can easily consume a lot of gas.
+    pub fn clear(&mut self) {
+        self.vec_mapper.clear();
+    }
+
+    /// Writing at the new index appends to the underlying `VecMapper`.
+    fn write_at(&mut self, index: usize, new_index: usize, item: &T) {
+        if index == new_index {
+            self.vec_mapper.push(item);
+        } else {
+            self.vec_mapper.set_unchecked(index, item);
+        }
+    }
+}
+
+impl<'a, SA, T, A> IntoIterator for &'a PriorityQueueMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: TopEncode + TopDecode + Ord + 'static,
+{
+    type Item = T;
+
+    type IntoIter = Iter<'a, SA, T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Behaves like a MultiResultVec when an endpoint result, items in heap order.
+impl<SA, T> TopEncodeMulti for PriorityQueueMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + Ord,
+{
+    fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        multi_encode_iter_or_handle_err(self.iter(), output, h)
+    }
+}
+
+impl<SA, T> TypeAbiFrom<PriorityQueueMapper<SA, T, CurrentStorage>> for MultiValueEncoded<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + Ord,
+{
+}
+
+impl<SA, T> TypeAbiFrom<Self> for PriorityQueueMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + Ord,
+{
+}
+
+/// Behaves like a MultiResultVec when an endpoint result.
+impl<SA, T> TypeAbi for PriorityQueueMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + Ord + TypeAbi,
+{
+    type Unmanaged = Self;
+
+    fn type_name() -> TypeName {
+        crate::abi::type_name_variadic::<T>()
+    }
+
+    fn type_name_rust() -> TypeName {
+        crate::abi::type_name_multi_value_encoded::<T>()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+
+    fn is_variadic() -> bool {
+        true
+    }
+}
@@ -219,8 +219,8 @@ where
         result
     }
 
-    /// Keeping `set_unchecked` private on purpose, so developers don't write out of index limits by accident.
-    fn set_unchecked(&self, index: usize, item: &T) {
+    /// Keeping `set_unchecked` crate-private on purpose, so developers don't write out of index limits by accident.
+    pub(crate) fn set_unchecked(&self, index: usize, item: &T) {
         storage_set(self.item_key(index).as_ref(), item);
     }
 
@@ -264,6 +264,18 @@ where
         last_item_as_option
     }
 
+    /// Removes the last item and returns it, or `None` if the list is empty.
+    pub(crate) fn pop(&mut self) -> Option<T> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        let last_item = self.get_unchecked(len);
+        self.clear_entry_unchecked(len);
+        self.save_count(len - 1);
+        Some(last_item)
+    }
+
     /// Deletes all contents form storage and sets count to 0.
     /// Can easily consume a lot of gas.
     pub fn clear(&mut self) {
@@ -6,12 +6,14 @@ use crate::types::{
 };
 
 use crate::chain_core::builtin_func_names::{
-    CHANGE_OWNER_BUILTIN_FUNC_NAME, CLAIM_DEVELOPER_REWARDS_FUNC_NAME, DELETE_USERNAME_FUNC_NAME,
-    ESDT_LOCAL_BURN_FUNC_NAME, ESDT_LOCAL_MINT_FUNC_NAME, ESDT_METADATA_RECREATE_FUNC_NAME,
-    ESDT_METADATA_UPDATE_FUNC_NAME, ESDT_MODIFY_CREATOR_FUNC_NAME, ESDT_MODIFY_ROYALTIES_FUNC_NAME,
+    CHANGE_OWNER_BUILTIN_FUNC_NAME, CLAIM_DEVELOPER_REWARDS_FUNC_NAME, DELETE_CONTRACT_FUNC_NAME,
+    DELETE_USERNAME_FUNC_NAME, ESDT_LOCAL_BURN_FUNC_NAME, ESDT_LOCAL_MINT_FUNC_NAME,
+    ESDT_METADATA_RECREATE_FUNC_NAME, ESDT_METADATA_UPDATE_FUNC_NAME,
+    ESDT_MODIFY_CREATOR_FUNC_NAME, ESDT_MODIFY_ROYALTIES_FUNC_NAME,
     ESDT_NFT_ADD_QUANTITY_FUNC_NAME, ESDT_NFT_ADD_URI_FUNC_NAME, ESDT_NFT_BURN_FUNC_NAME,
     ESDT_NFT_CREATE_FUNC_NAME, ESDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME, ESDT_SET_NEW_URIS_FUNC_NAME,
-    SET_USERNAME_FUNC_NAME,
+    GUARD_ACCOUNT_FUNC_NAME, SET_GUARDIAN_FUNC_NAME, SET_USERNAME_FUNC_NAME,
+    UNGUARD_ACCOUNT_FUNC_NAME,
 };
 
 /// Proxy describing the user builtin function signatures.
@@ -84,6 +86,56 @@ where
             .original_result()
     }
 
+    /// Deletes the contract, together with its code and storage. Can only be called by the owner.
+    ///
+    /// The remaining EGLD and ESDT balances go to the beneficiary.
+    pub fn delete_contract(
+        self,
+        beneficiary: &ManagedAddress<Env::Api>,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call(DELETE_CONTRACT_FUNC_NAME)
+            .argument(&beneficiary)
+            .original_result()
+    }
+
+    /// Registers a guardian for the sender account.
+    ///
+    /// The guardian only becomes active after the protocol activation delay,
+    /// the account then needs to be guarded explicitly via `guard_account`.
+    pub fn set_guardian<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        guardian_address: Arg0,
+        service_uid: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call(SET_GUARDIAN_FUNC_NAME)
+            .argument(&guardian_address)
+            .argument(&service_uid)
+            .original_result()
+    }
+
+    /// Activates the previously set guardian on the sender account.
+    pub fn guard_account(self) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call(GUARD_ACCOUNT_FUNC_NAME)
+            .original_result()
+    }
+
+    /// Deactivates the guardian. Needs to be sent as a guarded transaction.
+    pub fn unguard_account(self) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call(UNGUARD_ACCOUNT_FUNC_NAME)
+            .original_result()
+    }
+
     pub fn esdt_local_burn<
         Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
         Arg1: ProxyArg<BigUint<Env::Api>>,
@@ -15,9 +15,9 @@ use super::{
     ContractDeploy, DeployCall, Egld, EgldPayment, ExplicitGas, FromSource, FunctionCall,
     ManagedArgBuffer, OriginalResultMarker, RHList, RHListAppendNoRet, RHListAppendRet, RHListItem,
     TxCodeSource, TxCodeValue, TxData, TxDataFunctionCall, TxEgldValue, TxEnv,
-    TxEnvMockDeployAddress, TxEnvWithTxHash, TxFrom, TxFromSourceValue, TxFromSpecified, TxGas,
-    TxGasValue, TxPayment, TxPaymentEgldOnly, TxProxyTrait, TxResultHandler, TxScEnv, TxTo,
-    TxToSpecified, UpgradeCall, UNSPECIFIED_GAS_LIMIT,
+    TxEnvMockDeployAddress, TxEnvWithRelayer, TxEnvWithTxHash, TxFrom, TxFromSourceValue,
+    TxFromSpecified, TxGas, TxGasValue, TxPayment, TxPaymentEgldOnly, TxProxyTrait,
+    TxResultHandler, TxScEnv, TxTo, TxToSpecified, UpgradeCall, UNSPECIFIED_GAS_LIMIT,
 };
 
 /// Universal representation of a blockchain transaction.
@@ -927,6 +927,30 @@ where
     }
 }
 
+impl<Env, From, To, Payment, Gas, Data, RH> Tx<Env, From, To, Payment, Gas, Data, RH>
+where
+    Env: TxEnvWithRelayer,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    Data: TxData<Env>,
+    RH: TxResultHandler<Env>,
+{
+    /// Sends the transaction as a relayed transaction (v3).
+    ///
+    /// The relayer pays for the gas, while the sender remains the caller.
+    /// On chain, the gas limit also needs to cover an extra 50,000 units for the relaying.
+    pub fn relayer<R>(mut self, relayer: R) -> Self
+    where
+        R: TxFromSpecified<Env>,
+    {
+        let relayer_address = relayer.resolve_address(&self.env).to_address();
+        self.env.set_relayer(relayer_address);
+        self
+    }
+}
+
 impl<Api, To, Payment, OriginalResult>
     From<
         Tx<
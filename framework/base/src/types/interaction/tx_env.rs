@@ -1,6 +1,9 @@
 use crate::{
     api::CallTypeApi,
-    types::{heap::H256, ManagedAddress, ManagedBuffer},
+    types::{
+        heap::{Address, H256},
+        ManagedAddress, ManagedBuffer,
+    },
 };
 
 use super::{AnnotatedValue, TxFromSpecified};
@@ -31,3 +34,10 @@ pub trait TxEnvWithTxHash: TxEnv {
     /// Retrieves current tx hash, while resetting it in self.
     fn take_tx_hash(&mut self) -> Option<H256>;
 }
+
+pub trait TxEnvWithRelayer: TxEnv {
+    fn set_relayer(&mut self, relayer: Address);
+
+    /// Retrieves current relayer, while resetting it in self.
+    fn take_relayer(&mut self) -> Option<Address>;
+}
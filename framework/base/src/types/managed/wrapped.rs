@@ -44,7 +44,8 @@ pub use managed_buffer_read_to_end::*;
 pub(crate) use managed_byte_array::ManagedBufferSizeContext;
 pub use managed_byte_array::ManagedByteArray;
 pub use managed_decimal::{
-    ConstDecimals, Decimals, ManagedDecimal, ManagedDecimalSigned, NumDecimals,
+    ConstDecimals, Decimals, ManagedDecimal, ManagedDecimalSigned, NumDecimals, RoundingMode,
+    MAX_EXP_ARGUMENT,
 };
 pub use managed_option::ManagedOption;
 pub use managed_ref::ManagedRef;
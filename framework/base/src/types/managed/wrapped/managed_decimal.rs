@@ -1,6 +1,7 @@
 mod decimals;
 mod managed_decimal_cmp;
 mod managed_decimal_cmp_signed;
+mod managed_decimal_fixed_point;
 mod managed_decimal_logarithm;
 mod managed_decimal_macros;
 mod managed_decimal_op_add;
@@ -11,9 +12,13 @@ mod managed_decimal_op_mul;
 mod managed_decimal_op_mul_signed;
 mod managed_decimal_op_sub;
 mod managed_decimal_op_sub_signed;
+mod managed_decimal_power;
+mod managed_decimal_rounding;
 mod managed_decimal_signed;
 
 pub use decimals::{ConstDecimals, Decimals, NumDecimals};
+pub use managed_decimal_fixed_point::MAX_EXP_ARGUMENT;
+pub use managed_decimal_rounding::RoundingMode;
 pub use managed_decimal_signed::ManagedDecimalSigned;
 
 use crate::{
@@ -0,0 +1,438 @@
+//! Fixed-point algorithms on raw `BigUint`/`BigInt` values, shared by the decimal types.
+//!
+//! All loops are bounded by the number of decimals involved and by the magnitude of the arguments,
+//! so the gas cost is deterministic for a given input.
+
+use core::cmp::Ordering;
+
+use crate::{
+    api::ManagedTypeApi,
+    contract_base::ErrorHelper,
+    types::{BigInt, BigUint, Sign},
+};
+
+use super::{NumDecimals, RoundingMode};
+
+/// Extra decimals kept in intermediate results, to absorb truncation errors.
+const GUARD_DECIMALS: NumDecimals = 10;
+
+/// Integer exponents up to this value are computed by repeated multiplication, which is exact.
+const MAX_EXACT_INTEGER_EXPONENT: u64 = 64;
+
+/// Largest integer part accepted as argument of `exp`, and as `exponent * ln(base)` in `pow`.
+///
+/// `e^1000` already has 435 integer digits. Larger results are rejected, to keep the gas cost bounded.
+/// Below `-MAX_EXP_ARGUMENT`, the result is rounded down to 0, as long as it has at most 400 decimals.
+pub const MAX_EXP_ARGUMENT: u64 = 1000;
+
+/// `e^(-MAX_EXP_ARGUMENT) < 10^(-400)`.
+const MAX_EXP_UNDERFLOW_DECIMALS: NumDecimals = 400;
+
+/// 10^num_decimals.
+///
+/// The cached scaling factors only cover 64 decimals, intermediate results can need more.
+pub(super) fn pow10<M: ManagedTypeApi>(num_decimals: NumDecimals) -> BigUint<M> {
+    BigUint::from(10u64).pow(num_decimals as u32)
+}
+
+/// Upper bound for the number of decimal digits of a number.
+fn decimal_digits_upper_bound<M: ManagedTypeApi>(value: &BigUint<M>) -> NumDecimals {
+    match value.log2_floor() {
+        // log10(2) < 0.31
+        Some(log2_floor) => (log2_floor as usize + 1) * 31 / 100 + 1,
+        None => 0,
+    }
+}
+
+/// Divides, rounding the result according to the given mode.
+pub(super) fn div_round<M: ManagedTypeApi>(
+    numerator: &BigUint<M>,
+    denominator: &BigUint<M>,
+    rounding: RoundingMode,
+) -> BigUint<M> {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if remainder == 0u64 {
+        return quotient;
+    }
+
+    let round_up = match rounding {
+        RoundingMode::Floor => false,
+        RoundingMode::Ceil => true,
+        RoundingMode::HalfEven => match (remainder << 1).cmp(denominator) {
+            Ordering::Less => false,
+            Ordering::Equal => &quotient % 2u64 == 1u64,
+            Ordering::Greater => true,
+        },
+    };
+
+    if round_up {
+        quotient + 1u64
+    } else {
+        quotient
+    }
+}
+
+/// Divides a signed numerator, rounding the result according to the given mode.
+///
+/// `Floor` and `Ceil` round towards negative and positive infinity, respectively.
+pub(super) fn div_round_signed<M: ManagedTypeApi>(
+    numerator: &BigInt<M>,
+    denominator: &BigUint<M>,
+    rounding: RoundingMode,
+) -> BigInt<M> {
+    let (sign, magnitude) = numerator.clone().to_parts();
+    let magnitude_rounding = if sign.is_minus() {
+        rounding.mirrored()
+    } else {
+        rounding
+    };
+    BigInt::from_biguint(sign, div_round(&magnitude, denominator, magnitude_rounding))
+}
+
+/// Changes the number of decimals of a raw value, rounding when decimals are dropped.
+pub(super) fn rescale_round<M: ManagedTypeApi>(
+    data: &BigUint<M>,
+    from_decimals: NumDecimals,
+    to_decimals: NumDecimals,
+    rounding: RoundingMode,
+) -> BigUint<M> {
+    match from_decimals.cmp(&to_decimals) {
+        Ordering::Less => data * &pow10(to_decimals - from_decimals),
+        Ordering::Equal => data.clone(),
+        Ordering::Greater => div_round(data, &pow10(from_decimals - to_decimals), rounding),
+    }
+}
+
+/// Changes the number of decimals of a signed raw value, rounding when decimals are dropped.
+pub(super) fn rescale_round_signed<M: ManagedTypeApi>(
+    data: &BigInt<M>,
+    from_decimals: NumDecimals,
+    to_decimals: NumDecimals,
+    rounding: RoundingMode,
+) -> BigInt<M> {
+    match from_decimals.cmp(&to_decimals) {
+        Ordering::Less => data * &pow10(to_decimals - from_decimals),
+        Ordering::Equal => data.clone(),
+        Ordering::Greater => div_round_signed(data, &pow10(from_decimals - to_decimals), rounding),
+    }
+}
+
+/// Divides `numerator / denominator`, both given with their number of decimals,
+/// producing a raw result with `result_decimals` decimals.
+pub(super) fn div_with_decimals<M: ManagedTypeApi>(
+    numerator: &BigUint<M>,
+    numerator_decimals: NumDecimals,
+    denominator: &BigUint<M>,
+    denominator_decimals: NumDecimals,
+    result_decimals: NumDecimals,
+    rounding: RoundingMode,
+) -> BigUint<M> {
+    let scaled_decimals = result_decimals + denominator_decimals;
+    if scaled_decimals >= numerator_decimals {
+        let scaled_numerator = numerator * &pow10(scaled_decimals - numerator_decimals);
+        div_round(&scaled_numerator, denominator, rounding)
+    } else {
+        let scaled_denominator = denominator * &pow10(numerator_decimals - scaled_decimals);
+        div_round(numerator, &scaled_denominator, rounding)
+    }
+}
+
+/// Signed version of `div_with_decimals`.
+pub(super) fn div_with_decimals_signed<M: ManagedTypeApi>(
+    numerator: &BigInt<M>,
+    numerator_decimals: NumDecimals,
+    denominator: &BigInt<M>,
+    denominator_decimals: NumDecimals,
+    result_decimals: NumDecimals,
+    rounding: RoundingMode,
+) -> BigInt<M> {
+    let (denominator_sign, denominator_magnitude) = denominator.clone().to_parts();
+    let numerator = if denominator_sign.is_minus() {
+        -numerator.clone()
+    } else {
+        numerator.clone()
+    };
+
+    let scaled_decimals = result_decimals + denominator_decimals;
+    if scaled_decimals >= numerator_decimals {
+        let scaled_numerator = &numerator * &pow10(scaled_decimals - numerator_decimals);
+        div_round_signed(&scaled_numerator, &denominator_magnitude, rounding)
+    } else {
+        let scaled_denominator =
+            denominator_magnitude * &pow10(numerator_decimals - scaled_decimals);
+        div_round_signed(&numerator, &scaled_denominator, rounding)
+    }
+}
+
+/// Square root, rounded down, with the same number of decimals as the input.
+pub(super) fn sqrt_raw<M: ManagedTypeApi>(
+    data: &BigUint<M>,
+    num_decimals: NumDecimals,
+) -> BigUint<M> {
+    (data * &pow10(num_decimals)).sqrt()
+}
+
+/// `2 * atanh(z) = 2 * (z + z^3/3 + z^5/5 + ...)`, for `0 <= z <= 1/3`.
+///
+/// Each term is at least 9 times smaller than the previous one,
+/// so the number of iterations is bounded by the number of decimals.
+fn double_atanh<M: ManagedTypeApi>(z: &BigUint<M>, one: &BigUint<M>) -> BigUint<M> {
+    let z_squared = z * z / one;
+    let mut power = z.clone();
+    let mut sum = z.clone();
+    let mut n = 3u64;
+    loop {
+        power = power * &z_squared / one;
+        let term = &power / n;
+        if term == 0u64 {
+            break;
+        }
+        sum += term;
+        n += 2;
+    }
+    sum << 1
+}
+
+/// Computes `numerator * 2^(-exponent) / denominator`, rounded down.
+fn shifted_div<M: ManagedTypeApi>(
+    numerator: &BigUint<M>,
+    denominator: &BigUint<M>,
+    exponent: i64,
+) -> BigUint<M> {
+    if exponent >= 0 {
+        numerator / &(denominator << exponent as usize)
+    } else {
+        (numerator << exponent.unsigned_abs() as usize) / denominator
+    }
+}
+
+/// Natural logarithm of a strictly positive value with `num_decimals` decimals.
+///
+/// The result has `result_decimals` decimals, rounded down.
+pub(super) fn ln_raw<M: ManagedTypeApi>(
+    data: &BigUint<M>,
+    num_decimals: NumDecimals,
+    result_decimals: NumDecimals,
+) -> BigInt<M> {
+    let working_decimals = result_decimals + GUARD_DECIMALS;
+    let one = pow10::<M>(working_decimals);
+    let denominator = pow10::<M>(num_decimals);
+
+    // value = mantissa * 2^exponent, with mantissa in [1, 2)
+    let data_log2 = data
+        .log2_floor()
+        .unwrap_or_else(|| ErrorHelper::<M>::signal_error_with_message("ln of zero"));
+    let denominator_log2 = denominator.log2_floor().unwrap_or_default();
+    let mut exponent = data_log2 as i64 - denominator_log2 as i64;
+    let scaled_data = data * &one;
+    let mut mantissa = shifted_div(&scaled_data, &denominator, exponent);
+    if mantissa < one {
+        exponent -= 1;
+        mantissa = shifted_div(&scaled_data, &denominator, exponent);
+    }
+
+    // ln(m) = 2 * atanh((m - 1) / (m + 1))
+    let z = (&mantissa - &one) * &one / (&mantissa + &one);
+    let ln_mantissa = double_atanh(&z, &one);
+    let ln_2 = double_atanh(&(&one / 3u64), &one);
+
+    let exponent_sign = if exponent < 0 {
+        Sign::Minus
+    } else {
+        Sign::Plus
+    };
+    let exponent_part = BigInt::from_biguint(exponent_sign, ln_2 * exponent.unsigned_abs());
+    let result = BigInt::from(ln_mantissa) + exponent_part;
+    rescale_round_signed(
+        &result,
+        working_decimals,
+        result_decimals,
+        RoundingMode::Floor,
+    )
+}
+
+/// `e^x`, for a signed `x` with `num_decimals` decimals.
+///
+/// The result has `result_decimals` decimals, rounded down.
+pub(super) fn exp_raw<M: ManagedTypeApi>(
+    x: &BigInt<M>,
+    num_decimals: NumDecimals,
+    result_decimals: NumDecimals,
+) -> BigUint<M> {
+    let (sign, magnitude) = x.clone().to_parts();
+    let integer_part = &magnitude / &pow10(num_decimals);
+    let Some(integer_part_u64) = integer_part.to_u64().filter(|n| *n <= MAX_EXP_ARGUMENT) else {
+        if sign.is_minus() && result_decimals <= MAX_EXP_UNDERFLOW_DECIMALS {
+            return BigUint::zero();
+        }
+        ErrorHelper::<M>::signal_error_with_message("exp argument too large")
+    };
+
+    // range reduction: e^x = (e^(x / 2^halvings))^(2^halvings), with x / 2^halvings < 1/16
+    let halvings = match integer_part.log2_floor() {
+        Some(log2_floor) => log2_floor as usize + 5,
+        None => 4,
+    };
+
+    // each squaring doubles the relative error, large results also need extra digits
+    let mut working_decimals = result_decimals + GUARD_DECIMALS + halvings * 31 / 100 + 1;
+    if !sign.is_minus() {
+        // log10(e) < 0.5
+        working_decimals += integer_part_u64 as usize / 2 + 1;
+    }
+    let one = pow10::<M>(working_decimals);
+
+    let reduced = rescale_round(
+        &magnitude,
+        num_decimals,
+        working_decimals,
+        RoundingMode::Floor,
+    ) >> halvings;
+
+    // Taylor series, converges quickly, since the argument is small
+    let mut term = one.clone();
+    let mut sum = one.clone();
+    let mut n = 1u64;
+    loop {
+        term = term * &reduced / &(&one * n);
+        if term == 0u64 {
+            break;
+        }
+        sum += &term;
+        n += 1;
+    }
+
+    for _ in 0..halvings {
+        sum = &sum * &sum / &one;
+    }
+
+    if sign.is_minus() {
+        sum = &one * &one / &sum;
+    }
+
+    rescale_round(&sum, working_decimals, result_decimals, RoundingMode::Floor)
+}
+
+/// Checks whether a signed decimal is an integer, and returns its absolute value if it is small enough.
+pub(super) fn small_integer_magnitude<M: ManagedTypeApi>(
+    data: &BigInt<M>,
+    num_decimals: NumDecimals,
+) -> Option<u64> {
+    let scaling_factor = pow10::<M>(num_decimals);
+    let magnitude = data.magnitude();
+    if &magnitude % &scaling_factor != 0u64 {
+        return None;
+    }
+    (magnitude / &scaling_factor).to_u64()
+}
+
+/// `base^exponent`, for a base with `base_decimals` decimals and a signed exponent with `exponent_decimals` decimals.
+///
+/// The result has `result_decimals` decimals, rounded down.
+/// Returns `None` when raising 0 to a negative power.
+pub(super) fn pow_raw<M: ManagedTypeApi>(
+    base: &BigUint<M>,
+    base_decimals: NumDecimals,
+    exponent: &BigInt<M>,
+    exponent_decimals: NumDecimals,
+    result_decimals: NumDecimals,
+) -> Option<BigUint<M>> {
+    let exponent_sign = exponent.sign();
+    if exponent_sign == Sign::NoSign {
+        return Some(pow10(result_decimals));
+    }
+    if *base == 0u64 {
+        return match exponent_sign {
+            Sign::Minus => None,
+            _ => Some(BigUint::zero()),
+        };
+    }
+
+    if let Some(n) = small_integer_magnitude(exponent, exponent_decimals) {
+        if n <= MAX_EXACT_INTEGER_EXPONENT {
+            return Some(integer_pow_raw(
+                base,
+                base_decimals,
+                n as u32,
+                exponent_sign.is_minus(),
+                result_decimals,
+            ));
+        }
+    }
+
+    // base^exponent = e^(exponent * ln(base))
+    // the error of ln(base) gets multiplied by the exponent,
+    // the error of the product gets multiplied by the result
+    let exponent_integer_part = exponent.magnitude() / &pow10(exponent_decimals);
+    let mut working_decimals =
+        result_decimals + GUARD_DECIMALS + decimal_digits_upper_bound(&exponent_integer_part);
+    let mut product = ln_times(
+        base,
+        base_decimals,
+        exponent,
+        exponent_decimals,
+        working_decimals,
+    );
+    if product.sign() == Sign::Plus {
+        let product_integer_part = product.magnitude() / &pow10(working_decimals);
+        let product_integer_part_u64 = product_integer_part
+            .to_u64()
+            .filter(|n| *n <= MAX_EXP_ARGUMENT)
+            .unwrap_or_else(|| ErrorHelper::<M>::signal_error_with_message("pow result too large"));
+        // log10(e) < 0.5
+        working_decimals += product_integer_part_u64 as usize / 2 + 1;
+        product = ln_times(
+            base,
+            base_decimals,
+            exponent,
+            exponent_decimals,
+            working_decimals,
+        );
+    }
+
+    Some(exp_raw(&product, working_decimals, result_decimals))
+}
+
+/// `exponent * ln(base)`, with `result_decimals` decimals.
+fn ln_times<M: ManagedTypeApi>(
+    base: &BigUint<M>,
+    base_decimals: NumDecimals,
+    exponent: &BigInt<M>,
+    exponent_decimals: NumDecimals,
+    result_decimals: NumDecimals,
+) -> BigInt<M> {
+    let ln_base = ln_raw(base, base_decimals, result_decimals);
+    let product = ln_base * exponent.clone();
+    rescale_round_signed(
+        &product,
+        result_decimals + exponent_decimals,
+        result_decimals,
+        RoundingMode::Floor,
+    )
+}
+
+/// Exact integer power, rounded down only once, at the end.
+fn integer_pow_raw<M: ManagedTypeApi>(
+    base: &BigUint<M>,
+    base_decimals: NumDecimals,
+    n: u32,
+    negative: bool,
+    result_decimals: NumDecimals,
+) -> BigUint<M> {
+    let power = base.pow(n);
+    let power_decimals = base_decimals * n as usize;
+    if negative {
+        // 1 / base^n
+        div_with_decimals(
+            &BigUint::from(1u64),
+            0,
+            &power,
+            power_decimals,
+            result_decimals,
+            RoundingMode::Floor,
+        )
+    } else {
+        rescale_round(&power, power_decimals, result_decimals, RoundingMode::Floor)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    api::ManagedTypeApi,
+    types::{BigInt, Decimals, ManagedDecimal, ManagedDecimalSigned, Sign},
+};
+
+use super::managed_decimal_fixed_point::{exp_raw, pow_raw, small_integer_magnitude, sqrt_raw};
+
+impl<M: ManagedTypeApi, D: Decimals + Clone> ManagedDecimal<M, D> {
+    /// Square root, with the same number of decimals, rounded down.
+    pub fn sqrt(&self) -> Self {
+        let data = sqrt_raw(&self.data, self.decimals.num_decimals());
+        ManagedDecimal::from_raw_units(data, self.decimals.clone())
+    }
+
+    /// Exponential function, `e^self`, with the same number of decimals, rounded down.
+    ///
+    /// All decimals are accurate, except for a possible error of one unit in the last place.
+    /// Signals an error if the argument is above [`MAX_EXP_ARGUMENT`](crate::types::MAX_EXP_ARGUMENT).
+    pub fn exp(&self) -> Self {
+        let data = exp_raw(
+            &BigInt::from(self.data.clone()),
+            self.decimals.num_decimals(),
+            self.decimals.num_decimals(),
+        );
+        ManagedDecimal::from_raw_units(data, self.decimals.clone())
+    }
+
+    /// Raises the number to a decimal power, with the same number of decimals, rounded down.
+    ///
+    /// Small integer exponents are computed exactly, all others via `e^(exponent * ln(self))`,
+    /// with an error of at most one unit in the last place.
+    /// Signals an error if `exponent * ln(self)` is above [`MAX_EXP_ARGUMENT`](crate::types::MAX_EXP_ARGUMENT).
+    ///
+    /// Returns `None` when raising 0 to a negative power.
+    pub fn pow<DE: Decimals>(&self, exponent: &ManagedDecimalSigned<M, DE>) -> Option<Self> {
+        let data = pow_raw(
+            &self.data,
+            self.decimals.num_decimals(),
+            &exponent.data,
+            exponent.decimals.num_decimals(),
+            self.decimals.num_decimals(),
+        )?;
+        Some(ManagedDecimal::from_raw_units(data, self.decimals.clone()))
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals + Clone> ManagedDecimalSigned<M, D> {
+    /// Square root, with the same number of decimals, rounded down.
+    ///
+    /// Returns `None` for negative numbers.
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.sign().is_minus() {
+            return None;
+        }
+
+        let data = sqrt_raw(&self.data.magnitude(), self.decimals.num_decimals());
+        Some(ManagedDecimalSigned::from_raw_units(
+            data.into_big_int(),
+            self.decimals.clone(),
+        ))
+    }
+
+    /// Exponential function, `e^self`, with the same number of decimals, rounded down.
+    ///
+    /// The result is always positive, so it is returned as unsigned.
+    /// All decimals are accurate, except for a possible error of one unit in the last place.
+    /// Signals an error if the argument is above [`MAX_EXP_ARGUMENT`](crate::types::MAX_EXP_ARGUMENT).
+    pub fn exp(&self) -> ManagedDecimal<M, D> {
+        let data = exp_raw(
+            &self.data,
+            self.decimals.num_decimals(),
+            self.decimals.num_decimals(),
+        );
+        ManagedDecimal::from_raw_units(data, self.decimals.clone())
+    }
+
+    /// Raises the number to a decimal power, with the same number of decimals.
+    ///
+    /// The magnitude of the result is rounded down.
+    /// Small integer exponents are computed exactly, all others via `e^(exponent * ln(self))`,
+    /// with an error of at most one unit in the last place.
+    /// Signals an error if `exponent * ln(self)` is above [`MAX_EXP_ARGUMENT`](crate::types::MAX_EXP_ARGUMENT).
+    ///
+    /// Returns `None` when raising 0 to a negative power,
+    /// or when raising a negative number to a non-integer power.
+    pub fn pow<DE: Decimals>(&self, exponent: &ManagedDecimalSigned<M, DE>) -> Option<Self> {
+        let (sign, magnitude) = self.data.clone().to_parts();
+        let result_sign = if sign.is_minus() {
+            let n = small_integer_magnitude(&exponent.data, exponent.decimals.num_decimals())?;
+            if n % 2 == 1 {
+                Sign::Minus
+            } else {
+                Sign::Plus
+            }
+        } else {
+            Sign::Plus
+        };
+
+        let data = pow_raw(
+            &magnitude,
+            self.decimals.num_decimals(),
+            &exponent.data,
+            exponent.decimals.num_decimals(),
+            self.decimals.num_decimals(),
+        )?;
+        Some(ManagedDecimalSigned::from_raw_units(
+            BigInt::from_biguint(result_sign, data),
+            self.decimals.clone(),
+        ))
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{
+    api::ManagedTypeApi,
+    types::{Decimals, ManagedDecimal, ManagedDecimalSigned},
+};
+
+use super::managed_decimal_fixed_point::{
+    div_with_decimals, div_with_decimals_signed, rescale_round, rescale_round_signed,
+};
+
+/// Specifies how to round when decimals are dropped.
+///
+/// The regular operators and `rescale` always truncate.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoundingMode {
+    /// Round towards negative infinity.
+    Floor,
+
+    /// Round towards positive infinity.
+    Ceil,
+
+    /// Round to the nearest value, ties go to the even neighbour (banker's rounding).
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// The mode to apply to the magnitude of a negative number.
+    pub(super) fn mirrored(self) -> Self {
+        match self {
+            RoundingMode::Floor => RoundingMode::Ceil,
+            RoundingMode::Ceil => RoundingMode::Floor,
+            RoundingMode::HalfEven => RoundingMode::HalfEven,
+        }
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> ManagedDecimal<M, D> {
+    /// Same as `rescale`, but with explicit rounding when the number of decimals decreases.
+    pub fn rescale_with_rounding<T: Decimals>(
+        &self,
+        scale_to: T,
+        rounding: RoundingMode,
+    ) -> ManagedDecimal<M, T> {
+        let data = rescale_round(
+            &self.data,
+            self.decimals.num_decimals(),
+            scale_to.num_decimals(),
+            rounding,
+        );
+        ManagedDecimal::from_raw_units(data, scale_to)
+    }
+
+    /// Multiplies, then rounds the result to the given precision.
+    pub fn mul_with_rounding<D2: Decimals, T: Decimals>(
+        self,
+        other: ManagedDecimal<M, D2>,
+        precision: T,
+        rounding: RoundingMode,
+    ) -> ManagedDecimal<M, T> {
+        let data = rescale_round(
+            &(self.data * other.data),
+            self.decimals.num_decimals() + other.decimals.num_decimals(),
+            precision.num_decimals(),
+            rounding,
+        );
+        ManagedDecimal::from_raw_units(data, precision)
+    }
+
+    /// Divides, computing the result directly at the given precision, then rounding it.
+    pub fn div_with_rounding<D2: Decimals, T: Decimals>(
+        self,
+        other: ManagedDecimal<M, D2>,
+        precision: T,
+        rounding: RoundingMode,
+    ) -> ManagedDecimal<M, T> {
+        let data = div_with_decimals(
+            &self.data,
+            self.decimals.num_decimals(),
+            &other.data,
+            other.decimals.num_decimals(),
+            precision.num_decimals(),
+            rounding,
+        );
+        ManagedDecimal::from_raw_units(data, precision)
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> ManagedDecimalSigned<M, D> {
+    /// Same as `rescale`, but with explicit rounding when the number of decimals decreases.
+    pub fn rescale_with_rounding<T: Decimals>(
+        &self,
+        scale_to: T,
+        rounding: RoundingMode,
+    ) -> ManagedDecimalSigned<M, T> {
+        let data = rescale_round_signed(
+            &self.data,
+            self.decimals.num_decimals(),
+            scale_to.num_decimals(),
+            rounding,
+        );
+        ManagedDecimalSigned::from_raw_units(data, scale_to)
+    }
+
+    /// Multiplies, then rounds the result to the given precision.
+    pub fn mul_with_rounding<D2: Decimals, T: Decimals>(
+        self,
+        other: ManagedDecimalSigned<M, D2>,
+        precision: T,
+        rounding: RoundingMode,
+    ) -> ManagedDecimalSigned<M, T> {
+        let data = rescale_round_signed(
+            &(self.data * other.data),
+            self.decimals.num_decimals() + other.decimals.num_decimals(),
+            precision.num_decimals(),
+            rounding,
+        );
+        ManagedDecimalSigned::from_raw_units(data, precision)
+    }
+
+    /// Divides, computing the result directly at the given precision, then rounding it.
+    pub fn div_with_rounding<D2: Decimals, T: Decimals>(
+        self,
+        other: ManagedDecimalSigned<M, D2>,
+        precision: T,
+        rounding: RoundingMode,
+    ) -> ManagedDecimalSigned<M, T> {
+        let data = div_with_decimals_signed(
+            &self.data,
+            self.decimals.num_decimals(),
+            &other.data,
+            other.decimals.num_decimals(),
+            precision.num_decimals(),
+            rounding,
+        );
+        ManagedDecimalSigned::from_raw_units(data, precision)
+    }
+}
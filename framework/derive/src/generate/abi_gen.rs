@@ -1,9 +1,13 @@
 use super::util::*;
 use crate::model::{
-    AutoImpl, ContractTrait, EndpointMutabilityMetadata, EndpointTypeMetadata, Method, MethodImpl,
-    PublicRole,
+    AutoImpl, ContractTrait, EndpointMutabilityMetadata, EndpointTypeMetadata, Method,
+    MethodArgument, MethodImpl, PublicRole,
 };
 
+/// Mapper kind reported for `#[storage_get]` and `#[storage_set]`,
+/// same as `multiversx_sc::abi::STORAGE_SINGLE_VALUE_MAPPER`.
+const STORAGE_SINGLE_VALUE_MAPPER: &str = "SingleValueMapper";
+
 fn generate_endpoint_snippet(
     m: &Method,
     endpoint_name: &str,
@@ -167,6 +171,7 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
 
 fn generate_event_snippet(m: &Method, event_name: &str) -> proc_macro2::TokenStream {
     let event_docs = &m.docs;
+    let rust_method_name = m.name.to_string();
     let input_snippets: Vec<proc_macro2::TokenStream> = m
         .method_args
         .iter()
@@ -187,6 +192,7 @@ fn generate_event_snippet(m: &Method, event_name: &str) -> proc_macro2::TokenStr
         let mut event_abi = multiversx_sc::abi::EventAbi::new(
             &[ #(#event_docs),* ],
             #event_name,
+            #rust_method_name,
         );
         #(#input_snippets)*
     }
@@ -210,6 +216,124 @@ fn generate_event_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenSt
         .collect()
 }
 
+/// The type arguments of a storage mapper type, without the leading `Self::Api`.
+fn mapper_kind_and_value_types(ty: &syn::Type) -> Option<(String, Vec<syn::Type>)> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let last_segment = type_path.path.segments.last()?;
+    let mut value_types = Vec::new();
+    if let syn::PathArguments::AngleBracketed(args) = &last_segment.arguments {
+        for arg in &args.args {
+            if let syn::GenericArgument::Type(arg_type) = arg {
+                if quote! { #arg_type }.to_string() != "Self :: Api" {
+                    value_types.push(arg_type.clone());
+                }
+            }
+        }
+    }
+    Some((last_segment.ident.to_string(), value_types))
+}
+
+fn generate_storage_snippet(m: &Method, identifier: &str) -> Option<proc_macro2::TokenStream> {
+    if !m.generics.params.is_empty() {
+        return None;
+    }
+
+    let mut key_args: Vec<&MethodArgument> = m.method_args.iter().collect();
+    let (mapper_kind, value_types) = match &m.implementation {
+        MethodImpl::Generated(AutoImpl::StorageMapper { .. }) => {
+            let syn::ReturnType::Type(_, ty) = &m.return_type else {
+                return None;
+            };
+            mapper_kind_and_value_types(ty)?
+        },
+        MethodImpl::Generated(AutoImpl::StorageGetter { .. }) => {
+            let syn::ReturnType::Type(_, ty) = &m.return_type else {
+                return None;
+            };
+            (
+                STORAGE_SINGLE_VALUE_MAPPER.to_string(),
+                vec![(**ty).clone()],
+            )
+        },
+        MethodImpl::Generated(AutoImpl::StorageSetter { .. }) => {
+            let value_arg = key_args.pop()?;
+            (
+                STORAGE_SINGLE_VALUE_MAPPER.to_string(),
+                vec![value_arg.ty.clone()],
+            )
+        },
+        _ => return None,
+    };
+
+    let storage_docs = &m.docs;
+    let rust_method_name = m.name.to_string();
+    let view_tokens = match &m.public_role {
+        PublicRole::Endpoint(endpoint_metadata) => {
+            let view_name = endpoint_metadata.public_name.to_string();
+            quote! { .with_view(#view_name) }
+        },
+        _ => quote! {},
+    };
+    let key_arg_snippets = key_args.iter().map(|arg| {
+        // the key is encoded the same way, whether the argument is passed by reference or not
+        let mut arg_type = match &arg.ty {
+            syn::Type::Reference(type_reference) => (*type_reference.elem).clone(),
+            ty => ty.clone(),
+        };
+        clear_all_type_lifetimes(&mut arg_type);
+        let arg_name_str = pat_string(&arg.pat);
+        quote! {
+            storage_abi.add_key_arg(
+                #arg_name_str,
+                (&multiversx_sc::abi::StorageTypeProbe::<#arg_type>::new())
+                    .storage_type_names(&mut contract_abi.type_descriptions),
+            );
+        }
+    });
+    let value_type_snippets = value_types.into_iter().map(|mut value_type| {
+        clear_all_type_lifetimes(&mut value_type);
+        quote! {
+            storage_abi.add_value_type(
+                (&multiversx_sc::abi::StorageTypeProbe::<#value_type>::new())
+                    .storage_type_names(&mut contract_abi.type_descriptions),
+            );
+        }
+    });
+
+    Some(quote! {
+        {
+            use multiversx_sc::abi::{StorageTypeAbi as _, StorageTypeAbiFallback as _};
+            let mut storage_abi = multiversx_sc::abi::StorageAbi::new(
+                &[ #(#storage_docs),* ],
+                #identifier,
+                #rust_method_name,
+                #mapper_kind,
+            )
+            #view_tokens;
+            #(#key_arg_snippets)*
+            #(#value_type_snippets)*
+            contract_abi.add_storage(storage_abi);
+        }
+    })
+}
+
+fn generate_storage_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
+    contract
+        .methods
+        .iter()
+        .filter_map(|m| match &m.implementation {
+            MethodImpl::Generated(
+                AutoImpl::StorageGetter { identifier }
+                | AutoImpl::StorageSetter { identifier }
+                | AutoImpl::StorageMapper { identifier },
+            ) => generate_storage_snippet(m, identifier),
+            _ => None,
+        })
+        .collect()
+}
+
 fn has_callback(contract: &ContractTrait) -> bool {
     contract.methods.iter().any(|m| {
         matches!(
@@ -256,6 +380,7 @@ fn generate_abi_method_body(
     let contract_name = &contract.trait_name.to_string();
     let endpoint_snippets = generate_endpoint_snippets(contract);
     let event_snippets = generate_event_snippets(contract);
+    let storage_snippets = generate_storage_snippets(contract);
     let has_callbacks = has_callback(contract);
     let supertrait_snippets: Vec<proc_macro2::TokenStream> = if is_contract_main {
         generate_supertrait_snippets(contract)
@@ -284,6 +409,7 @@ fn generate_abi_method_body(
         );
         #(#endpoint_snippets)*
         #(#event_snippets)*
+        #(#storage_snippets)*
         #(#supertrait_snippets)*
         #(#esdt_attributes)*
         contract_abi
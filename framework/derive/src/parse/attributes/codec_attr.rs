@@ -0,0 +1,89 @@
+/// All field and variant options are grouped under `#[codec(...)]`.
+static ATTR_CODEC: &str = "codec";
+
+static ATTR_CODEC_DEFAULT: &str = "default";
+static ATTR_CODEC_SKIP: &str = "skip";
+static ATTR_CODEC_WITH: &str = "with";
+static ATTR_CODEC_ENCODED_AS: &str = "encoded_as";
+static ATTR_CODEC_DISCRIMINANT: &str = "discriminant";
+
+/// Field-level codec options.
+#[derive(Default)]
+pub struct FieldCodecAttributes {
+    /// `#[codec(default)]`: if the input ends before this field, it gets decoded as `Default::default()`.
+    ///
+    /// Allows adding fields at the end of structs that are already in storage.
+    pub default: bool,
+
+    /// `#[codec(skip)]`: not encoded, always decoded as `Default::default()`.
+    pub skip: bool,
+
+    /// `#[codec(with = "module")]`: encoded with `module::dep_encode_or_handle_err`
+    /// and decoded with `module::dep_decode_or_handle_err`,
+    /// which have the same signatures as the `NestedEncode`/`NestedDecode` trait methods.
+    pub with: Option<syn::Path>,
+
+    /// `#[codec(encoded_as = "Type")]`: the type whose encoding the `with` module produces.
+    ///
+    /// The field shows up in the ABI with this type. Required by the ABI for all fields with custom encoding.
+    pub encoded_as: Option<syn::Type>,
+}
+
+impl FieldCodecAttributes {
+    pub fn parse(field: &syn::Field) -> Self {
+        let mut result = FieldCodecAttributes::default();
+        for attr in codec_attributes(&field.attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident(ATTR_CODEC_DEFAULT) {
+                    result.default = true;
+                } else if meta.path.is_ident(ATTR_CODEC_SKIP) {
+                    result.skip = true;
+                } else if meta.path.is_ident(ATTR_CODEC_WITH) {
+                    let module: syn::LitStr = meta.value()?.parse()?;
+                    result.with = Some(module.parse()?);
+                } else if meta.path.is_ident(ATTR_CODEC_ENCODED_AS) {
+                    let encoded_type: syn::LitStr = meta.value()?.parse()?;
+                    result.encoded_as = Some(encoded_type.parse()?);
+                } else {
+                    return Err(meta.error(
+                        "unknown codec field attribute, expected `default`, `skip`, `with` or `encoded_as`",
+                    ));
+                }
+                Ok(())
+            })
+            .unwrap_or_else(|err| panic!("invalid codec attribute: {err}"));
+        }
+
+        assert!(
+            !result.skip || (!result.default && result.with.is_none()),
+            "#[codec(skip)] cannot be combined with other codec attributes"
+        );
+        assert!(
+            result.encoded_as.is_none() || result.with.is_some(),
+            "#[codec(encoded_as)] is only allowed together with #[codec(with)]"
+        );
+        result
+    }
+}
+
+/// Parses `#[codec(discriminant = N)]`, if present on the variant.
+pub fn variant_codec_discriminant(variant: &syn::Variant) -> Option<u8> {
+    let mut result = None;
+    for attr in codec_attributes(&variant.attrs) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(ATTR_CODEC_DISCRIMINANT) {
+                let value: syn::LitInt = meta.value()?.parse()?;
+                result = Some(value.base10_parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown codec variant attribute, expected `discriminant`"))
+            }
+        })
+        .unwrap_or_else(|err| panic!("invalid codec attribute: {err}"));
+    }
+    result
+}
+
+fn codec_attributes(attrs: &[syn::Attribute]) -> impl Iterator<Item = &syn::Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident(ATTR_CODEC))
+}
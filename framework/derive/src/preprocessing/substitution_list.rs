@@ -114,6 +114,7 @@ fn add_storage_mappers(substitutions: &mut SubstitutionsMap) {
     add_storage_mapper_single_generic_arg(substitutions, &quote!(UserMapper));
     add_storage_mapper_single_generic_arg(substitutions, &quote!(AddressToIdMapper));
 
+    add_storage_mapper(substitutions, &quote!(AvlTreeMapper));
     add_storage_mapper(substitutions, &quote!(BiDiMapper));
     add_storage_mapper(substitutions, &quote!(LinkedListMapper));
     add_storage_mapper(substitutions, &quote!(MapMapper));
@@ -122,6 +123,7 @@ fn add_storage_mappers(substitutions: &mut SubstitutionsMap) {
     add_storage_mapper(substitutions, &quote!(UnorderedSetMapper));
     add_storage_mapper(substitutions, &quote!(SingleValueMapper));
     add_storage_mapper(substitutions, &quote!(VecMapper));
+    add_storage_mapper(substitutions, &quote!(PriorityQueueMapper));
     add_storage_mapper(substitutions, &quote!(QueueMapper));
     add_storage_mapper(substitutions, &quote!(WhitelistMapper));
 }
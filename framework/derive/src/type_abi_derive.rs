@@ -1,4 +1,6 @@
-use crate::parse::attributes::extract_macro_attributes;
+use crate::parse::attributes::{
+    extract_macro_attributes, variant_codec_discriminant, FieldCodecAttributes,
+};
 
 use super::parse::attributes::extract_doc;
 use quote::quote;
@@ -9,19 +11,37 @@ pub struct ExplicitDiscriminant {
 }
 
 fn field_snippet(index: usize, field: &syn::Field) -> proc_macro2::TokenStream {
+    let codec_attributes = FieldCodecAttributes::parse(field);
+    if codec_attributes.skip {
+        // skipped fields are not serialized, so they do not show up in the ABI
+        return quote! {};
+    }
+    if codec_attributes.with.is_some() && codec_attributes.encoded_as.is_none() {
+        panic!("#[codec(with)] requires #[codec(encoded_as = \"...\")] in types with ABI");
+    }
+
     let field_docs = extract_doc(field.attrs.as_slice());
     let field_name_str = if let Some(ident) = &field.ident {
         ident.to_string()
     } else {
         index.to_string()
     };
-    let field_ty = &field.ty;
+    // custom encodings show up as the type they produce
+    let field_ty = codec_attributes.encoded_as.as_ref().unwrap_or(&field.ty);
+    let default_if_missing_snippet = if codec_attributes.default {
+        quote! { .with_default_if_missing() }
+    } else {
+        quote! {}
+    };
     quote! {
-        field_descriptions.push(multiversx_sc::abi::StructFieldDescription::new(
-            &[ #(#field_docs),* ],
-            #field_name_str,
-            <#field_ty>::type_names(),
-        ));
+        field_descriptions.push(
+            multiversx_sc::abi::StructFieldDescription::new(
+                &[ #(#field_docs),* ],
+                #field_name_str,
+                <#field_ty>::type_names(),
+            )
+            #default_if_missing_snippet
+        );
         <#field_ty>::provide_type_descriptions(accumulator);
     }
 }
@@ -154,6 +174,16 @@ pub fn get_discriminant(
     variant: &syn::Variant,
     previous_disc: &mut Vec<ExplicitDiscriminant>,
 ) -> proc_macro2::TokenStream {
+    // the codec attribute takes precedence over the Rust discriminant
+    if let Some(value) = variant_codec_discriminant(variant) {
+        let value = usize::from(value);
+        previous_disc.push(ExplicitDiscriminant {
+            variant_index,
+            value,
+        });
+        return quote! { #value};
+    }
+
     // if it has explicit discriminant
     if let Some((_, syn::Expr::Lit(expr))) = &variant.discriminant {
         let lit = match &expr.lit {
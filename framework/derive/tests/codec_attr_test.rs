@@ -0,0 +1,12 @@
+/// The codec derive macros and the `#[type_abi]` macro each have a copy of the `#[codec(...)]` parser,
+/// since proc-macro crates can only export macros.
+///
+/// The two copies must not diverge, otherwise the ABI would disagree with the actual encoding.
+#[test]
+fn codec_attr_parsers_identical_test() {
+    assert_eq!(
+        include_str!("../src/parse/attributes/codec_attr.rs"),
+        include_str!("../../../data/codec-derive/src/codec_attr.rs"),
+        "the #[codec(...)] attribute parsers in multiversx-sc-derive and multiversx-sc-codec-derive differ"
+    );
+}
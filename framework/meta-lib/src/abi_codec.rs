@@ -0,0 +1,107 @@
+//! Encodes and decodes JSON values to and from bytes, based solely on the contract ABI JSON.
+//!
+//! Meant for tools that only have the `.abi.json` of a contract, and no Rust types.
+//!
+//! The JSON representation of the values:
+//! - integers up to 64 bits: JSON numbers, decimal strings are also accepted when encoding;
+//! - `BigUint`, `BigInt`: decimal strings, JSON numbers are also accepted when encoding;
+//! - `bool`: JSON booleans;
+//! - `()`: `null`;
+//! - `bytes`, `bytes-read-to-end`, `H256`, `CodeMetadata`: hex strings;
+//! - `ManagedDecimal<N>`, `ManagedDecimalSigned<N>`: decimal strings, e.g. `"1.50"`,
+//!   with `usize` decimals the number of decimals is the one of the string;
+//! - `utf-8 string`, `TokenIdentifier`, `EgldOrEsdtTokenIdentifier`: strings;
+//! - `Address`: bech32 strings, hex is also accepted when encoding;
+//! - `Option<T>`, `optional<T>`: `null`, or the value;
+//! - `List<T>`, `arrayN<T>`, `tuple<...>`, `variadic<T>`, `counted-variadic<T>`, `multi<...>`: JSON arrays;
+//! - structs: JSON objects, with the fields by name;
+//! - enums: the variant name as string for variants without fields,
+//!   otherwise an object with a single key, the variant name, and the fields object as value.
+
+mod abi_basic_types;
+mod abi_decimal;
+mod abi_decoder;
+mod abi_encoder;
+mod abi_multi_codec;
+mod abi_type_node;
+
+pub use abi_type_node::AbiTypeNode;
+
+use crate::abi_json::{deserialize_abi_from_json, ContractAbiJson, TypeDescriptionJson};
+
+/// Top-encoding is used for single arguments, results and storage values,
+/// nested encoding for everything contained in another value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CodecMode {
+    Top,
+    Nested,
+}
+
+/// Encodes and decodes JSON values of the types declared in a contract ABI.
+pub struct AbiCodec {
+    pub abi: ContractAbiJson,
+}
+
+impl AbiCodec {
+    pub fn new(abi: ContractAbiJson) -> Self {
+        AbiCodec { abi }
+    }
+
+    /// Parses the contents of an `.abi.json` file.
+    pub fn from_abi_json(abi_json: &str) -> Result<Self, String> {
+        deserialize_abi_from_json(abi_json).map(AbiCodec::new)
+    }
+
+    fn type_description(&self, type_name: &str) -> Result<&TypeDescriptionJson, String> {
+        self.abi
+            .types
+            .get(type_name)
+            .ok_or_else(|| format!("unknown type `{type_name}`"))
+    }
+
+    /// Top-encodes a single value.
+    pub fn encode_top(
+        &self,
+        type_name: &str,
+        value: &serde_json::Value,
+    ) -> Result<Vec<u8>, String> {
+        let node = AbiTypeNode::parse(type_name)?;
+        let mut dest = Vec::new();
+        self.encode_value(&node, value, CodecMode::Top, &mut dest)?;
+        Ok(dest)
+    }
+
+    /// Nested-encodes a single value.
+    pub fn encode_nested(
+        &self,
+        type_name: &str,
+        value: &serde_json::Value,
+    ) -> Result<Vec<u8>, String> {
+        let node = AbiTypeNode::parse(type_name)?;
+        let mut dest = Vec::new();
+        self.encode_value(&node, value, CodecMode::Nested, &mut dest)?;
+        Ok(dest)
+    }
+
+    /// Top-decodes a single value.
+    pub fn decode_top(&self, type_name: &str, bytes: &[u8]) -> Result<serde_json::Value, String> {
+        let node = AbiTypeNode::parse(type_name)?;
+        let mut input = bytes;
+        self.decode_value(&node, &mut input, CodecMode::Top)
+    }
+
+    /// Nested-decodes a single value. The entire input must be consumed.
+    pub fn decode_nested(
+        &self,
+        type_name: &str,
+        bytes: &[u8],
+    ) -> Result<serde_json::Value, String> {
+        let node = AbiTypeNode::parse(type_name)?;
+        let mut input = bytes;
+        let value = self.decode_value(&node, &mut input, CodecMode::Nested)?;
+        if !input.is_empty() {
+            return Err(format!("{} bytes left after decoding", input.len()));
+        }
+        Ok(value)
+    }
+}
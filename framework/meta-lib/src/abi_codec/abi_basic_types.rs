@@ -0,0 +1,259 @@
+use bech32::{Bech32, Hrp};
+use multiversx_sc::codec::{
+    num_bigint::{BigInt, BigUint},
+    NestedDecode, NestedEncode, TopDecode, TopEncode,
+};
+use serde_json::Value;
+
+use super::CodecMode;
+
+const ADDRESS_HRP: &str = "erd";
+const ADDRESS_LEN: usize = 32;
+const H256_LEN: usize = 32;
+const CODE_METADATA_LEN: usize = 2;
+
+/// Encodes the types that the ABI refers to by name only, without a type description.
+///
+/// Returns `None` if the type is not one of them.
+pub(crate) fn encode_basic_type(
+    type_name: &str,
+    value: &Value,
+    mode: CodecMode,
+    dest: &mut Vec<u8>,
+) -> Option<Result<(), String>> {
+    let result = match type_name {
+        "()" => match value {
+            Value::Null => Ok(()),
+            _ => Err(format!("expected null, got {value}")),
+        },
+        "u8" => encode_unsigned::<u8>(value, mode, dest),
+        "u16" => encode_unsigned::<u16>(value, mode, dest),
+        "u32" | "usize" => encode_unsigned::<u32>(value, mode, dest),
+        "u64" => encode_unsigned::<u64>(value, mode, dest),
+        "i8" => encode_signed::<i8>(value, mode, dest),
+        "i16" => encode_signed::<i16>(value, mode, dest),
+        "i32" | "isize" => encode_signed::<i32>(value, mode, dest),
+        "i64" => encode_signed::<i64>(value, mode, dest),
+        "NonZeroUsize" => json_to_u64(value).and_then(|n| {
+            if n == 0 {
+                return Err("NonZeroUsize cannot be 0".to_string());
+            }
+            encode_unsigned::<u32>(value, mode, dest)
+        }),
+        "bool" => value
+            .as_bool()
+            .ok_or_else(|| format!("expected bool, got {value}"))
+            .and_then(|b| encode_with_codec(&b, mode, dest)),
+        "BigUint" => json_to_biguint(value).and_then(|n| encode_with_codec(&n, mode, dest)),
+        "BigInt" => json_to_bigint(value).and_then(|n| encode_with_codec(&n, mode, dest)),
+        "bytes" => json_to_hex_bytes(value).and_then(|bytes| encode_with_codec(&bytes, mode, dest)),
+        // no length prefix, it gets decoded until the end of the input
+        "bytes-read-to-end" => json_to_hex_bytes(value).map(|bytes| dest.extend_from_slice(&bytes)),
+        "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" => {
+            json_to_str(value).and_then(|s| encode_with_codec(&s.to_string(), mode, dest))
+        },
+        "Address" => {
+            json_to_address(value).and_then(|address| encode_with_codec(&address, mode, dest))
+        },
+        "H256" => json_to_fixed_hex::<H256_LEN>(value)
+            .and_then(|hash| encode_with_codec(&hash, mode, dest)),
+        "CodeMetadata" => json_to_fixed_hex::<CODE_METADATA_LEN>(value)
+            .and_then(|bits| encode_with_codec(&bits, mode, dest)),
+        _ => return None,
+    };
+    Some(result)
+}
+
+/// Decodes the types that the ABI refers to by name only, without a type description.
+///
+/// Returns `None` if the type is not one of them.
+pub(crate) fn decode_basic_type(
+    type_name: &str,
+    input: &mut &[u8],
+    mode: CodecMode,
+) -> Option<Result<Value, String>> {
+    let result = match type_name {
+        "()" => Ok(Value::Null),
+        "u8" => decode_with_codec::<u8>(input, mode).map(Value::from),
+        "u16" => decode_with_codec::<u16>(input, mode).map(Value::from),
+        "u32" | "usize" => decode_with_codec::<u32>(input, mode).map(Value::from),
+        "u64" => decode_with_codec::<u64>(input, mode).map(Value::from),
+        "i8" => decode_with_codec::<i8>(input, mode).map(Value::from),
+        "i16" => decode_with_codec::<i16>(input, mode).map(Value::from),
+        "i32" | "isize" => decode_with_codec::<i32>(input, mode).map(Value::from),
+        "i64" => decode_with_codec::<i64>(input, mode).map(Value::from),
+        "NonZeroUsize" => {
+            decode_with_codec::<core::num::NonZeroUsize>(input, mode).map(|n| Value::from(n.get()))
+        },
+        "bool" => decode_with_codec::<bool>(input, mode).map(Value::from),
+        "BigUint" => decode_with_codec::<BigUint>(input, mode).map(|n| Value::from(n.to_string())),
+        "BigInt" => decode_with_codec::<BigInt>(input, mode).map(|n| Value::from(n.to_string())),
+        "bytes" => {
+            decode_with_codec::<Vec<u8>>(input, mode).map(|bytes| Value::from(hex::encode(bytes)))
+        },
+        "bytes-read-to-end" => {
+            let bytes = core::mem::take(input);
+            Ok(Value::from(hex::encode(bytes)))
+        },
+        "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" => {
+            decode_with_codec::<String>(input, mode).map(Value::from)
+        },
+        "Address" => decode_with_codec::<[u8; ADDRESS_LEN]>(input, mode)
+            .map(|address| address_to_json(&address)),
+        "H256" => decode_with_codec::<[u8; H256_LEN]>(input, mode)
+            .map(|hash| Value::from(hex::encode(hash))),
+        "CodeMetadata" => decode_with_codec::<[u8; CODE_METADATA_LEN]>(input, mode)
+            .map(|bits| Value::from(hex::encode(bits))),
+        _ => return None,
+    };
+    Some(result)
+}
+
+/// The value the Rust type would get from `Default::default()`.
+///
+/// Returns `None` if the type is not a basic type.
+pub(crate) fn basic_type_default(type_name: &str) -> Option<Result<Value, String>> {
+    let result = match type_name {
+        "()" => Ok(Value::Null),
+        "u8" | "u16" | "u32" | "usize" | "u64" | "i8" | "i16" | "i32" | "isize" | "i64" => {
+            Ok(Value::from(0))
+        },
+        "NonZeroUsize" => Err("NonZeroUsize has no default value".to_string()),
+        "bool" => Ok(Value::from(false)),
+        "BigUint" | "BigInt" => Ok(Value::from("0")),
+        "bytes"
+        | "bytes-read-to-end"
+        | "utf-8 string"
+        | "TokenIdentifier"
+        | "EgldOrEsdtTokenIdentifier" => Ok(Value::from("")),
+        "Address" => Ok(address_to_json(&[0u8; ADDRESS_LEN])),
+        "H256" => Ok(Value::from(hex::encode([0u8; H256_LEN]))),
+        "CodeMetadata" => Ok(Value::from(hex::encode([0u8; CODE_METADATA_LEN]))),
+        _ => return None,
+    };
+    Some(result)
+}
+
+pub(crate) fn encode_with_codec<T>(
+    obj: &T,
+    mode: CodecMode,
+    dest: &mut Vec<u8>,
+) -> Result<(), String>
+where
+    T: TopEncode + NestedEncode,
+{
+    match mode {
+        CodecMode::Top => obj.top_encode(dest),
+        CodecMode::Nested => obj.dep_encode(dest),
+    }
+    .map_err(|err| err.message_str().to_string())
+}
+
+/// In top mode, the entire input is consumed.
+pub(crate) fn decode_with_codec<T>(input: &mut &[u8], mode: CodecMode) -> Result<T, String>
+where
+    T: TopDecode + NestedDecode,
+{
+    match mode {
+        CodecMode::Top => {
+            let result = T::top_decode(*input);
+            *input = &[];
+            result
+        },
+        CodecMode::Nested => T::dep_decode(input),
+    }
+    .map_err(|err| err.message_str().to_string())
+}
+
+fn encode_unsigned<T>(value: &Value, mode: CodecMode, dest: &mut Vec<u8>) -> Result<(), String>
+where
+    T: TryFrom<u64> + TopEncode + NestedEncode,
+{
+    let n = json_to_u64(value)?;
+    let n = T::try_from(n).map_err(|_| format!("value {n} out of range"))?;
+    encode_with_codec(&n, mode, dest)
+}
+
+fn encode_signed<T>(value: &Value, mode: CodecMode, dest: &mut Vec<u8>) -> Result<(), String>
+where
+    T: TryFrom<i64> + TopEncode + NestedEncode,
+{
+    let n = json_to_i64(value)?;
+    let n = T::try_from(n).map_err(|_| format!("value {n} out of range"))?;
+    encode_with_codec(&n, mode, dest)
+}
+
+/// Numbers can also be given as strings, since not all JSON tools handle 64-bit integers well.
+fn json_to_u64(value: &Value) -> Result<u64, String> {
+    match value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| format!("expected unsigned integer, got {value}"))
+}
+
+fn json_to_i64(value: &Value) -> Result<i64, String> {
+    match value {
+        Value::Number(n) => n.as_i64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| format!("expected integer, got {value}"))
+}
+
+fn json_to_biguint(value: &Value) -> Result<BigUint, String> {
+    match value {
+        Value::Number(n) => n.as_u64().map(BigUint::from),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| format!("expected unsigned big integer, got {value}"))
+}
+
+fn json_to_bigint(value: &Value) -> Result<BigInt, String> {
+    match value {
+        Value::Number(n) => n.as_i64().map(BigInt::from),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| format!("expected big integer, got {value}"))
+}
+
+fn json_to_str(value: &Value) -> Result<&str, String> {
+    value
+        .as_str()
+        .ok_or_else(|| format!("expected string, got {value}"))
+}
+
+fn json_to_hex_bytes(value: &Value) -> Result<Vec<u8>, String> {
+    let s = json_to_str(value)?;
+    hex::decode(s).map_err(|err| format!("invalid hex `{s}`: {err}"))
+}
+
+fn json_to_fixed_hex<const N: usize>(value: &Value) -> Result<[u8; N], String> {
+    let bytes = json_to_hex_bytes(value)?;
+    bytes
+        .try_into()
+        .map_err(|bytes: Vec<u8>| format!("expected {N} bytes, got {}", bytes.len()))
+}
+
+/// Accepts both bech32 and hex.
+fn json_to_address(value: &Value) -> Result<[u8; ADDRESS_LEN], String> {
+    let s = json_to_str(value)?;
+    if let Ok((hrp, bytes)) = bech32::decode(s) {
+        if hrp.as_str() != ADDRESS_HRP {
+            return Err(format!("invalid address `{s}`: unexpected prefix `{hrp}`"));
+        }
+        return bytes
+            .try_into()
+            .map_err(|_| format!("invalid address `{s}`: wrong length"));
+    }
+    json_to_fixed_hex::<ADDRESS_LEN>(value).map_err(|err| format!("invalid address `{s}`: {err}"))
+}
+
+fn address_to_json(address: &[u8; ADDRESS_LEN]) -> Value {
+    let hrp = Hrp::parse(ADDRESS_HRP).expect("invalid hrp");
+    let bech32 = bech32::encode::<Bech32>(hrp, address).expect("bech32 encode error");
+    Value::from(bech32)
+}
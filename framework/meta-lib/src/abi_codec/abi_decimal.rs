@@ -0,0 +1,152 @@
+use multiversx_sc::codec::num_bigint::{BigInt, BigUint, Sign};
+use serde_json::Value;
+
+use super::{
+    abi_basic_types::{decode_with_codec, encode_with_codec},
+    AbiTypeNode, CodecMode,
+};
+
+pub(crate) const MANAGED_DECIMAL_TYPE_NAME: &str = "ManagedDecimal";
+pub(crate) const MANAGED_DECIMAL_SIGNED_TYPE_NAME: &str = "ManagedDecimalSigned";
+
+/// The type argument of decimals that are only known at runtime, e.g. `ManagedDecimal<usize>`.
+const VAR_DECIMALS_ARG: &str = "usize";
+
+enum Decimals {
+    /// `ManagedDecimal<N>`: only the raw units are encoded.
+    Const(usize),
+    /// `ManagedDecimal<usize>`: the number of decimals is encoded after the raw units.
+    Var,
+}
+
+fn decimals_arg(node: &AbiTypeNode) -> Result<Decimals, String> {
+    let arg = node.single_arg()?;
+    if arg.args.is_empty() && arg.name == VAR_DECIMALS_ARG {
+        return Ok(Decimals::Var);
+    }
+    arg.name
+        .parse()
+        .ok()
+        .filter(|_| arg.args.is_empty())
+        .map(Decimals::Const)
+        .ok_or_else(|| format!("invalid number of decimals in `{node}`"))
+}
+
+fn is_signed(node: &AbiTypeNode) -> bool {
+    node.name == MANAGED_DECIMAL_SIGNED_TYPE_NAME
+}
+
+/// Decimals are given as decimal strings, e.g. `"1.50"`.
+///
+/// With constant decimals, the string can have fewer decimals, but not more.
+/// Otherwise, the number of decimals is the one of the string.
+pub(crate) fn encode_decimal(
+    node: &AbiTypeNode,
+    value: &Value,
+    mode: CodecMode,
+    dest: &mut Vec<u8>,
+) -> Result<(), String> {
+    let (raw_units, value_decimals) = json_to_decimal(value)?;
+    match decimals_arg(node)? {
+        Decimals::Const(decimals) => {
+            if value_decimals > decimals {
+                return Err(format!(
+                    "cannot encode {value} as {node}: more than {decimals} decimals"
+                ));
+            }
+            let scale = BigInt::from(10u32).pow((decimals - value_decimals) as u32);
+            encode_raw_units(node, &(raw_units * scale), mode, dest)
+        },
+        Decimals::Var => {
+            // nested encoding, also at top level, same as the Rust type
+            encode_raw_units(node, &raw_units, CodecMode::Nested, dest)?;
+            encode_with_codec(&value_decimals, CodecMode::Nested, dest)
+        },
+    }
+    .map_err(|err| format!("cannot encode {value} as {node}: {err}"))
+}
+
+pub(crate) fn decode_decimal(
+    node: &AbiTypeNode,
+    input: &mut &[u8],
+    mode: CodecMode,
+) -> Result<Value, String> {
+    let (raw_units, decimals) = match decimals_arg(node)? {
+        Decimals::Const(decimals) => (decode_raw_units(node, input, mode)?, decimals),
+        Decimals::Var => {
+            let raw_units = decode_raw_units(node, input, CodecMode::Nested)?;
+            let decimals = decode_with_codec::<usize>(input, CodecMode::Nested)?;
+            (raw_units, decimals)
+        },
+    };
+    Ok(Value::from(format_decimal(&raw_units, decimals)))
+}
+
+pub(crate) fn decimal_default(node: &AbiTypeNode) -> Result<Value, String> {
+    let decimals = match decimals_arg(node)? {
+        Decimals::Const(decimals) => decimals,
+        Decimals::Var => 0,
+    };
+    Ok(Value::from(format_decimal(&BigInt::default(), decimals)))
+}
+
+fn encode_raw_units(
+    node: &AbiTypeNode,
+    raw_units: &BigInt,
+    mode: CodecMode,
+    dest: &mut Vec<u8>,
+) -> Result<(), String> {
+    if is_signed(node) {
+        return encode_with_codec(raw_units, mode, dest);
+    }
+    let raw_units = raw_units
+        .to_biguint()
+        .ok_or_else(|| "negative value for unsigned decimal".to_string())?;
+    encode_with_codec(&raw_units, mode, dest)
+}
+
+fn decode_raw_units(
+    node: &AbiTypeNode,
+    input: &mut &[u8],
+    mode: CodecMode,
+) -> Result<BigInt, String> {
+    if is_signed(node) {
+        decode_with_codec::<BigInt>(input, mode)
+    } else {
+        decode_with_codec::<BigUint>(input, mode).map(BigInt::from)
+    }
+    .map_err(|err| format!("cannot decode {node}: {err}"))
+}
+
+/// Returns the raw units and the number of decimals, e.g. `"-1.50"` is `(-150, 2)`.
+///
+/// Integers can also be given as JSON numbers.
+fn json_to_decimal(value: &Value) -> Result<(BigInt, usize), String> {
+    let s = match value {
+        Value::Number(n) if n.is_i64() || n.is_u64() => n.to_string(),
+        Value::String(s) => s.clone(),
+        _ => return Err(format!("expected decimal string, got {value}")),
+    };
+    let (integer_part, fractional_part) = s.split_once('.').unwrap_or((&s, ""));
+    if !fractional_part.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("invalid decimal `{s}`"));
+    }
+    let raw_units = format!("{integer_part}{fractional_part}")
+        .parse()
+        .map_err(|_| format!("invalid decimal `{s}`"))?;
+    Ok((raw_units, fractional_part.len()))
+}
+
+fn format_decimal(raw_units: &BigInt, decimals: usize) -> String {
+    if decimals == 0 {
+        return raw_units.to_string();
+    }
+    let digits = format!("{:0>width$}", raw_units.magnitude(), width = decimals + 1);
+    let (integer_part, fractional_part) = digits.split_at(digits.len() - decimals);
+    let sign = if raw_units.sign() == Sign::Minus {
+        "-"
+    } else {
+        ""
+    };
+    format!("{sign}{integer_part}.{fractional_part}")
+}
@@ -0,0 +1,276 @@
+use serde_json::{Map, Value};
+
+use crate::abi_json::{
+    EnumVariantDescriptionJson, StructFieldDescriptionJson, TYPE_DESCRIPTION_JSON_TYPE_ENUM,
+    TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM, TYPE_DESCRIPTION_JSON_TYPE_STRUCT,
+};
+
+use super::{
+    abi_basic_types::{basic_type_default, decode_basic_type, decode_with_codec},
+    abi_decimal::{
+        decimal_default, decode_decimal, MANAGED_DECIMAL_SIGNED_TYPE_NAME,
+        MANAGED_DECIMAL_TYPE_NAME,
+    },
+    abi_encoder::{variant_discriminant, MULTI_VALUE_TYPE_NAMES},
+    AbiCodec, AbiTypeNode, CodecMode,
+};
+
+impl AbiCodec {
+    /// In top mode, the entire input is consumed, otherwise only the bytes of the value.
+    pub(crate) fn decode_value(
+        &self,
+        node: &AbiTypeNode,
+        input: &mut &[u8],
+        mode: CodecMode,
+    ) -> Result<Value, String> {
+        if node.args.is_empty() {
+            if let Some(result) = decode_basic_type(&node.name, input, mode) {
+                return result.map_err(|err| format!("cannot decode {node}: {err}"));
+            }
+        }
+
+        let value = if let Some(size) = node.array_size() {
+            let item_type = node.single_arg()?;
+            let items = (0..size)
+                .map(|_| self.decode_value(item_type, input, CodecMode::Nested))
+                .collect::<Result<Vec<_>, _>>()?;
+            Value::Array(items)
+        } else {
+            match node.name.as_str() {
+                "Option" => self.decode_option(node.single_arg()?, input, mode)?,
+                "List" => self.decode_list(node.single_arg()?, input, mode)?,
+                "tuple" => {
+                    let items = node
+                        .args
+                        .iter()
+                        .map(|item_type| self.decode_value(item_type, input, CodecMode::Nested))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Value::Array(items)
+                },
+                MANAGED_DECIMAL_TYPE_NAME | MANAGED_DECIMAL_SIGNED_TYPE_NAME => {
+                    decode_decimal(node, input, mode)?
+                },
+                name if MULTI_VALUE_TYPE_NAMES.contains(&name) => {
+                    return Err(format!(
+                        "multi-value type `{node}` can only be used for arguments and results"
+                    ))
+                },
+                name if node.args.is_empty() => self.decode_custom_type(name, input, mode)?,
+                _ => return Err(format!("unsupported generic type `{node}`")),
+            }
+        };
+
+        if mode == CodecMode::Top && !input.is_empty() {
+            return Err(format!("input too long for {node}"));
+        }
+        Ok(value)
+    }
+
+    fn decode_option(
+        &self,
+        item_type: &AbiTypeNode,
+        input: &mut &[u8],
+        mode: CodecMode,
+    ) -> Result<Value, String> {
+        if mode == CodecMode::Top && input.is_empty() {
+            return Ok(Value::Null);
+        }
+        match read_u8(input)? {
+            0 => Ok(Value::Null),
+            1 => self.decode_value(item_type, input, CodecMode::Nested),
+            _ => Err("invalid Option flag".to_string()),
+        }
+    }
+
+    fn decode_list(
+        &self,
+        item_type: &AbiTypeNode,
+        input: &mut &[u8],
+        mode: CodecMode,
+    ) -> Result<Value, String> {
+        let mut items = Vec::new();
+        match mode {
+            CodecMode::Top => {
+                while !input.is_empty() {
+                    let remaining = input.len();
+                    items.push(self.decode_value(item_type, input, CodecMode::Nested)?);
+                    if input.len() == remaining {
+                        // the items have no bytes, so the end of the input would never be reached
+                        return Err(format!("cannot top-decode a list of `{item_type}`"));
+                    }
+                }
+            },
+            CodecMode::Nested => {
+                let len = u32::from_be_bytes(read_bytes(input, 4)?.try_into().unwrap());
+                for _ in 0..len {
+                    items.push(self.decode_value(item_type, input, CodecMode::Nested)?);
+                }
+            },
+        }
+        Ok(Value::Array(items))
+    }
+
+    fn decode_custom_type(
+        &self,
+        type_name: &str,
+        input: &mut &[u8],
+        mode: CodecMode,
+    ) -> Result<Value, String> {
+        let type_description = self.type_description(type_name)?;
+        match type_description.content_type.as_str() {
+            TYPE_DESCRIPTION_JSON_TYPE_STRUCT => {
+                self.decode_fields(type_name, &type_description.fields, input)
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_ENUM => {
+                self.decode_enum(type_name, &type_description.variants, input, mode)
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM => {
+                let variant_name = decode_with_codec::<String>(input, mode)?;
+                if !type_description
+                    .variants
+                    .iter()
+                    .any(|variant| variant.name == variant_name)
+                {
+                    return Err(format!("unknown {type_name} variant `{variant_name}`"));
+                }
+                Ok(Value::String(variant_name))
+            },
+            content_type => Err(format!(
+                "type `{type_name}` of kind `{content_type}` cannot be decoded"
+            )),
+        }
+    }
+
+    /// Fields marked with `defaultIfMissing` get their default value if the input ends before them.
+    fn decode_fields(
+        &self,
+        type_name: &str,
+        fields: &[StructFieldDescriptionJson],
+        input: &mut &[u8],
+    ) -> Result<Value, String> {
+        let mut object = Map::new();
+        for field in fields {
+            let field_type = AbiTypeNode::parse(&field.field_type)?;
+            let field_value = if input.is_empty() && field.default_if_missing.unwrap_or_default() {
+                self.default_value(&field_type)?
+            } else {
+                self.decode_value(&field_type, input, CodecMode::Nested)
+                    .map_err(|err| format!("{type_name} field `{}`: {err}", field.name))?
+            };
+            object.insert(field.name.clone(), field_value);
+        }
+        Ok(Value::Object(object))
+    }
+
+    fn decode_enum(
+        &self,
+        type_name: &str,
+        variants: &[EnumVariantDescriptionJson],
+        input: &mut &[u8],
+        mode: CodecMode,
+    ) -> Result<Value, String> {
+        let fieldless = variants.iter().all(|variant| variant.fields.is_empty());
+        let discriminant = if fieldless {
+            decode_with_codec::<u8>(input, mode)?
+        } else if mode == CodecMode::Top && input.is_empty() {
+            // a top-encoded fieldless variant with discriminant 0
+            0
+        } else {
+            read_u8(input)?
+        };
+
+        let mut found_variant = None;
+        for (variant_index, variant) in variants.iter().enumerate() {
+            if variant_discriminant(variant_index, variant)? == discriminant {
+                found_variant = Some(variant);
+                break;
+            }
+        }
+        let variant = found_variant
+            .ok_or_else(|| format!("invalid {type_name} discriminant {discriminant}"))?;
+
+        if variant.fields.is_empty() {
+            return Ok(Value::String(variant.name.clone()));
+        }
+        let fields_value = self.decode_fields(type_name, &variant.fields, input)?;
+        let mut object = Map::new();
+        object.insert(variant.name.clone(), fields_value);
+        Ok(Value::Object(object))
+    }
+
+    /// The value the Rust type would get from `Default::default()`.
+    pub(crate) fn default_value(&self, node: &AbiTypeNode) -> Result<Value, String> {
+        if node.args.is_empty() {
+            if let Some(result) = basic_type_default(&node.name) {
+                return result;
+            }
+        }
+
+        if let Some(size) = node.array_size() {
+            let item_default = self.default_value(node.single_arg()?)?;
+            return Ok(Value::Array(vec![item_default; size]));
+        }
+
+        match node.name.as_str() {
+            "Option" => Ok(Value::Null),
+            "List" => Ok(Value::Array(Vec::new())),
+            "tuple" => node
+                .args
+                .iter()
+                .map(|item_type| self.default_value(item_type))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array),
+            MANAGED_DECIMAL_TYPE_NAME | MANAGED_DECIMAL_SIGNED_TYPE_NAME => decimal_default(node),
+            type_name if node.args.is_empty() => self.custom_type_default(type_name),
+            _ => Err(format!("type `{node}` has no default value")),
+        }
+    }
+
+    fn custom_type_default(&self, type_name: &str) -> Result<Value, String> {
+        let type_description = self.type_description(type_name)?;
+        match type_description.content_type.as_str() {
+            TYPE_DESCRIPTION_JSON_TYPE_STRUCT => self.fields_default(&type_description.fields),
+            TYPE_DESCRIPTION_JSON_TYPE_ENUM => {
+                // same as the derived codec: the default is the variant with discriminant 0
+                let mut zero_variant = None;
+                for (variant_index, variant) in type_description.variants.iter().enumerate() {
+                    if variant_discriminant(variant_index, variant)? == 0 {
+                        zero_variant = Some(variant);
+                        break;
+                    }
+                }
+                let variant = zero_variant
+                    .ok_or_else(|| format!("type `{type_name}` has no default value"))?;
+                if variant.fields.is_empty() {
+                    return Ok(Value::String(variant.name.clone()));
+                }
+                let mut object = Map::new();
+                object.insert(variant.name.clone(), self.fields_default(&variant.fields)?);
+                Ok(Value::Object(object))
+            },
+            _ => Err(format!("type `{type_name}` has no default value")),
+        }
+    }
+
+    fn fields_default(&self, fields: &[StructFieldDescriptionJson]) -> Result<Value, String> {
+        let mut object = Map::new();
+        for field in fields {
+            let field_type = AbiTypeNode::parse(&field.field_type)?;
+            object.insert(field.name.clone(), self.default_value(&field_type)?);
+        }
+        Ok(Value::Object(object))
+    }
+}
+
+fn read_bytes<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], String> {
+    if input.len() < len {
+        return Err("input too short".to_string());
+    }
+    let (bytes, rest) = input.split_at(len);
+    *input = rest;
+    Ok(bytes)
+}
+
+fn read_u8(input: &mut &[u8]) -> Result<u8, String> {
+    read_bytes(input, 1).map(|bytes| bytes[0])
+}
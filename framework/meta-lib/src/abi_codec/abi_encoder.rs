@@ -0,0 +1,267 @@
+use serde_json::Value;
+
+use crate::abi_json::{
+    EnumVariantDescriptionJson, StructFieldDescriptionJson, TYPE_DESCRIPTION_JSON_TYPE_ENUM,
+    TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM, TYPE_DESCRIPTION_JSON_TYPE_STRUCT,
+};
+
+use super::{
+    abi_basic_types::{encode_basic_type, encode_with_codec},
+    abi_decimal::{encode_decimal, MANAGED_DECIMAL_SIGNED_TYPE_NAME, MANAGED_DECIMAL_TYPE_NAME},
+    AbiCodec, AbiTypeNode, CodecMode,
+};
+
+pub(crate) const MULTI_VALUE_TYPE_NAMES: &[&str] = &[
+    "variadic",
+    "counted-variadic",
+    "optional",
+    "multi",
+    "ignore",
+];
+
+impl AbiCodec {
+    pub(crate) fn encode_value(
+        &self,
+        node: &AbiTypeNode,
+        value: &Value,
+        mode: CodecMode,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        if node.args.is_empty() {
+            if let Some(result) = encode_basic_type(&node.name, value, mode, dest) {
+                return result.map_err(|err| format!("cannot encode {value} as {node}: {err}"));
+            }
+        }
+
+        if let Some(size) = node.array_size() {
+            return self.encode_array(node.single_arg()?, size, value, dest);
+        }
+
+        match node.name.as_str() {
+            "Option" => self.encode_option(node.single_arg()?, value, mode, dest),
+            "List" => self.encode_list(node.single_arg()?, value, mode, dest),
+            "tuple" => self.encode_tuple(&node.args, value, dest),
+            MANAGED_DECIMAL_TYPE_NAME | MANAGED_DECIMAL_SIGNED_TYPE_NAME => {
+                encode_decimal(node, value, mode, dest)
+            },
+            name if MULTI_VALUE_TYPE_NAMES.contains(&name) => Err(format!(
+                "multi-value type `{node}` can only be used for arguments and results"
+            )),
+            name if node.args.is_empty() => self.encode_custom_type(name, value, mode, dest),
+            _ => Err(format!("unsupported generic type `{node}`")),
+        }
+    }
+
+    fn encode_option(
+        &self,
+        item_type: &AbiTypeNode,
+        value: &Value,
+        mode: CodecMode,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        if value.is_null() {
+            // top-encoded `None` is empty
+            if mode == CodecMode::Nested {
+                dest.push(0);
+            }
+            return Ok(());
+        }
+
+        dest.push(1);
+        self.encode_value(item_type, value, CodecMode::Nested, dest)
+    }
+
+    fn encode_list(
+        &self,
+        item_type: &AbiTypeNode,
+        value: &Value,
+        mode: CodecMode,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        let items = json_to_array(value)?;
+        // top-encoded lists have no length prefix
+        if mode == CodecMode::Nested {
+            let len = u32::try_from(items.len()).map_err(|_| "list too long".to_string())?;
+            dest.extend_from_slice(&len.to_be_bytes());
+        }
+        for item in items {
+            self.encode_value(item_type, item, CodecMode::Nested, dest)?;
+        }
+        Ok(())
+    }
+
+    fn encode_array(
+        &self,
+        item_type: &AbiTypeNode,
+        size: usize,
+        value: &Value,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        let items = json_to_array(value)?;
+        if items.len() != size {
+            return Err(format!(
+                "expected array of {size} items, got {}",
+                items.len()
+            ));
+        }
+        for item in items {
+            self.encode_value(item_type, item, CodecMode::Nested, dest)?;
+        }
+        Ok(())
+    }
+
+    fn encode_tuple(
+        &self,
+        item_types: &[AbiTypeNode],
+        value: &Value,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        let items = json_to_array(value)?;
+        if items.len() != item_types.len() {
+            return Err(format!(
+                "expected tuple of {} items, got {}",
+                item_types.len(),
+                items.len()
+            ));
+        }
+        for (item_type, item) in item_types.iter().zip(items) {
+            self.encode_value(item_type, item, CodecMode::Nested, dest)?;
+        }
+        Ok(())
+    }
+
+    fn encode_custom_type(
+        &self,
+        type_name: &str,
+        value: &Value,
+        mode: CodecMode,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        let type_description = self.type_description(type_name)?;
+        match type_description.content_type.as_str() {
+            TYPE_DESCRIPTION_JSON_TYPE_STRUCT => {
+                self.encode_fields(type_name, &type_description.fields, value, dest)
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_ENUM => {
+                self.encode_enum(type_name, &type_description.variants, value, mode, dest)
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM => {
+                let variant_name = value
+                    .as_str()
+                    .ok_or_else(|| format!("expected {type_name} variant name, got {value}"))?;
+                if !type_description
+                    .variants
+                    .iter()
+                    .any(|variant| variant.name == variant_name)
+                {
+                    return Err(format!("unknown {type_name} variant `{variant_name}`"));
+                }
+                encode_with_codec(&variant_name.to_string(), mode, dest)
+            },
+            content_type => Err(format!(
+                "type `{type_name}` of kind `{content_type}` cannot be encoded"
+            )),
+        }
+    }
+
+    /// Struct fields and enum variant fields are always nested-encoded, one after the other.
+    fn encode_fields(
+        &self,
+        type_name: &str,
+        fields: &[StructFieldDescriptionJson],
+        value: &Value,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| format!("expected {type_name} object, got {value}"))?;
+        if let Some(unknown_field) = object
+            .keys()
+            .find(|key| !fields.iter().any(|field| &field.name == *key))
+        {
+            return Err(format!("unknown {type_name} field `{unknown_field}`"));
+        }
+
+        for field in fields {
+            let field_type = AbiTypeNode::parse(&field.field_type)?;
+            match object.get(&field.name) {
+                Some(field_value) => {
+                    self.encode_value(&field_type, field_value, CodecMode::Nested, dest)?
+                },
+                None if field.default_if_missing.unwrap_or_default() => {
+                    let default_value = self.default_value(&field_type)?;
+                    self.encode_value(&field_type, &default_value, CodecMode::Nested, dest)?
+                },
+                None => return Err(format!("missing {type_name} field `{}`", field.name)),
+            }
+        }
+        Ok(())
+    }
+
+    fn encode_enum(
+        &self,
+        type_name: &str,
+        variants: &[EnumVariantDescriptionJson],
+        value: &Value,
+        mode: CodecMode,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        let (variant_name, fields_value) = match value {
+            Value::String(variant_name) => (variant_name.as_str(), None),
+            Value::Object(object) if object.len() == 1 => {
+                let (variant_name, fields_value) = object.iter().next().unwrap();
+                (variant_name.as_str(), Some(fields_value))
+            },
+            _ => {
+                return Err(format!(
+                    "expected {type_name} variant name or single-key object, got {value}"
+                ))
+            },
+        };
+        let (variant_index, variant) = variants
+            .iter()
+            .enumerate()
+            .find(|(_, variant)| variant.name == variant_name)
+            .ok_or_else(|| format!("unknown {type_name} variant `{variant_name}`"))?;
+        let discriminant = variant_discriminant(variant_index, variant)?;
+
+        if variant.fields.is_empty() {
+            if fields_value.is_some_and(|fields_value| !is_empty_fields(fields_value)) {
+                return Err(format!(
+                    "{type_name} variant `{variant_name}` has no fields, got {value}"
+                ));
+            }
+            // same as the derived codec: the discriminant alone is top-encoded, so 0 becomes empty
+            return encode_with_codec(&discriminant, mode, dest);
+        }
+
+        let fields_value = fields_value
+            .ok_or_else(|| format!("{type_name} variant `{variant_name}` requires fields"))?;
+        dest.push(discriminant);
+        self.encode_fields(type_name, &variant.fields, fields_value, dest)
+    }
+}
+
+/// Older ABIs might be missing the discriminants, in which case they are the variant indexes.
+pub(crate) fn variant_discriminant(
+    variant_index: usize,
+    variant: &EnumVariantDescriptionJson,
+) -> Result<u8, String> {
+    let discriminant = variant.discriminant.unwrap_or(variant_index);
+    u8::try_from(discriminant)
+        .map_err(|_| format!("variant `{}` discriminant out of range", variant.name))
+}
+
+fn is_empty_fields(fields_value: &Value) -> bool {
+    match fields_value {
+        Value::Null => true,
+        Value::Object(object) => object.is_empty(),
+        _ => false,
+    }
+}
+
+fn json_to_array(value: &Value) -> Result<&Vec<Value>, String> {
+    value
+        .as_array()
+        .ok_or_else(|| format!("expected array, got {value}"))
+}
@@ -0,0 +1,249 @@
+use serde_json::Value;
+
+use crate::abi_json::{InputAbiJson, OutputAbiJson};
+
+use super::{AbiCodec, AbiTypeNode, CodecMode};
+
+const INIT_ENDPOINT_NAME: &str = "init";
+const UPGRADE_ENDPOINT_NAME: &str = "upgrade";
+
+/// The raw arguments or results that remain to be decoded.
+struct ArgsCursor<'a> {
+    args: &'a [Vec<u8>],
+}
+
+impl<'a> ArgsCursor<'a> {
+    fn has_next(&self) -> bool {
+        !self.args.is_empty()
+    }
+
+    fn next_arg(&mut self) -> Result<&'a [u8], String> {
+        let (first, rest) = self
+            .args
+            .split_first()
+            .ok_or_else(|| "not enough arguments".to_string())?;
+        self.args = rest;
+        Ok(first)
+    }
+}
+
+impl AbiCodec {
+    /// Encodes a value as a list of top-encoded arguments.
+    ///
+    /// Multi-value types, such as `variadic<T>` or `optional<T>`, can produce any number of arguments,
+    /// all other types produce exactly one.
+    pub fn encode_multi(&self, type_name: &str, value: &Value) -> Result<Vec<Vec<u8>>, String> {
+        let node = AbiTypeNode::parse(type_name)?;
+        let mut args = Vec::new();
+        self.encode_multi_value(&node, value, &mut args)?;
+        Ok(args)
+    }
+
+    /// Decodes a value from a list of top-encoded arguments. All arguments must be consumed.
+    pub fn decode_multi(&self, type_name: &str, args: &[Vec<u8>]) -> Result<Value, String> {
+        let node = AbiTypeNode::parse(type_name)?;
+        let mut cursor = ArgsCursor { args };
+        let value = self.decode_multi_value(&node, &mut cursor)?;
+        if cursor.has_next() {
+            return Err("too many arguments".to_string());
+        }
+        Ok(value)
+    }
+
+    /// Encodes the arguments of an endpoint, one JSON value for each of its inputs.
+    ///
+    /// Missing trailing values are treated as `null`, so optional and variadic inputs can be left out.
+    /// Use `"init"` and `"upgrade"` for the constructors.
+    pub fn encode_endpoint_args(
+        &self,
+        endpoint_name: &str,
+        args: &[Value],
+    ) -> Result<Vec<Vec<u8>>, String> {
+        let (inputs, _) = self.endpoint_io(endpoint_name)?;
+        if args.len() > inputs.len() {
+            return Err(format!(
+                "endpoint `{endpoint_name}` takes {} arguments, got {}",
+                inputs.len(),
+                args.len()
+            ));
+        }
+
+        let mut raw_args = Vec::new();
+        for (index, input) in inputs.iter().enumerate() {
+            let node = AbiTypeNode::parse(&input.type_name)?;
+            let value = args.get(index).unwrap_or(&Value::Null);
+            self.encode_multi_value(&node, value, &mut raw_args)
+                .map_err(|err| format!("argument `{}`: {err}", input.arg_name))?;
+        }
+        Ok(raw_args)
+    }
+
+    /// Decodes the results of an endpoint, one JSON value for each of its outputs.
+    ///
+    /// Use `"init"` and `"upgrade"` for the constructors.
+    pub fn decode_endpoint_results(
+        &self,
+        endpoint_name: &str,
+        results: &[Vec<u8>],
+    ) -> Result<Vec<Value>, String> {
+        let (_, outputs) = self.endpoint_io(endpoint_name)?;
+        let mut cursor = ArgsCursor { args: results };
+        let mut values = Vec::new();
+        for output in outputs {
+            let node = AbiTypeNode::parse(&output.type_name)?;
+            values.push(self.decode_multi_value(&node, &mut cursor)?);
+        }
+        if cursor.has_next() {
+            return Err(format!(
+                "endpoint `{endpoint_name}` returned too many results"
+            ));
+        }
+        Ok(values)
+    }
+
+    fn endpoint_io(
+        &self,
+        endpoint_name: &str,
+    ) -> Result<(&[InputAbiJson], &[OutputAbiJson]), String> {
+        let constructor = match endpoint_name {
+            INIT_ENDPOINT_NAME => self.abi.constructor.as_ref(),
+            UPGRADE_ENDPOINT_NAME => self.abi.upgrade_constructor.as_ref(),
+            _ => None,
+        };
+        if let Some(constructor) = constructor {
+            return Ok((&constructor.inputs, &constructor.outputs));
+        }
+
+        self.abi
+            .endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == endpoint_name)
+            .map(|endpoint| (endpoint.inputs.as_slice(), endpoint.outputs.as_slice()))
+            .ok_or_else(|| format!("endpoint `{endpoint_name}` not found in the ABI"))
+    }
+
+    fn encode_multi_value(
+        &self,
+        node: &AbiTypeNode,
+        value: &Value,
+        args: &mut Vec<Vec<u8>>,
+    ) -> Result<(), String> {
+        match node.name.as_str() {
+            "optional" => {
+                if !value.is_null() {
+                    self.encode_multi_value(node.single_arg()?, value, args)?;
+                }
+                Ok(())
+            },
+            "variadic" | "counted-variadic" => {
+                let items = multi_items(value)?;
+                if node.name == "counted-variadic" {
+                    let mut count = Vec::new();
+                    self.encode_value(
+                        &AbiTypeNode::parse("u32")?,
+                        &Value::from(items.len()),
+                        CodecMode::Top,
+                        &mut count,
+                    )?;
+                    args.push(count);
+                }
+                let item_type = node.single_arg()?;
+                for item in items {
+                    self.encode_multi_value(item_type, item, args)?;
+                }
+                Ok(())
+            },
+            "multi" => {
+                let items = multi_items(value)?;
+                if items.len() != node.args.len() {
+                    return Err(format!(
+                        "expected {} values for `{node}`, got {}",
+                        node.args.len(),
+                        items.len()
+                    ));
+                }
+                for (item_type, item) in node.args.iter().zip(items) {
+                    self.encode_multi_value(item_type, item, args)?;
+                }
+                Ok(())
+            },
+            "ignore" => {
+                args.push(Vec::new());
+                Ok(())
+            },
+            // no arguments at all, same as the Rust unit type
+            "()" => self.encode_value(node, value, CodecMode::Top, &mut Vec::new()),
+            _ => {
+                let mut arg = Vec::new();
+                self.encode_value(node, value, CodecMode::Top, &mut arg)?;
+                args.push(arg);
+                Ok(())
+            },
+        }
+    }
+
+    fn decode_multi_value(
+        &self,
+        node: &AbiTypeNode,
+        cursor: &mut ArgsCursor<'_>,
+    ) -> Result<Value, String> {
+        match node.name.as_str() {
+            "optional" => {
+                if cursor.has_next() {
+                    self.decode_multi_value(node.single_arg()?, cursor)
+                } else {
+                    Ok(Value::Null)
+                }
+            },
+            "variadic" => {
+                let item_type = node.single_arg()?;
+                let mut items = Vec::new();
+                while cursor.has_next() {
+                    let remaining = cursor.args.len();
+                    items.push(self.decode_multi_value(item_type, cursor)?);
+                    if cursor.args.len() == remaining {
+                        return Err(format!(
+                            "`{item_type}` takes no arguments, cannot be variadic"
+                        ));
+                    }
+                }
+                Ok(Value::Array(items))
+            },
+            "counted-variadic" => {
+                let mut count_arg = cursor.next_arg()?;
+                let count =
+                    self.decode_value(&AbiTypeNode::parse("u32")?, &mut count_arg, CodecMode::Top)?;
+                let item_type = node.single_arg()?;
+                let mut items = Vec::new();
+                for _ in 0..count.as_u64().unwrap_or_default() {
+                    items.push(self.decode_multi_value(item_type, cursor)?);
+                }
+                Ok(Value::Array(items))
+            },
+            "multi" => node
+                .args
+                .iter()
+                .map(|item_type| self.decode_multi_value(item_type, cursor))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array),
+            "ignore" => {
+                cursor.next_arg()?;
+                Ok(Value::Null)
+            },
+            "()" => Ok(Value::Null),
+            _ => {
+                let mut arg = cursor.next_arg()?;
+                self.decode_value(node, &mut arg, CodecMode::Top)
+            },
+        }
+    }
+}
+
+/// `null` is accepted as an empty list, for variadic arguments that are left out.
+fn multi_items(value: &Value) -> Result<&[Value], String> {
+    match value {
+        Value::Null => Ok(&[]),
+        Value::Array(items) => Ok(items),
+        _ => Err(format!("expected array, got {value}")),
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt::Display;
+
+const ARRAY_PREFIX: &str = "array";
+
+/// A parsed ABI type name, e.g. `Option<List<u32>>` or `multi<Address,BigUint>`.
+///
+/// Type names in the ABI JSON are plain strings, this is their tree form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbiTypeNode {
+    pub name: String,
+    pub args: Vec<AbiTypeNode>,
+}
+
+impl AbiTypeNode {
+    pub fn parse(type_name: &str) -> Result<Self, String> {
+        let (node, rest) = parse_node(type_name)?;
+        if !rest.trim().is_empty() {
+            return Err(format!(
+                "invalid type name `{type_name}`: unexpected `{rest}` at the end"
+            ));
+        }
+        Ok(node)
+    }
+
+    /// The type argument of single-argument generic types, such as `Option<T>` or `List<T>`.
+    pub fn single_arg(&self) -> Result<&AbiTypeNode, String> {
+        match self.args.as_slice() {
+            [arg] => Ok(arg),
+            _ => Err(format!(
+                "type `{self}` should have exactly one type argument"
+            )),
+        }
+    }
+
+    /// The size of fixed-size arrays, e.g. `array32<u8>` has size 32.
+    pub fn array_size(&self) -> Option<usize> {
+        self.name
+            .strip_prefix(ARRAY_PREFIX)
+            .and_then(|size| size.parse().ok())
+    }
+}
+
+impl Display for AbiTypeNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)?;
+        if !self.args.is_empty() {
+            f.write_str("<")?;
+            for (i, arg) in self.args.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(",")?;
+                }
+                arg.fmt(f)?;
+            }
+            f.write_str(">")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses one node from the start of the input, and returns what is left after it.
+fn parse_node(input: &str) -> Result<(AbiTypeNode, &str), String> {
+    let name_end = input.find(['<', ',', '>']).unwrap_or(input.len());
+    let name = input[..name_end].trim();
+    if name.is_empty() {
+        return Err(format!("missing type name before `{input}`"));
+    }
+
+    let mut rest = &input[name_end..];
+    let mut args = Vec::new();
+    if let Some(after_open) = rest.strip_prefix('<') {
+        rest = after_open;
+        loop {
+            let (arg, after_arg) = parse_node(rest)?;
+            args.push(arg);
+            if let Some(after_comma) = after_arg.strip_prefix(',') {
+                rest = after_comma;
+            } else if let Some(after_close) = after_arg.strip_prefix('>') {
+                rest = after_close;
+                break;
+            } else {
+                return Err(format!("unclosed type arguments for `{name}`"));
+            }
+        }
+    }
+
+    Ok((
+        AbiTypeNode {
+            name: name.to_string(),
+            args,
+        },
+        rest,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(name: &str) -> AbiTypeNode {
+        AbiTypeNode {
+            name: name.to_string(),
+            args: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn parse_type_names() {
+        assert_eq!(AbiTypeNode::parse("u32").unwrap(), leaf("u32"));
+        assert_eq!(
+            AbiTypeNode::parse("utf-8 string").unwrap(),
+            leaf("utf-8 string")
+        );
+
+        let node = AbiTypeNode::parse("variadic<multi<Address,Option<List<u8>>>>").unwrap();
+        assert_eq!(node.name, "variadic");
+        let multi = node.single_arg().unwrap();
+        assert_eq!(multi.args.len(), 2);
+        assert_eq!(multi.args[0], leaf("Address"));
+        assert_eq!(multi.args[1].to_string(), "Option<List<u8>>");
+        assert_eq!(
+            node.to_string(),
+            "variadic<multi<Address,Option<List<u8>>>>"
+        );
+
+        assert_eq!(
+            AbiTypeNode::parse("array32<u8>").unwrap().array_size(),
+            Some(32)
+        );
+        assert_eq!(AbiTypeNode::parse("List<u8>").unwrap().array_size(), None);
+    }
+
+    #[test]
+    fn parse_invalid_type_names() {
+        assert!(AbiTypeNode::parse("").is_err());
+        assert!(AbiTypeNode::parse("Option<u8").is_err());
+        assert!(AbiTypeNode::parse("Option<u8>>").is_err());
+        assert!(AbiTypeNode::parse("tuple<u8,>").is_err());
+    }
+}
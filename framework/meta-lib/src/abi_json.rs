@@ -4,6 +4,7 @@ mod endpoint_abi_json;
 mod esdt_attribute_abi_json;
 mod esdt_attribute_json;
 mod event_abi_json;
+mod storage_abi_json;
 mod type_abi_json;
 
 pub use build_info_abi_json::{BuildInfoAbiJson, RustcAbiJson};
@@ -12,6 +13,7 @@ pub use endpoint_abi_json::*;
 pub use esdt_attribute_abi_json::EsdtAttributeAbiJson;
 pub use esdt_attribute_json::EsdtAttributeJson;
 pub use event_abi_json::*;
+pub use storage_abi_json::*;
 use multiversx_sc::{abi::ContractAbi, contract_base::ContractAbiProvider};
 pub use type_abi_json::*;
 
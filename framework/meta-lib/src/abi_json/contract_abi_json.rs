@@ -35,6 +35,10 @@ pub struct ContractAbiJson {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub events: Vec<EventAbiJson>,
 
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub storage: Vec<StorageAbiJson>,
+
     #[serde(default)]
     pub esdt_attributes: Vec<EsdtAttributeJson>,
 
@@ -63,6 +67,7 @@ impl From<&ContractAbi> for ContractAbiJson {
                 .map(|endpoint| endpoint.name.to_string())
                 .collect(),
             events: abi.events.iter().map(EventAbiJson::from).collect(),
+            storage: abi.storage.iter().map(StorageAbiJson::from).collect(),
             has_callback: abi.has_callback,
             types: convert_type_descriptions_to_json(&abi.type_descriptions),
             esdt_attributes: abi
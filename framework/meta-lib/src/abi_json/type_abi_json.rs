@@ -102,6 +102,12 @@ pub struct StructFieldDescriptionJson {
 
     #[serde(rename = "type")]
     pub field_type: String,
+
+    /// Bool that is only serialized when true
+    #[serde(rename = "defaultIfMissing")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_if_missing: Option<bool>,
 }
 
 impl From<&StructFieldDescription> for StructFieldDescriptionJson {
@@ -110,6 +116,11 @@ impl From<&StructFieldDescription> for StructFieldDescriptionJson {
             docs: abi.docs.iter().map(|d| d.to_string()).collect(),
             name: abi.name.to_string(),
             field_type: abi.field_type.abi.clone(),
+            default_if_missing: if abi.default_if_missing {
+                Some(true)
+            } else {
+                None
+            },
         }
     }
 }
@@ -123,6 +134,7 @@ impl StructFieldDescriptionJson {
                 abi: self.field_type.clone(),
                 rust: "<unspecified>".into(),
             },
+            default_if_missing: self.default_if_missing.unwrap_or_default(),
         }
     }
 }
@@ -1,15 +1,15 @@
 use std::fmt::Display;
 
 use multiversx_sc::abi::{
-    EndpointAbi, EnumVariantDescription, InputAbi, OutputAbi, StructFieldDescription, TypeContents,
-    TypeDescription,
+    EndpointAbi, EnumVariantDescription, EventAbi, InputAbi, OutputAbi, StructFieldDescription,
+    TypeContents, TypeDescription,
 };
 
 use crate::contract::{meta_config::MetaConfig, sc_config::proxy_config::ProxyConfig};
 
 use super::proxy_process_type_name::{
-    extract_paths, extract_struct_crate, process_rust_type, proxy_methods_type_name,
-    proxy_type_name,
+    event_type_name, extract_paths, extract_struct_crate, process_rust_type,
+    proxy_methods_type_name, proxy_type_name,
 };
 
 const PRELUDE: &str = "// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
@@ -76,6 +76,9 @@ impl<'a> ProxyGenerator<'a> {
         self.write_struct_tx_proxy_methods();
         self.write_content();
         self.write_types();
+        if self.proxy_config.add_events {
+            self.write_events();
+        }
     }
 
     fn write_header(&mut self) {
@@ -174,6 +177,98 @@ where
         }
     }
 
+    fn write_events(&mut self) {
+        for event_abi in self.proxy_config.abi.events.clone() {
+            self.write_event(&event_abi);
+        }
+    }
+
+    fn write_event(&mut self, event_abi: &EventAbi) {
+        let name = event_type_name(&event_abi.rust_method_name);
+        let fields: Vec<(String, String)> = event_abi
+            .inputs
+            .iter()
+            .map(|input| {
+                (
+                    input.arg_name.clone(),
+                    self.adjust_type_name_with_api(&input.type_name_rust),
+                )
+            })
+            .collect();
+        let generics = if fields
+            .iter()
+            .any(|(_, field_type)| field_type.contains("<Api"))
+        {
+            "<Api>"
+        } else {
+            ""
+        };
+        let where_clause = if generics.is_empty() {
+            " "
+        } else {
+            "\nwhere\n    Api: ManagedTypeApi,\n"
+        };
+
+        self.writeln("");
+        for doc in &event_abi.docs {
+            self.writeln(format!("/// {doc}"));
+        }
+        self.write(format!("pub struct {name}{generics}{where_clause}{{"));
+        if fields.is_empty() {
+            self.writeln("}");
+        } else {
+            self.writeln("");
+            for (field_name, field_type) in &fields {
+                self.writeln(format!("    pub {field_name}: {field_type},"));
+            }
+            self.writeln("}");
+        }
+
+        let identifier = &event_abi.identifier;
+        let log_arg = if fields.is_empty() { "_log" } else { "log" };
+        self.writeln(format!(
+            r#"
+#[rustfmt::skip]
+impl{generics} TypedEvent for {name}{generics}{where_clause}{{
+    const IDENTIFIER: &'static str = "{identifier}";
+
+    fn decode_event({log_arg}: &mut EventLogInput) -> Result<Self, DecodeError> {{"#
+        ));
+        self.write(format!("        Ok({name} {{"));
+        if fields.is_empty() {
+            self.writeln("})");
+        } else {
+            self.writeln("");
+            for input in &event_abi.inputs {
+                let decode_method = if input.indexed { "next_topic" } else { "data" };
+                self.writeln(format!(
+                    "            {}: {log_arg}.{decode_method}()?,",
+                    input.arg_name
+                ));
+            }
+            self.writeln("        })");
+        }
+        self.writeln("    }");
+        self.writeln("");
+        self.writeln(format!(
+            "    fn encode_event(&self, {log_arg}: &mut EventLogOutput) -> Result<(), EncodeError> {{"
+        ));
+        for input in &event_abi.inputs {
+            let encode_method = if input.indexed {
+                "push_topic"
+            } else {
+                "set_data"
+            };
+            self.writeln(format!(
+                "        {log_arg}.{encode_method}(&self.{})?;",
+                input.arg_name
+            ));
+        }
+        self.writeln("        Ok(())");
+        self.writeln("    }");
+        self.writeln("}");
+    }
+
     fn write_constructors(&mut self) {
         let constructors: Vec<EndpointAbi> = self.proxy_config.abi.constructors.clone();
 
@@ -449,7 +544,16 @@ where
 
         self.writeln("");
 
+        // without an attribute, the codec gives each variant the previous discriminant + 1
+        let mut implicit_discriminant = 0;
         for variant in enum_variants {
+            if variant.discriminant != implicit_discriminant {
+                self.writeln(format!(
+                    "    #[codec(discriminant = {})]",
+                    variant.discriminant
+                ));
+            }
+            implicit_discriminant = variant.discriminant + 1;
             self.write(format!("    {}", variant.name));
             if variant.fields.is_empty() {
                 self.writeln(",");
@@ -481,6 +585,7 @@ where
         self.writeln("");
 
         for field in struct_fields {
+            self.write_field_codec_attributes(field, "    ");
             let adjusted_type_name = self.adjust_type_name_with_api(&field.field_type.rust);
             self.writeln(format!("    pub {}: {adjusted_type_name},", field.name));
         }
@@ -494,6 +599,9 @@ where
             if i > 0 {
                 self.write(", ");
             }
+            for codec_attribute in field_codec_attributes(field) {
+                self.write(format!("{codec_attribute} "));
+            }
             let adjusted_type_name = self.adjust_type_name_with_api(&field.field_type.rust);
             self.write(adjusted_type_name);
         }
@@ -505,6 +613,7 @@ where
         self.writeln(" {");
 
         for field in fields {
+            self.write_field_codec_attributes(field, "        ");
             let adjusted_type_name = self.adjust_type_name_with_api(&field.field_type.rust);
             self.writeln(format!("        {}: {adjusted_type_name},", field.name,));
         }
@@ -512,6 +621,12 @@ where
         self.writeln("    },");
     }
 
+    fn write_field_codec_attributes(&mut self, field: &StructFieldDescription, indent: &str) {
+        for codec_attribute in field_codec_attributes(field) {
+            self.writeln(format!("{indent}{codec_attribute}"));
+        }
+    }
+
     pub fn clean_paths(&mut self, rust_type: &str) -> String {
         let paths = extract_paths(rust_type);
 
@@ -620,6 +735,15 @@ where
     }
 }
 
+/// Codec attributes need to be replicated in the proxy types, to keep the same encoding.
+fn field_codec_attributes(field: &StructFieldDescription) -> Vec<String> {
+    let mut codec_attributes = Vec::new();
+    if field.default_if_missing {
+        codec_attributes.push("#[codec(default)]".to_string());
+    }
+    codec_attributes
+}
+
 #[cfg(test)]
 pub mod tests {
     use multiversx_sc::abi::{BuildInfoAbi, ContractAbi, ContractCrateBuildAbi, FrameworkBuildAbi};
@@ -6,6 +6,25 @@ pub(super) fn proxy_methods_type_name(contract_trait_name: &str) -> String {
     format!("{contract_trait_name}ProxyMethods")
 }
 
+/// The name of the struct generated for an event, based on the name of the event method.
+///
+/// E.g. `deposit_event` becomes `DepositEvent`, and `deposit` also becomes `DepositEvent`.
+pub(super) fn event_type_name(event_method_name: &str) -> String {
+    let mut type_name: String = event_method_name
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            core::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect();
+    if !type_name.ends_with("Event") {
+        type_name.push_str("Event");
+    }
+    type_name
+}
+
 pub(super) fn extract_struct_crate(struct_path: &str) -> String {
     let crate_name = struct_path.split("::").next().unwrap_or(struct_path);
     crate_name.to_string()
@@ -197,6 +197,7 @@ fn build_contract_abi(builder: ContractVariantBuilder, original_abi: &ContractAb
         endpoints,
         promise_callbacks,
         events: original_abi.events.clone(),
+        storage: original_abi.storage.clone(),
         has_callback,
         type_descriptions: original_abi.type_descriptions.clone(),
         esdt_attributes: original_abi.esdt_attributes.clone(),
@@ -329,6 +330,7 @@ fn process_proxy_contracts(config: &ScConfigSerde, original_abi: &ContractAbi) -
                 proxy_config.path.to_owned(),
                 proxy_config.override_import.to_owned(),
                 proxy_config.path_rename.to_owned(),
+                proxy_config.add_events,
                 contract.abi,
             ));
         }
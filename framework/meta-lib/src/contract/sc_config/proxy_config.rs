@@ -8,6 +8,7 @@ pub struct ProxyConfig {
     pub path: String,
     pub override_import: String,
     pub path_rename: Vec<PathRename>,
+    pub add_events: bool,
     pub abi: ContractAbi,
 }
 
@@ -16,12 +17,14 @@ impl ProxyConfig {
         path: String,
         override_imports: Option<String>,
         path_rename: Option<Vec<PathRename>>,
+        add_events: Option<bool>,
         abi: ContractAbi,
     ) -> Self {
         ProxyConfig {
             path,
             override_import: override_imports.unwrap_or_default(),
             path_rename: path_rename.unwrap_or_default(),
+            add_events: add_events.unwrap_or_default(),
             abi,
         }
     }
@@ -31,6 +34,7 @@ impl ProxyConfig {
             path: DEFAULT_PATH.to_string(),
             override_import: String::new(),
             path_rename: Vec::new(),
+            add_events: false,
             abi,
         }
     }
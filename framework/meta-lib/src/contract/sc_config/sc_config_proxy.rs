@@ -29,6 +29,13 @@ pub struct ProxyConfigSerde {
     #[serde(default)]
     #[serde(rename = "add-endpoints")]
     pub add_endpoints: Vec<String>,
+
+    /// Generates typed structs for the contract events, to decode logs in tests and interactors.
+    ///
+    /// All event argument types need to be both encodable and decodable.
+    #[serde(default)]
+    #[serde(rename = "add-events")]
+    pub add_events: Option<bool>,
 }
 
 impl ProxyConfigSerde {
@@ -41,6 +48,7 @@ impl ProxyConfigSerde {
             add_unlabelled: None,
             add_labels: Vec::new(),
             add_endpoints: Vec::new(),
+            add_events: None,
         }
     }
 }
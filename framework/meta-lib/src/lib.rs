@@ -1,3 +1,4 @@
+pub mod abi_codec;
 pub mod abi_json;
 pub mod cargo_toml;
 pub mod cli;
@@ -0,0 +1,487 @@
+use multiversx_sc::{
+    abi::{TypeAbi, TypeDescriptionContainer, TypeDescriptionContainerImpl},
+    codec::{
+        dep_encode_to_vec,
+        num_bigint::{BigInt, BigUint},
+        top_encode_to_vec_u8,
+    },
+    derive_imports::*,
+    types::heap::Address,
+};
+use multiversx_sc_meta_lib::{
+    abi_codec::AbiCodec,
+    abi_json::{convert_type_descriptions_to_json, deserialize_abi_from_json},
+};
+use serde_json::{json, Value};
+
+const OWNER_HEX: &str = "0139472eff6886771a982f3083da5d421f24c29181e63888228dc81ca60d69e1";
+const OWNER_BECH32: &str = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub enum Status {
+    Inactive,
+    Active,
+    #[codec(discriminant = 5)]
+    Paused,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub enum Action {
+    Nothing,
+    Transfer { to: Address, amount: BigUint },
+    Batch(Vec<u16>, Option<u32>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct Record {
+    pub id: u64,
+    pub delta: i32,
+    pub name: String,
+    pub data: Vec<u8>,
+    pub pair: (u8, bool),
+    pub status: Status,
+    pub actions: Vec<Action>,
+    #[codec(default)]
+    pub added_later: u8,
+}
+
+const ENDPOINTS_ABI_JSON: &str = r#"{
+    "name": "AbiCodecTest",
+    "constructor": {
+        "inputs": [
+            {
+                "name": "initial_status",
+                "type": "Status"
+            }
+        ],
+        "outputs": []
+    },
+    "endpoints": [
+        {
+            "name": "deposit",
+            "mutability": "mutable",
+            "inputs": [
+                {
+                    "name": "owner",
+                    "type": "Address"
+                },
+                {
+                    "name": "amounts",
+                    "type": "counted-variadic<BigUint>",
+                    "multi_arg": true
+                },
+                {
+                    "name": "memo",
+                    "type": "optional<utf-8 string>",
+                    "multi_arg": true
+                }
+            ],
+            "outputs": [
+                {
+                    "type": "Record"
+                },
+                {
+                    "type": "variadic<multi<u32,Status>>",
+                    "multi_result": true
+                }
+            ]
+        }
+    ]
+}"#;
+
+fn abi_codec() -> AbiCodec {
+    let mut type_descriptions = TypeDescriptionContainerImpl::new();
+    Record::provide_type_descriptions(&mut type_descriptions);
+    let mut abi = deserialize_abi_from_json(ENDPOINTS_ABI_JSON).unwrap();
+    abi.types = convert_type_descriptions_to_json(&type_descriptions);
+    AbiCodec::new(abi)
+}
+
+fn owner() -> Address {
+    Address::from_slice(&hex::decode(OWNER_HEX).unwrap())
+}
+
+fn sample_record() -> Record {
+    Record {
+        id: 1_000_000_000_000,
+        delta: -7,
+        name: "record".to_string(),
+        data: vec![0xca, 0xfe],
+        pair: (3, true),
+        status: Status::Paused,
+        actions: vec![
+            Action::Nothing,
+            Action::Transfer {
+                to: owner(),
+                amount: BigUint::from(10u32).pow(20),
+            },
+            Action::Batch(vec![1, 2], None),
+        ],
+        added_later: 9,
+    }
+}
+
+fn sample_record_json() -> Value {
+    json!({
+        "id": 1_000_000_000_000u64,
+        "delta": -7,
+        "name": "record",
+        "data": "cafe",
+        "pair": [3, true],
+        "status": "Paused",
+        "actions": [
+            "Nothing",
+            { "Transfer": { "to": OWNER_BECH32, "amount": "100000000000000000000" } },
+            { "Batch": { "0": [1, 2], "1": null } }
+        ],
+        "added_later": 9
+    })
+}
+
+/// Checks that the ABI codec produces the same bytes as the derived codec, and decodes them back.
+fn check_same_as_derived<T>(abi_codec: &AbiCodec, type_name: &str, obj: &T, value: &Value)
+where
+    T: codec::TopEncode + codec::NestedEncode,
+{
+    let top_bytes = top_encode_to_vec_u8(obj).unwrap();
+    assert_eq!(abi_codec.encode_top(type_name, value).unwrap(), top_bytes);
+    assert_eq!(&abi_codec.decode_top(type_name, &top_bytes).unwrap(), value);
+
+    let nested_bytes = dep_encode_to_vec(obj).unwrap();
+    assert_eq!(
+        abi_codec.encode_nested(type_name, value).unwrap(),
+        nested_bytes
+    );
+    assert_eq!(
+        &abi_codec.decode_nested(type_name, &nested_bytes).unwrap(),
+        value
+    );
+}
+
+#[test]
+fn abi_codec_struct_test() {
+    let abi_codec = abi_codec();
+    check_same_as_derived(
+        &abi_codec,
+        "Record",
+        &sample_record(),
+        &sample_record_json(),
+    );
+}
+
+#[test]
+fn abi_codec_enum_test() {
+    let abi_codec = abi_codec();
+    check_same_as_derived(&abi_codec, "Status", &Status::Inactive, &json!("Inactive"));
+    check_same_as_derived(&abi_codec, "Status", &Status::Active, &json!("Active"));
+    check_same_as_derived(&abi_codec, "Status", &Status::Paused, &json!("Paused"));
+    check_same_as_derived(&abi_codec, "Action", &Action::Nothing, &json!("Nothing"));
+    check_same_as_derived(
+        &abi_codec,
+        "Action",
+        &Action::Batch(vec![], Some(4)),
+        &json!({ "Batch": { "0": [], "1": 4 } }),
+    );
+
+    // top-encoded discriminant 0 is empty
+    assert!(abi_codec
+        .encode_top("Status", &json!("Inactive"))
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        abi_codec.decode_top("Status", &[2]).unwrap_err(),
+        "invalid Status discriminant 2"
+    );
+}
+
+#[test]
+fn abi_codec_default_if_missing_test() {
+    let abi_codec = abi_codec();
+
+    let mut bytes = top_encode_to_vec_u8(&sample_record()).unwrap();
+    bytes.pop();
+    let mut expected = sample_record_json();
+    expected["added_later"] = json!(0);
+    assert_eq!(abi_codec.decode_top("Record", &bytes).unwrap(), expected);
+
+    let mut value = sample_record_json();
+    value.as_object_mut().unwrap().remove("added_later");
+    assert_eq!(
+        abi_codec.encode_top("Record", &value).unwrap().last(),
+        Some(&0)
+    );
+
+    value.as_object_mut().unwrap().remove("name");
+    assert_eq!(
+        abi_codec.encode_top("Record", &value).unwrap_err(),
+        "missing Record field `name`"
+    );
+}
+
+#[test]
+fn abi_codec_input_formats_test() {
+    let abi_codec = abi_codec();
+    let address_bytes = owner().as_bytes().to_vec();
+    assert_eq!(
+        abi_codec.encode_top("Address", &json!(OWNER_HEX)).unwrap(),
+        address_bytes
+    );
+    assert_eq!(
+        abi_codec.decode_top("Address", &address_bytes).unwrap(),
+        json!(OWNER_BECH32)
+    );
+    assert_eq!(
+        abi_codec.encode_top("u64", &json!("258")).unwrap(),
+        vec![1, 2]
+    );
+    assert_eq!(
+        abi_codec.encode_top("BigUint", &json!(258)).unwrap(),
+        vec![1, 2]
+    );
+    assert!(abi_codec.encode_top("()", &Value::Null).unwrap().is_empty());
+    assert_eq!(abi_codec.decode_top("()", &[]).unwrap(), Value::Null);
+    assert!(abi_codec.encode_top("u8", &json!(256)).is_err());
+    assert!(abi_codec.encode_top("Address", &json!("cafe")).is_err());
+    assert!(abi_codec.decode_top("u16", &[1, 2, 3]).is_err());
+    assert!(abi_codec.decode_nested("u16", &[1, 2, 3]).is_err());
+}
+
+#[test]
+fn abi_codec_errors_test() {
+    let abi_codec = abi_codec();
+    let mut value = sample_record_json();
+    value["unknown"] = json!(1);
+    assert_eq!(
+        abi_codec.encode_top("Record", &value).unwrap_err(),
+        "unknown Record field `unknown`"
+    );
+    assert_eq!(
+        abi_codec
+            .encode_top("Status", &json!("Closed"))
+            .unwrap_err(),
+        "unknown Status variant `Closed`"
+    );
+    assert_eq!(
+        abi_codec.encode_top("Unknown", &json!(1)).unwrap_err(),
+        "unknown type `Unknown`"
+    );
+    assert!(abi_codec
+        .encode_top("Action", &json!({ "Nothing": { "a": 1 } }))
+        .is_err());
+}
+
+#[test]
+fn abi_codec_endpoint_test() {
+    let abi_codec = abi_codec();
+
+    let args = abi_codec
+        .encode_endpoint_args("deposit", &[json!(OWNER_BECH32), json!(["1", "2"])])
+        .unwrap();
+    assert_eq!(
+        args,
+        vec![owner().as_bytes().to_vec(), vec![2], vec![1], vec![2]]
+    );
+
+    let args = abi_codec
+        .encode_endpoint_args("deposit", &[json!(OWNER_BECH32), json!([]), json!("memo")])
+        .unwrap();
+    assert_eq!(
+        args,
+        vec![owner().as_bytes().to_vec(), vec![], b"memo".to_vec()]
+    );
+
+    assert!(abi_codec
+        .encode_endpoint_args(
+            "deposit",
+            &[json!(OWNER_BECH32), json!([]), json!("memo"), json!(1)]
+        )
+        .is_err());
+    assert_eq!(
+        abi_codec.encode_endpoint_args("missing", &[]).unwrap_err(),
+        "endpoint `missing` not found in the ABI"
+    );
+
+    let init_args = abi_codec
+        .encode_endpoint_args("init", &[json!("Active")])
+        .unwrap();
+    assert_eq!(init_args, vec![vec![1]]);
+
+    let results = vec![
+        top_encode_to_vec_u8(&sample_record()).unwrap(),
+        vec![7],
+        vec![5],
+        vec![],
+        vec![],
+    ];
+    assert_eq!(
+        abi_codec
+            .decode_endpoint_results("deposit", &results)
+            .unwrap(),
+        vec![
+            sample_record_json(),
+            json!([[7, "Paused"], [0, "Inactive"]])
+        ]
+    );
+    assert!(abi_codec
+        .decode_endpoint_results("deposit", &results[..2])
+        .is_err());
+}
+
+#[test]
+fn abi_codec_multi_test() {
+    let abi_codec = abi_codec();
+    let args = abi_codec
+        .encode_multi("variadic<multi<u8,bool>>", &json!([[1, true], [2, false]]))
+        .unwrap();
+    assert_eq!(args, vec![vec![1], vec![1], vec![2], vec![]]);
+    assert_eq!(
+        abi_codec
+            .decode_multi("variadic<multi<u8,bool>>", &args)
+            .unwrap(),
+        json!([[1, true], [2, false]])
+    );
+
+    assert!(abi_codec
+        .encode_multi("optional<u8>", &Value::Null)
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        abi_codec.decode_multi("optional<u8>", &[]).unwrap(),
+        Value::Null
+    );
+    assert_eq!(
+        abi_codec
+            .decode_multi("u8", &[vec![1], vec![2]])
+            .unwrap_err(),
+        "too many arguments"
+    );
+
+    // the unit type takes no arguments
+    let args = abi_codec
+        .encode_multi("multi<u8,()>", &json!([1, null]))
+        .unwrap();
+    assert_eq!(args, vec![vec![1]]);
+    assert_eq!(
+        abi_codec.decode_multi("multi<u8,()>", &args).unwrap(),
+        json!([1, null])
+    );
+    assert!(abi_codec.decode_multi("variadic<()>", &args).is_err());
+    assert!(abi_codec.decode_top("List<()>", &[1]).is_err());
+}
+
+#[test]
+fn abi_codec_managed_decimal_test() {
+    let abi_codec = abi_codec();
+    let raw_units = BigUint::from(150u32);
+
+    // constant decimals: only the raw units
+    let top_bytes = top_encode_to_vec_u8(&raw_units).unwrap();
+    let nested_bytes = dep_encode_to_vec(&raw_units).unwrap();
+    assert_eq!(
+        abi_codec
+            .encode_top("ManagedDecimal<2>", &json!("1.50"))
+            .unwrap(),
+        top_bytes
+    );
+    assert_eq!(
+        abi_codec
+            .encode_top("ManagedDecimal<2>", &json!("1.5"))
+            .unwrap(),
+        top_bytes
+    );
+    assert_eq!(
+        abi_codec
+            .encode_nested("ManagedDecimal<2>", &json!("1.50"))
+            .unwrap(),
+        nested_bytes
+    );
+    assert_eq!(
+        abi_codec
+            .decode_top("ManagedDecimal<2>", &top_bytes)
+            .unwrap(),
+        json!("1.50")
+    );
+    assert_eq!(
+        abi_codec
+            .decode_top("ManagedDecimal<4>", &top_bytes)
+            .unwrap(),
+        json!("0.0150")
+    );
+    assert_eq!(
+        abi_codec
+            .encode_top("ManagedDecimal<0>", &json!(150))
+            .unwrap(),
+        top_bytes
+    );
+
+    // variable decimals: the nested raw units, followed by the number of decimals, also at top level
+    let mut var_bytes = nested_bytes.clone();
+    var_bytes.extend_from_slice(&dep_encode_to_vec(&2u32).unwrap());
+    for encoded in [
+        abi_codec.encode_top("ManagedDecimal<usize>", &json!("1.50")),
+        abi_codec.encode_nested("ManagedDecimal<usize>", &json!("1.50")),
+    ] {
+        assert_eq!(encoded.unwrap(), var_bytes);
+    }
+    assert_eq!(
+        abi_codec
+            .decode_top("ManagedDecimal<usize>", &var_bytes)
+            .unwrap(),
+        json!("1.50")
+    );
+
+    // signed
+    let negative = dep_encode_to_vec(&BigInt::from(-5)).unwrap();
+    assert_eq!(
+        abi_codec
+            .encode_nested("ManagedDecimalSigned<2>", &json!("-0.05"))
+            .unwrap(),
+        negative
+    );
+    assert_eq!(
+        abi_codec
+            .decode_nested("ManagedDecimalSigned<2>", &negative)
+            .unwrap(),
+        json!("-0.05")
+    );
+
+    assert_eq!(
+        abi_codec
+            .encode_top("ManagedDecimal<1>", &json!("1.50"))
+            .unwrap_err(),
+        "cannot encode \"1.50\" as ManagedDecimal<1>: more than 1 decimals"
+    );
+    assert!(abi_codec
+        .encode_top("ManagedDecimal<2>", &json!("-1"))
+        .is_err());
+    assert!(abi_codec
+        .encode_top("ManagedDecimal<2>", &json!("1.5x"))
+        .is_err());
+    assert!(abi_codec
+        .encode_top("ManagedDecimal<Test>", &json!("1"))
+        .is_err());
+}
+
+#[test]
+fn abi_codec_bytes_read_to_end_test() {
+    let abi_codec = abi_codec();
+    let tuple_type = "tuple<u8,bytes-read-to-end>";
+    let value = json!([1, "cafe"]);
+    assert_eq!(
+        abi_codec.encode_nested(tuple_type, &value).unwrap(),
+        vec![1, 0xca, 0xfe]
+    );
+    assert_eq!(
+        abi_codec
+            .decode_nested(tuple_type, &[1, 0xca, 0xfe])
+            .unwrap(),
+        value
+    );
+    assert_eq!(
+        abi_codec.decode_nested(tuple_type, &[1]).unwrap(),
+        json!([1, ""])
+    );
+}
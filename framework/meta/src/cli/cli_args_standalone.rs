@@ -79,6 +79,24 @@ pub enum StandaloneCliAction {
     )]
     LocalDeps(LocalDepsArgs),
 
+    #[command(
+        name = "storage-diff",
+        about = "Compares the storage layouts of two contract ABIs and flags incompatible changes."
+    )]
+    StorageDiff(StorageDiffArgs),
+
+    #[command(
+        name = "query",
+        about = "Queries a contract endpoint, encoding the arguments and decoding the results as JSON, based on the contract ABI."
+    )]
+    Query(QueryArgs),
+
+    #[command(
+        name = "call",
+        about = "Calls a contract endpoint in a transaction, encoding the arguments and decoding the results as JSON, based on the contract ABI."
+    )]
+    Call(CallArgs),
+
     #[command(
         name = "wallet",
         about = "Generates a new wallet or performs actions on an existing wallet."
@@ -244,6 +262,86 @@ pub struct ConvertArgs {
     pub output: PathBuf,
 }
 
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct StorageDiffArgs {
+    /// Path to the ABI JSON of the currently deployed version of the contract.
+    pub old: PathBuf,
+
+    /// Path to the ABI JSON of the new version of the contract.
+    pub new: PathBuf,
+
+    /// Also fails if some keys cannot be verified, because their types are unknown in both versions.
+    /// Default value will be "false" if not specified.
+    #[arg(long, default_value = "false", verbatim_doc_comment)]
+    pub strict: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct ContractEndpointArgs {
+    /// The gateway to send the request to, e.g. https://devnet-gateway.multiversx.com
+    #[arg(long, verbatim_doc_comment)]
+    pub gateway: String,
+
+    /// Path to the ABI JSON of the contract.
+    #[arg(long, verbatim_doc_comment)]
+    pub abi: PathBuf,
+
+    /// The bech32 address of the contract.
+    #[arg(long, verbatim_doc_comment)]
+    pub address: String,
+
+    /// The name of the endpoint, as it appears in the ABI.
+    #[arg(long, verbatim_doc_comment)]
+    pub endpoint: String,
+
+    /// The endpoint arguments, as a JSON array with one value for each ABI input.
+    /// Trailing optional and variadic arguments can be left out.
+    #[arg(long, default_value = "[]", verbatim_doc_comment)]
+    pub args: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct QueryArgs {
+    #[command(flatten)]
+    pub contract: ContractEndpointArgs,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct CallArgs {
+    #[command(flatten)]
+    pub contract: ContractEndpointArgs,
+
+    /// PEM file of the wallet that signs the transaction.
+    #[arg(long, required_unless_present = "keystore", verbatim_doc_comment)]
+    pub pem: Option<String>,
+
+    /// Keystore file of the wallet that signs the transaction.
+    /// The password is read from the standard input.
+    #[arg(long, conflicts_with = "pem", verbatim_doc_comment)]
+    pub keystore: Option<String>,
+
+    /// The gas limit of the transaction.
+    #[arg(long, verbatim_doc_comment)]
+    pub gas: u64,
+
+    /// EGLD to transfer, in the smallest denomination (1 EGLD = 10^18).
+    #[arg(long, conflicts_with = "esdt", verbatim_doc_comment)]
+    pub egld: Option<String>,
+
+    /// ESDT to transfer, as TOKEN-ID:AMOUNT or TOKEN-ID:NONCE:AMOUNT, in the smallest denomination.
+    /// Can be repeated for a multi-transfer.
+    #[arg(long, verbatim_doc_comment)]
+    pub esdt: Vec<String>,
+
+    /// Generates blocks until the transaction is processed, when running against the chain simulator.
+    #[arg(
+        long = "chain-simulator",
+        default_value = "false",
+        verbatim_doc_comment
+    )]
+    pub chain_simulator: bool,
+}
+
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
 pub struct AllArgs {
     #[command(subcommand)]
@@ -1,6 +1,8 @@
 use crate::cli::{StandaloneCliAction, StandaloneCliArgs};
 use crate::cmd::chain_simulator::chain_simulator;
+use crate::cmd::contract_call::{call, query};
 use crate::cmd::retrieve_address::retrieve_address;
+use crate::cmd::storage_diff::storage_diff;
 use crate::cmd::wallet::wallet;
 use clap::Parser;
 
@@ -48,6 +50,15 @@ pub async fn cli_main_standalone() {
         Some(StandaloneCliAction::LocalDeps(args)) => {
             local_deps(args);
         },
+        Some(StandaloneCliAction::StorageDiff(args)) => {
+            storage_diff(args);
+        },
+        Some(StandaloneCliAction::Query(args)) => {
+            query(args).await;
+        },
+        Some(StandaloneCliAction::Call(args)) => {
+            call(args).await;
+        },
         Some(StandaloneCliAction::Wallet(args)) => {
             wallet(args);
         },
@@ -1,12 +1,14 @@
 pub mod all;
 pub mod chain_simulator;
 pub mod code_report;
+pub mod contract_call;
 pub mod info;
 pub mod install;
 pub mod local_deps;
 pub mod print_util;
 pub mod retrieve_address;
 pub mod scen_test_gen;
+pub mod storage_diff;
 pub mod template;
 pub mod test;
 pub mod test_coverage;
@@ -0,0 +1,245 @@
+use std::{
+    fs,
+    io::{self, Write},
+    process,
+};
+
+use colored::Colorize;
+use multiversx_sc_meta_lib::abi_codec::AbiCodec;
+use multiversx_sc_snippets::{
+    imports::*,
+    multiversx_sc::codec::num_bigint,
+    multiversx_sc_scenario::scenario_model::TxResponseStatus,
+    sdk::{bech32, gateway::GatewayAsyncService},
+};
+use serde_json::Value;
+
+use crate::cli::{CallArgs, ContractEndpointArgs, QueryArgs};
+
+/// Queries the endpoint and prints the decoded results.
+pub async fn query(args: &QueryArgs) {
+    let contract = &args.contract;
+    let request = EndpointRequest::new(contract).unwrap_or_else(|err| exit_with_error(&err));
+
+    let results = {
+        let _stdout_redirect = StdoutToStderr::redirect();
+        let mut interactor = Interactor::new(&contract.gateway).await;
+        request.query(&mut interactor).await
+    };
+    print_results(results);
+}
+
+/// Sends a transaction to the endpoint, waits for it to be processed and prints the decoded results.
+pub async fn call(args: &CallArgs) {
+    let contract = &args.contract;
+    let request = EndpointRequest::new(contract).unwrap_or_else(|err| exit_with_error(&err));
+    let payment = parse_payment(args).unwrap_or_else(|err| exit_with_error(&err));
+    let results = {
+        let _stdout_redirect = StdoutToStderr::redirect();
+        let wallet = load_wallet(args);
+        let mut interactor = Interactor::new(&contract.gateway)
+            .await
+            .use_chain_simulator(args.chain_simulator);
+        let sender = interactor.register_wallet(wallet).await;
+        request
+            .call(&mut interactor, &sender, args.gas, payment)
+            .await
+    };
+    print_results(results);
+}
+
+/// The wallet and the interactor print their progress to the standard output.
+///
+/// While alive, it sends all of it to the standard error instead,
+/// so that only the results end up in the standard output.
+struct StdoutToStderr {
+    #[cfg(unix)]
+    saved_stdout: libc::c_int,
+}
+
+impl StdoutToStderr {
+    fn redirect() -> Self {
+        let _ = io::stdout().flush();
+        StdoutToStderr {
+            #[cfg(unix)]
+            saved_stdout: unsafe {
+                let saved_stdout = libc::dup(libc::STDOUT_FILENO);
+                libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO);
+                saved_stdout
+            },
+        }
+    }
+}
+
+impl Drop for StdoutToStderr {
+    fn drop(&mut self) {
+        let _ = io::stdout().flush();
+        #[cfg(unix)]
+        unsafe {
+            libc::dup2(self.saved_stdout, libc::STDOUT_FILENO);
+            libc::close(self.saved_stdout);
+        }
+    }
+}
+
+/// A contract endpoint, with its arguments already encoded based on the ABI.
+///
+/// Everything is checked before connecting to the gateway.
+pub struct EndpointRequest {
+    abi_codec: AbiCodec,
+    address: Bech32Address,
+    endpoint: String,
+    raw_args: ManagedArgBuffer<StaticApi>,
+}
+
+impl EndpointRequest {
+    /// Reads the ABI, checks the contract address and encodes the arguments.
+    pub fn new(contract: &ContractEndpointArgs) -> Result<Self, String> {
+        let abi_codec = read_abi_codec(contract)?;
+        let address = bech32::try_decode(&contract.address)
+            .map(Bech32Address::from)
+            .map_err(|err| format!("invalid contract address: {err}"))?;
+        let raw_args = parse_json_args(&contract.args)
+            .and_then(|args| abi_codec.encode_endpoint_args(&contract.endpoint, &args))?;
+        Ok(EndpointRequest {
+            abi_codec,
+            address,
+            endpoint: contract.endpoint.clone(),
+            raw_args: ManagedArgBuffer::from(raw_args),
+        })
+    }
+
+    /// Queries the endpoint and decodes the results.
+    pub async fn query<GatewayProxy>(
+        &self,
+        interactor: &mut InteractorBase<GatewayProxy>,
+    ) -> Result<Vec<Value>, String>
+    where
+        GatewayProxy: GatewayAsyncService,
+    {
+        let response = interactor
+            .query()
+            .to(&self.address)
+            .raw_call(self.endpoint.as_str())
+            .arguments_raw(self.raw_args.clone())
+            .returns(ReturnsHandledOrError::new().returns(ReturnsRawResult))
+            .run()
+            .await;
+        self.decode_response(response)
+    }
+
+    /// Sends a transaction to the endpoint, waits for it to be processed and decodes the results.
+    pub async fn call<GatewayProxy>(
+        &self,
+        interactor: &mut InteractorBase<GatewayProxy>,
+        sender: &Address,
+        gas: u64,
+        payment: EgldOrMultiEsdtPayment<StaticApi>,
+    ) -> Result<Vec<Value>, String>
+    where
+        GatewayProxy: GatewayAsyncService,
+    {
+        let response = interactor
+            .tx()
+            .from(sender)
+            .to(&self.address)
+            .gas(gas)
+            .raw_call(self.endpoint.as_str())
+            .arguments_raw(self.raw_args.clone())
+            .payment(payment)
+            .returns(ReturnsHandledOrError::new().returns(ReturnsRawResult))
+            .run()
+            .await;
+        self.decode_response(response)
+    }
+
+    fn decode_response(
+        &self,
+        response: Result<ManagedVec<StaticApi, ManagedBuffer<StaticApi>>, TxResponseStatus>,
+    ) -> Result<Vec<Value>, String> {
+        let raw_results =
+            response.map_err(|tx_err| format!("`{}` failed: {}", self.endpoint, tx_err.message))?;
+        let raw_results: Vec<Vec<u8>> = raw_results.iter().map(|result| result.to_vec()).collect();
+        self.abi_codec
+            .decode_endpoint_results(&self.endpoint, &raw_results)
+            .map_err(|err| format!("cannot decode results: {err}"))
+    }
+}
+
+/// Parses the `--args` value, which needs to be a JSON array.
+pub fn parse_json_args(args_json: &str) -> Result<Vec<Value>, String> {
+    match serde_json::from_str(args_json) {
+        Ok(Value::Array(args)) => Ok(args),
+        Ok(other) => Err(format!("arguments must be a JSON array, got {other}")),
+        Err(err) => Err(format!("invalid arguments JSON: {err}")),
+    }
+}
+
+/// Parses an ESDT payment, given as `TOKEN-ID:AMOUNT` or `TOKEN-ID:NONCE:AMOUNT`.
+pub fn parse_esdt_payment(payment: &str) -> Result<EsdtTokenPayment<StaticApi>, String> {
+    let (token_identifier, nonce, amount) = match payment.split(':').collect::<Vec<_>>()[..] {
+        [token_identifier, amount] => (token_identifier, "0", amount),
+        [token_identifier, nonce, amount] => (token_identifier, nonce, amount),
+        _ => return Err(format!("invalid ESDT payment `{payment}`")),
+    };
+    let nonce = nonce
+        .parse::<u64>()
+        .map_err(|_| format!("invalid nonce in ESDT payment `{payment}`"))?;
+    Ok(EsdtTokenPayment::new(
+        TokenIdentifier::from(token_identifier),
+        nonce,
+        parse_amount(amount)?,
+    ))
+}
+
+fn parse_amount(amount: &str) -> Result<BigUint<StaticApi>, String> {
+    amount
+        .parse::<num_bigint::BigUint>()
+        .map(BigUint::from)
+        .map_err(|_| format!("invalid amount `{amount}`"))
+}
+
+fn parse_payment(args: &CallArgs) -> Result<EgldOrMultiEsdtPayment<StaticApi>, String> {
+    if !args.esdt.is_empty() {
+        let mut payments = ManagedVec::new();
+        for esdt in &args.esdt {
+            payments.push(parse_esdt_payment(esdt)?);
+        }
+        return Ok(EgldOrMultiEsdtPayment::MultiEsdt(payments));
+    }
+
+    let egld_amount = match &args.egld {
+        Some(egld) => parse_amount(egld)?,
+        None => BigUint::zero(),
+    };
+    Ok(EgldOrMultiEsdtPayment::Egld(egld_amount))
+}
+
+fn load_wallet(args: &CallArgs) -> Wallet {
+    let wallet = match (&args.pem, &args.keystore) {
+        (Some(pem), _) => Wallet::from_pem_file(pem),
+        (None, Some(keystore)) => {
+            Wallet::from_keystore_secret(keystore, InsertPassword::StandardInput)
+        },
+        (None, None) => exit_with_error("either --pem or --keystore is required"),
+    };
+    wallet.unwrap_or_else(|err| exit_with_error(&format!("cannot load wallet: {err}")))
+}
+
+fn read_abi_codec(contract: &ContractEndpointArgs) -> Result<AbiCodec, String> {
+    let abi_json = fs::read_to_string(&contract.abi)
+        .map_err(|err| format!("failed to read ABI file {}: {err}", contract.abi.display()))?;
+    AbiCodec::from_abi_json(&abi_json)
+        .map_err(|err| format!("failed to parse ABI file {}: {err}", contract.abi.display()))
+}
+
+/// Only the JSON results go to the standard output, so that they can be piped into other tools.
+fn print_results(results: Result<Vec<Value>, String>) {
+    let results = results.unwrap_or_else(|err| exit_with_error(&err));
+    println!("{}", serde_json::to_string_pretty(&results).unwrap());
+}
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("{} {message}", "error:".red().bold());
+    process::exit(1);
+}
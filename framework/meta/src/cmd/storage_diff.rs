@@ -0,0 +1,291 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path, process};
+
+use colored::Colorize;
+use multiversx_sc::abi::STORAGE_UNKNOWN_TYPE;
+use multiversx_sc_meta_lib::abi_json::{
+    deserialize_abi_from_json, ContractAbiJson, StorageAbiJson, TypeDescriptionJson,
+};
+
+use crate::cli::StorageDiffArgs;
+
+/// A difference between the storage layouts of two versions of a contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageChange {
+    /// New storage key, harmless for existing data.
+    Added { key: String },
+
+    /// The key is no longer declared, its data will be orphaned.
+    Removed { key: String },
+
+    /// The same key is now accessed through a different mapper, which usually lays out data differently.
+    MapperChanged {
+        key: String,
+        old: String,
+        new: String,
+    },
+
+    /// The arguments that get appended to the key changed.
+    KeyArgsChanged {
+        key: String,
+        old: Vec<String>,
+        new: Vec<String>,
+    },
+
+    /// The stored types changed, existing data might not decode anymore.
+    ValueTypesChanged {
+        key: String,
+        old: Vec<String>,
+        new: Vec<String>,
+    },
+
+    /// The type name is unchanged, but its structure (fields, variants) is different.
+    TypeDescriptionChanged { key: String, type_name: String },
+
+    /// The stored types do not implement `TypeAbi`, so they appear as `unknown` in both versions.
+    ///
+    /// Any change in their encoding goes unnoticed.
+    Unverifiable { key: String },
+}
+
+impl StorageChange {
+    /// Changes that can break the decoding of data already in storage.
+    pub fn is_breaking(&self) -> bool {
+        !matches!(
+            self,
+            StorageChange::Added { .. } | StorageChange::Unverifiable { .. }
+        )
+    }
+
+    /// Keys whose compatibility cannot be determined from the ABI.
+    pub fn is_unverifiable(&self) -> bool {
+        matches!(self, StorageChange::Unverifiable { .. })
+    }
+}
+
+impl fmt::Display for StorageChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageChange::Added { key } => write!(f, "added key `{key}`"),
+            StorageChange::Removed { key } => write!(f, "removed key `{key}`"),
+            StorageChange::MapperChanged { key, old, new } => {
+                write!(f, "key `{key}`: mapper changed from {old} to {new}")
+            },
+            StorageChange::KeyArgsChanged { key, old, new } => write!(
+                f,
+                "key `{key}`: key arguments changed from [{}] to [{}]",
+                old.join(", "),
+                new.join(", ")
+            ),
+            StorageChange::ValueTypesChanged { key, old, new } => write!(
+                f,
+                "key `{key}`: value types changed from [{}] to [{}]",
+                old.join(", "),
+                new.join(", ")
+            ),
+            StorageChange::TypeDescriptionChanged { key, type_name } => {
+                write!(f, "key `{key}`: structure of type {type_name} changed")
+            },
+            StorageChange::Unverifiable { key } => write!(
+                f,
+                "key `{key}`: stored types are {STORAGE_UNKNOWN_TYPE} in both versions, cannot verify"
+            ),
+        }
+    }
+}
+
+pub fn storage_diff(args: &StorageDiffArgs) {
+    let old_abi = read_abi(&args.old);
+    let new_abi = read_abi(&args.new);
+    let changes = compare_storage_layouts(&old_abi, &new_abi);
+
+    if changes.is_empty() {
+        println!("{}", "Storage layouts are identical.".green());
+        return;
+    }
+
+    for change in &changes {
+        if change.is_breaking() {
+            println!("{} {change}", "error:".red().bold());
+        } else if change.is_unverifiable() {
+            println!("{} {change}", "warning:".yellow().bold());
+        } else {
+            println!("{} {change}", "info:".truecolor(128, 128, 128));
+        }
+    }
+
+    let num_breaking = changes.iter().filter(|change| change.is_breaking()).count();
+    let num_unverifiable = changes
+        .iter()
+        .filter(|change| change.is_unverifiable())
+        .count();
+    if num_breaking > 0 {
+        println!(
+            "\n{}",
+            format!("Found {num_breaking} incompatible storage change(s).").red()
+        );
+        process::exit(1);
+    }
+    if num_unverifiable > 0 && args.strict {
+        println!(
+            "\n{}",
+            format!("Could not verify {num_unverifiable} storage key(s).").red()
+        );
+        process::exit(1);
+    }
+}
+
+fn read_abi(path: &Path) -> ContractAbiJson {
+    let json = fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("failed to read ABI file {}: {err}", path.display()));
+    deserialize_abi_from_json(&json)
+        .unwrap_or_else(|err| panic!("failed to parse ABI file {}: {err}", path.display()))
+}
+
+/// Compares the storage sections of two ABIs, key by key.
+///
+/// Type names alone are not enough to detect all incompatibilities,
+/// so the descriptions of all types reachable from the stored types are also compared.
+pub fn compare_storage_layouts(
+    old_abi: &ContractAbiJson,
+    new_abi: &ContractAbiJson,
+) -> Vec<StorageChange> {
+    let mut changes = Vec::new();
+    for old_storage in &old_abi.storage {
+        let Some(new_storage) = new_abi.storage.iter().find(|s| s.key == old_storage.key) else {
+            changes.push(StorageChange::Removed {
+                key: old_storage.key.clone(),
+            });
+            continue;
+        };
+
+        let key = &old_storage.key;
+        if old_storage.mapper != new_storage.mapper {
+            changes.push(StorageChange::MapperChanged {
+                key: key.clone(),
+                old: old_storage.mapper.clone(),
+                new: new_storage.mapper.clone(),
+            });
+        }
+
+        let old_key_args = key_arg_types(old_storage);
+        let new_key_args = key_arg_types(new_storage);
+        if old_key_args != new_key_args {
+            changes.push(StorageChange::KeyArgsChanged {
+                key: key.clone(),
+                old: old_key_args,
+                new: new_key_args,
+            });
+        }
+
+        if old_storage.value_types != new_storage.value_types {
+            changes.push(StorageChange::ValueTypesChanged {
+                key: key.clone(),
+                old: old_storage.value_types.clone(),
+                new: new_storage.value_types.clone(),
+            });
+        } else if old_storage
+            .value_types
+            .iter()
+            .any(|type_name| type_name == STORAGE_UNKNOWN_TYPE)
+        {
+            changes.push(StorageChange::Unverifiable { key: key.clone() });
+        } else {
+            for type_name in reachable_types(&old_storage.value_types, &old_abi.types) {
+                if type_description_changed(&type_name, old_abi, new_abi) {
+                    changes.push(StorageChange::TypeDescriptionChanged {
+                        key: key.clone(),
+                        type_name,
+                    });
+                }
+            }
+        }
+    }
+
+    for new_storage in &new_abi.storage {
+        if !old_abi.storage.iter().any(|s| s.key == new_storage.key) {
+            changes.push(StorageChange::Added {
+                key: new_storage.key.clone(),
+            });
+        }
+    }
+
+    changes
+}
+
+fn key_arg_types(storage: &StorageAbiJson) -> Vec<String> {
+    storage
+        .key_args
+        .iter()
+        .map(|arg| arg.type_name.clone())
+        .collect()
+}
+
+fn type_description_changed(
+    type_name: &str,
+    old_abi: &ContractAbiJson,
+    new_abi: &ContractAbiJson,
+) -> bool {
+    let old_description = old_abi.types.get(type_name).map(type_description_to_value);
+    let new_description = new_abi.types.get(type_name).map(type_description_to_value);
+    old_description != new_description
+}
+
+/// Docs do not influence the encoding, so they are ignored.
+fn type_description_to_value(type_description: &TypeDescriptionJson) -> serde_json::Value {
+    let mut value = serde_json::to_value(type_description).unwrap();
+    strip_docs(&mut value);
+    value
+}
+
+fn strip_docs(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            map.remove("docs");
+            map.values_mut().for_each(strip_docs);
+        },
+        serde_json::Value::Array(items) => items.iter_mut().for_each(strip_docs),
+        _ => {},
+    }
+}
+
+/// All described types that appear in the given type names, directly, as generic arguments,
+/// or as fields of other described types.
+fn reachable_types(
+    type_names: &[String],
+    types: &BTreeMap<String, TypeDescriptionJson>,
+) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut pending: Vec<String> = type_names.to_vec();
+    while let Some(type_name) = pending.pop() {
+        for component in type_name_components(&type_name) {
+            if result.contains(&component) {
+                continue;
+            }
+            if let Some(type_description) = types.get(&component) {
+                result.push(component);
+                for field in &type_description.fields {
+                    pending.push(field.field_type.clone());
+                }
+                for variant in &type_description.variants {
+                    for field in &variant.fields {
+                        pending.push(field.field_type.clone());
+                    }
+                }
+            }
+        }
+    }
+    result.sort();
+    result
+}
+
+/// Splits a type name such as `List<Option<MyStruct>>` into the names it is built from.
+fn type_name_components(type_name: &str) -> Vec<String> {
+    let mut components = vec![type_name.to_string()];
+    components.extend(
+        type_name
+            .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+            .filter(|part| !part.is_empty())
+            .map(str::to_string),
+    );
+    components
+}
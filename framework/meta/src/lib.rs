@@ -2,6 +2,7 @@ pub mod cli;
 pub mod cmd;
 pub mod folder_structure;
 
+pub use multiversx_sc_meta_lib::abi_codec;
 pub use multiversx_sc_meta_lib::abi_json;
 pub use multiversx_sc_meta_lib::ei;
 pub use multiversx_sc_meta_lib::ei_check_json;
@@ -0,0 +1,126 @@
+use clap::Parser;
+use multiversx_sc::types::{BigUint, TokenIdentifier};
+use multiversx_sc_meta::{
+    cli::{StandaloneCliAction, StandaloneCliArgs},
+    cmd::contract_call::{parse_esdt_payment, parse_json_args},
+};
+use multiversx_sc_snippets::imports::StaticApi;
+use serde_json::json;
+
+const CONTRACT_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgqfzydqmdw7m2vazsp6u5p95yxz76t2p9rd8ss0zp9ts";
+
+#[test]
+fn parse_json_args_test() {
+    assert_eq!(
+        parse_json_args(r#"[1, "erd1", {"a": null}]"#).unwrap(),
+        vec![json!(1), json!("erd1"), json!({"a": null})]
+    );
+    assert!(parse_json_args("[]").unwrap().is_empty());
+    assert!(parse_json_args(r#"{"a": 1}"#).is_err());
+    assert!(parse_json_args("[1,").is_err());
+}
+
+#[test]
+fn parse_esdt_payment_test() {
+    let payment = parse_esdt_payment("TOKEN-123456:1000").unwrap();
+    assert_eq!(
+        payment.token_identifier,
+        TokenIdentifier::from("TOKEN-123456")
+    );
+    assert_eq!(payment.token_nonce, 0);
+    assert_eq!(payment.amount, BigUint::<StaticApi>::from(1000u32));
+
+    let payment = parse_esdt_payment("NFT-123456:5:100000000000000000000").unwrap();
+    assert_eq!(
+        payment.token_identifier,
+        TokenIdentifier::from("NFT-123456")
+    );
+    assert_eq!(payment.token_nonce, 5);
+    assert_eq!(
+        payment.amount,
+        BigUint::<StaticApi>::from(100_000_000_000_000_000_000u128)
+    );
+
+    assert!(parse_esdt_payment("TOKEN-123456").is_err());
+    assert!(parse_esdt_payment("TOKEN-123456:x:1").is_err());
+    assert!(parse_esdt_payment("TOKEN-123456:-1").is_err());
+    assert!(parse_esdt_payment("TOKEN-123456:1:2:3").is_err());
+}
+
+#[test]
+fn parse_call_cli_args_test() {
+    let cli_args = StandaloneCliArgs::try_parse_from([
+        "sc-meta",
+        "call",
+        "--gateway",
+        "http://localhost:8085",
+        "--abi",
+        "adder.abi.json",
+        "--address",
+        CONTRACT_ADDRESS,
+        "--endpoint",
+        "add",
+        "--args",
+        "[5]",
+        "--pem",
+        "alice.pem",
+        "--gas",
+        "5000000",
+        "--esdt",
+        "TOKEN-123456:10",
+        "--esdt",
+        "NFT-123456:1:1",
+    ])
+    .unwrap();
+    let Some(StandaloneCliAction::Call(call_args)) = cli_args.command else {
+        panic!("expected call command");
+    };
+    assert_eq!(call_args.contract.endpoint, "add");
+    assert_eq!(call_args.contract.args, "[5]");
+    assert_eq!(call_args.gas, 5_000_000);
+    assert_eq!(call_args.esdt.len(), 2);
+
+    // a signer is required, and EGLD cannot be combined with ESDT
+    let base_args = [
+        "sc-meta",
+        "call",
+        "--gateway",
+        "http://localhost:8085",
+        "--abi",
+        "adder.abi.json",
+        "--address",
+        CONTRACT_ADDRESS,
+        "--endpoint",
+        "add",
+        "--gas",
+        "5000000",
+    ];
+    assert!(StandaloneCliArgs::try_parse_from(base_args).is_err());
+    assert!(StandaloneCliArgs::try_parse_from(base_args.iter().chain(&[
+        "--pem",
+        "alice.pem",
+        "--egld",
+        "1",
+        "--esdt",
+        "TOKEN-123456:1"
+    ]))
+    .is_err());
+
+    let cli_args = StandaloneCliArgs::try_parse_from([
+        "sc-meta",
+        "query",
+        "--gateway",
+        "http://localhost:8085",
+        "--abi",
+        "adder.abi.json",
+        "--address",
+        CONTRACT_ADDRESS,
+        "--endpoint",
+        "getSum",
+    ])
+    .unwrap();
+    let Some(StandaloneCliAction::Query(query_args)) = cli_args.command else {
+        panic!("expected query command");
+    };
+    assert_eq!(query_args.contract.args, "[]");
+}
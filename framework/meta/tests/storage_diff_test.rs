@@ -0,0 +1,142 @@
+use multiversx_sc_meta::{
+    abi_json::deserialize_abi_from_json,
+    cmd::storage_diff::{compare_storage_layouts, StorageChange},
+};
+
+const OLD_ABI_JSON: &str = r#"{
+    "name": "Adder",
+    "endpoints": [],
+    "storage": [
+        {
+            "key": "sum",
+            "mapper": "SingleValueMapper",
+            "view": "getSum",
+            "valueTypes": ["BigUint"]
+        },
+        {
+            "key": "users",
+            "mapper": "UnorderedSetMapper",
+            "valueTypes": ["Address"]
+        },
+        {
+            "key": "userInfo",
+            "mapper": "SingleValueMapper",
+            "keyArgs": [{"name": "user", "type": "Address"}],
+            "valueTypes": ["UserInfo"]
+        },
+        {
+            "key": "lastOperation",
+            "mapper": "SingleValueMapper",
+            "valueTypes": ["u64"]
+        }
+    ],
+    "types": {
+        "UserInfo": {
+            "type": "struct",
+            "fields": [
+                {"name": "deposit", "type": "BigUint"},
+                {"name": "status", "type": "Status"}
+            ]
+        },
+        "Status": {
+            "type": "enum",
+            "variants": [
+                {"name": "Active", "discriminant": 0},
+                {"name": "Inactive", "discriminant": 1}
+            ]
+        }
+    }
+}"#;
+
+#[test]
+fn storage_diff_identical_test() {
+    let old_abi = deserialize_abi_from_json(OLD_ABI_JSON).unwrap();
+    let new_abi = deserialize_abi_from_json(OLD_ABI_JSON).unwrap();
+    assert!(compare_storage_layouts(&old_abi, &new_abi).is_empty());
+}
+
+#[test]
+fn storage_diff_changes_test() {
+    let new_abi_json = OLD_ABI_JSON
+        .replace(r#""valueTypes": ["BigUint"]"#, r#""valueTypes": ["u64"]"#)
+        .replace(r#""UnorderedSetMapper""#, r#""VecMapper""#)
+        .replace(
+            r#""key": "lastOperation""#,
+            r#""key": "lastOperationTimestamp""#,
+        )
+        .replace(
+            r#"{"name": "Inactive", "discriminant": 1}"#,
+            r#"{"name": "Inactive", "discriminant": 1}, {"name": "Banned", "discriminant": 2}"#,
+        );
+    let old_abi = deserialize_abi_from_json(OLD_ABI_JSON).unwrap();
+    let new_abi = deserialize_abi_from_json(&new_abi_json).unwrap();
+
+    let changes = compare_storage_layouts(&old_abi, &new_abi);
+    assert_eq!(
+        changes,
+        vec![
+            StorageChange::ValueTypesChanged {
+                key: "sum".to_string(),
+                old: vec!["BigUint".to_string()],
+                new: vec!["u64".to_string()],
+            },
+            StorageChange::MapperChanged {
+                key: "users".to_string(),
+                old: "UnorderedSetMapper".to_string(),
+                new: "VecMapper".to_string(),
+            },
+            StorageChange::TypeDescriptionChanged {
+                key: "userInfo".to_string(),
+                type_name: "Status".to_string(),
+            },
+            StorageChange::Removed {
+                key: "lastOperation".to_string(),
+            },
+            StorageChange::Added {
+                key: "lastOperationTimestamp".to_string(),
+            },
+        ]
+    );
+    assert!(changes[..4].iter().all(StorageChange::is_breaking));
+    assert!(!changes[4].is_breaking());
+}
+
+#[test]
+fn storage_diff_ignores_docs_test() {
+    let new_abi_json = OLD_ABI_JSON.replace(
+        r#""type": "struct","#,
+        r#""type": "struct", "docs": ["Per-user data."],"#,
+    );
+    let old_abi = deserialize_abi_from_json(OLD_ABI_JSON).unwrap();
+    let new_abi = deserialize_abi_from_json(&new_abi_json).unwrap();
+    assert!(compare_storage_layouts(&old_abi, &new_abi).is_empty());
+}
+
+#[test]
+fn storage_diff_unknown_types_test() {
+    let old_abi_json =
+        OLD_ABI_JSON.replace(r#""valueTypes": ["u64"]"#, r#""valueTypes": ["unknown"]"#);
+    let old_abi = deserialize_abi_from_json(&old_abi_json).unwrap();
+
+    // unknown in both versions, nothing can be said about compatibility
+    let changes = compare_storage_layouts(&old_abi, &old_abi);
+    assert_eq!(
+        changes,
+        vec![StorageChange::Unverifiable {
+            key: "lastOperation".to_string(),
+        }]
+    );
+    assert!(!changes[0].is_breaking());
+    assert!(changes[0].is_unverifiable());
+
+    // becoming known is a regular type change
+    let new_abi = deserialize_abi_from_json(OLD_ABI_JSON).unwrap();
+    assert_eq!(
+        compare_storage_layouts(&old_abi, &new_abi),
+        vec![StorageChange::ValueTypesChanged {
+            key: "lastOperation".to_string(),
+            old: vec!["unknown".to_string()],
+            new: vec!["u64".to_string()],
+        }]
+    );
+}
@@ -3,6 +3,8 @@ use multiversx_sc::{
     types::{Address, EsdtLocalRoleFlags, H256},
 };
 
+use multiversx_chain_vm::executor::{MemLength, MemPtr};
+
 use crate::api::{i32_to_bool, VMHooksApi, VMHooksApiBackend};
 
 impl<VHB: VMHooksApiBackend> BlockchainApi for VMHooksApi<VHB> {
@@ -36,6 +38,18 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
         self.with_vm_hooks(|vh| vh.managed_owner_address(dest.get_raw_handle_unchecked()));
     }
 
+    fn load_original_caller_managed(&self, dest: Self::ManagedBufferHandle) {
+        self.assert_live_handle(&dest);
+        self.with_vm_hooks(|vh| {
+            vh.managed_get_original_caller_addr(dest.get_raw_handle_unchecked())
+        });
+    }
+
+    fn load_relayer_managed(&self, dest: Self::ManagedBufferHandle) {
+        self.assert_live_handle(&dest);
+        self.with_vm_hooks(|vh| vh.managed_get_relayer_addr(dest.get_raw_handle_unchecked()));
+    }
+
     fn get_shard_of_address_legacy(&self, _address: &Address) -> u32 {
         panic!("legacy BlockchainApi functionality no longer supported")
     }
@@ -73,8 +87,9 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
         });
     }
 
-    fn load_state_root_hash_managed(&self, _dest: Self::ManagedBufferHandle) {
-        panic!("state root hash not implemented")
+    fn load_state_root_hash_managed(&self, dest: Self::ManagedBufferHandle) {
+        self.assert_live_handle(&dest);
+        self.with_vm_hooks(|vh| vh.managed_get_state_root_hash(dest.get_raw_handle_unchecked()));
     }
 
     fn get_tx_hash_legacy(&self) -> H256 {
@@ -86,6 +101,18 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
         self.with_vm_hooks(|vh| vh.managed_get_original_tx_hash(dest.get_raw_handle_unchecked()));
     }
 
+    fn get_current_tx_hash_legacy(&self) -> H256 {
+        let mut res = H256::zero();
+        self.with_vm_hooks(|vh| vh.get_current_tx_hash(res.as_mut_ptr() as MemPtr));
+        res
+    }
+
+    fn get_prev_tx_hash_legacy(&self) -> H256 {
+        let mut res = H256::zero();
+        self.with_vm_hooks(|vh| vh.get_prev_tx_hash(res.as_mut_ptr() as MemPtr));
+        res
+    }
+
     fn get_gas_left(&self) -> u64 {
         self.with_vm_hooks(|vh| vh.get_gas_left()) as u64
     }
@@ -138,6 +165,12 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
         });
     }
 
+    fn get_block_hash_legacy(&self, nonce: u64) -> H256 {
+        let mut res = H256::zero();
+        self.with_vm_hooks(|vh| vh.get_block_hash(nonce as i64, res.as_mut_ptr() as MemPtr));
+        res
+    }
+
     fn get_current_esdt_nft_nonce(
         &self,
         address_handle: Self::ManagedBufferHandle,
@@ -286,4 +319,31 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
             )
         });
     }
+
+    fn set_storage_lock(&self, key: &[u8], lock_timestamp: u64) {
+        self.with_vm_hooks(|vh| {
+            vh.set_storage_lock(
+                key.as_ptr() as MemPtr,
+                key.len() as MemLength,
+                lock_timestamp as i64,
+            )
+        });
+    }
+
+    fn get_storage_lock(&self, key: &[u8]) -> u64 {
+        self.with_vm_hooks(|vh| vh.get_storage_lock(key.as_ptr() as MemPtr, key.len() as MemLength))
+            as u64
+    }
+
+    fn is_storage_locked(&self, key: &[u8]) -> bool {
+        i32_to_bool(self.with_vm_hooks(|vh| {
+            vh.is_storage_locked(key.as_ptr() as MemPtr, key.len() as MemLength)
+        }))
+    }
+
+    fn clear_storage_lock(&self, key: &[u8]) {
+        self.with_vm_hooks(|vh| {
+            vh.clear_storage_lock(key.as_ptr() as MemPtr, key.len() as MemLength)
+        });
+    }
 }
@@ -1,8 +1,21 @@
-use multiversx_chain_vm::mem_conv;
-use multiversx_sc::api::{const_handles, RawHandle, SendApi, SendApiImpl};
+use multiversx_chain_vm::{executor::MemPtr, mem_conv};
+use multiversx_sc::{
+    api::{const_handles, RawHandle, SendApi, SendApiImpl},
+    types::heap::ArgBuffer,
+};
 
 use crate::api::{VMHooksApi, VMHooksApiBackend};
 
+/// The legacy VM hooks expect the argument lengths as 4-byte little endian integers,
+/// as they are laid out in wasm memory.
+fn legacy_arg_lengths(arg_buffer: &ArgBuffer) -> Vec<u8> {
+    arg_buffer
+        .arg_lengths()
+        .iter()
+        .flat_map(|arg_length| (*arg_length as i32).to_le_bytes())
+        .collect()
+}
+
 impl<VHB: VMHooksApiBackend> SendApi for VMHooksApi<VHB> {
     type SendApiImpl = Self;
 
@@ -191,6 +204,54 @@ impl<VHB: VMHooksApiBackend> SendApiImpl for VMHooksApi<VHB> {
         });
     }
 
+    fn deploy_from_source_contract_legacy(
+        &self,
+        gas: u64,
+        egld_value: &[u8; 32],
+        source_contract_address: &[u8; 32],
+        code_metadata: &[u8; 2],
+        arg_buffer: &ArgBuffer,
+        new_address: &mut [u8; 32],
+    ) {
+        let arg_lengths = legacy_arg_lengths(arg_buffer);
+        self.with_vm_hooks(|vh| {
+            vh.deploy_from_source_contract(
+                gas as i64,
+                egld_value.as_ptr() as MemPtr,
+                source_contract_address.as_ptr() as MemPtr,
+                code_metadata.as_ptr() as MemPtr,
+                new_address.as_mut_ptr() as MemPtr,
+                arg_buffer.num_args() as i32,
+                arg_lengths.as_ptr() as MemPtr,
+                arg_buffer.arg_data_ptr() as MemPtr,
+            )
+        });
+    }
+
+    fn upgrade_from_source_contract_legacy(
+        &self,
+        sc_address: &[u8; 32],
+        gas: u64,
+        egld_value: &[u8; 32],
+        source_contract_address: &[u8; 32],
+        code_metadata: &[u8; 2],
+        arg_buffer: &ArgBuffer,
+    ) {
+        let arg_lengths = legacy_arg_lengths(arg_buffer);
+        self.with_vm_hooks(|vh| {
+            vh.upgrade_from_source_contract(
+                sc_address.as_ptr() as MemPtr,
+                gas as i64,
+                egld_value.as_ptr() as MemPtr,
+                source_contract_address.as_ptr() as MemPtr,
+                code_metadata.as_ptr() as MemPtr,
+                arg_buffer.num_args() as i32,
+                arg_lengths.as_ptr() as MemPtr,
+                arg_buffer.arg_data_ptr() as MemPtr,
+            )
+        });
+    }
+
     fn upgrade_contract(
         &self,
         sc_address_handle: RawHandle,
@@ -217,6 +278,17 @@ impl<VHB: VMHooksApiBackend> SendApiImpl for VMHooksApi<VHB> {
         });
     }
 
+    fn delete_contract(
+        &self,
+        sc_address_handle: RawHandle,
+        gas: u64,
+        arg_buffer_handle: RawHandle,
+    ) {
+        self.with_vm_hooks(|vh| {
+            vh.managed_delete_contract(sc_address_handle, gas as i64, arg_buffer_handle)
+        });
+    }
+
     fn execute_on_dest_context_raw(
         &self,
         gas: u64,
@@ -240,25 +312,42 @@ impl<VHB: VMHooksApiBackend> SendApiImpl for VMHooksApi<VHB> {
 
     fn execute_on_same_context_raw(
         &self,
-        _gas: u64,
-        _to_handle: RawHandle,
-        _egld_value_handle: RawHandle,
-        _endpoint_name_handle: RawHandle,
-        _arg_buffer_handle: RawHandle,
-        _result_handle: RawHandle,
+        gas: u64,
+        to_handle: RawHandle,
+        egld_value_handle: RawHandle,
+        endpoint_name_handle: RawHandle,
+        arg_buffer_handle: RawHandle,
+        result_handle: RawHandle,
     ) {
-        panic!("execute_on_same_context_raw not implemented yet!");
+        self.with_vm_hooks(|vh| {
+            vh.managed_execute_on_same_context(
+                gas as i64,
+                to_handle,
+                egld_value_handle,
+                endpoint_name_handle,
+                arg_buffer_handle,
+                result_handle,
+            )
+        });
     }
 
     fn execute_on_dest_context_readonly_raw(
         &self,
-        _gas: u64,
-        _to_handle: RawHandle,
-        _endpoint_name_handle: RawHandle,
-        _arg_buffer_handle: RawHandle,
-        _result_handle: RawHandle,
+        gas: u64,
+        to_handle: RawHandle,
+        endpoint_name_handle: RawHandle,
+        arg_buffer_handle: RawHandle,
+        result_handle: RawHandle,
     ) {
-        panic!("execute_on_dest_context_readonly_raw not implemented yet!");
+        self.with_vm_hooks(|vh| {
+            vh.managed_execute_read_only(
+                gas as i64,
+                to_handle,
+                endpoint_name_handle,
+                arg_buffer_handle,
+                result_handle,
+            )
+        });
     }
 
     fn clean_return_data(&self) {
@@ -3,6 +3,7 @@ mod debugger_backend;
 pub mod expr;
 pub mod result_handlers;
 mod scenario_world;
+mod scenario_world_chain_time;
 mod scenario_world_register;
 mod scenario_world_runner;
 mod scenario_world_steps;
@@ -13,4 +14,5 @@ pub mod world_tx;
 
 pub use contract_info::ContractInfo;
 pub use scenario_world::ScenarioWorld;
+pub(crate) use scenario_world_chain_time::BlockProductionConfig;
 pub use whitebox_contract::WhiteboxContract;
@@ -0,0 +1,128 @@
+use multiversx_sc::{
+    log_util::{EventLogOutput, TypedEvent},
+    types::{
+        RHList, RHListAppendNoRet, RHListItem, RHListItemExec, Tx, TxData, TxEnv, TxFrom, TxGas,
+        TxPayment, TxTo,
+    },
+};
+
+use crate::scenario_model::TxResponse;
+
+use super::returns_events::{event_log_input, log_has_identifier};
+
+/// Verifies that the transaction emitted the given event, with exactly the given arguments.
+///
+/// Other events, of the same or of other types, are allowed.
+///
+/// Can only be used in tests and interactors, not available in contracts.
+pub struct ExpectEvent<E>(pub E);
+
+impl<Env, Original, E> RHListItem<Env, Original> for ExpectEvent<E>
+where
+    Env: TxEnv,
+    E: TypedEvent,
+{
+    type Returns = ();
+}
+
+impl<Env, Original, E> RHListItemExec<TxResponse, Env, Original> for ExpectEvent<E>
+where
+    Env: TxEnv,
+    E: TypedEvent,
+{
+    fn item_process_result(self, raw_result: &TxResponse) -> Self::Returns {
+        let mut expected = EventLogOutput::new();
+        self.0.encode_event(&mut expected).unwrap_or_else(|err| {
+            panic!(
+                "could not encode expected event `{}`: {}",
+                E::IDENTIFIER,
+                err.message_str()
+            )
+        });
+
+        let candidates: Vec<_> = raw_result
+            .logs
+            .iter()
+            .filter(|log| log_has_identifier(log, E::IDENTIFIER))
+            .map(event_log_input)
+            .collect();
+        if candidates
+            .iter()
+            .any(|actual| actual.topics == expected.topics && actual.data == expected.data)
+        {
+            return;
+        }
+
+        let mut message = format!(
+            "expected event `{}` not found\n  expected: {}",
+            E::IDENTIFIER,
+            format_event_log(&expected.topics, &expected.data)
+        );
+        if candidates.is_empty() {
+            message.push_str("\n  no events with this identifier were emitted");
+        }
+        for actual in &candidates {
+            message.push_str("\n  actual:   ");
+            message.push_str(&format_event_log(&actual.topics, &actual.data));
+        }
+        panic!("{message}");
+    }
+}
+
+fn format_event_log(topics: &[Vec<u8>], data: &[u8]) -> String {
+    let topics: Vec<String> = topics.iter().map(hex::encode).collect();
+    format!(
+        "topics: [{}], data: {}",
+        topics.join(", "),
+        hex::encode(data)
+    )
+}
+
+/// Adds the `expect_event` shorthand to all transactions.
+pub trait TxExpectEvent<Env, From, To, Payment, Gas, Data, ResultList>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    Data: TxData<Env>,
+    ResultList: RHList<Env>,
+{
+    /// Checks that the transaction emitted the given event.
+    ///
+    /// Shorthand for `.with_result(ExpectEvent(event))`.
+    fn expect_event<E>(
+        self,
+        event: E,
+    ) -> Tx<Env, From, To, Payment, Gas, Data, ResultList::NoRetOutput>
+    where
+        E: TypedEvent,
+        ExpectEvent<E>: RHListItem<Env, ResultList::OriginalResult, Returns = ()>,
+        ResultList: RHListAppendNoRet<Env, ExpectEvent<E>>;
+}
+
+impl<Env, From, To, Payment, Gas, Data, ResultList>
+    TxExpectEvent<Env, From, To, Payment, Gas, Data, ResultList>
+    for Tx<Env, From, To, Payment, Gas, Data, ResultList>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    Data: TxData<Env>,
+    ResultList: RHList<Env>,
+{
+    fn expect_event<E>(
+        self,
+        event: E,
+    ) -> Tx<Env, From, To, Payment, Gas, Data, ResultList::NoRetOutput>
+    where
+        E: TypedEvent,
+        ExpectEvent<E>: RHListItem<Env, ResultList::OriginalResult, Returns = ()>,
+        ResultList: RHListAppendNoRet<Env, ExpectEvent<E>>,
+    {
+        self.with_result(ExpectEvent(event))
+    }
+}
@@ -0,0 +1,71 @@
+use core::marker::PhantomData;
+
+use multiversx_sc::{
+    log_util::{EventLogInput, TypedEvent},
+    types::{RHListItem, RHListItemExec, TxEnv},
+};
+
+use crate::scenario_model::{Log, TxResponse};
+
+/// Decodes all events of a given type from the transaction logs, in the order they were emitted.
+///
+/// Logs with a different identifier are ignored.
+///
+/// Can only be used in tests and interactors, not available in contracts.
+pub struct ReturnsEvents<E>(PhantomData<E>);
+
+impl<E> ReturnsEvents<E> {
+    pub fn new() -> Self {
+        ReturnsEvents(PhantomData)
+    }
+}
+
+impl<E> Default for ReturnsEvents<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Env, Original, E> RHListItem<Env, Original> for ReturnsEvents<E>
+where
+    Env: TxEnv,
+    E: TypedEvent,
+{
+    type Returns = Vec<E>;
+}
+
+impl<Env, Original, E> RHListItemExec<TxResponse, Env, Original> for ReturnsEvents<E>
+where
+    Env: TxEnv,
+    E: TypedEvent,
+{
+    fn item_process_result(self, raw_result: &TxResponse) -> Self::Returns {
+        raw_result
+            .logs
+            .iter()
+            .filter(|log| log_has_identifier(log, E::IDENTIFIER))
+            .map(|log| {
+                E::decode_event(&mut event_log_input(log)).unwrap_or_else(|err| {
+                    panic!(
+                        "could not decode event `{}`: {}",
+                        E::IDENTIFIER,
+                        err.message_str()
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+pub(super) fn log_has_identifier(log: &Log, identifier: &str) -> bool {
+    log.topics
+        .first()
+        .is_some_and(|first_topic| first_topic.as_slice() == identifier.as_bytes())
+}
+
+/// The first topic is the event identifier, the rest are the indexed arguments.
+pub(super) fn event_log_input(log: &Log) -> EventLogInput {
+    let topics = log.topics.iter().skip(1).cloned().collect();
+    let data = log.data.first().cloned().unwrap_or_default();
+    EventLogInput::new(topics, data)
+}
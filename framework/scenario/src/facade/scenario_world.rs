@@ -1,4 +1,4 @@
-use multiversx_chain_vm::world_mock::BlockchainState;
+use multiversx_chain_vm::{schedule::GasSchedule, world_mock::BlockchainState};
 
 use crate::{
     scenario::{run_trace::ScenarioTrace, run_vm::ScenarioVMRunner},
@@ -7,7 +7,7 @@ use crate::{
 use multiversx_sc_meta_lib::tools::find_current_workspace;
 use std::path::{Path, PathBuf};
 
-use super::debugger_backend::DebuggerBackend;
+use super::{debugger_backend::DebuggerBackend, BlockProductionConfig};
 
 /// A facade for contracts tests.
 ///
@@ -18,6 +18,7 @@ use super::debugger_backend::DebuggerBackend;
 pub struct ScenarioWorld {
     pub(crate) current_dir: PathBuf,
     pub(crate) backend: Backend,
+    pub(crate) block_production: BlockProductionConfig,
 }
 
 pub(crate) enum Backend {
@@ -39,6 +40,7 @@ impl ScenarioWorld {
                 vm_runner: ScenarioVMRunner::new(),
                 trace: None,
             })),
+            block_production: BlockProductionConfig::default(),
         }
     }
 
@@ -51,6 +53,7 @@ impl ScenarioWorld {
         ScenarioWorld {
             current_dir: std::env::current_dir().unwrap(),
             backend: Backend::VmGoBackend,
+            block_production: BlockProductionConfig::default(),
         }
     }
 
@@ -98,6 +101,27 @@ impl ScenarioWorld {
             .state
     }
 
+    /// Enables gas metering in the debugger.
+    ///
+    /// Gas is charged for VM hooks and builtin functions, but not for the contract code itself.
+    pub fn gas_schedule(&mut self, gas_schedule: GasSchedule) -> &mut Self {
+        self.get_mut_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .set_gas_schedule(Some(gas_schedule));
+        self
+    }
+
+    /// Enables gas metering in the debugger, with a protocol gas schedule file (e.g. `gasScheduleV8.toml`).
+    ///
+    /// The path is relative to the current directory.
+    pub fn gas_schedule_file<P: AsRef<Path>>(&mut self, relative_path: P) -> &mut Self {
+        let mut absolute_path = self.current_dir.clone();
+        absolute_path.push(relative_path);
+        self.gas_schedule(GasSchedule::load_file(absolute_path))
+    }
+
     pub fn start_trace(&mut self) -> &mut Self {
         self.get_mut_debugger_backend().trace = Some(ScenarioTrace::default());
         self
@@ -0,0 +1,158 @@
+use std::time::Duration;
+
+use multiversx_chain_vm::{crypto_functions::sha256, world_mock::BlockInfo};
+
+use crate::{
+    facade::ScenarioWorld,
+    scenario::{model::SetStateStep, ScenarioRunner},
+};
+
+/// Mainnet round duration.
+const DEFAULT_ROUND_DURATION: Duration = Duration::from_secs(6);
+
+/// Mainnet epoch length, i.e. 24 hours.
+const DEFAULT_ROUNDS_PER_EPOCH: u64 = 14_400;
+
+const RANDOM_SEED_LEN: usize = 48;
+
+/// Determines how blocks are produced when advancing chain time in tests.
+///
+/// Each produced block occupies one round, there are no empty rounds.
+#[derive(Clone, Debug)]
+pub(crate) struct BlockProductionConfig {
+    pub(crate) round_duration: Duration,
+    pub(crate) rounds_per_epoch: u64,
+}
+
+impl Default for BlockProductionConfig {
+    fn default() -> Self {
+        BlockProductionConfig {
+            round_duration: DEFAULT_ROUND_DURATION,
+            rounds_per_epoch: DEFAULT_ROUNDS_PER_EPOCH,
+        }
+    }
+}
+
+impl BlockProductionConfig {
+    fn round_duration_seconds(&self) -> u64 {
+        self.round_duration.as_secs()
+    }
+
+    /// The block that comes `num_blocks` after `start`.
+    ///
+    /// The epoch changes whenever the round crosses a multiple of `rounds_per_epoch`.
+    fn block_after(&self, start: &BlockInfo, num_blocks: u64) -> BlockInfo {
+        if num_blocks == 0 {
+            return start.clone();
+        }
+
+        let block_nonce = start.block_nonce + num_blocks;
+        let block_round = start.block_round + num_blocks;
+        let epochs_passed =
+            block_round / self.rounds_per_epoch - start.block_round / self.rounds_per_epoch;
+        BlockInfo {
+            block_timestamp: start.block_timestamp + num_blocks * self.round_duration_seconds(),
+            block_nonce,
+            block_round,
+            block_epoch: start.block_epoch + epochs_passed,
+            block_random_seed: Box::new(random_seed_for_nonce(block_nonce)),
+        }
+    }
+}
+
+/// Deterministic, only depends on the block nonce.
+fn random_seed_for_nonce(block_nonce: u64) -> [u8; RANDOM_SEED_LEN] {
+    let first = sha256(&block_nonce.to_be_bytes());
+    let second = sha256(&first);
+    let mut seed = [0u8; RANDOM_SEED_LEN];
+    seed[..first.len()].copy_from_slice(&first);
+    seed[first.len()..].copy_from_slice(&second[..RANDOM_SEED_LEN - first.len()]);
+    seed
+}
+
+impl ScenarioWorld {
+    /// Sets the round duration used when advancing chain time. The default is 6 seconds.
+    ///
+    /// Block timestamps are in seconds, so it must be a whole number of seconds.
+    pub fn round_duration(&mut self, round_duration: Duration) -> &mut Self {
+        assert!(
+            round_duration.as_secs() > 0 && round_duration.subsec_nanos() == 0,
+            "round duration must be a whole, non-zero number of seconds"
+        );
+        self.block_production.round_duration = round_duration;
+        self
+    }
+
+    /// Sets the epoch length used when advancing chain time. The default is 14400 rounds.
+    pub fn rounds_per_epoch(&mut self, rounds_per_epoch: u64) -> &mut Self {
+        assert!(rounds_per_epoch > 0, "rounds per epoch must be non-zero");
+        self.block_production.rounds_per_epoch = rounds_per_epoch;
+        self
+    }
+
+    /// Produces `num_blocks` blocks, updating nonce, round, timestamp, epoch and random seed.
+    ///
+    /// The previous block info becomes the one of the last block before the new current block.
+    ///
+    /// Same as `generate_blocks` in the chain simulator interactor.
+    ///
+    /// Reads the current block from the blockchain mock, so it only works with the debugger backend,
+    /// it panics on a world created with `ScenarioWorld::vm_go()`.
+    pub fn advance_blocks(&mut self, num_blocks: u64) -> &mut Self {
+        if num_blocks == 0 {
+            return self;
+        }
+
+        let start = self.get_state().current_block_info.clone();
+        let previous = self.block_production.block_after(&start, num_blocks - 1);
+        let current = self.block_production.block_after(&start, num_blocks);
+
+        let mut step = SetStateStep::new()
+            .prev_block_timestamp(previous.block_timestamp)
+            .prev_block_nonce(previous.block_nonce)
+            .prev_block_round(previous.block_round)
+            .prev_block_epoch(previous.block_epoch)
+            .prev_block_random_seed(previous.block_random_seed.to_vec())
+            .block_timestamp(current.block_timestamp)
+            .block_nonce(current.block_nonce)
+            .block_round(current.block_round)
+            .block_epoch(current.block_epoch)
+            .block_random_seed(current.block_random_seed.to_vec());
+        step.comment = Some(format!("advance {num_blocks} block(s)"));
+        self.run_set_state_step(&step);
+        self
+    }
+
+    /// Produces enough blocks for at least `duration` to pass.
+    ///
+    /// The duration is rounded up to a whole number of rounds.
+    ///
+    /// Reads the current block from the blockchain mock, so it only works with the debugger backend,
+    /// it panics on a world created with `ScenarioWorld::vm_go()`.
+    pub fn advance_time(&mut self, duration: Duration) -> &mut Self {
+        let round_millis = self.block_production.round_duration.as_millis();
+        let num_blocks = duration.as_millis().div_ceil(round_millis);
+        self.advance_blocks(num_blocks as u64)
+    }
+
+    /// Produces blocks until the first round of the given epoch.
+    ///
+    /// Does nothing if the epoch was already reached.
+    ///
+    /// Same as `generate_blocks_until_epoch` in the chain simulator interactor.
+    ///
+    /// Reads the current block from the blockchain mock, so it only works with the debugger backend,
+    /// it panics on a world created with `ScenarioWorld::vm_go()`.
+    pub fn advance_to_epoch(&mut self, epoch: u64) -> &mut Self {
+        let start = &self.get_state().current_block_info;
+        if epoch <= start.block_epoch {
+            return self;
+        }
+
+        let rounds_per_epoch = self.block_production.rounds_per_epoch;
+        let target_round =
+            (start.block_round / rounds_per_epoch + epoch - start.block_epoch) * rounds_per_epoch;
+        let num_blocks = target_round - start.block_round;
+        self.advance_blocks(num_blocks)
+    }
+}
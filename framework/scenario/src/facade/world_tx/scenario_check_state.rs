@@ -31,6 +31,23 @@ impl ScenarioWorld {
         let address_value = address_annotated(&self.new_env_data(), &address);
         CheckStateBuilder::new(self, address_value.into())
     }
+
+    /// Checks that the account was deleted, or never existed.
+    ///
+    /// The only check that accepts a missing account, all others expect it to exist.
+    /// Not recorded in the scenario trace, since scenario files have no syntax for it.
+    pub fn check_account_deleted<A>(&mut self, address: A) -> &mut Self
+    where
+        A: AnnotatedValue<ScenarioTxEnvData, ManagedAddress<StaticApi>>,
+    {
+        let address_value = address_annotated(&self.new_env_data(), &address);
+        assert!(
+            !self.get_state().accounts.contains_key(&address_value.value),
+            "account expected to be deleted, but it exists. Address: {}",
+            address_value
+        );
+        self
+    }
 }
 
 pub struct CheckStateBuilder<'w> {
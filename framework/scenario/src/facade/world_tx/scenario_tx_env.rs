@@ -1,5 +1,7 @@
 use multiversx_chain_scenario_format::interpret_trait::InterpreterContext;
-use multiversx_sc::types::{ManagedAddress, ManagedBuffer, TxEnv, TxEnvWithTxHash, H256};
+use multiversx_sc::types::{
+    Address, ManagedAddress, ManagedBuffer, TxEnv, TxEnvWithRelayer, TxEnvWithTxHash, H256,
+};
 
 use crate::{api::StaticApi, scenario_model::TxExpect, ScenarioWorld};
 
@@ -13,6 +15,7 @@ pub trait ScenarioTxEnv: TxEnv {
 pub struct ScenarioTxEnvData {
     pub interpreter_context: InterpreterContext,
     pub tx_hash: Option<H256>,
+    pub relayer: Option<Address>,
 }
 
 impl TxEnv for ScenarioTxEnvData {
@@ -44,6 +47,17 @@ impl TxEnvWithTxHash for ScenarioTxEnvData {
     }
 }
 
+impl TxEnvWithRelayer for ScenarioTxEnvData {
+    fn set_relayer(&mut self, relayer: Address) {
+        assert!(self.relayer.is_none(), "relayer set twice");
+        self.relayer = Some(relayer);
+    }
+
+    fn take_relayer(&mut self) -> Option<Address> {
+        core::mem::take(&mut self.relayer)
+    }
+}
+
 impl ScenarioTxEnvData {
     pub fn interpreter_context(&self) -> InterpreterContext {
         self.interpreter_context.clone()
@@ -63,6 +77,7 @@ impl ScenarioWorld {
                 .with_dir(self.current_dir.clone())
                 .with_allowed_missing_files(),
             tx_hash: None,
+            relayer: None,
         }
     }
 }
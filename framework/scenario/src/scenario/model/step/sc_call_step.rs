@@ -58,6 +58,15 @@ impl ScCallStep {
         self
     }
 
+    /// Turns the call into a relayed transaction (v3), with gas paid by the relayer.
+    pub fn relayer<A>(mut self, address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        self.tx.relayer = Some(AddressValue::from(address));
+        self
+    }
+
     pub fn egld_value<A>(mut self, amount: A) -> Self
     where
         BigUintValue: From<A>,
@@ -211,10 +220,9 @@ impl ScCallStep {
                 expect.update_from_response(&tx_response)
             }
         }
-        tx_response.tx_hash = self
-            .explicit_tx_hash
-            .as_ref()
-            .map(|vm_hash| vm_hash.as_array().into());
+        if let Some(explicit_tx_hash) = &self.explicit_tx_hash {
+            tx_response.tx_hash = Some(explicit_tx_hash.as_array().into());
+        }
         self.response = Some(tx_response);
     }
 }
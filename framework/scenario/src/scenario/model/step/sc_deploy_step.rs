@@ -52,6 +52,15 @@ impl ScDeployStep {
         self
     }
 
+    /// Turns the deploy into a relayed transaction (v3), with gas paid by the relayer.
+    pub fn relayer<A>(mut self, address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        self.tx.relayer = Some(AddressValue::from(address));
+        self
+    }
+
     pub fn egld_value<V>(mut self, expr: V) -> Self
     where
         BigUintValue: From<V>,
@@ -141,10 +150,9 @@ impl ScDeployStep {
                 expect.update_from_response(&tx_response)
             }
         }
-        tx_response.tx_hash = self
-            .explicit_tx_hash
-            .as_ref()
-            .map(|vm_hash| vm_hash.as_array().into());
+        if let Some(explicit_tx_hash) = &self.explicit_tx_hash {
+            tx_response.tx_hash = Some(explicit_tx_hash.as_array().into());
+        }
         self.response = Some(tx_response);
     }
 }
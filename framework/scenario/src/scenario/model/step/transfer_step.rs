@@ -41,6 +41,15 @@ impl TransferStep {
         self
     }
 
+    /// Turns the transfer into a relayed transaction (v3), with gas paid by the relayer.
+    pub fn relayer<A>(mut self, address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        self.tx.relayer = Some(AddressValue::from(address));
+        self
+    }
+
     pub fn egld_value<A>(mut self, amount: A) -> Self
     where
         BigUintValue: From<A>,
@@ -27,6 +27,7 @@ pub struct TxCall {
     pub arguments: Vec<BytesValue>,
     pub gas_limit: U64Value,
     pub gas_price: U64Value,
+    pub relayer: Option<AddressValue>,
 }
 
 impl Default for TxCall {
@@ -40,6 +41,7 @@ impl Default for TxCall {
             arguments: Default::default(),
             gas_limit: U64Value::from(DEFAULT_GAS_EXPR),
             gas_price: Default::default(),
+            relayer: None,
         }
     }
 }
@@ -63,6 +65,9 @@ impl InterpretableFrom<TxCallRaw> for TxCall {
                 .collect(),
             gas_limit: U64Value::interpret_from(from.gas_limit, context),
             gas_price: U64Value::interpret_from(from.gas_price.unwrap_or_default(), context),
+            relayer: from
+                .relayer
+                .map(|relayer| AddressValue::interpret_from(relayer, context)),
         }
     }
 }
@@ -87,6 +92,7 @@ impl IntoRaw<TxCallRaw> for TxCall {
                 .collect(),
             gas_limit: self.gas_limit.into_raw(),
             gas_price: self.gas_price.into_raw_opt(),
+            relayer: self.relayer.map(|relayer| relayer.into_raw()),
         }
     }
 }
@@ -149,6 +155,7 @@ impl TxCall {
             arguments,
             gas_limit: self.gas_limit.clone(),
             gas_price: self.gas_price.clone(),
+            relayer: self.relayer.clone(),
         }
     }
 
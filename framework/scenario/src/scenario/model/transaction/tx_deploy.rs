@@ -18,6 +18,7 @@ pub struct TxDeploy {
     pub arguments: Vec<BytesValue>,
     pub gas_limit: U64Value,
     pub gas_price: U64Value,
+    pub relayer: Option<AddressValue>,
 }
 
 impl Default for TxDeploy {
@@ -30,6 +31,7 @@ impl Default for TxDeploy {
             arguments: Default::default(),
             gas_limit: U64Value::from(DEFAULT_GAS_EXPR),
             gas_price: Default::default(),
+            relayer: None,
         }
     }
 }
@@ -48,6 +50,9 @@ impl InterpretableFrom<TxDeployRaw> for TxDeploy {
                 .collect(),
             gas_limit: U64Value::interpret_from(from.gas_limit, context),
             gas_price: U64Value::interpret_from(from.gas_price.unwrap_or_default(), context),
+            relayer: from
+                .relayer
+                .map(|relayer| AddressValue::interpret_from(relayer, context)),
         }
     }
 }
@@ -66,6 +71,7 @@ impl IntoRaw<TxDeployRaw> for TxDeploy {
                 .collect(),
             gas_limit: self.gas_limit.into_raw(),
             gas_price: self.gas_price.into_raw_opt(),
+            relayer: self.relayer.map(|relayer| relayer.into_raw()),
         }
     }
 }
@@ -16,6 +16,7 @@ pub struct TxTransfer {
     pub esdt_value: Vec<TxESDT>,
     pub gas_limit: U64Value,
     pub gas_price: U64Value,
+    pub relayer: Option<AddressValue>,
 }
 
 impl InterpretableFrom<TxTransferRaw> for TxTransfer {
@@ -31,6 +32,9 @@ impl InterpretableFrom<TxTransferRaw> for TxTransfer {
                 .collect(),
             gas_limit: U64Value::interpret_from(from.gas_limit.unwrap_or_default(), context),
             gas_price: U64Value::interpret_from(from.gas_price.unwrap_or_default(), context),
+            relayer: from
+                .relayer
+                .map(|relayer| AddressValue::interpret_from(relayer, context)),
         }
     }
 }
@@ -49,6 +53,7 @@ impl IntoRaw<TxTransferRaw> for TxTransfer {
                 .collect(),
             gas_limit: self.gas_limit.into_raw_opt(),
             gas_price: self.gas_price.into_raw_opt(),
+            relayer: self.relayer.map(|relayer| relayer.into_raw()),
         }
     }
 }
@@ -65,6 +70,7 @@ impl TxTransfer {
             arguments: Vec::new(),
             gas_limit: self.gas_limit.clone(),
             gas_price: self.gas_price.clone(),
+            relayer: self.relayer.clone(),
         }
     }
 }
@@ -17,9 +17,14 @@ impl ScenarioVMRunner {
     ///
     /// The result of the operation gets saved back in the step's response field.
     pub fn perform_sc_call_update_results(&mut self, step: &mut ScCallStep) {
-        let tx_result =
-            self.perform_sc_call_lambda_and_check(step, execute_current_tx_context_input);
-        let response = TxResponse::from_tx_result(tx_result);
+        let tx_input = self.tx_input_from_call(step);
+        let tx_hash = tx_input.tx_hash.clone();
+        let tx_result = self.perform_sc_call_input(tx_input, execute_current_tx_context_input);
+        if let Some(tx_expect) = &step.expect {
+            check_tx_output(&step.id, tx_expect, &tx_result);
+        }
+        let mut response = TxResponse::from_tx_result(tx_result);
+        response.tx_hash = Some(tx_hash.as_array().into());
         step.save_response(response);
     }
 
@@ -53,8 +58,14 @@ impl ScenarioVMRunner {
     where
         F: FnOnce(),
     {
-        let tx_input = tx_input_from_call(sc_call_step);
+        let tx_input = self.tx_input_from_call(sc_call_step);
+        self.perform_sc_call_input(tx_input, f)
+    }
 
+    fn perform_sc_call_input<F>(&mut self, tx_input: TxInput, f: F) -> TxResult
+    where
+        F: FnOnce(),
+    {
         // nonce gets increased irrespective of whether the tx fails or not
         self.blockchain_mock
             .state
@@ -81,6 +92,17 @@ impl ScenarioVMRunner {
         }
         tx_result
     }
+
+    fn tx_input_from_call(&self, sc_call_step: &ScCallStep) -> TxInput {
+        let mut tx_input = tx_input_from_call(sc_call_step);
+        tx_input.tx_hash = generate_tx_hash(
+            &sc_call_step.id,
+            &sc_call_step.explicit_tx_hash,
+            &tx_input,
+            &self.blockchain_mock.state,
+        );
+        tx_input
+    }
 }
 
 fn tx_input_from_call(sc_call_step: &ScCallStep) -> TxInput {
@@ -98,7 +120,7 @@ fn tx_input_from_call(sc_call_step: &ScCallStep) -> TxInput {
             .collect(),
         gas_limit: tx.gas_limit.value,
         gas_price: tx.gas_price.value,
-        tx_hash: generate_tx_hash(&sc_call_step.id, &sc_call_step.explicit_tx_hash),
+        relayer: tx.relayer.as_ref().map(|relayer| relayer.to_address()),
         ..Default::default()
     }
 }
@@ -15,10 +15,12 @@ impl ScenarioVMRunner {
     ///
     /// The result of the operation gets saved back in the step's response field.
     pub fn perform_sc_deploy_update_results(&mut self, step: &mut ScDeployStep) {
+        let tx_hash = self.tx_input_from_deploy(step).tx_hash;
         let (new_address, tx_result) =
             self.perform_sc_deploy_lambda_and_check(step, execute_current_tx_context_input);
         let mut response = TxResponse::from_tx_result(tx_result);
         response.new_deployed_address = Some(new_address);
+        response.tx_hash = Some(tx_hash.as_array().into());
         step.save_response(response);
     }
 
@@ -30,7 +32,7 @@ impl ScenarioVMRunner {
     where
         F: FnOnce(),
     {
-        let tx_input = tx_input_from_deploy(sc_deploy_step);
+        let tx_input = self.tx_input_from_deploy(sc_deploy_step);
         let contract_code = &sc_deploy_step.tx.contract_code.value;
         let (new_address, tx_result) = self.blockchain_mock.vm.sc_create(
             tx_input,
@@ -60,6 +62,17 @@ impl ScenarioVMRunner {
         }
         (new_address, tx_result)
     }
+
+    fn tx_input_from_deploy(&self, sc_deploy_step: &ScDeployStep) -> TxInput {
+        let mut tx_input = tx_input_from_deploy(sc_deploy_step);
+        tx_input.tx_hash = generate_tx_hash(
+            &sc_deploy_step.id,
+            &sc_deploy_step.explicit_tx_hash,
+            &tx_input,
+            &self.blockchain_mock.state,
+        );
+        tx_input
+    }
 }
 
 fn tx_input_from_deploy(sc_deploy_step: &ScDeployStep) -> TxInput {
@@ -77,7 +90,7 @@ fn tx_input_from_deploy(sc_deploy_step: &ScDeployStep) -> TxInput {
             .collect(),
         gas_limit: tx.gas_limit.value,
         gas_price: tx.gas_price.value,
-        tx_hash: generate_tx_hash(&sc_deploy_step.id, &sc_deploy_step.explicit_tx_hash),
+        relayer: tx.relayer.as_ref().map(|relayer| relayer.to_address()),
         ..Default::default()
     }
 }
@@ -21,7 +21,13 @@ impl ScenarioVMRunner {
     where
         F: FnOnce(),
     {
-        let tx_input = tx_input_from_query(step);
+        let mut tx_input = tx_input_from_query(step);
+        tx_input.tx_hash = generate_tx_hash(
+            &step.id,
+            &step.explicit_tx_hash,
+            &tx_input,
+            &self.blockchain_mock.state,
+        );
         let tx_result = self.blockchain_mock.vm.execute_sc_query_lambda(
             tx_input,
             &mut self.blockchain_mock.state,
@@ -61,7 +67,6 @@ fn tx_input_from_query(sc_query_step: &ScQueryStep) -> TxInput {
             .collect(),
         gas_limit: u64::MAX,
         gas_price: 0u64,
-        tx_hash: generate_tx_hash(&sc_query_step.id, &sc_query_step.explicit_tx_hash),
         ..Default::default()
     }
 }
@@ -1,7 +1,7 @@
 use crate::scenario::model::SetStateStep;
 
 use multiversx_chain_vm::{
-    types::VMCodeMetadata,
+    types::{VMAddress, VMCodeMetadata},
     world_mock::{
         AccountData, AccountEsdt, BlockInfo as CrateBlockInfo, BlockchainState, EsdtData,
         EsdtInstance, EsdtInstanceMetadata, EsdtInstances, EsdtRoles,
@@ -20,6 +20,7 @@ impl ScenarioVMRunner {
 }
 
 fn execute(state: &mut BlockchainState, set_state_step: &SetStateStep) {
+    let mut token_holders = Vec::new();
     for (address, account) in set_state_step.accounts.iter() {
         let storage = account
             .storage
@@ -34,6 +35,8 @@ fn execute(state: &mut BlockchainState, set_state_step: &SetStateStep) {
                 .collect(),
         );
 
+        token_holders.push((address.to_address(), esdt.clone()));
+
         state.validate_and_add_account(AccountData {
             address: address.to_address(),
             nonce: account
@@ -73,6 +76,7 @@ fn execute(state: &mut BlockchainState, set_state_step: &SetStateStep) {
                 .unwrap_or_default(),
         });
     }
+    register_esdt_token_info(state, &token_holders);
     for new_address in set_state_step.new_addresses.iter() {
         assert!(
             new_address.new_address.value.is_smart_contract_address(),
@@ -95,6 +99,29 @@ fn execute(state: &mut BlockchainState, set_state_step: &SetStateStep) {
     }
 }
 
+/// Tokens that were not issued get their global data derived from the accounts holding them.
+///
+/// The owner is the first account with roles for the token, or else the first account holding it.
+fn register_esdt_token_info(
+    state: &mut BlockchainState,
+    token_holders: &[(VMAddress, AccountEsdt)],
+) {
+    for (address, esdt) in token_holders {
+        let tokens_with_roles = esdt
+            .iter()
+            .filter(|(_, esdt_data)| !esdt_data.roles.is_empty())
+            .map(|(token_identifier, esdt_data)| (token_identifier.clone(), esdt_data.clone()))
+            .collect();
+        state.register_account_esdt_tokens(
+            address,
+            &AccountEsdt::new_from_raw_map(tokens_with_roles),
+        );
+    }
+    for (address, esdt) in token_holders {
+        state.register_account_esdt_tokens(address, esdt);
+    }
+}
+
 fn convert_mandos_esdt_to_world_mock(mandos_esdt: &crate::scenario::model::Esdt) -> EsdtData {
     match mandos_esdt {
         crate::scenario::model::Esdt::Short(short_esdt) => {
@@ -103,48 +130,57 @@ fn convert_mandos_esdt_to_world_mock(mandos_esdt: &crate::scenario::model::Esdt)
             esdt_data.instances.add(0, balance);
             esdt_data
         },
-        crate::scenario::model::Esdt::Full(full_esdt) => EsdtData {
-            instances: EsdtInstances::new_from_hash(
-                full_esdt
-                    .instances
-                    .iter()
-                    .map(|mandos_instance| {
-                        let mock_instance =
-                            convert_scenario_esdt_instance_to_world_mock(mandos_instance);
-                        (mock_instance.nonce, mock_instance)
-                    })
-                    .collect(),
-            ),
-            last_nonce: full_esdt
-                .last_nonce
+        crate::scenario::model::Esdt::Full(full_esdt) => {
+            let frozen = full_esdt
+                .frozen
                 .as_ref()
-                .map(|last_nonce| last_nonce.value)
-                .unwrap_or_default(),
-            roles: EsdtRoles::new(
-                full_esdt
-                    .roles
-                    .iter()
-                    .map(|role| role.as_bytes().to_vec())
-                    .collect(),
-            ),
-            frozen: if let Some(u64_value) = &full_esdt.frozen {
-                u64_value.value > 0
-            } else {
-                false
-            },
+                .map(|u64_value| u64_value.value > 0)
+                .unwrap_or_default();
+            EsdtData {
+                instances: EsdtInstances::new_from_hash(
+                    full_esdt
+                        .instances
+                        .iter()
+                        .map(|mandos_instance| {
+                            let mock_instance = convert_scenario_esdt_instance_to_world_mock(
+                                mandos_instance,
+                                frozen,
+                            );
+                            (mock_instance.nonce, mock_instance)
+                        })
+                        .collect(),
+                ),
+                last_nonce: full_esdt
+                    .last_nonce
+                    .as_ref()
+                    .map(|last_nonce| last_nonce.value)
+                    .unwrap_or_default(),
+                roles: EsdtRoles::new(
+                    full_esdt
+                        .roles
+                        .iter()
+                        .map(|role| role.as_bytes().to_vec())
+                        .collect(),
+                ),
+                frozen,
+            }
         },
     }
 }
 
+/// The token-level frozen flag also applies to each NFT instance,
+/// so that they stay frozen after an account-level `unFreeze`, until `unFreezeSingleNFT`.
 fn convert_scenario_esdt_instance_to_world_mock(
     scenario_esdt: &crate::scenario::model::EsdtInstance,
+    frozen: bool,
 ) -> EsdtInstance {
+    let nonce = scenario_esdt
+        .nonce
+        .as_ref()
+        .map(|nonce| nonce.value)
+        .unwrap_or_default();
     EsdtInstance {
-        nonce: scenario_esdt
-            .nonce
-            .as_ref()
-            .map(|nonce| nonce.value)
-            .unwrap_or_default(),
+        nonce,
         balance: scenario_esdt
             .balance
             .as_ref()
@@ -173,6 +209,7 @@ fn convert_scenario_esdt_instance_to_world_mock(
                 .map(|attributes| attributes.value.clone())
                 .unwrap_or_default(),
         },
+        frozen: frozen && nonce > 0,
     }
 }
 
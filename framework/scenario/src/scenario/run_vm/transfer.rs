@@ -1,4 +1,6 @@
-use super::{sc_call::tx_esdt_transfers_from_scenario, ScenarioVMRunner};
+use super::{
+    sc_call::tx_esdt_transfers_from_scenario, tx_input_util::generate_tx_hash, ScenarioVMRunner,
+};
 use crate::scenario::model::{TransferStep, TxTransfer, ValidatorRewardStep};
 use multiversx_chain_vm::{
     tx_execution::{execute_current_tx_context_input, BlockchainVMRef},
@@ -12,6 +14,7 @@ impl ScenarioVMRunner {
         execute(
             self.blockchain_mock.vm.clone(),
             &mut self.blockchain_mock.state,
+            &transfer_step.id,
             &transfer_step.tx,
         );
     }
@@ -34,12 +37,22 @@ fn tx_input_from_transfer(tx_transfer: &TxTransfer) -> TxInput {
         args: Vec::new(),
         gas_limit: tx_transfer.gas_limit.value,
         gas_price: tx_transfer.gas_price.value,
+        relayer: tx_transfer
+            .relayer
+            .as_ref()
+            .map(|relayer| relayer.to_address()),
         ..Default::default()
     }
 }
 
-fn execute(vm: BlockchainVMRef, state: &mut Shareable<BlockchainState>, tx_transfer: &TxTransfer) {
-    let tx_input = tx_input_from_transfer(tx_transfer);
+fn execute(
+    vm: BlockchainVMRef,
+    state: &mut Shareable<BlockchainState>,
+    tx_id: &str,
+    tx_transfer: &TxTransfer,
+) {
+    let mut tx_input = tx_input_from_transfer(tx_transfer);
+    tx_input.tx_hash = generate_tx_hash(tx_id, &None, &tx_input, state);
 
     // nonce gets increased irrespective of whether the tx fails or not
     state.increase_account_nonce(&tx_input.from);
@@ -1,10 +1,23 @@
+use multiversx_chain_vm::{
+    tx_mock::{generate_user_tx_hash, TxInput},
+    types::H256,
+    world_mock::BlockchainState,
+};
+
+/// Explicit hashes are used as they are.
+///
+/// Steps with an id get the id, padded with dots, to stay compatible with the Go scenario runner.
+///
+/// All other steps get a hash generated from the transaction contents and the sender nonce.
 pub fn generate_tx_hash(
     tx_id: &str,
     explicit_tx_hash: &Option<multiversx_sc::types::H256>,
-) -> multiversx_chain_vm::types::H256 {
+    tx_input: &TxInput,
+    state: &BlockchainState,
+) -> H256 {
     if let Some(explicit_tx_hash) = explicit_tx_hash {
         explicit_tx_hash.as_array().into()
-    } else {
+    } else if !tx_id.is_empty() {
         let bytes = tx_id.as_bytes();
         let mut result = [b'.'; 32];
         if bytes.len() > 32 {
@@ -13,5 +26,12 @@ pub fn generate_tx_hash(
             result[..bytes.len()].copy_from_slice(bytes);
         }
         result.into()
+    } else {
+        let sender_nonce = state
+            .accounts
+            .get(&tx_input.from)
+            .map(|account| account.nonce)
+            .unwrap_or_default();
+        generate_user_tx_hash(tx_input, sender_nonce)
     }
 }
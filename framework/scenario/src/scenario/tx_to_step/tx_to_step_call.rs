@@ -1,16 +1,16 @@
 use multiversx_sc::types::{
-    FunctionCall, RHListExec, Tx, TxEnv, TxEnvWithTxHash, TxFromSpecified, TxGas, TxPayment,
-    TxToSpecified,
+    FunctionCall, RHListExec, Tx, TxEnv, TxEnvWithRelayer, TxEnvWithTxHash, TxFromSpecified, TxGas,
+    TxPayment, TxToSpecified,
 };
 
-use crate::scenario_model::{ScCallStep, TxESDT, TxExpect, TxResponse};
+use crate::scenario_model::{AddressValue, ScCallStep, TxESDT, TxExpect, TxResponse};
 
 use super::{address_annotated, gas_annotated, StepWrapper, TxToStep};
 
 impl<Env, From, To, Payment, Gas, RH> TxToStep<Env, RH>
     for Tx<Env, From, To, Payment, Gas, FunctionCall<Env::Api>, RH>
 where
-    Env: TxEnvWithTxHash<RHExpect = TxExpect>,
+    Env: TxEnvWithTxHash<RHExpect = TxExpect> + TxEnvWithRelayer,
     From: TxFromSpecified<Env>,
     To: TxToSpecified<Env>,
     Payment: TxPayment<Env>,
@@ -29,6 +29,10 @@ where
             self.data,
         );
         step.explicit_tx_hash = self.env.take_tx_hash();
+        step.tx.relayer = self
+            .env
+            .take_relayer()
+            .map(|relayer| AddressValue::from(&relayer));
         step.expect = Some(self.result_handler.list_tx_expect());
 
         StepWrapper {
@@ -1,16 +1,16 @@
 use multiversx_sc::types::{
-    Code, DeployCall, RHListExec, Tx, TxCodeValue, TxEnv, TxEnvWithTxHash, TxFromSpecified, TxGas,
-    TxPayment,
+    Code, DeployCall, RHListExec, Tx, TxCodeValue, TxEnv, TxEnvWithRelayer, TxEnvWithTxHash,
+    TxFromSpecified, TxGas, TxPayment,
 };
 
-use crate::scenario_model::{ScDeployStep, TxExpect, TxResponse};
+use crate::scenario_model::{AddressValue, ScDeployStep, TxExpect, TxResponse};
 
 use super::{address_annotated, code_annotated, gas_annotated, StepWrapper, TxToStep};
 
 impl<Env, From, Payment, Gas, CodeValue, RH> TxToStep<Env, RH>
     for Tx<Env, From, (), Payment, Gas, DeployCall<Env, Code<CodeValue>>, RH>
 where
-    Env: TxEnvWithTxHash<RHExpect = TxExpect>,
+    Env: TxEnvWithTxHash<RHExpect = TxExpect> + TxEnvWithRelayer,
     From: TxFromSpecified<Env>,
     Payment: TxPayment<Env>,
     Gas: TxGas<Env>,
@@ -23,6 +23,10 @@ where
         let mut step =
             tx_to_sc_deploy_step(&self.env, self.from, self.payment, self.gas, self.data);
         step.explicit_tx_hash = self.env.take_tx_hash();
+        step.tx.relayer = self
+            .env
+            .take_relayer()
+            .map(|relayer| AddressValue::from(&relayer));
         step.expect = Some(self.result_handler.list_tx_expect());
 
         StepWrapper {
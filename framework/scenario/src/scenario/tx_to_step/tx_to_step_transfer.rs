@@ -1,12 +1,17 @@
-use multiversx_sc::types::{Tx, TxEnv, TxFromSpecified, TxGas, TxPayment, TxToSpecified};
+use multiversx_sc::types::{
+    Tx, TxEnv, TxEnvWithRelayer, TxFromSpecified, TxGas, TxPayment, TxToSpecified,
+};
 
-use crate::{imports::TxESDT, scenario_model::TransferStep};
+use crate::{
+    imports::TxESDT,
+    scenario_model::{AddressValue, TransferStep},
+};
 
 use super::{address_annotated, gas_annotated, StepWrapper, TxToStep};
 
 impl<Env, From, To, Payment, Gas> TxToStep<Env, ()> for Tx<Env, From, To, Payment, Gas, (), ()>
 where
-    Env: TxEnv,
+    Env: TxEnvWithRelayer,
     From: TxFromSpecified<Env>,
     To: TxToSpecified<Env>,
     Payment: TxPayment<Env>,
@@ -14,8 +19,12 @@ where
 {
     type Step = TransferStep;
 
-    fn tx_to_step(self) -> StepWrapper<Env, Self::Step, ()> {
-        let step = tx_to_transfer_step(&self.env, self.from, self.to, self.payment, self.gas);
+    fn tx_to_step(mut self) -> StepWrapper<Env, Self::Step, ()> {
+        let mut step = tx_to_transfer_step(&self.env, self.from, self.to, self.payment, self.gas);
+        step.tx.relayer = self
+            .env
+            .take_relayer()
+            .map(|relayer| AddressValue::from(&relayer));
 
         StepWrapper {
             env: self.env,
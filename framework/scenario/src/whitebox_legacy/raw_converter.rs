@@ -126,6 +126,7 @@ pub(crate) fn tx_call_as_raw(tx_call: &ScCallMandos) -> TxCallRaw {
         arguments: arguments_raw,
         gas_limit: u64_as_raw(tx_call.gas_limit),
         gas_price: u64_as_raw_opt(tx_call.gas_price),
+        relayer: None,
     }
 }
 
@@ -1,4 +1,7 @@
-use multiversx_sc::types::{BigFloat, BigInt, BigUint};
+use multiversx_sc::{
+    codec::test_util::{check_dep_encode_decode, check_top_encode_decode},
+    types::{BigFloat, BigInt, BigUint, ManagedBuffer},
+};
 use multiversx_sc_scenario::api::StaticApi;
 
 #[test]
@@ -113,3 +116,36 @@ fn big_float_ln_test_rs() {
         &BigFloat::from_frac(1, 1_000_000_000)
     ));
 }
+
+#[test]
+fn big_float_buffer_test() {
+    // encoding produced by the Go VM
+    let encoded = hex::decode("010b0000003500000016eb34e40000000000").unwrap();
+
+    let x = BigFloat::<StaticApi>::from(-3853625i64);
+    assert_eq!(
+        x.to_buffer().to_boxed_bytes().as_slice(),
+        encoded.as_slice()
+    );
+    assert_eq!(
+        BigFloat::<StaticApi>::from_buffer(&ManagedBuffer::new_from_bytes(&encoded)),
+        x
+    );
+
+    check_top_encode_decode(x.clone(), &encoded);
+
+    let mut nested_encoded = vec![0, 0, 0, encoded.len() as u8];
+    nested_encoded.extend_from_slice(&encoded);
+    check_dep_encode_decode(x, &nested_encoded);
+
+    let zero_encoded = hex::decode("010800000035").unwrap();
+    check_top_encode_decode(BigFloat::<StaticApi>::zero(), &zero_encoded);
+}
+
+#[test]
+fn big_float_buffer_round_trip_test() {
+    for (numerator, denominator) in [(1i64, 3i64), (-22, 7), (1, 1_000_000_007), (i64::MAX, 3)] {
+        let x = BigFloat::<StaticApi>::from_frac(numerator, denominator);
+        assert_eq!(BigFloat::from_buffer(&x.to_buffer()), x);
+    }
+}
@@ -0,0 +1,354 @@
+use multiversx_sc_scenario::{imports::*, scenario_model::TxResponseStatus};
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const USER_ADDRESS: TestAddress = TestAddress::new("user");
+const NEW_OWNER_ADDRESS: TestAddress = TestAddress::new("new-owner");
+const TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("TOKEN-123456");
+const NFT_ID: TestTokenIdentifier = TestTokenIdentifier::new("NFT-123456");
+
+/// The tokens only appear in the set state, their global data gets derived from the accounts.
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world
+        .account(OWNER_ADDRESS)
+        .nonce(1)
+        .balance(1_000_000)
+        .esdt_balance(TOKEN_ID, 1_000)
+        .esdt_roles(NFT_ID, vec!["ESDTRoleNFTCreate".to_string()]);
+    world
+        .account(USER_ADDRESS)
+        .nonce(1)
+        .esdt_balance(TOKEN_ID, 100)
+        .esdt_nft_balance(NFT_ID, 1, 1, ());
+    world.account(NEW_OWNER_ADDRESS).nonce(1);
+    world
+}
+
+fn transfer_esdt(
+    world: &mut ScenarioWorld,
+    from: TestAddress,
+    to: TestAddress,
+    token_id: TestTokenIdentifier,
+    nonce: u64,
+    amount: u64,
+) -> Result<(), TxResponseStatus> {
+    world
+        .tx()
+        .from(from)
+        .to(to)
+        .single_esdt(&token_id.into(), nonce, &BigUint::from(amount))
+        .raw_call("")
+        .returns(ReturnsHandledOrError::new())
+        .run()
+}
+
+fn assert_frozen(result: Result<(), TxResponseStatus>) {
+    assert_eq!(
+        result.unwrap_err().message,
+        "account is frozen for this esdt"
+    );
+}
+
+#[test]
+fn esdt_system_sc_issue_test() {
+    let mut world = world();
+
+    let token_id = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .issue_fungible(
+            BigUint::from(50_000u64),
+            ManagedBuffer::from("Issued"),
+            ManagedBuffer::from("ISSUED"),
+            BigUint::from(2_000u64),
+            FungibleTokenProperties {
+                num_decimals: 0,
+                can_freeze: false,
+                can_wipe: false,
+                can_pause: false,
+                can_mint: false,
+                can_burn: false,
+                can_change_owner: false,
+                can_upgrade: false,
+                can_add_special_roles: true,
+            },
+        )
+        .returns(ReturnsResult)
+        .run();
+
+    world
+        .check_account(OWNER_ADDRESS)
+        .esdt_balance(&token_id, 2_000);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .freeze(&token_id, USER_ADDRESS)
+        .returns(ExpectError(10, "cannot freeze"))
+        .run();
+}
+
+#[test]
+fn esdt_system_sc_freeze_test() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .freeze(TOKEN_ID, USER_ADDRESS)
+        .run();
+
+    assert_frozen(transfer_esdt(
+        &mut world,
+        USER_ADDRESS,
+        OWNER_ADDRESS,
+        TOKEN_ID,
+        0,
+        10,
+    ));
+    assert_frozen(transfer_esdt(
+        &mut world,
+        OWNER_ADDRESS,
+        USER_ADDRESS,
+        TOKEN_ID,
+        0,
+        10,
+    ));
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .unfreeze(TOKEN_ID, USER_ADDRESS)
+        .run();
+
+    transfer_esdt(&mut world, USER_ADDRESS, OWNER_ADDRESS, TOKEN_ID, 0, 10).unwrap();
+    world.check_account(USER_ADDRESS).esdt_balance(TOKEN_ID, 90);
+}
+
+#[test]
+fn esdt_system_sc_freeze_nft_test() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .freeze_nft(NFT_ID, 1, USER_ADDRESS)
+        .run();
+
+    assert_frozen(transfer_esdt(
+        &mut world,
+        USER_ADDRESS,
+        OWNER_ADDRESS,
+        NFT_ID,
+        1,
+        1,
+    ));
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .wipe_nft(NFT_ID, 1, USER_ADDRESS)
+        .run();
+
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_nft_balance_and_attributes(NFT_ID, 1, 0, "");
+}
+
+#[test]
+fn esdt_system_sc_pause_test() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .pause(TOKEN_ID)
+        .returns(ExpectError(10, "can be called by owner only"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .pause(TOKEN_ID)
+        .run();
+
+    assert_eq!(
+        transfer_esdt(&mut world, OWNER_ADDRESS, USER_ADDRESS, TOKEN_ID, 0, 10)
+            .unwrap_err()
+            .message,
+        "esdt token is paused"
+    );
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .unpause(TOKEN_ID)
+        .run();
+
+    transfer_esdt(&mut world, OWNER_ADDRESS, USER_ADDRESS, TOKEN_ID, 0, 10).unwrap();
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(TOKEN_ID, 110);
+}
+
+#[test]
+fn esdt_system_sc_wipe_test() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .wipe(TOKEN_ID, USER_ADDRESS)
+        .returns(ExpectError(
+            10,
+            "cannot wipe because the account is not frozen for this esdt token",
+        ))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .freeze(TOKEN_ID, USER_ADDRESS)
+        .run();
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .wipe(TOKEN_ID, USER_ADDRESS)
+        .run();
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .unfreeze(TOKEN_ID, USER_ADDRESS)
+        .run();
+
+    world.check_account(USER_ADDRESS).esdt_balance(TOKEN_ID, 0);
+}
+
+#[test]
+fn esdt_system_sc_wipe_supply_test() {
+    let mut world = world();
+
+    let token_id = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .issue_fungible(
+            BigUint::from(50_000u64),
+            ManagedBuffer::from("Wiped"),
+            ManagedBuffer::from("WIPED"),
+            BigUint::from(2_000u64),
+            FungibleTokenProperties {
+                num_decimals: 0,
+                can_freeze: true,
+                can_wipe: true,
+                can_pause: false,
+                can_mint: false,
+                can_burn: false,
+                can_change_owner: false,
+                can_upgrade: false,
+                can_add_special_roles: false,
+            },
+        )
+        .returns(ReturnsResult)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(USER_ADDRESS)
+        .single_esdt(&token_id, 0, &BigUint::from(300u64))
+        .raw_call("")
+        .run();
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .freeze(&token_id, USER_ADDRESS)
+        .run();
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .wipe(&token_id, USER_ADDRESS)
+        .run();
+
+    // the supply is the minted value minus the burnt value, wiping counts as burning
+    let properties = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .raw_call("getTokenProperties")
+        .argument(&token_id)
+        .returns(ReturnsRawResult)
+        .run();
+    assert_eq!(properties.get(3).to_vec(), b"2000");
+    assert_eq!(properties.get(4).to_vec(), b"300");
+}
+
+#[test]
+fn esdt_system_sc_transfer_ownership_test() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .transfer_ownership(TOKEN_ID, NEW_OWNER_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .freeze(TOKEN_ID, USER_ADDRESS)
+        .returns(ExpectError(10, "can be called by owner only"))
+        .run();
+
+    world
+        .tx()
+        .from(NEW_OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(ESDTSystemSCProxy)
+        .freeze(TOKEN_ID, USER_ADDRESS)
+        .run();
+
+    assert_frozen(transfer_esdt(
+        &mut world,
+        USER_ADDRESS,
+        OWNER_ADDRESS,
+        TOKEN_ID,
+        0,
+        10,
+    ));
+}
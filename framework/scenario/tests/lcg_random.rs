@@ -0,0 +1,18 @@
+/// Simple deterministic linear congruential generator, for reproducible randomized mapper tests.
+pub struct LcgRandom {
+    seed: u64,
+}
+
+impl LcgRandom {
+    pub fn new(seed: u64) -> Self {
+        LcgRandom { seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.seed = self
+            .seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.seed >> 33
+    }
+}
@@ -0,0 +1,273 @@
+//! Checks the `ManagedDecimal` math functions against straightforward reference implementations,
+//! over pseudo-random inputs.
+
+use multiversx_sc::types::{
+    BigInt, BigUint, ManagedDecimal, ManagedDecimalSigned, NumDecimals, RoundingMode,
+};
+use multiversx_sc_scenario::{api::StaticApi, num_bigint};
+
+const NUM_ITERATIONS: usize = 200;
+
+/// Small deterministic generator, so failures are reproducible.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    fn signed_below(&mut self, bound: u64) -> i64 {
+        let magnitude = self.below(bound) as i64;
+        if self.next() % 2 == 0 {
+            magnitude
+        } else {
+            -magnitude
+        }
+    }
+}
+
+fn to_num_biguint(value: &BigUint<StaticApi>) -> num_bigint::BigUint {
+    num_bigint::BigUint::from_bytes_be(value.to_bytes_be().as_slice())
+}
+
+fn to_num_bigint(value: &BigInt<StaticApi>) -> num_bigint::BigInt {
+    let (sign, magnitude) = value.clone().to_parts();
+    let sign = if sign.is_minus() {
+        num_bigint::Sign::Minus
+    } else {
+        num_bigint::Sign::Plus
+    };
+    num_bigint::BigInt::from_biguint(sign, to_num_biguint(&magnitude))
+}
+
+fn pow10(exp: usize) -> num_bigint::BigUint {
+    num_bigint::BigUint::from(10u32).pow(exp as u32)
+}
+
+fn reference_round(numerator: i128, denominator: i128, rounding: RoundingMode) -> i128 {
+    let floor = numerator.div_euclid(denominator);
+    let remainder = numerator.rem_euclid(denominator);
+    if remainder == 0 {
+        return floor;
+    }
+    match rounding {
+        RoundingMode::Floor => floor,
+        RoundingMode::Ceil => floor + 1,
+        RoundingMode::HalfEven => match (2 * remainder).cmp(&denominator) {
+            core::cmp::Ordering::Less => floor,
+            core::cmp::Ordering::Greater => floor + 1,
+            core::cmp::Ordering::Equal => floor + floor.rem_euclid(2),
+        },
+    }
+}
+
+const ROUNDING_MODES: [RoundingMode; 3] = [
+    RoundingMode::Floor,
+    RoundingMode::Ceil,
+    RoundingMode::HalfEven,
+];
+
+#[test]
+fn managed_decimal_rounding_random_test() {
+    let mut rng = XorShift(0x5eed_0001);
+    for _ in 0..NUM_ITERATIONS {
+        let a = rng.signed_below(1_000_000);
+        let b = rng.signed_below(1_000) + 1_000; // never 0
+        let a_decimals = rng.below(6) as usize;
+        let b_decimals = rng.below(4) as usize;
+        let precision = rng.below(8) as usize;
+
+        let x = ManagedDecimalSigned::<StaticApi, NumDecimals>::from_raw_units(
+            BigInt::from(a),
+            a_decimals,
+        );
+        let y = ManagedDecimalSigned::<StaticApi, NumDecimals>::from_raw_units(
+            BigInt::from(b),
+            b_decimals,
+        );
+        for rounding in ROUNDING_MODES {
+            let rescaled = x.rescale_with_rounding(precision, rounding);
+            let expected = if precision >= a_decimals {
+                a as i128 * 10i128.pow((precision - a_decimals) as u32)
+            } else {
+                reference_round(
+                    a as i128,
+                    10i128.pow((a_decimals - precision) as u32),
+                    rounding,
+                )
+            };
+            assert_eq!(
+                rescaled.into_raw_units().to_i64().unwrap() as i128,
+                expected,
+                "rescale {a}e-{a_decimals} to {precision} decimals, {rounding:?}"
+            );
+
+            let product = x.clone().mul_with_rounding(y.clone(), precision, rounding);
+            let product_decimals = a_decimals + b_decimals;
+            let expected = if precision >= product_decimals {
+                a as i128 * b as i128 * 10i128.pow((precision - product_decimals) as u32)
+            } else {
+                reference_round(
+                    a as i128 * b as i128,
+                    10i128.pow((product_decimals - precision) as u32),
+                    rounding,
+                )
+            };
+            assert_eq!(
+                product.into_raw_units().to_i64().unwrap() as i128,
+                expected,
+                "{a}e-{a_decimals} * {b}e-{b_decimals} at {precision} decimals, {rounding:?}"
+            );
+
+            let quotient = x.clone().div_with_rounding(y.clone(), precision, rounding);
+            let expected = reference_round(
+                a as i128 * 10i128.pow((precision + b_decimals) as u32),
+                b as i128 * 10i128.pow(a_decimals as u32),
+                rounding,
+            );
+            assert_eq!(
+                quotient.into_raw_units().to_i64().unwrap() as i128,
+                expected,
+                "{a}e-{a_decimals} / {b}e-{b_decimals} at {precision} decimals, {rounding:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn managed_decimal_sqrt_random_test() {
+    let mut rng = XorShift(0x5eed_0002);
+    for _ in 0..NUM_ITERATIONS {
+        let raw = rng.below(i64::MAX as u64);
+        let decimals = rng.below(19) as usize;
+        let x =
+            ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(BigUint::from(raw), decimals);
+
+        let expected = (num_bigint::BigUint::from(raw) * pow10(decimals)).sqrt();
+        assert_eq!(
+            to_num_biguint(x.sqrt().into_raw_units()),
+            expected,
+            "sqrt({raw}e-{decimals})"
+        );
+    }
+}
+
+/// e^(raw * 10^-decimals), at the same number of decimals, using a plain Taylor series
+/// with plenty of extra precision.
+fn reference_exp(raw: i64, decimals: usize) -> num_bigint::BigUint {
+    let precision = decimals + 50;
+    let one = pow10(precision);
+    let x = num_bigint::BigUint::from(raw.unsigned_abs()) * pow10(precision - decimals);
+
+    let mut sum = one.clone();
+    let mut term = one.clone();
+    let mut n = 1u32;
+    while term > num_bigint::BigUint::from(0u32) {
+        term = term * &x / &one / n;
+        sum += &term;
+        n += 1;
+    }
+
+    let result = if raw < 0 { &one * &one / sum } else { sum };
+    result / pow10(precision - decimals)
+}
+
+#[test]
+fn managed_decimal_exp_random_test() {
+    let mut rng = XorShift(0x5eed_0003);
+    for _ in 0..NUM_ITERATIONS {
+        let decimals = 1 + rng.below(17) as usize;
+        let raw = rng.signed_below(30 * 10u64.pow(decimals as u32));
+        let x = ManagedDecimalSigned::<StaticApi, NumDecimals>::from_raw_units(
+            BigInt::from(raw),
+            decimals,
+        );
+
+        let result = to_num_biguint(x.exp().into_raw_units());
+        let expected = reference_exp(raw, decimals);
+        let diff = if result > expected {
+            &result - &expected
+        } else {
+            &expected - &result
+        };
+        assert!(
+            diff <= num_bigint::BigUint::from(1u32),
+            "exp({raw}e-{decimals}): got {result}, expected {expected}"
+        );
+    }
+}
+
+#[test]
+fn managed_decimal_pow_random_test() {
+    let mut rng = XorShift(0x5eed_0004);
+    let decimals = 18usize;
+    let scale = 10f64.powi(decimals as i32);
+    for _ in 0..NUM_ITERATIONS {
+        let base_raw = 1 + rng.below(100_000_000) * 1_000_000_000; // (0, 100]
+        let exponent_raw = rng.signed_below(5_000_000); // (-5, 5)
+        let base = ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(
+            BigUint::from(base_raw),
+            decimals,
+        );
+        let exponent = ManagedDecimalSigned::<StaticApi, NumDecimals>::from_raw_units(
+            BigInt::from(exponent_raw),
+            6usize,
+        );
+
+        let result = base.pow(&exponent).unwrap();
+        let actual = to_num_biguint(result.into_raw_units())
+            .to_string()
+            .parse::<f64>()
+            .unwrap()
+            / scale;
+        let expected = (base_raw as f64 / scale).powf(exponent_raw as f64 / 1e6);
+        let tolerance = expected * 1e-9 + 1.0 / scale;
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{base_raw}e-18 ^ {exponent_raw}e-6: got {actual}, expected {expected}"
+        );
+    }
+}
+
+#[test]
+fn managed_decimal_pow_integer_exponent_random_test() {
+    let mut rng = XorShift(0x5eed_0005);
+    for _ in 0..NUM_ITERATIONS {
+        let decimals = rng.below(10) as usize;
+        let base_raw = rng.signed_below(1_000_000);
+        let exponent = rng.below(8) as i64;
+        let base = ManagedDecimalSigned::<StaticApi, NumDecimals>::from_raw_units(
+            BigInt::from(base_raw),
+            decimals,
+        );
+        let exponent_decimal = ManagedDecimalSigned::<StaticApi, NumDecimals>::from_raw_units(
+            BigInt::from(exponent * 100),
+            2usize,
+        );
+
+        // the magnitude is rounded down, then the sign is applied
+        let magnitude = num_bigint::BigUint::from(base_raw.unsigned_abs()).pow(exponent as u32)
+            * pow10(decimals)
+            / pow10(decimals * exponent as usize);
+        let expected_sign = if base_raw < 0 && exponent % 2 == 1 {
+            num_bigint::Sign::Minus
+        } else {
+            num_bigint::Sign::Plus
+        };
+        let expected = num_bigint::BigInt::from_biguint(expected_sign, magnitude);
+
+        let result = base.pow(&exponent_decimal).unwrap();
+        assert_eq!(
+            to_num_bigint(result.into_raw_units()),
+            expected,
+            "({base_raw}e-{decimals}) ^ {exponent}"
+        );
+    }
+}
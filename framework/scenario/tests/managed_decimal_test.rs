@@ -3,7 +3,7 @@ use multiversx_sc::{
     derive::{debug_const_managed_decimal, debug_managed_decimal},
     types::{
         BigFloat, BigInt, BigUint, ConstDecimals, Decimals, ManagedDecimal, ManagedDecimalSigned,
-        NumDecimals,
+        NumDecimals, RoundingMode, MAX_EXP_ARGUMENT,
     },
 };
 use multiversx_sc_scenario::api::StaticApi;
@@ -558,3 +558,130 @@ fn test_managed_decimal_log2() {
 
     assert_eq!(log2_hp_frac.to_string(), "8.823953218");
 }
+
+#[test]
+fn test_managed_decimal_rounding() {
+    let tie =
+        ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(BigUint::from(125u64), 3usize); // 0.125
+    let above =
+        ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(BigUint::from(135u64), 3usize); // 0.135
+    let negative_tie =
+        ManagedDecimalSigned::<StaticApi, NumDecimals>::from_raw_units(BigInt::from(-125), 3usize); // -0.125
+
+    let floor = tie.rescale_with_rounding(2usize, RoundingMode::Floor);
+    assert_exact(&floor, 12, 2);
+    let ceil = tie.rescale_with_rounding(2usize, RoundingMode::Ceil);
+    assert_exact(&ceil, 13, 2);
+    let half_even = tie.rescale_with_rounding(2usize, RoundingMode::HalfEven);
+    assert_exact(&half_even, 12, 2);
+    let half_even = above.rescale_with_rounding(2usize, RoundingMode::HalfEven);
+    assert_exact(&half_even, 14, 2);
+
+    let floor = negative_tie.rescale_with_rounding(2usize, RoundingMode::Floor);
+    assert_exact_signed(&floor, -13, 2);
+    let ceil = negative_tie.rescale_with_rounding(2usize, RoundingMode::Ceil);
+    assert_exact_signed(&ceil, -12, 2);
+    let half_even = negative_tie.rescale_with_rounding(2usize, RoundingMode::HalfEven);
+    assert_exact_signed(&half_even, -12, 2);
+
+    let one = ManagedDecimal::<StaticApi, ConstDecimals<2>>::from(BigUint::from(1u64));
+    let three = ManagedDecimal::<StaticApi, ConstDecimals<0>>::from(BigUint::from(3u64));
+    let third_floor = one
+        .clone()
+        .div_with_rounding(three.clone(), 4usize, RoundingMode::Floor);
+    assert_exact(&third_floor, 3333, 4);
+    let third_ceil = one.div_with_rounding(three, 4usize, RoundingMode::Ceil);
+    assert_exact(&third_ceil, 3334, 4);
+
+    let x = ManagedDecimalSigned::<StaticApi, ConstDecimals<2>>::from(-1i64);
+    let y = ManagedDecimalSigned::<StaticApi, ConstDecimals<2>>::const_decimals_from_raw(
+        BigInt::from(150),
+    ); // 1.5
+    let product = x.mul_with_rounding(y, 0usize, RoundingMode::HalfEven); // -1.5
+    assert_exact_signed(&product, -2, 0);
+}
+
+#[test]
+fn test_managed_decimal_sqrt() {
+    let two = ManagedDecimal::<StaticApi, ConstDecimals<9>>::from(BigUint::from(2u64));
+    assert_eq!(two.sqrt().to_string(), "1.414213562");
+
+    let square =
+        ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(BigUint::from(15129u64), 2usize); // 151.29
+    assert_exact(&square.sqrt(), 1230, 2);
+
+    let negative = ManagedDecimalSigned::<StaticApi, ConstDecimals<2>>::from(-4i64);
+    assert!(negative.sqrt().is_none());
+}
+
+#[test]
+fn test_managed_decimal_exp() {
+    let one = ManagedDecimal::<StaticApi, ConstDecimals<18>>::from(BigUint::from(1u64));
+    assert_eq!(one.exp().to_string(), "2.718281828459045235");
+
+    let zero = ManagedDecimal::<StaticApi, ConstDecimals<9>>::from(BigUint::zero());
+    assert_eq!(zero.exp().to_string(), "1.000000000");
+
+    let ten = ManagedDecimalSigned::<StaticApi, ConstDecimals<9>>::from(10i64);
+    assert_eq!(ten.exp().to_string(), "22026.465794806");
+
+    let minus_one = ManagedDecimalSigned::<StaticApi, ConstDecimals<18>>::from(-1i64);
+    assert_eq!(minus_one.exp().to_string(), "0.367879441171442321");
+}
+
+#[test]
+fn test_managed_decimal_exp_bounds() {
+    let max = ManagedDecimalSigned::<StaticApi, ConstDecimals<2>>::from(MAX_EXP_ARGUMENT as i64);
+    // e^1000 = 2^1442.69...
+    assert_eq!(max.exp().trunc().log2_floor(), Some(1442));
+
+    let min = ManagedDecimalSigned::<StaticApi, ConstDecimals<18>>::from(-1_000_000_000i64);
+    assert_eq!(min.exp().to_string(), "0.000000000000000000");
+}
+
+#[test]
+#[should_panic(expected = "exp argument too large")]
+fn test_managed_decimal_exp_too_large() {
+    let x =
+        ManagedDecimal::<StaticApi, ConstDecimals<9>>::from(BigUint::from(MAX_EXP_ARGUMENT + 1));
+    let _ = x.exp();
+}
+
+#[test]
+#[should_panic(expected = "pow result too large")]
+fn test_managed_decimal_pow_too_large() {
+    let ten = ManagedDecimal::<StaticApi, ConstDecimals<9>>::from(BigUint::from(10u64));
+    let exponent = ManagedDecimalSigned::<StaticApi, ConstDecimals<1>>::const_decimals_from_raw(
+        BigInt::from(1_000_000_005),
+    ); // 100000000.5
+    let _ = ten.pow(&exponent);
+}
+
+#[test]
+fn test_managed_decimal_pow() {
+    let two = ManagedDecimal::<StaticApi, ConstDecimals<9>>::from(BigUint::from(2u64));
+    let half = ManagedDecimalSigned::<StaticApi, ConstDecimals<1>>::const_decimals_from_raw(
+        BigInt::from(5),
+    );
+    assert_eq!(two.pow(&half).unwrap(), two.sqrt());
+
+    let ten = ManagedDecimalSigned::<StaticApi, ConstDecimals<0>>::from(10i64);
+    assert_eq!(two.pow(&ten).unwrap().to_string(), "1024.000000000");
+
+    let base = ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(
+        BigUint::from(1_050_000_000u64),
+        9usize,
+    ); // 1.05
+    let exponent =
+        ManagedDecimalSigned::<StaticApi, NumDecimals>::from_raw_units(BigInt::from(-37), 1usize); // -3.7
+    assert_eq!(base.pow(&exponent).unwrap().to_string(), "0.834832972");
+
+    let zero = ManagedDecimal::<StaticApi, ConstDecimals<9>>::from(BigUint::zero());
+    let minus_one = ManagedDecimalSigned::<StaticApi, ConstDecimals<0>>::from(-1i64);
+    assert!(zero.pow(&minus_one).is_none());
+
+    let minus_two = ManagedDecimalSigned::<StaticApi, ConstDecimals<9>>::from(-2i64);
+    let three = ManagedDecimalSigned::<StaticApi, ConstDecimals<0>>::from(3i64);
+    assert_eq!(minus_two.pow(&three).unwrap().to_string(), "-8.000000000");
+    assert!(minus_two.pow(&half).is_none());
+}
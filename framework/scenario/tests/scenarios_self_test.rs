@@ -185,3 +185,8 @@ fn transfer_esdt_rs() {
 fn validator_reward_rs() {
     world().run("tests/scenarios-self/validatorReward.scen.json");
 }
+
+#[test]
+fn system_sc_esdt_frozen_set_state_rs() {
+    world().run("tests/scenarios-self/system-sc/esdt-frozen-set-state.scen.json");
+}
@@ -0,0 +1,165 @@
+mod lcg_random;
+
+use std::collections::BTreeSet;
+
+use lcg_random::LcgRandom;
+use multiversx_sc::storage::{
+    mappers::{AvlTreeMapper, StorageClearable, StorageMapper},
+    StorageKey,
+};
+use multiversx_sc_scenario::api::SingleTxApi;
+
+fn create_tree() -> AvlTreeMapper<SingleTxApi, u64> {
+    let base_key = StorageKey::new(&b"my_tree"[..]);
+    AvlTreeMapper::new(base_key)
+}
+
+fn check_tree(tree: &AvlTreeMapper<SingleTxApi, u64>, expected: &BTreeSet<u64>) {
+    assert!(tree.check_internal_consistency());
+    assert_eq!(tree.len(), expected.len());
+    let vec: Vec<u64> = tree.iter().collect();
+    let expected_vec: Vec<u64> = expected.iter().cloned().collect();
+    assert_eq!(vec, expected_vec);
+    assert_eq!(tree.first(), expected.first().cloned());
+    assert_eq!(tree.last(), expected.last().cloned());
+}
+
+#[test]
+fn test_avl_tree_simple() {
+    let mut tree = create_tree();
+    assert!(tree.check_internal_consistency());
+    assert!(tree.is_empty());
+    assert_eq!(tree.first(), None);
+    assert_eq!(tree.last(), None);
+
+    assert!(tree.insert(42));
+    assert!(tree.insert(44));
+    assert!(tree.insert(43));
+    assert!(!tree.insert(43));
+    assert_eq!(tree.len(), 3);
+    assert!(tree.check_internal_consistency());
+
+    assert!(tree.contains(&42));
+    assert!(!tree.contains(&45));
+    let vec: Vec<u64> = tree.iter().collect();
+    assert_eq!(vec, vec![42, 43, 44]);
+
+    assert!(tree.remove(&43));
+    assert!(!tree.remove(&43));
+    assert_eq!(tree.len(), 2);
+    assert!(tree.check_internal_consistency());
+}
+
+#[test]
+fn test_avl_tree_sorted_inserts_stay_balanced() {
+    let mut tree = create_tree();
+    let mut expected = BTreeSet::new();
+    for i in 1..=255 {
+        tree.insert(i);
+        expected.insert(i);
+        assert!(tree.check_internal_consistency());
+    }
+    check_tree(&tree, &expected);
+
+    // a perfectly balanced tree with 255 nodes has height 8
+    assert_eq!(tree.height(), 8);
+}
+
+#[test]
+fn test_avl_tree_rank_select() {
+    let mut tree = create_tree();
+    for i in 0..50 {
+        tree.insert(i * 2);
+    }
+
+    for i in 0..50 {
+        assert_eq!(tree.select(i as usize), Some(i * 2));
+        assert_eq!(tree.rank(&(i * 2)), i as usize);
+        assert_eq!(tree.rank(&(i * 2 + 1)), i as usize + 1);
+    }
+    assert_eq!(tree.select(50), None);
+    assert_eq!(tree.rank(&1000), 50);
+}
+
+#[test]
+fn test_avl_tree_range() {
+    let mut tree = create_tree();
+    for i in 0..100 {
+        tree.insert(i * 3);
+    }
+
+    let vec: Vec<u64> = tree.range(10..20).collect();
+    assert_eq!(vec, vec![12, 15, 18]);
+
+    let vec: Vec<u64> = tree.range(12..=18).collect();
+    assert_eq!(vec, vec![12, 15, 18]);
+
+    let vec: Vec<u64> = tree.range(290..).collect();
+    assert_eq!(vec, vec![291, 294, 297]);
+
+    let vec: Vec<u64> = tree.range(..7).collect();
+    assert_eq!(vec, vec![0, 3, 6]);
+
+    assert_eq!(tree.range(1000..).count(), 0);
+    assert_eq!(tree.range(13..15).count(), 0);
+    assert_eq!(tree.range(..).count(), 100);
+}
+
+#[test]
+fn test_avl_tree_pop() {
+    let mut tree = create_tree();
+    assert_eq!(tree.pop_min(), None);
+    assert_eq!(tree.pop_max(), None);
+
+    let mut expected = BTreeSet::new();
+    for i in [5, 3, 8, 1, 4, 7, 9, 2, 6] {
+        tree.insert(i);
+        expected.insert(i);
+    }
+
+    while !expected.is_empty() {
+        assert_eq!(tree.pop_min(), expected.pop_first());
+        check_tree(&tree, &expected);
+        assert_eq!(tree.pop_max(), expected.pop_last());
+        check_tree(&tree, &expected);
+    }
+    assert!(tree.is_empty());
+}
+
+#[test]
+fn test_avl_tree_random_operations() {
+    let mut tree = create_tree();
+    let mut expected = BTreeSet::new();
+
+    let mut random = LcgRandom::new(12345);
+    for _ in 0..1000 {
+        let value = random.next_u64() % 200;
+        if random.next_u64().is_multiple_of(3) {
+            assert_eq!(tree.remove(&value), expected.remove(&value));
+        } else {
+            assert_eq!(tree.insert(value), expected.insert(value));
+        }
+        assert!(tree.check_internal_consistency());
+    }
+    check_tree(&tree, &expected);
+
+    for (index, value) in expected.iter().enumerate() {
+        assert_eq!(tree.rank(value), index);
+        assert_eq!(tree.select(index), Some(*value));
+    }
+}
+
+#[test]
+fn test_avl_tree_clear() {
+    let mut tree = create_tree();
+    for i in 0..10 {
+        tree.insert(i);
+    }
+    tree.clear();
+    assert!(tree.is_empty());
+    assert!(tree.check_internal_consistency());
+    assert_eq!(tree.iter().count(), 0);
+
+    tree.insert(5);
+    check_tree(&tree, &BTreeSet::from([5]));
+}
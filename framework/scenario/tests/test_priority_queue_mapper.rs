@@ -0,0 +1,112 @@
+mod lcg_random;
+
+use lcg_random::LcgRandom;
+use multiversx_sc::storage::{
+    mappers::{PriorityQueueMapper, StorageMapper, VecMapper},
+    StorageKey,
+};
+use multiversx_sc_scenario::api::SingleTxApi;
+
+fn create_priority_queue() -> PriorityQueueMapper<SingleTxApi, u64> {
+    let base_key = StorageKey::new(&b"my_priority_queue"[..]);
+    PriorityQueueMapper::new(base_key)
+}
+
+#[test]
+fn test_priority_queue_simple() {
+    let mut queue = create_priority_queue();
+    assert!(queue.is_empty());
+    assert_eq!(queue.peek(), None);
+    assert_eq!(queue.pop(), None);
+
+    queue.push(44);
+    queue.push(42);
+    queue.push(43);
+    assert_eq!(queue.len(), 3);
+    assert!(queue.check_internal_consistency());
+    assert_eq!(queue.peek(), Some(42));
+
+    assert_eq!(queue.pop(), Some(42));
+    assert!(queue.check_internal_consistency());
+    assert_eq!(queue.pop(), Some(43));
+    assert_eq!(queue.pop(), Some(44));
+    assert_eq!(queue.pop(), None);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn test_priority_queue_duplicates() {
+    let mut queue = create_priority_queue();
+    for item in [5, 1, 5, 3, 1, 5] {
+        queue.push(item);
+        assert!(queue.check_internal_consistency());
+    }
+
+    let mut popped = Vec::new();
+    while let Some(item) = queue.pop() {
+        assert!(queue.check_internal_consistency());
+        popped.push(item);
+    }
+    assert_eq!(popped, vec![1, 1, 3, 5, 5, 5]);
+}
+
+#[test]
+fn test_priority_queue_sorts() {
+    let mut queue = create_priority_queue();
+    let mut expected = Vec::new();
+
+    let mut random = LcgRandom::new(12345);
+    for _ in 0..200 {
+        let item = random.next_u64() % 1000;
+        queue.push(item);
+        expected.push(item);
+    }
+    assert_eq!(queue.len(), 200);
+    assert!(queue.check_internal_consistency());
+
+    expected.sort_unstable();
+    assert_eq!(queue.peek(), expected.first().cloned());
+
+    let mut popped = Vec::new();
+    while let Some(item) = queue.pop() {
+        popped.push(item);
+    }
+    assert_eq!(popped, expected);
+}
+
+#[test]
+fn test_priority_queue_interleaved() {
+    let mut queue = create_priority_queue();
+    queue.push(10);
+    queue.push(20);
+    assert_eq!(queue.pop(), Some(10));
+    queue.push(5);
+    queue.push(30);
+    queue.push(15);
+    assert!(queue.check_internal_consistency());
+    assert_eq!(queue.pop(), Some(5));
+    assert_eq!(queue.pop(), Some(15));
+    queue.push(1);
+    assert_eq!(queue.pop(), Some(1));
+    assert_eq!(queue.pop(), Some(20));
+    assert_eq!(queue.pop(), Some(30));
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn test_priority_queue_vec_mapper_layout() {
+    let mut queue = create_priority_queue();
+    for item in [3, 2, 1] {
+        queue.push(item);
+    }
+
+    let vec_mapper: VecMapper<SingleTxApi, u64> =
+        VecMapper::new(StorageKey::new(&b"my_priority_queue"[..]));
+    assert_eq!(vec_mapper.len(), 3);
+    assert_eq!(vec_mapper.get(1), 1);
+    assert_eq!(queue.iter().collect::<Vec<u64>>(), vec_mapper.load_as_vec());
+
+    queue.clear();
+    assert!(queue.is_empty());
+    assert!(vec_mapper.is_empty());
+}
@@ -0,0 +1,208 @@
+mod vm_account;
+mod vm_block;
+mod vm_chain_simulator;
+mod vm_network;
+mod vm_tx;
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::anyhow;
+use multiversx_sc_scenario::scenario::run_vm::ScenarioVMRunner;
+use multiversx_sdk::{
+    data::{network_config::NetworkConfig, transaction::TransactionOnNetwork},
+    gateway::{GatewayAsyncService, GatewayRequest, GatewayRequestType},
+};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// Serves gateway requests from an in-process Rust VM, instead of a real network.
+///
+/// Allows interactors to run as regular tests, with no network access.
+/// It behaves like a chain simulator: transactions are executed as soon as they are sent,
+/// and blocks are only produced on request.
+#[derive(Clone)]
+pub struct GatewayVMProxy {
+    pub(crate) state: Arc<Mutex<GatewayVMState>>,
+}
+
+/// Everything the in-process gateway keeps track of.
+pub(crate) struct GatewayVMState {
+    pub(crate) vm_runner: ScenarioVMRunner,
+    pub(crate) network_config: NetworkConfig,
+    pub(crate) txs: HashMap<String, VMTxRecord>,
+}
+
+/// A transaction executed by the VM, as reported by the gateway.
+pub(crate) struct VMTxRecord {
+    pub(crate) tx: TransactionOnNetwork,
+    pub(crate) reason: String,
+}
+
+impl GatewayVMProxy {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(GatewayVMState {
+                vm_runner: ScenarioVMRunner::new(),
+                network_config: vm_network::default_network_config(),
+                txs: HashMap::new(),
+            })),
+        }
+    }
+
+    /// Gives direct access to the underlying VM, e.g. to register contracts or to inspect the state.
+    pub fn with_vm_runner<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut ScenarioVMRunner) -> R,
+    {
+        f(&mut self.state.lock().unwrap().vm_runner)
+    }
+
+    /// Performs a request against the VM.
+    ///
+    /// The response goes through the same JSON decoding as the one coming from a real gateway.
+    pub fn vm_request<G>(&self, request: G) -> anyhow::Result<G::Result>
+    where
+        G: GatewayRequest,
+    {
+        let payload = serde_json::to_value(request.get_payload())?;
+        let response = self.state.lock().unwrap().handle_request(
+            request.request_type(),
+            &request.get_endpoint(),
+            payload,
+        );
+        let decoded = serde_json::from_value::<G::DecodedJson>(response)?;
+        request.process_json(decoded)
+    }
+}
+
+impl Default for GatewayVMProxy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GatewayAsyncService for GatewayVMProxy {
+    type Instant = std::time::Instant;
+
+    /// The URI is ignored, there is no network involved.
+    fn from_uri(_uri: &str) -> Self {
+        Self::new()
+    }
+
+    fn request<G>(&self, request: G) -> impl std::future::Future<Output = anyhow::Result<G::Result>>
+    where
+        G: GatewayRequest,
+    {
+        std::future::ready(self.vm_request(request))
+    }
+
+    fn sleep(&self, _millis: u64) -> impl std::future::Future<Output = ()> {
+        // transactions are executed on the spot, there is never anything to wait for
+        std::future::ready(())
+    }
+
+    fn now(&self) -> Self::Instant {
+        std::time::Instant::now()
+    }
+
+    fn elapsed_seconds(&self, instant: &Self::Instant) -> f32 {
+        instant.elapsed().as_secs_f32()
+    }
+}
+
+impl GatewayVMState {
+    /// Wraps the result in the usual gateway response envelope.
+    fn handle_request(
+        &mut self,
+        request_type: GatewayRequestType,
+        endpoint: &str,
+        payload: Value,
+    ) -> Value {
+        match self.route_request(request_type, endpoint, payload) {
+            Ok(data) => json!({ "data": data, "error": "", "code": "successful" }),
+            Err(err) => json!({ "data": null, "error": err.to_string(), "code": "bad_request" }),
+        }
+    }
+
+    fn route_request(
+        &mut self,
+        request_type: GatewayRequestType,
+        endpoint: &str,
+        payload: Value,
+    ) -> anyhow::Result<Value> {
+        let path = endpoint.split('?').next().unwrap_or_default();
+        let segments: Vec<&str> = path.split('/').collect();
+
+        match (request_type, segments.as_slice()) {
+            (GatewayRequestType::Get, ["address", address]) => to_data(self.account(address)),
+            (GatewayRequestType::Get, ["address", address, "keys"]) => {
+                to_data(self.account_storage(address))
+            },
+            (GatewayRequestType::Get, ["address", address, "esdt"]) => {
+                to_data(self.account_esdt_tokens(address))
+            },
+            (GatewayRequestType::Get, ["address", address, "esdts", "roles"]) => {
+                to_data(self.account_esdt_roles(address))
+            },
+            (GatewayRequestType::Get, ["network", "config"]) => to_data(Ok(self.network_config())),
+            (GatewayRequestType::Get, ["network", "economics"]) => {
+                to_data(Ok(self.network_economics()))
+            },
+            (GatewayRequestType::Get, ["network", "status", _shard]) => {
+                to_data(Ok(self.network_status()))
+            },
+            (GatewayRequestType::Get, ["hyperblock", "by-nonce", nonce]) => {
+                to_data(self.hyperblock_by_nonce(nonce.parse()?))
+            },
+            (GatewayRequestType::Get, ["hyperblock", "by-hash", hash]) => {
+                to_data(self.hyperblock_by_hash(hash))
+            },
+            (GatewayRequestType::Post, ["transaction", "cost"]) => {
+                to_data(self.tx_cost(serde_json::from_value(payload)?))
+            },
+            (GatewayRequestType::Post, ["transaction", "send"]) => {
+                to_data(self.send_tx(serde_json::from_value(payload)?))
+            },
+            (GatewayRequestType::Post, ["transaction", "send-multiple"]) => {
+                to_data(self.send_multiple_txs(serde_json::from_value(payload)?))
+            },
+            (GatewayRequestType::Post, ["transaction", "send-user-funds"]) => {
+                to_data(self.send_user_funds(serde_json::from_value(payload)?))
+            },
+            (GatewayRequestType::Get, ["transaction", hash]) => to_data(self.tx_info(hash)),
+            (GatewayRequestType::Get, ["transaction", hash, "status"]) => {
+                to_data(self.tx_status(hash))
+            },
+            (GatewayRequestType::Get, ["transaction", hash, "process-status"]) => {
+                to_data(self.tx_process_status(hash))
+            },
+            (GatewayRequestType::Post, ["vm-values", "query"]) => {
+                to_data(self.vm_query(serde_json::from_value(payload)?))
+            },
+            (GatewayRequestType::Post, ["simulator", "generate-blocks", num_blocks]) => {
+                to_data(self.generate_blocks(num_blocks.parse()?))
+            },
+            (
+                GatewayRequestType::Post,
+                ["simulator", "generate-blocks-until-epoch-reached", epoch],
+            ) => to_data(self.generate_blocks_until_epoch(epoch.parse()?)),
+            (
+                GatewayRequestType::Post,
+                ["simulator", "generate-blocks-until-transaction-processed", hash],
+            ) => to_data(self.generate_blocks_until_tx_processed(hash)),
+            (GatewayRequestType::Post, ["simulator", "set-state"]) => {
+                to_data(self.set_state(serde_json::from_value(payload)?))
+            },
+            _ => Err(anyhow!(
+                "endpoint not supported by the VM gateway: {endpoint}"
+            )),
+        }
+    }
+}
+
+fn to_data<T: Serialize>(result: anyhow::Result<T>) -> anyhow::Result<Value> {
+    Ok(serde_json::to_value(result?)?)
+}
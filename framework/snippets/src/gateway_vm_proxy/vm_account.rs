@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use multiversx_sc_scenario::multiversx_chain_vm::{
+    crypto_functions::keccak256,
+    types::VMAddress,
+    world_mock::{AccountData, EsdtData},
+};
+use multiversx_sdk::{
+    data::{
+        account::{Account, AccountData as AccountResponseData},
+        account_storage::AccountStorage,
+        esdt::{EsdtBalance, EsdtBalanceData, EsdtRolesData},
+        sdk_address::SdkAddress,
+    },
+    utils::base64_encode,
+};
+
+use super::GatewayVMState;
+
+impl GatewayVMState {
+    /// Missing accounts are reported as empty, the same as on a real network.
+    pub(crate) fn account(&self, bech32_address: &str) -> Result<AccountResponseData> {
+        let address = SdkAddress::from_bech32_string(bech32_address)?;
+        let account = match self.vm_account(&address.0) {
+            Some(account_data) => vm_account_to_api(account_data),
+            None => Account {
+                address,
+                nonce: 0,
+                balance: "0".to_string(),
+                username: String::new(),
+                code: String::new(),
+                code_hash: None,
+                root_hash: None,
+                code_metadata: None,
+                developer_reward: None,
+                owner_address: None,
+            },
+        };
+        Ok(AccountResponseData { account })
+    }
+
+    /// Keys and values are hex-encoded.
+    pub(crate) fn account_storage(&self, bech32_address: &str) -> Result<AccountStorage> {
+        let address = SdkAddress::from_bech32_string(bech32_address)?;
+        let pairs = self
+            .vm_account(&address.0)
+            .map(|account_data| {
+                account_data
+                    .storage
+                    .iter()
+                    .map(|(key, value)| (hex::encode(key), hex::encode(value)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(AccountStorage { pairs })
+    }
+
+    /// Non-fungible instances are listed separately, the nonce is appended to their identifier.
+    pub(crate) fn account_esdt_tokens(&self, bech32_address: &str) -> Result<EsdtBalanceData> {
+        let address = SdkAddress::from_bech32_string(bech32_address)?;
+        let mut esdts = HashMap::new();
+        if let Some(account_data) = self.vm_account(&address.0) {
+            for (token_identifier, esdt_data) in account_data.esdt.iter() {
+                add_esdt_balances(&mut esdts, token_identifier, esdt_data);
+            }
+        }
+        Ok(EsdtBalanceData { esdts })
+    }
+
+    pub(crate) fn account_esdt_roles(&self, bech32_address: &str) -> Result<EsdtRolesData> {
+        let address = SdkAddress::from_bech32_string(bech32_address)?;
+        let mut roles = HashMap::new();
+        if let Some(account_data) = self.vm_account(&address.0) {
+            for (token_identifier, esdt_data) in account_data.esdt.iter() {
+                let token_roles: Vec<String> = esdt_data
+                    .get_roles()
+                    .into_iter()
+                    .map(|role| String::from_utf8_lossy(&role).into_owned())
+                    .collect();
+                if !token_roles.is_empty() {
+                    roles.insert(
+                        String::from_utf8_lossy(token_identifier).into_owned(),
+                        token_roles,
+                    );
+                }
+            }
+        }
+        Ok(EsdtRolesData { roles })
+    }
+
+    fn vm_account(&self, address: &VMAddress) -> Option<&AccountData> {
+        self.vm_runner.blockchain_mock.state.accounts.get(address)
+    }
+}
+
+fn vm_account_to_api(account_data: &AccountData) -> Account {
+    let code = account_data.contract_path.clone().unwrap_or_default();
+    let is_contract = !code.is_empty();
+    Account {
+        address: account_data.address.clone().into(),
+        nonce: account_data.nonce,
+        balance: account_data.egld_balance.to_string(),
+        username: String::from_utf8_lossy(&account_data.username).into_owned(),
+        code: hex::encode(&code),
+        code_hash: is_contract.then(|| base64_encode(keccak256(&code))),
+        root_hash: None,
+        code_metadata: is_contract
+            .then(|| base64_encode(account_data.code_metadata.to_byte_array())),
+        developer_reward: Some(account_data.developer_rewards.to_string()),
+        owner_address: account_data
+            .contract_owner
+            .as_ref()
+            .map(|owner| SdkAddress(owner.clone()).to_string()),
+    }
+}
+
+fn add_esdt_balances(
+    esdts: &mut HashMap<String, EsdtBalance>,
+    token_identifier: &[u8],
+    esdt_data: &EsdtData,
+) {
+    let token_identifier = String::from_utf8_lossy(token_identifier).into_owned();
+    for (nonce, instance) in esdt_data.instances.get_instances() {
+        if instance.balance.bits() == 0 {
+            continue;
+        }
+
+        let key = if *nonce == 0 {
+            token_identifier.clone()
+        } else {
+            format!("{token_identifier}-{}", hex_nonce(*nonce))
+        };
+        esdts.insert(
+            key.clone(),
+            EsdtBalance {
+                token_identifier: key,
+                balance: instance.balance.to_string(),
+            },
+        );
+    }
+}
+
+/// Nonces appear in token identifiers as hex, with an even number of digits.
+fn hex_nonce(nonce: u64) -> String {
+    let mut encoded = format!("{nonce:x}");
+    if encoded.len() % 2 == 1 {
+        encoded.insert(0, '0');
+    }
+    encoded
+}
@@ -0,0 +1,100 @@
+use anyhow::{anyhow, Result};
+use multiversx_sc_scenario::multiversx_chain_vm::world_mock::BlockInfo;
+use multiversx_sdk::data::hyperblock::{HyperBlock, ShardBlocks};
+
+use super::GatewayVMState;
+
+/// Same as the chain simulator default.
+pub(crate) const ROUNDS_PER_EPOCH: u64 = 20;
+
+impl GatewayVMState {
+    /// Blocks are produced one by one, each round has a block, epochs change every [`ROUNDS_PER_EPOCH`] rounds.
+    pub(crate) fn produce_block(&mut self) {
+        let state = &mut self.vm_runner.blockchain_mock.state;
+        state.previous_block_info = state.current_block_info.clone();
+        state.current_block_info.block_nonce += 1;
+
+        // the hash, and with it the random seed, only become available once the nonce is current
+        let current_block_info = self.block_info(self.current_block_nonce());
+        self.vm_runner.blockchain_mock.state.current_block_info = current_block_info;
+    }
+
+    /// Block data only depends on the nonce, so it can be reconstructed for past blocks too.
+    pub(crate) fn block_info(&self, block_nonce: u64) -> BlockInfo {
+        let mut block_random_seed = Box::new([0u8; 48]);
+        if let Some(block_hash) = self.vm_runner.blockchain_mock.state.block_hash(block_nonce) {
+            block_random_seed[..32].copy_from_slice(block_hash.as_bytes());
+            block_random_seed[32..].copy_from_slice(&block_hash.as_bytes()[..16]);
+        }
+
+        BlockInfo {
+            block_timestamp: block_nonce * self.round_duration_seconds(),
+            block_nonce,
+            block_round: block_nonce,
+            block_epoch: block_nonce / ROUNDS_PER_EPOCH,
+            block_random_seed,
+        }
+    }
+
+    pub(crate) fn current_block_nonce(&self) -> u64 {
+        self.vm_runner
+            .blockchain_mock
+            .state
+            .current_block_info
+            .block_nonce
+    }
+
+    pub(crate) fn block_hash_hex(&self, block_nonce: u64) -> Option<String> {
+        self.vm_runner
+            .blockchain_mock
+            .state
+            .block_hash(block_nonce)
+            .map(|block_hash| hex::encode(block_hash.as_bytes()))
+    }
+
+    pub(crate) fn hyperblock_by_nonce(&self, block_nonce: u64) -> Result<HyperBlock> {
+        let hash = self
+            .block_hash_hex(block_nonce)
+            .ok_or_else(|| anyhow!("block with nonce {block_nonce} not found"))?;
+        let block_info = self.block_info(block_nonce);
+        let prev_block_hash = block_nonce
+            .checked_sub(1)
+            .and_then(|prev_nonce| self.block_hash_hex(prev_nonce))
+            .unwrap_or_default();
+        let num_txs = self
+            .txs
+            .values()
+            .filter(|record| record.tx.block_nonce == block_nonce)
+            .count() as u64;
+
+        Ok(HyperBlock {
+            nonce: block_nonce,
+            round: block_info.block_round,
+            hash: hash.clone(),
+            prev_block_hash,
+            epoch: block_info.block_epoch,
+            num_txs,
+            shard_blocks: vec![ShardBlocks {
+                hash,
+                nonce: block_nonce,
+                shard: 0,
+            }],
+            timestamp: block_info.block_timestamp,
+            accumulated_fees: "0".to_string(),
+            developer_fees: "0".to_string(),
+            accumulated_fees_in_epoch: "0".to_string(),
+            developer_fees_in_epoch: "0".to_string(),
+        })
+    }
+
+    pub(crate) fn hyperblock_by_hash(&self, hash: &str) -> Result<HyperBlock> {
+        let block_nonce = (0..=self.current_block_nonce())
+            .find(|block_nonce| self.block_hash_hex(*block_nonce).as_deref() == Some(hash))
+            .ok_or_else(|| anyhow!("block with hash {hash} not found"))?;
+        self.hyperblock_by_nonce(block_nonce)
+    }
+
+    fn round_duration_seconds(&self) -> u64 {
+        self.network_config.round_duration as u64 / 1000
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use multiversx_sc_scenario::{
+    multiversx_chain_vm::{types::VMCodeMetadata, world_mock::AccountData},
+    num_bigint::BigUint,
+};
+use multiversx_sdk::{
+    data::sdk_address::SdkAddress, gateway::SetStateAccount, utils::base64_decode,
+};
+use serde_json::Value;
+
+use super::GatewayVMState;
+
+/// Same amount as the one sent by the chain simulator: 10000 EGLD.
+const USER_FUNDS: u64 = 10_000;
+const EGLD_DECIMALS: u32 = 18;
+
+impl GatewayVMState {
+    pub(crate) fn send_user_funds(&mut self, payload: HashMap<String, String>) -> Result<Value> {
+        let receiver = payload
+            .get("receiver")
+            .ok_or_else(|| anyhow!("missing receiver"))?;
+        let address = SdkAddress::from_bech32_string(receiver)?.0;
+        let amount = BigUint::from(USER_FUNDS) * BigUint::from(10u32).pow(EGLD_DECIMALS);
+
+        let state = &mut self.vm_runner.blockchain_mock.state;
+        state
+            .accounts
+            .entry(address.clone())
+            .or_insert_with(|| AccountData::new_empty(address))
+            .egld_balance += amount;
+        Ok(Value::Null)
+    }
+
+    pub(crate) fn generate_blocks(&mut self, num_blocks: u64) -> Result<Value> {
+        for _ in 0..num_blocks {
+            self.produce_block();
+        }
+        Ok(Value::Null)
+    }
+
+    pub(crate) fn generate_blocks_until_epoch(&mut self, epoch: u64) -> Result<Value> {
+        while self
+            .vm_runner
+            .blockchain_mock
+            .state
+            .current_block_info
+            .block_epoch
+            < epoch
+        {
+            self.produce_block();
+        }
+        Ok(Value::Null)
+    }
+
+    /// Transactions are executed as soon as they are sent, so there is nothing to wait for.
+    pub(crate) fn generate_blocks_until_tx_processed(&mut self, tx_hash: &str) -> Result<Value> {
+        if !self.txs.contains_key(tx_hash) {
+            return Err(anyhow!("transaction {tx_hash} not found"));
+        }
+        Ok(Value::Null)
+    }
+
+    /// Accounts are replaced completely, the same as in the chain simulator.
+    pub(crate) fn set_state(&mut self, accounts: Vec<SetStateAccount>) -> Result<Value> {
+        for set_state_account in accounts {
+            let account_data = account_data_from_set_state(set_state_account)?;
+            self.vm_runner
+                .blockchain_mock
+                .state
+                .add_account(account_data);
+        }
+        Ok(Value::Null)
+    }
+}
+
+fn account_data_from_set_state(set_state_account: SetStateAccount) -> Result<AccountData> {
+    let address = SdkAddress::from_bech32_string(&set_state_account.address)?.0;
+    let mut account_data = AccountData::new_empty(address);
+    account_data.nonce = set_state_account.nonce;
+    account_data.egld_balance = parse_biguint(&set_state_account.balance)?;
+    account_data.developer_rewards = parse_biguint(&set_state_account.developer_reward)?;
+
+    for (key, value) in &set_state_account.keys {
+        account_data
+            .storage
+            .insert(hex::decode(key)?, hex::decode(value)?);
+    }
+
+    if !set_state_account.code.is_empty() {
+        account_data.contract_path = Some(hex::decode(&set_state_account.code)?);
+    }
+    match (
+        account_data.address.is_smart_contract_address(),
+        account_data.contract_path.is_some(),
+    ) {
+        (true, false) => {
+            return Err(anyhow!(
+                "account {} has a smart contract address but no code",
+                set_state_account.address
+            ))
+        },
+        (false, true) => {
+            return Err(anyhow!(
+                "account {} has code but no smart contract address",
+                set_state_account.address
+            ))
+        },
+        _ => {},
+    }
+    if !set_state_account.code_metadata.is_empty() {
+        account_data.code_metadata =
+            VMCodeMetadata::from(&base64_decode(&set_state_account.code_metadata));
+    }
+    if !set_state_account.owner_address.is_empty() {
+        account_data.contract_owner =
+            Some(SdkAddress::from_bech32_string(&set_state_account.owner_address)?.0);
+    }
+
+    Ok(account_data)
+}
+
+fn parse_biguint(s: &str) -> Result<BigUint> {
+    if s.is_empty() {
+        return Ok(BigUint::default());
+    }
+    Ok(s.parse()?)
+}
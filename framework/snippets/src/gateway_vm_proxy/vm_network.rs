@@ -0,0 +1,76 @@
+use multiversx_sc_scenario::num_bigint::BigUint;
+use multiversx_sdk::data::{
+    network_config::{NetworkConfig, NetworkConfigData},
+    network_economics::{NetworkEconomics, NetworkEconomicsData},
+    network_status::{NetworkStatus, NetworkStatusData},
+};
+
+use super::{vm_block::ROUNDS_PER_EPOCH, GatewayVMState};
+
+/// Mirrors the configuration of a local chain simulator.
+pub(crate) fn default_network_config() -> NetworkConfig {
+    NetworkConfig {
+        chain_id: "chain".to_string(),
+        denomination: 18,
+        gas_per_data_byte: 1_500,
+        latest_tag_software_version: "rust-vm".to_string(),
+        meta_consensus_group_size: 1,
+        min_gas_limit: 50_000,
+        min_gas_price: 1_000_000_000,
+        min_transaction_version: 1,
+        num_metachain_nodes: 1,
+        num_nodes_in_shard: 1,
+        num_shards_without_meta: 1,
+        round_duration: 6_000,
+        shard_consensus_group_size: 1,
+        start_time: 0,
+    }
+}
+
+impl GatewayVMState {
+    pub(crate) fn network_config(&self) -> NetworkConfigData {
+        NetworkConfigData {
+            config: self.network_config.clone(),
+        }
+    }
+
+    /// Only the total supply is tracked, as the sum of all EGLD balances.
+    pub(crate) fn network_economics(&self) -> NetworkEconomicsData {
+        let state = &self.vm_runner.blockchain_mock.state;
+        let total_supply: BigUint = state
+            .accounts
+            .values()
+            .map(|account| &account.egld_balance)
+            .sum();
+
+        NetworkEconomicsData {
+            metrics: NetworkEconomics {
+                dev_rewards: "0".to_string(),
+                epoch_for_economics_data: state.current_block_info.block_epoch as u32,
+                inflation: "0".to_string(),
+                total_fees: "0".to_string(),
+                total_base_staked_value: "0".to_string(),
+                total_supply: total_supply.to_string(),
+                total_top_up_value: "0".to_string(),
+            },
+        }
+    }
+
+    pub(crate) fn network_status(&self) -> NetworkStatusData {
+        let block_info = &self.vm_runner.blockchain_mock.state.current_block_info;
+        let round_at_epoch_start = block_info.block_epoch * ROUNDS_PER_EPOCH;
+
+        NetworkStatusData {
+            status: NetworkStatus {
+                current_round: block_info.block_round,
+                epoch_number: block_info.block_epoch,
+                nonce: block_info.block_nonce,
+                nonce_at_epoch_start: round_at_epoch_start,
+                nonces_passed_in_current_epoch: block_info.block_nonce - round_at_epoch_start,
+                round_at_epoch_start,
+                rounds_passed_in_current_epoch: block_info.block_round - round_at_epoch_start,
+                rounds_per_epoch: ROUNDS_PER_EPOCH as u32,
+            },
+        }
+    }
+}
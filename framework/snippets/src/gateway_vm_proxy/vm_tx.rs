@@ -0,0 +1,575 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use multiversx_sc_scenario::{
+    imports::ReturnCode,
+    multiversx_chain_vm::{
+        crypto_functions::verify_ed25519,
+        tx_execution::{execute_current_tx_context_input, BlockchainVMRef},
+        tx_mock::{
+            derive_child_tx_hash, generate_user_tx_hash, TxFunctionName, TxInput, TxLog, TxResult,
+        },
+        types::{VMAddress, VMCodeMetadata, H256},
+        with_shared::Shareable,
+        world_mock::{AccountData, BlockchainState},
+    },
+    num_bigint::BigUint,
+};
+use multiversx_sdk::{
+    data::{
+        sdk_address::SdkAddress,
+        transaction::{
+            ApiLogs, ApiSmartContractResult, Events, LogData, SendTransactionData,
+            SendTransactionsResponseData, Transaction, TransactionInfoData, TransactionOnNetwork,
+            TransactionProcessStatusData, TransactionStatusData, TxCostResponseData,
+        },
+        vm::{CallType, VMOutputApi, VMQueryInput, VmValuesResponseData},
+    },
+    signer::tx_signing_payload,
+    utils::{base64_decode, base64_encode},
+};
+
+use crate::network_response::{
+    compute_deployed_address, LOG_IDENTIFIER_SIGNAL_ERROR, SC_DEPLOY_PROCESSING_TYPE,
+};
+
+use super::{GatewayVMState, VMTxRecord};
+
+const MOVE_BALANCE_PROCESSING_TYPE: &str = "MoveBalance";
+const SC_INVOKING_PROCESSING_TYPE: &str = "SCInvoking";
+const STATUS_SUCCESS: &str = "success";
+const STATUS_FAIL: &str = "fail";
+
+/// Smart contract results start with the result code, "ok", hex-encoded.
+const SCR_DATA_OK: &str = "@6f6b";
+
+/// A transaction, decoded and ready to be run by the VM.
+struct VMTx {
+    tx_input: TxInput,
+    /// Only set for deploys.
+    deploy: Option<(Vec<u8>, VMCodeMetadata)>,
+}
+
+impl GatewayVMState {
+    pub(crate) fn send_tx(&mut self, tx: Transaction) -> Result<SendTransactionData> {
+        let tx_hash = self.execute_tx(&tx)?;
+        Ok(SendTransactionData { tx_hash })
+    }
+
+    /// Transactions are executed in order. Rejected transactions are missing from the result.
+    pub(crate) fn send_multiple_txs(
+        &mut self,
+        txs: Vec<Transaction>,
+    ) -> Result<SendTransactionsResponseData> {
+        let mut txs_hashes = HashMap::new();
+        for (index, tx) in txs.iter().enumerate() {
+            if let Ok(tx_hash) = self.execute_tx(tx) {
+                txs_hashes.insert(index as i32, tx_hash);
+            }
+        }
+
+        Ok(SendTransactionsResponseData {
+            num_of_sent_txs: txs_hashes.len() as i32,
+            txs_hashes,
+        })
+    }
+
+    /// Runs the transaction on a copy of the state, which then gets discarded.
+    ///
+    /// Nonce and balance are not checked, and no gas fee is charged.
+    pub(crate) fn tx_cost(&self, tx: Transaction) -> Result<TxCostResponseData> {
+        if !self.account_exists(&tx.sender.0) {
+            return Err(anyhow!("sender account not found"));
+        }
+
+        let data_len = tx_data(&tx).len() as u64;
+        let mut vm_tx = self.decode_tx(&tx)?;
+        vm_tx.tx_input.gas_price = 0;
+
+        let mut state_copy = Shareable::new((*self.vm_runner.blockchain_mock.state).clone());
+        let (_, tx_result) = execute_vm_tx(
+            &self.vm_runner.blockchain_mock.vm,
+            &mut state_copy,
+            vm_tx,
+            tx.nonce,
+        );
+
+        Ok(TxCostResponseData {
+            tx_gas_units: self.network_config.min_gas_limit
+                + self.network_config.gas_per_data_byte * data_len
+                + tx_result.gas_used,
+            return_message: tx_result.result_message,
+        })
+    }
+
+    pub(crate) fn tx_info(&self, tx_hash: &str) -> Result<TransactionInfoData> {
+        Ok(TransactionInfoData {
+            transaction: self.tx_record(tx_hash)?.tx.clone(),
+        })
+    }
+
+    pub(crate) fn tx_status(&self, tx_hash: &str) -> Result<TransactionStatusData> {
+        Ok(TransactionStatusData {
+            status: self.tx_record(tx_hash)?.tx.status.clone(),
+        })
+    }
+
+    pub(crate) fn tx_process_status(&self, tx_hash: &str) -> Result<TransactionProcessStatusData> {
+        let record = self.tx_record(tx_hash)?;
+        Ok(TransactionProcessStatusData {
+            reason: record.reason.clone(),
+            status: record.tx.status.clone(),
+        })
+    }
+
+    /// Failed queries are reported through the return code and message, the same as on a real network.
+    pub(crate) fn vm_query(&mut self, query: VMQueryInput) -> Result<VmValuesResponseData> {
+        let sc_address = query.sc_address.0;
+        let args = query
+            .args
+            .iter()
+            .map(hex::decode)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let tx_result = if self.account_has_code(&sc_address) {
+            let tx_input = TxInput {
+                from: sc_address.clone(),
+                to: sc_address,
+                func_name: query.func_name.into(),
+                args,
+                gas_limit: u64::MAX,
+                gas_price: 0,
+                ..Default::default()
+            };
+            let blockchain_mock = &mut self.vm_runner.blockchain_mock;
+            blockchain_mock.vm.execute_sc_query_lambda(
+                tx_input,
+                &mut blockchain_mock.state,
+                execute_current_tx_context_input,
+            )
+        } else {
+            contract_not_found_result()
+        };
+
+        Ok(VmValuesResponseData {
+            data: VMOutputApi {
+                return_data: tx_result.result_values.iter().map(base64_encode).collect(),
+                return_code: tx_result.result_status.message().to_string(),
+                return_message: tx_result.result_message,
+                gas_remaining: 0,
+                gas_refund: 0,
+                output_accounts: HashMap::new(),
+                deleted_accounts: None,
+                touched_accounts: None,
+                logs: None,
+            },
+        })
+    }
+
+    /// Checks, executes and records a transaction, in one go.
+    fn execute_tx(&mut self, tx: &Transaction) -> Result<String> {
+        self.check_tx(tx)?;
+        let vm_tx = self.decode_tx(tx)?;
+        let tx_hash = vm_tx.tx_input.tx_hash.clone();
+        let processing_type = processing_type(&vm_tx);
+
+        let blockchain_mock = &mut self.vm_runner.blockchain_mock;
+        let (executed_on, tx_result) = execute_vm_tx(
+            &blockchain_mock.vm,
+            &mut blockchain_mock.state,
+            vm_tx,
+            tx.nonce,
+        );
+
+        let tx_on_network =
+            self.tx_on_network(tx, &tx_hash, processing_type, &executed_on, &tx_result);
+        let tx_hash_hex = hex::encode(tx_hash.as_bytes());
+        self.txs.insert(
+            tx_hash_hex.clone(),
+            VMTxRecord {
+                tx: tx_on_network,
+                reason: failure_reason(&tx_result),
+            },
+        );
+
+        Ok(tx_hash_hex)
+    }
+
+    /// Rejects the transactions that would never make it into a block.
+    fn check_tx(&self, tx: &Transaction) -> Result<()> {
+        if tx.chain_id != self.network_config.chain_id {
+            return Err(anyhow!("invalid chain ID"));
+        }
+        if tx.gas_price < self.network_config.min_gas_price {
+            return Err(anyhow!("insufficient gas price in tx"));
+        }
+        let min_gas_limit = self.network_config.min_gas_limit
+            + self.network_config.gas_per_data_byte * tx_data(tx).len() as u64;
+        if tx.gas_limit < min_gas_limit {
+            return Err(anyhow!("insufficient gas limit in tx"));
+        }
+        check_signatures(tx)?;
+
+        let sender = self
+            .vm_runner
+            .blockchain_mock
+            .state
+            .accounts
+            .get(&tx.sender.0)
+            .ok_or_else(|| anyhow!("sender account not found"))?;
+        if tx.nonce < sender.nonce {
+            return Err(anyhow!("nonce too low"));
+        }
+        if tx.nonce > sender.nonce {
+            return Err(anyhow!("nonce too high"));
+        }
+
+        let value: BigUint = tx.value.parse()?;
+        let fee = BigUint::from(tx.gas_limit) * tx.gas_price;
+        match &tx.relayer {
+            Some(relayer) => {
+                let relayer_balance = self
+                    .account_balance(&relayer.0)
+                    .ok_or_else(|| anyhow!("relayer account not found"))?;
+                if *relayer_balance < fee || sender.egld_balance < value {
+                    return Err(anyhow!("insufficient funds"));
+                }
+            },
+            None => {
+                if sender.egld_balance < fee + value {
+                    return Err(anyhow!("insufficient funds"));
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Deploys are recognized by the zero receiver, their data field is `code@vm_type@code_metadata@args...`.
+    ///
+    /// All other transactions have a data field of the form `function@args...`, or no data at all.
+    fn decode_tx(&self, tx: &Transaction) -> Result<VMTx> {
+        let data = String::from_utf8(tx_data(tx))?;
+        let mut data_parts = data.split('@');
+        let first_part = data_parts.next().unwrap_or_default();
+
+        let mut tx_input = TxInput {
+            from: tx.sender.0.clone(),
+            to: tx.receiver.0.clone(),
+            egld_value: tx.value.parse()?,
+            gas_limit: tx.gas_limit,
+            gas_price: tx.gas_price,
+            relayer: tx.relayer.as_ref().map(|relayer| relayer.0.clone()),
+            ..Default::default()
+        };
+
+        let deploy = if tx.receiver.0.is_zero() {
+            let code = hex::decode(first_part)?;
+            if !self
+                .vm_runner
+                .contract_map_ref
+                .lock()
+                .contains_contract(&code)
+            {
+                return Err(anyhow!("contract code not registered in the VM"));
+            }
+            let _vm_type = data_parts
+                .next()
+                .ok_or_else(|| anyhow!("missing VM type"))?;
+            let code_metadata = data_parts
+                .next()
+                .ok_or_else(|| anyhow!("missing code metadata"))?;
+            tx_input.func_name = TxFunctionName::INIT;
+            Some((code, VMCodeMetadata::from(&hex::decode(code_metadata)?)))
+        } else {
+            tx_input.func_name = first_part.into();
+            None
+        };
+
+        tx_input.args = data_parts.map(hex::decode).collect::<Result<_, _>>()?;
+        tx_input.tx_hash = generate_user_tx_hash(&tx_input, tx.nonce);
+
+        Ok(VMTx { tx_input, deploy })
+    }
+
+    fn tx_on_network(
+        &self,
+        tx: &Transaction,
+        tx_hash: &H256,
+        processing_type: &str,
+        executed_on: &VMAddress,
+        tx_result: &TxResult,
+    ) -> TransactionOnNetwork {
+        let block_info = &self.vm_runner.blockchain_mock.state.current_block_info;
+        let block_hash = self
+            .block_hash_hex(block_info.block_nonce)
+            .unwrap_or_default();
+        let is_success = tx_result.result_status.is_success();
+
+        let mut smart_contract_results = Vec::new();
+        if is_success && processing_type != MOVE_BALANCE_PROCESSING_TYPE {
+            smart_contract_results.push(out_scr(tx, tx_hash, executed_on, tx_result));
+        }
+
+        let mut events: Vec<Events> = tx_result.result_logs.iter().map(log_to_event).collect();
+        if !is_success {
+            events.push(signal_error_event(&tx.sender, &tx_result.result_message));
+        }
+
+        TransactionOnNetwork {
+            kind: "normal".to_string(),
+            hash: Some(hex::encode(tx_hash.as_bytes())),
+            nonce: tx.nonce,
+            round: block_info.block_round,
+            epoch: block_info.block_epoch,
+            value: tx.value.clone(),
+            receiver: tx.receiver.clone(),
+            sender: tx.sender.clone(),
+            gas_price: tx.gas_price,
+            gas_limit: tx.gas_limit,
+            gas_used: tx_result.gas_used,
+            signature: tx.signature.clone().unwrap_or_default(),
+            source_shard: 0,
+            destination_shard: 0,
+            block_nonce: block_info.block_nonce,
+            block_hash: block_hash.clone(),
+            notarized_at_source_in_meta_nonce: Some(block_info.block_nonce),
+            notarized_at_source_in_meta_hash: Some(block_hash.clone()),
+            notarized_at_destination_in_meta_nonce: Some(block_info.block_nonce),
+            notarized_at_destination_in_meta_hash: Some(block_hash.clone()),
+            processing_type_on_destination: processing_type.to_string(),
+            miniblock_type: "TxBlock".to_string(),
+            miniblock_hash: block_hash.clone(),
+            timestamp: block_info.block_timestamp,
+            data: Some(tx.data.clone().unwrap_or_default()),
+            status: if is_success {
+                STATUS_SUCCESS
+            } else {
+                STATUS_FAIL
+            }
+            .to_string(),
+            hyperblock_nonce: Some(block_info.block_nonce),
+            hyperblock_hash: Some(block_hash),
+            smart_contract_results,
+            logs: Some(ApiLogs {
+                address: executed_on.clone().into(),
+                events,
+            }),
+        }
+    }
+
+    fn tx_record(&self, tx_hash: &str) -> Result<&VMTxRecord> {
+        self.txs
+            .get(tx_hash)
+            .ok_or_else(|| anyhow!("transaction {tx_hash} not found"))
+    }
+
+    fn account_exists(&self, address: &VMAddress) -> bool {
+        self.vm_runner.blockchain_mock.state.account_exists(address)
+    }
+
+    fn account_has_code(&self, address: &VMAddress) -> bool {
+        self.vm_runner
+            .blockchain_mock
+            .state
+            .accounts
+            .get(address)
+            .is_some_and(|account| account.contract_path.is_some())
+    }
+
+    fn account_balance(&self, address: &VMAddress) -> Option<&BigUint> {
+        self.vm_runner
+            .blockchain_mock
+            .state
+            .accounts
+            .get(address)
+            .map(|account| &account.egld_balance)
+    }
+}
+
+/// Runs the transaction, the same way the scenario runner does.
+///
+/// Returns the address the transaction was executed on, i.e. the new contract address, in case of deploy.
+fn execute_vm_tx(
+    vm: &BlockchainVMRef,
+    state: &mut Shareable<BlockchainState>,
+    vm_tx: VMTx,
+    sender_nonce: u64,
+) -> (VMAddress, TxResult) {
+    let tx_input = vm_tx.tx_input;
+
+    if let Some((code, code_metadata)) = vm_tx.deploy {
+        // the VM needs to be told the new address, we give it the one the protocol would produce
+        let new_address = compute_deployed_address(&tx_input.from, sender_nonce);
+        state.put_new_address(tx_input.from.clone(), sender_nonce, new_address);
+        return vm.sc_create(
+            tx_input,
+            &code,
+            code_metadata,
+            state,
+            execute_current_tx_context_input,
+        );
+    }
+
+    let receiver = tx_input.to.clone();
+    if !state.account_exists(&receiver) {
+        if receiver.is_smart_contract_address() {
+            // nonce gets increased and gas gets paid, even if the tx fails
+            state.increase_account_nonce(&tx_input.from);
+            state.subtract_tx_gas(tx_input.gas_payer(), tx_input.gas_limit, tx_input.gas_price);
+            return (receiver, contract_not_found_result());
+        }
+
+        // transfers to new addresses create them
+        state.add_account(AccountData::new_empty(receiver.clone()));
+    }
+
+    state.increase_account_nonce(&tx_input.from);
+    let tx_result =
+        vm.sc_call_with_async_and_callback(tx_input, state, execute_current_tx_context_input);
+    (receiver, tx_result)
+}
+
+fn processing_type(vm_tx: &VMTx) -> &'static str {
+    if vm_tx.deploy.is_some() {
+        SC_DEPLOY_PROCESSING_TYPE
+    } else if vm_tx.tx_input.func_name.is_empty() {
+        MOVE_BALANCE_PROCESSING_TYPE
+    } else {
+        SC_INVOKING_PROCESSING_TYPE
+    }
+}
+
+/// The sender, the relayer and the guardian all sign the same payload, which excludes all signatures.
+fn check_signatures(tx: &Transaction) -> Result<()> {
+    let payload = tx_signing_payload(tx);
+    check_signature(
+        &tx.sender,
+        tx.signature.as_deref(),
+        &payload,
+        "invalid signature",
+    )?;
+    if let Some(relayer) = &tx.relayer {
+        check_signature(
+            relayer,
+            tx.relayer_signature.as_deref(),
+            &payload,
+            "invalid relayer signature",
+        )?;
+    }
+    if tx.is_guarded() {
+        let guardian = tx
+            .guardian
+            .as_ref()
+            .ok_or_else(|| anyhow!("missing guardian address"))?;
+        check_signature(
+            guardian,
+            tx.guardian_signature.as_deref(),
+            &payload,
+            "invalid guardian signature",
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Signatures are hex-encoded, account addresses are their Ed25519 public keys.
+fn check_signature(
+    signer: &SdkAddress,
+    signature_hex: Option<&str>,
+    payload: &[u8],
+    error_message: &str,
+) -> Result<()> {
+    let signature = hex::decode(signature_hex.unwrap_or_default())?;
+    if !verify_ed25519(signer.0.as_bytes(), payload, &signature) {
+        return Err(anyhow!("{error_message}"));
+    }
+
+    Ok(())
+}
+
+fn tx_data(tx: &Transaction) -> Vec<u8> {
+    tx.data.as_ref().map(base64_decode).unwrap_or_default()
+}
+
+fn contract_not_found_result() -> TxResult {
+    TxResult {
+        result_status: ReturnCode::ContractNotFound,
+        result_message: ReturnCode::ContractNotFound.message().to_string(),
+        ..Default::default()
+    }
+}
+
+/// Same format as the reason given by a real network: `@<return code>@<hex-encoded message>`.
+///
+/// Empty for successful transactions.
+fn failure_reason(tx_result: &TxResult) -> String {
+    if tx_result.result_status.is_success() {
+        return String::new();
+    }
+
+    format!(
+        "@{:02x}@{}",
+        tx_result.result_status.as_u64(),
+        hex::encode(&tx_result.result_message)
+    )
+}
+
+/// The smart contract result that carries the results back to the sender.
+fn out_scr(
+    tx: &Transaction,
+    tx_hash: &H256,
+    executed_on: &VMAddress,
+    tx_result: &TxResult,
+) -> ApiSmartContractResult {
+    let mut data = SCR_DATA_OK.to_string();
+    for result_value in &tx_result.result_values {
+        data.push('@');
+        data.push_str(&hex::encode(result_value));
+    }
+
+    let tx_hash_hex = hex::encode(tx_hash.as_bytes());
+    let scr_hash = derive_child_tx_hash(tx_hash, executed_on, &tx.sender.0, 0);
+
+    ApiSmartContractResult {
+        hash: hex::encode(scr_hash.as_bytes()),
+        nonce: tx.nonce + 1,
+        value: 0,
+        receiver: tx.sender.clone(),
+        sender: executed_on.clone().into(),
+        data,
+        prev_tx_hash: tx_hash_hex.clone(),
+        original_tx_hash: tx_hash_hex,
+        gas_limit: 0,
+        gas_price: tx.gas_price,
+        call_type: CallType::DirectCall,
+        relayer_address: None,
+        relayed_value: None,
+        code: None,
+        code_metadata: None,
+        return_message: None,
+        original_sender: None,
+    }
+}
+
+/// Topics and data are base64-encoded, as on a real network.
+fn log_to_event(log: &TxLog) -> Events {
+    Events {
+        address: log.address.clone().into(),
+        identifier: log.endpoint.as_str().to_string(),
+        topics: Some(log.topics.iter().map(base64_encode).collect()),
+        data: LogData::Vec(log.data.iter().map(base64_encode).collect()),
+    }
+}
+
+fn signal_error_event(sender: &SdkAddress, message: &str) -> Events {
+    Events {
+        address: sender.clone(),
+        identifier: LOG_IDENTIFIER_SIGNAL_ERROR.to_string(),
+        topics: Some(vec![
+            base64_encode(sender.to_bytes()),
+            base64_encode(message),
+        ]),
+        data: LogData::Empty,
+    }
+}
@@ -1,10 +1,16 @@
 pub use crate::multiversx_sc_scenario::imports::*;
 
 pub use crate::{
-    dns_address_for_name, InteractorBase, InteractorPrepareAsync, InteractorRunAsync, StepBuffer,
+    dns_address_for_name, GatewayVMProxy, InteractorBase, InteractorPrepareAsync,
+    InteractorRunAsync, StepBuffer, VMInteractor,
 };
 
-pub use crate::sdk::{data::keystore::InsertPassword, test_wallets, wallet::Wallet};
+pub use crate::sdk::{
+    data::keystore::InsertPassword,
+    signer::{ExternalSigner, MockSigner, Signer},
+    test_wallets,
+    wallet::Wallet,
+};
 
 pub use env_logger;
 
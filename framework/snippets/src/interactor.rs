@@ -1,11 +1,14 @@
 mod interactor_base;
 mod interactor_chain_simulator;
 mod interactor_dns;
+mod interactor_nonce;
 mod interactor_scenario;
 mod interactor_sender;
 mod interactor_tx;
+mod interactor_vm;
 
 pub use interactor_base::*;
 pub use interactor_dns::*;
+pub use interactor_nonce::*;
 pub use interactor_sender::*;
 pub use interactor_tx::*;
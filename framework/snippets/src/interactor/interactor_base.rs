@@ -1,11 +1,15 @@
-use crate::sdk::{data::network_config::NetworkConfig, wallet::Wallet};
+use crate::sdk::{data::network_config::NetworkConfig, signer::Signer, wallet::Wallet};
 use multiversx_sc_scenario::{
-    imports::{Bech32Address, ScenarioRunner},
+    imports::{Bech32Address, ReturnCode, ScenarioRunner},
     mandos_system::{run_list::ScenarioRunnerList, run_trace::ScenarioTraceFile},
     meta::tools::find_current_workspace,
     multiversx_sc::types::Address,
 };
-use multiversx_sdk::gateway::{GatewayAsyncService, NetworkConfigRequest, SetStateAccount};
+use multiversx_sdk::{
+    data::transaction::TransactionOnNetwork,
+    gateway::{GatewayAsyncService, NetworkConfigRequest, SetStateAccount},
+    TxWatchError, TxWatcher,
+};
 use std::{
     collections::HashMap,
     fs::File,
@@ -14,7 +18,9 @@ use std::{
     time::Duration,
 };
 
-use crate::{account_tool::retrieve_account_as_scenario_set_state, Sender};
+use crate::{
+    account_tool::retrieve_account_as_scenario_set_state, Sender, DEFAULT_MULTI_TX_CHUNK_SIZE,
+};
 
 pub const INTERACTOR_SCENARIO_TRACE_PATH: &str = "interactor_trace.scen.json";
 pub const INTERACTOR_SET_STATE_PATH: &str = "set_state.json";
@@ -27,6 +33,8 @@ where
     pub use_chain_simulator: bool,
     pub network_config: NetworkConfig,
     pub sender_map: HashMap<Address, Sender>,
+    pub tx_watcher: TxWatcher,
+    pub multi_tx_chunk_size: usize,
 
     pub waiting_time_ms: u64,
     pub pre_runners: ScenarioRunnerList,
@@ -48,6 +56,8 @@ where
             use_chain_simulator: false,
             network_config,
             sender_map: HashMap::new(),
+            tx_watcher: TxWatcher::default(),
+            multi_tx_chunk_size: DEFAULT_MULTI_TX_CHUNK_SIZE,
             waiting_time_ms: 0,
             pre_runners: ScenarioRunnerList::empty(),
             post_runners: ScenarioRunnerList::empty(),
@@ -60,8 +70,39 @@ where
         self
     }
 
+    /// Configures how sent transactions are awaited (polling, timeout, finality).
+    pub fn with_tx_watcher(mut self, tx_watcher: TxWatcher) -> Self {
+        self.tx_watcher = tx_watcher;
+        self
+    }
+
+    /// Maximum number of transactions sent to the gateway in a single request, when sending in bulk.
+    pub fn with_multi_tx_chunk_size(mut self, multi_tx_chunk_size: usize) -> Self {
+        self.multi_tx_chunk_size = multi_tx_chunk_size;
+        self
+    }
+
+    /// Waits for a sent transaction to complete, using the configured watcher.
+    ///
+    /// Failed transactions are not an error, only transactions that could not be retrieved in time.
+    pub async fn retrieve_tx_on_network(
+        &self,
+        tx_hash: &str,
+    ) -> Result<(TransactionOnNetwork, ReturnCode), TxWatchError> {
+        self.proxy.watch_tx(&self.tx_watcher, tx_hash).await
+    }
+
     pub async fn register_wallet(&mut self, wallet: Wallet) -> Address {
-        let address = wallet.to_address();
+        self.register_signer(wallet).await
+    }
+
+    /// Registers an account whose transactions are signed by the given signer,
+    /// e.g. an external process, so that no private key needs to be loaded in memory.
+    pub async fn register_signer<S>(&mut self, signer: S) -> Address
+    where
+        S: Signer + 'static,
+    {
+        let address = signer.to_address();
 
         self.send_user_funds(&address).await.unwrap();
         self.generate_blocks(1).await.unwrap();
@@ -69,13 +110,28 @@ where
             address.clone(),
             Sender {
                 address: address.clone(),
-                wallet,
+                signer: Box::new(signer),
                 current_nonce: None,
+                guardian: None,
             },
         );
         address
     }
 
+    /// Sets the guardian of an already registered account.
+    ///
+    /// All subsequent transactions from that account will be guarded and co-signed by the guardian.
+    pub fn register_guardian<S>(&mut self, address: &Address, guardian: S)
+    where
+        S: Signer + 'static,
+    {
+        let sender = self
+            .sender_map
+            .get_mut(address)
+            .expect("the guarded wallet is not registered");
+        sender.guardian = Some(Box::new(guardian));
+    }
+
     pub async fn sleep(&mut self, duration: Duration) {
         let millis = duration.as_millis() as u64;
         self.waiting_time_ms += millis;
@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use crate::sdk::data::transaction::Transaction;
+use log::info;
+use multiversx_sc_scenario::multiversx_sc::types::Address;
+use multiversx_sdk::gateway::{GatewayAsyncService, SendMultiTxIndexedRequest, SendTxRequest};
+
+use crate::InteractorBase;
+
+/// Default number of transactions sent in a single `send-multiple` request.
+pub const DEFAULT_MULTI_TX_CHUNK_SIZE: usize = 100;
+
+/// Maximum number of times the nonces are resynchronized before giving up on sending a chunk of transactions.
+const MAX_NONCE_RESYNCS: usize = 3;
+
+/// Fragments of the gateway error messages signaling that the nonce of a transaction is out of sync.
+const NONCE_ERROR_PATTERNS: &[&str] = &[
+    "nonce too low",
+    "nonce too high",
+    "lowerNonceInTx",
+    "veryHighNonceInTx",
+];
+
+/// Checks whether a gateway error was caused by a nonce mismatch.
+pub fn is_nonce_error(err: &anyhow::Error) -> bool {
+    let message = err.to_string();
+    NONCE_ERROR_PATTERNS
+        .iter()
+        .any(|pattern| message.contains(pattern))
+}
+
+impl<GatewayProxy> InteractorBase<GatewayProxy>
+where
+    GatewayProxy: GatewayAsyncService,
+{
+    /// Provides the nonce for the next transaction of a sender.
+    ///
+    /// The nonce is tracked locally, the network is only queried the first time,
+    /// or after the local nonce was reset.
+    pub async fn next_nonce(&mut self, sender_address: &Address) -> u64 {
+        let current_nonce = self
+            .sender_map
+            .get(sender_address)
+            .expect("the wallet that was supposed to sign is not registered")
+            .current_nonce;
+
+        let nonce = match current_nonce {
+            Some(nonce) => nonce,
+            None => {
+                let nonce = self.recall_nonce(sender_address).await;
+                println!("sender's recalled nonce: {nonce}");
+                nonce
+            },
+        };
+
+        let sender = self
+            .sender_map
+            .get_mut(sender_address)
+            .expect("the wallet that was supposed to sign is not registered");
+        sender.current_nonce = Some(nonce + 1);
+        nonce
+    }
+
+    /// Discards the locally tracked nonce of a sender. It will be recalled from the network on next use.
+    pub fn reset_nonce(&mut self, sender_address: &Address) {
+        if let Some(sender) = self.sender_map.get_mut(sender_address) {
+            sender.current_nonce = None;
+        }
+    }
+
+    /// Sets the nonce, signs and sends a transaction.
+    ///
+    /// If the gateway rejects it because of a nonce mismatch,
+    /// the nonce is resynchronized with the network and the transaction is sent once more.
+    pub(crate) async fn sign_and_send_tx(
+        &mut self,
+        sender_address: &Address,
+        transaction: &mut Transaction,
+    ) -> String {
+        self.set_nonce_and_sign_tx(sender_address, transaction)
+            .await;
+        match self.proxy.request(SendTxRequest(transaction)).await {
+            Ok(tx_hash) => tx_hash,
+            Err(err) if is_nonce_error(&err) => {
+                info!("nonce out of sync, resynchronizing: {err}");
+                self.reset_nonce(sender_address);
+                self.set_nonce_and_sign_tx(sender_address, transaction)
+                    .await;
+                self.proxy
+                    .request(SendTxRequest(transaction))
+                    .await
+                    .expect("error sending tx (possible API failure)")
+            },
+            Err(err) => panic!("error sending tx (possible API failure): {err}"),
+        }
+    }
+
+    /// Sends already signed transactions, in chunks, via the `send-multiple` gateway endpoint.
+    ///
+    /// Returns the transaction hashes, in the same order as the transactions.
+    /// Transactions rejected because of a nonce mismatch get their nonce resynchronized with the network,
+    /// are signed again and resent, so the nonces in `txs` might change.
+    /// Panics if transactions are rejected while their nonces are in sync with the network.
+    pub async fn send_txs(&mut self, txs: &mut [Transaction]) -> Vec<String> {
+        let mut tx_hashes = Vec::with_capacity(txs.len());
+        for chunk in txs.chunks_mut(self.multi_tx_chunk_size.max(1)) {
+            tx_hashes.extend(self.send_tx_chunk(chunk).await);
+        }
+        tx_hashes
+    }
+
+    async fn send_tx_chunk(&mut self, chunk: &mut [Transaction]) -> Vec<String> {
+        let mut tx_hashes: Vec<Option<String>> = vec![None; chunk.len()];
+        let mut num_resyncs = 0;
+        loop {
+            let pending: Vec<usize> = (0..chunk.len())
+                .filter(|index| tx_hashes[*index].is_none())
+                .collect();
+            let pending_txs: Vec<Transaction> =
+                pending.iter().map(|index| chunk[*index].clone()).collect();
+            match self
+                .proxy
+                .request(SendMultiTxIndexedRequest(&pending_txs))
+                .await
+            {
+                Ok(sent_hashes) => {
+                    for (index, tx_hash) in pending.into_iter().zip(sent_hashes) {
+                        tx_hashes[index] = tx_hash;
+                    }
+                },
+                Err(err) if is_nonce_error(&err) => {
+                    info!("nonce out of sync, resynchronizing: {err}");
+                },
+                Err(err) => panic!("error sending txs (possible API failure): {err}"),
+            }
+
+            let num_rejected = tx_hashes.iter().filter(|tx_hash| tx_hash.is_none()).count();
+            if num_rejected == 0 {
+                return tx_hashes.into_iter().flatten().collect();
+            }
+
+            num_resyncs += 1;
+            assert!(
+                num_resyncs <= MAX_NONCE_RESYNCS,
+                "{num_rejected} out of {} transactions rejected by the gateway, even after resynchronizing nonces",
+                chunk.len()
+            );
+            info!("{num_rejected} transactions rejected by the gateway, resynchronizing nonces");
+            let nonces_changed = self.resync_rejected_txs(chunk, &tx_hashes).await;
+            assert!(
+                nonces_changed,
+                "{num_rejected} out of {} transactions rejected by the gateway, but not because of their nonces",
+                chunk.len()
+            );
+        }
+    }
+
+    /// Recalls the nonces of the senders of rejected transactions, then assigns new nonces and signs them again.
+    ///
+    /// The new nonces always come after the ones of the accepted transactions of the same sender,
+    /// since those are already pending.
+    ///
+    /// Returns `false` if the nonces were already in sync, i.e. the transactions were rejected for another reason.
+    async fn resync_rejected_txs(
+        &mut self,
+        chunk: &mut [Transaction],
+        tx_hashes: &[Option<String>],
+    ) -> bool {
+        let mut min_nonces: HashMap<Address, u64> = HashMap::new();
+        for (tx, tx_hash) in chunk.iter().zip(tx_hashes) {
+            if tx_hash.is_none() {
+                min_nonces.entry(tx.sender.0.clone()).or_default();
+            }
+        }
+        for (tx, tx_hash) in chunk.iter().zip(tx_hashes) {
+            if let (Some(min_nonce), Some(_)) = (min_nonces.get_mut(&tx.sender.0), tx_hash) {
+                *min_nonce = (*min_nonce).max(tx.nonce + 1);
+            }
+        }
+
+        for (sender_address, min_nonce) in min_nonces {
+            let nonce = self.recall_nonce(&sender_address).await.max(min_nonce);
+            if let Some(sender) = self.sender_map.get_mut(&sender_address) {
+                sender.current_nonce = Some(nonce);
+            }
+        }
+
+        let mut nonces_changed = false;
+        for (tx, tx_hash) in chunk.iter_mut().zip(tx_hashes) {
+            if tx_hash.is_none() {
+                let sender_address = tx.sender.0.clone();
+                let nonce = self.next_nonce(&sender_address).await;
+                nonces_changed |= nonce != tx.nonce;
+                tx.nonce = nonce;
+                self.sign_tx(&sender_address, tx);
+            }
+        }
+        nonces_changed
+    }
+}
@@ -4,11 +4,8 @@ use multiversx_sc_scenario::{
     scenario::ScenarioRunner,
     scenario_model::{ScCallStep, SetStateStep, TxCall},
 };
+use multiversx_sdk::gateway::GatewayAsyncService;
 use multiversx_sdk::{data::transaction::Transaction, utils::base64_encode};
-use multiversx_sdk::{
-    gateway::{GatewayAsyncService, SendTxRequest},
-    retrieve_tx_on_network,
-};
 
 impl<GatewayProxy> InteractorBase<GatewayProxy>
 where
@@ -23,9 +20,9 @@ where
         self.generate_blocks_until_tx_processed(&tx_hash)
             .await
             .unwrap();
-        let (tx, return_code) = retrieve_tx_on_network(&self.proxy, tx_hash.clone()).await;
+        let tx_result = self.retrieve_tx_on_network(&tx_hash).await;
 
-        sc_call_step.save_response(network_response::parse_tx_response(tx, return_code));
+        sc_call_step.save_response(network_response::parse_tx_watch_result(tx_result));
 
         if let Some(token_identifier) = sc_call_step.response().new_issued_token_identifier.clone()
         {
@@ -44,13 +41,9 @@ where
 
         let sender_address = &sc_call_step.tx.from.value;
         let mut transaction = self.tx_call_to_blockchain_tx(&sc_call_step.tx);
-        self.set_nonce_and_sign_tx(sender_address, &mut transaction)
-            .await;
         let tx_hash = self
-            .proxy
-            .request(SendTxRequest(&transaction))
-            .await
-            .expect("Launch sc call failed");
+            .sign_and_send_tx(sender_address, &mut transaction)
+            .await;
         println!("sc call tx hash: {tx_hash}");
         info!("sc call tx hash: {}", tx_hash);
 
@@ -78,6 +71,13 @@ where
             chain_id: self.network_config.chain_id.clone(),
             version: self.network_config.min_transaction_version,
             options: 0,
+            guardian: None,
+            guardian_signature: None,
+            relayer: normalized
+                .relayer
+                .as_ref()
+                .map(|relayer| relayer.to_address().into()),
+            relayer_signature: None,
         }
     }
 }
@@ -5,11 +5,8 @@ use multiversx_sc_scenario::{
     mandos_system::ScenarioRunner,
     scenario_model::{ScDeployStep, SetStateStep},
 };
+use multiversx_sdk::gateway::GatewayAsyncService;
 use multiversx_sdk::{data::transaction::Transaction, utils::base64_encode};
-use multiversx_sdk::{
-    gateway::{GatewayAsyncService, SendTxRequest},
-    retrieve_tx_on_network,
-};
 
 impl<GatewayProxy> InteractorBase<GatewayProxy>
 where
@@ -28,6 +25,14 @@ where
             chain_id: self.network_config.chain_id.clone(),
             version: self.network_config.min_transaction_version,
             options: 0,
+            guardian: None,
+            guardian_signature: None,
+            relayer: sc_deploy_step
+                .tx
+                .relayer
+                .as_ref()
+                .map(|relayer| relayer.to_address().into()),
+            relayer_signature: None,
         }
     }
 
@@ -36,13 +41,9 @@ where
 
         let sender_address = &sc_deploy_step.tx.from.value;
         let mut transaction = self.sc_deploy_to_blockchain_tx(sc_deploy_step);
-        self.set_nonce_and_sign_tx(sender_address, &mut transaction)
-            .await;
         let tx_hash = self
-            .proxy
-            .request(SendTxRequest(&transaction))
-            .await
-            .expect("error sending tx (possible API failure)");
+            .sign_and_send_tx(sender_address, &mut transaction)
+            .await;
         println!("sc deploy tx hash: {tx_hash}");
         info!("sc deploy tx hash: {}", tx_hash);
 
@@ -58,11 +59,14 @@ where
         self.generate_blocks_until_tx_processed(&tx_hash)
             .await
             .unwrap();
-        let (tx, return_code) = retrieve_tx_on_network(&self.proxy, tx_hash.clone()).await;
+        let tx_result = self.retrieve_tx_on_network(&tx_hash).await;
 
         let addr = sc_deploy_step.tx.from.clone();
-        let nonce = tx.nonce;
-        sc_deploy_step.save_response(network_response::parse_tx_response(tx, return_code));
+        let nonce = tx_result
+            .as_ref()
+            .map(|(tx, _)| tx.nonce)
+            .unwrap_or_default();
+        sc_deploy_step.save_response(network_response::parse_tx_watch_result(tx_result));
 
         let deploy_address = sc_deploy_step
             .response()
@@ -1,10 +1,7 @@
 use crate::InteractorBase;
 use log::info;
 use multiversx_sc_scenario::{scenario::ScenarioRunner, scenario_model::TransferStep};
-use multiversx_sdk::{
-    gateway::{GatewayAsyncService, SendTxRequest},
-    retrieve_tx_on_network,
-};
+use multiversx_sdk::gateway::GatewayAsyncService;
 
 impl<GatewayProxy> InteractorBase<GatewayProxy>
 where
@@ -15,13 +12,9 @@ where
 
         let sender_address = &transfer_step.tx.from.value;
         let mut transaction = self.tx_call_to_blockchain_tx(&transfer_step.tx.to_tx_call());
-        self.set_nonce_and_sign_tx(sender_address, &mut transaction)
-            .await;
         let tx_hash = self
-            .proxy
-            .request(SendTxRequest(&transaction))
-            .await
-            .unwrap();
+            .sign_and_send_tx(sender_address, &mut transaction)
+            .await;
         self.generate_blocks_until_tx_processed(&tx_hash)
             .await
             .unwrap();
@@ -29,7 +22,9 @@ where
         println!("transfer tx hash: {tx_hash}");
         info!("transfer tx hash: {}", tx_hash);
 
-        retrieve_tx_on_network(&self.proxy, tx_hash.clone()).await;
+        self.retrieve_tx_on_network(&tx_hash)
+            .await
+            .unwrap_or_else(|err| panic!("transfer failed: {err}"));
 
         self.post_runners.run_transfer_step(&transfer_step);
 
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::sdk::{data::transaction::Transaction, wallet::Wallet};
+use crate::sdk::{data::transaction::Transaction, signer::Signer};
 use log::debug;
 use multiversx_sc_scenario::multiversx_sc::types::Address;
 use multiversx_sdk::data::account::Account;
@@ -8,11 +8,14 @@ use multiversx_sdk::gateway::{GatewayAsyncService, GetAccountRequest, GetAccount
 
 use crate::InteractorBase;
 
-/// A user account that can sign transactions (a pem is present).
+/// A user account that can sign transactions.
 pub struct Sender {
     pub address: Address,
-    pub wallet: Wallet,
+    pub signer: Box<dyn Signer>,
+    /// Next nonce, tracked locally. `None` means it needs to be recalled from the network.
     pub current_nonce: Option<u64>,
+    /// Guardian, for accounts that are guarded. All transactions are then co-signed.
+    pub guardian: Option<Box<dyn Signer>>,
 }
 
 impl<GatewayProxy> InteractorBase<GatewayProxy>
@@ -47,30 +50,52 @@ where
         sender_address: &Address,
         transaction: &mut Transaction,
     ) {
-        // read
-        let sender = self
-            .sender_map
-            .get(sender_address)
-            .expect("the wallet that was supposed to sign is not registered");
-
-        // recall
-        let nonce = self.recall_nonce(&sender.address).await;
-        println!("sender's recalled nonce: {nonce}");
-
-        // set tx nonce
-        transaction.nonce = nonce;
+        transaction.nonce = self.next_nonce(sender_address).await;
         println!("-- tx nonce: {}", transaction.nonce);
+        self.sign_tx(sender_address, transaction);
+    }
 
-        // update
+    /// Signs the transaction with the sender wallet, as well as with the relayer and guardian wallets, if needed.
+    ///
+    /// The nonce must already be set.
+    pub(crate) fn sign_tx(&self, sender_address: &Address, transaction: &mut Transaction) {
         let sender = self
             .sender_map
-            .get_mut(sender_address)
+            .get(sender_address)
             .expect("the wallet that was supposed to sign is not registered");
-        sender.current_nonce = Some(nonce + 1);
+
+        // guarded accounts need the guardian set before signing, since it is part of the payload
+        if let Some(guardian) = &sender.guardian {
+            transaction.set_guardian(guardian.to_address().into());
+        }
 
         // sign
-        let signature = sender.wallet.sign_tx(transaction);
+        let signature = sender
+            .signer
+            .sign_transaction(transaction)
+            .expect("failed to sign transaction");
         transaction.signature = Some(hex::encode(signature));
+
+        // relayed transactions (v3) are also signed by the relayer
+        if let Some(relayer_address) = &transaction.relayer {
+            let relayer = self
+                .sender_map
+                .get(&relayer_address.0)
+                .expect("the relayer wallet is not registered");
+            let relayer_signature = relayer
+                .signer
+                .sign_transaction_as_relayer(transaction)
+                .expect("failed to sign transaction as relayer");
+            transaction.relayer_signature = Some(hex::encode(relayer_signature));
+        }
+
+        // guarded transactions are co-signed by the guardian
+        if let Some(guardian) = &sender.guardian {
+            let guardian_signature = guardian
+                .sign_transaction_as_guardian(transaction)
+                .expect("failed to sign transaction as guardian");
+            transaction.guardian_signature = Some(hex::encode(guardian_signature));
+        }
         debug!("transaction {:#?}", transaction);
     }
 }
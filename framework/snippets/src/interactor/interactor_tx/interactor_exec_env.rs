@@ -1,7 +1,8 @@
 use multiversx_sc_scenario::{
     api::StaticApi,
     multiversx_sc::types::{
-        ManagedAddress, ManagedBuffer, Tx, TxBaseWithEnv, TxEnv, TxEnvWithTxHash, H256,
+        Address, ManagedAddress, ManagedBuffer, Tx, TxBaseWithEnv, TxEnv, TxEnvWithRelayer,
+        TxEnvWithTxHash, H256,
     },
     scenario_model::TxExpect,
     ScenarioTxEnv, ScenarioTxEnvData,
@@ -72,3 +73,16 @@ where
         self.data.take_tx_hash()
     }
 }
+
+impl<GatewayProxy> TxEnvWithRelayer for InteractorEnvExec<'_, GatewayProxy>
+where
+    GatewayProxy: GatewayAsyncService,
+{
+    fn set_relayer(&mut self, relayer: Address) {
+        self.data.set_relayer(relayer);
+    }
+
+    fn take_relayer(&mut self) -> Option<Address> {
+        self.data.take_relayer()
+    }
+}
@@ -11,6 +11,7 @@ where
         ScenarioTxEnvData {
             interpreter_context: InterpreterContext::new().with_dir(self.current_dir.clone()),
             tx_hash: None,
+            relayer: None,
         }
     }
 }
@@ -0,0 +1,36 @@
+use multiversx_sc_scenario::{
+    debug_executor::ContractContainer, imports::RegisterCodeSource,
+    multiversx_sc::contract_base::CallableContractBuilder, DebugApi,
+};
+
+use crate::{GatewayVMProxy, InteractorBase};
+
+impl InteractorBase<GatewayVMProxy> {
+    /// Creates an interactor backed by an in-process Rust VM, no network needed.
+    ///
+    /// Works like a chain simulator interactor, registered wallets get funded automatically.
+    pub async fn new_vm() -> Self {
+        Self::new("").await.use_chain_simulator(true)
+    }
+
+    /// Links a contract code path to a contract implementation, same as in blackbox tests.
+    ///
+    /// Only registered contracts can be deployed on the VM gateway.
+    pub fn register_contract<B: CallableContractBuilder>(
+        &mut self,
+        expression: impl RegisterCodeSource,
+        contract_builder: B,
+    ) {
+        let contract_bytes = expression.into_code(self.new_env_data());
+        self.proxy.with_vm_runner(|vm_runner| {
+            vm_runner.contract_map_ref.lock().register_contract(
+                contract_bytes,
+                ContractContainer::new(
+                    contract_builder.new_contract_obj::<DebugApi>(),
+                    None,
+                    false,
+                ),
+            )
+        });
+    }
+}
@@ -1,9 +1,11 @@
 pub mod account_tool;
+mod gateway_vm_proxy;
 mod interactor;
 mod multi;
 pub mod network_response;
 
 pub use env_logger;
+pub use gateway_vm_proxy::GatewayVMProxy;
 pub use hex;
 pub use interactor::*;
 pub use log;
@@ -18,6 +20,9 @@ pub mod imports;
 /// Backwards compatibility.
 pub use crate::sdk_core::test_wallets;
 
+/// Interactor running against an in-process Rust VM, useful for offline tests.
+pub type VMInteractor = crate::InteractorBase<crate::GatewayVMProxy>;
+
 #[cfg(feature = "http")]
 pub type HttpInteractor = crate::InteractorBase<multiversx_sdk_http::GatewayHttpProxy>;
 
@@ -1,4 +1,4 @@
-use super::interactor_multi_sc_process::{update_nonces_and_sign_tx, SenderSet, Txs};
+use super::interactor_multi_sc_process::{SenderSet, Txs};
 use super::InteractorStepRef;
 use crate::sdk::data::transaction::Transaction;
 use crate::sdk::gateway::GatewayAsyncService;
@@ -16,12 +16,11 @@ where
         let senders = retrieve_senders(buffer.refs.as_slice());
         self.recall_senders_nonce(senders).await;
 
-        let txs = self.retrieve_txs(&mut buffer);
+        let txs = self.retrieve_txs(&mut buffer).await;
         let results = self.process_txs(txs).await;
 
-        for (i, sc_call_step) in buffer.refs.iter_mut().enumerate() {
-            let (tx, return_code) = results.get(i).unwrap().clone();
-            sc_call_step.set_response(network_response::parse_tx_response(tx, return_code));
+        for (sc_call_step, tx_result) in buffer.refs.iter_mut().zip(results) {
+            sc_call_step.set_response(network_response::parse_tx_watch_result(tx_result));
         }
 
         for step in buffer.refs.iter_mut() {
@@ -29,18 +28,14 @@ where
         }
     }
 
-    fn retrieve_txs(&mut self, buffer: &mut StepBuffer<'_>) -> Vec<Transaction> {
+    async fn retrieve_txs(&mut self, buffer: &mut StepBuffer<'_>) -> Vec<Transaction> {
         let mut txs = Txs::new();
 
         for sc_call_step in &mut buffer.refs {
             let mut transaction = sc_call_step.to_transaction(self);
             let sender_address = &sc_call_step.sender_address().value;
-            let sender = self
-                .sender_map
-                .get_mut(sender_address)
-                .expect("sender not registered");
-
-            update_nonces_and_sign_tx(&mut transaction, sender);
+            transaction.nonce = self.next_nonce(sender_address).await;
+            self.sign_tx(sender_address, &mut transaction);
             txs.push(transaction);
         }
         txs
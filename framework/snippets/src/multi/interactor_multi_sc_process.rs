@@ -1,9 +1,9 @@
 use crate::sdk::data::transaction::{Transaction, TransactionOnNetwork};
-use crate::{multiversx_sc::types::Address, InteractorBase, Sender};
+use crate::{multiversx_sc::types::Address, InteractorBase};
 use futures::future::join_all;
 use multiversx_sc_scenario::imports::ReturnCode;
-use multiversx_sdk::gateway::{GatewayAsyncService, SendTxRequest};
-use multiversx_sdk::retrieve_tx_on_network;
+use multiversx_sdk::gateway::GatewayAsyncService;
+use multiversx_sdk::TxWatchError;
 use std::collections::HashSet;
 
 pub(crate) type Txs = Vec<Transaction>;
@@ -13,8 +13,17 @@ impl<GatewayProxy> InteractorBase<GatewayProxy>
 where
     GatewayProxy: GatewayAsyncService,
 {
+    /// Only recalls the nonces of the senders that are not tracked locally yet.
     pub(crate) async fn recall_senders_nonce(&mut self, senders: HashSet<Address>) {
         for sender_address in &senders {
+            let sender = self
+                .sender_map
+                .get(sender_address)
+                .expect("sender not registered");
+            if sender.current_nonce.is_some() {
+                continue;
+            }
+
             let nonce = self.recall_nonce(sender_address).await;
             let sender = self
                 .sender_map
@@ -28,30 +37,19 @@ where
 
     pub(crate) async fn process_txs(
         &mut self,
-        txs: Vec<Transaction>,
-    ) -> Vec<(TransactionOnNetwork, ReturnCode)> {
-        let mut futures = Vec::new();
-
-        for tx in &txs {
-            let tx_hash = self
-                .proxy
-                .request(SendTxRequest(tx))
-                .await
-                .expect("failed to send transaction");
+        mut txs: Vec<Transaction>,
+    ) -> Vec<Result<(TransactionOnNetwork, ReturnCode), TxWatchError>> {
+        let tx_hashes = self.send_txs(&mut txs).await;
 
+        let tx_watcher = &self.tx_watcher;
+        let proxy = &self.proxy;
+        let mut futures = Vec::new();
+        for (tx, tx_hash) in txs.iter().zip(tx_hashes) {
             println!("process tx hash: {tx_hash} with nonce: {}", tx.nonce);
-            futures.push(retrieve_tx_on_network(&self.proxy, tx_hash.clone()));
+            futures.push(async move { proxy.watch_tx(tx_watcher, &tx_hash).await });
         }
 
         self.generate_blocks(4).await.unwrap();
         join_all(futures).await
     }
 }
-
-pub(crate) fn update_nonces_and_sign_tx(transaction: &mut Transaction, sender: &mut Sender) {
-    transaction.nonce = sender.current_nonce.unwrap();
-    sender.current_nonce = Some(sender.current_nonce.unwrap() + 1);
-
-    let signature = sender.wallet.sign_tx(&*transaction);
-    transaction.signature = Some(hex::encode(signature));
-}
@@ -1,6 +1,7 @@
 use crate::sdk::{
     data::transaction::{ApiSmartContractResult, Events, TransactionOnNetwork},
     utils::base64_decode,
+    TxWatchError,
 };
 use multiversx_sc_scenario::{
     imports::{Address, ESDTSystemSCAddress, ReturnCode},
@@ -8,8 +9,8 @@ use multiversx_sc_scenario::{
     scenario_model::{Log, TxResponse, TxResponseStatus},
 };
 
-const SC_DEPLOY_PROCESSING_TYPE: &str = "SCDeployment";
-const LOG_IDENTIFIER_SIGNAL_ERROR: &str = "signalError";
+pub const SC_DEPLOY_PROCESSING_TYPE: &str = "SCDeployment";
+pub const LOG_IDENTIFIER_SIGNAL_ERROR: &str = "signalError";
 
 /// Creates a [`TxResponse`] from a [`TransactionOnNetwork`].
 pub fn parse_tx_response(tx: TransactionOnNetwork, return_code: ReturnCode) -> TxResponse {
@@ -25,6 +26,21 @@ pub fn parse_tx_response(tx: TransactionOnNetwork, return_code: ReturnCode) -> T
     process_success(&tx)
 }
 
+/// Creates a [`TxResponse`] from the outcome of watching a transaction.
+///
+/// Transactions that could not be retrieved yield an error response, with the watcher error as message.
+pub fn parse_tx_watch_result(
+    result: Result<(TransactionOnNetwork, ReturnCode), TxWatchError>,
+) -> TxResponse {
+    match result {
+        Ok((tx, return_code)) => parse_tx_response(tx, return_code),
+        Err(err) => TxResponse {
+            tx_error: TxResponseStatus::new(err.return_code(), &err.to_string()),
+            ..Default::default()
+        },
+    }
+}
+
 fn process_signal_error(tx: &TransactionOnNetwork, return_code: ReturnCode) -> TxResponseStatus {
     if let Some(event) = find_log(tx, LOG_IDENTIFIER_SIGNAL_ERROR) {
         let topics = event.topics.as_ref();
@@ -129,10 +145,15 @@ fn process_new_deployed_address(tx: &TransactionOnNetwork) -> Option<Address> {
         return None;
     }
 
-    let sender_address_bytes = tx.sender.to_bytes();
-    let sender_nonce_bytes = tx.nonce.to_le_bytes();
+    Some(compute_deployed_address(&tx.sender.0, tx.nonce))
+}
+
+/// The address of a contract deployed by the given sender, with the given nonce, as computed by the protocol.
+pub fn compute_deployed_address(sender: &Address, sender_nonce: u64) -> Address {
+    let sender_address_bytes = sender.as_bytes();
+    let sender_nonce_bytes = sender_nonce.to_le_bytes();
     let mut bytes_to_hash: Vec<u8> = Vec::new();
-    bytes_to_hash.extend_from_slice(&sender_address_bytes);
+    bytes_to_hash.extend_from_slice(sender_address_bytes);
     bytes_to_hash.extend_from_slice(&sender_nonce_bytes);
 
     let address_keccak = keccak256(&bytes_to_hash);
@@ -144,7 +165,7 @@ fn process_new_deployed_address(tx: &TransactionOnNetwork) -> Option<Address> {
     address[10..30].copy_from_slice(&address_keccak[10..30]);
     address[30..32].copy_from_slice(&sender_address_bytes[30..32]);
 
-    Some(Address::from(address))
+    Address::from(address)
 }
 
 fn process_new_issued_token_identifier(tx: &TransactionOnNetwork) -> Option<String> {
@@ -0,0 +1,265 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    future::{ready, Future},
+};
+
+use anyhow::anyhow;
+use futures::executor::block_on;
+use multiversx_sc_snippets::{
+    imports::{Address, Bech32Address},
+    is_nonce_error,
+    sdk::{
+        data::transaction::Transaction,
+        gateway::{GatewayAsyncService, GatewayRequest},
+        test_wallets,
+    },
+    InteractorBase,
+};
+use serde_json::{json, Value};
+
+const NETWORK_CONFIG_ENDPOINT: &str = "network/config";
+const SEND_MULTIPLE_ENDPOINT: &str = "transaction/send-multiple";
+
+/// Gateway mock with a mempool that only accepts transactions with the expected nonce.
+#[derive(Default)]
+struct MockGateway {
+    state: RefCell<MockGatewayState>,
+}
+
+#[derive(Default)]
+struct MockGatewayState {
+    /// Nonces as reported by the account endpoint, i.e. of the executed transactions.
+    account_nonces: HashMap<Address, u64>,
+    /// Next nonce accepted in the mempool.
+    mempool_nonces: HashMap<Address, u64>,
+    /// Rejects all transactions, regardless of their nonce, e.g. because of insufficient funds.
+    reject_all: bool,
+    account_requests: usize,
+    send_multiple_chunk_sizes: Vec<usize>,
+}
+
+impl MockGateway {
+    fn set_account_nonce(&self, address: &Address, nonce: u64) {
+        let mut state = self.state.borrow_mut();
+        state.account_nonces.insert(address.clone(), nonce);
+        state.mempool_nonces.insert(address.clone(), nonce);
+    }
+
+    fn handle_request(&self, endpoint: &str, payload: Value) -> anyhow::Result<Value> {
+        let mut state = self.state.borrow_mut();
+        if endpoint == NETWORK_CONFIG_ENDPOINT {
+            return Ok(json!({ "config": network_config() }));
+        }
+
+        if endpoint == SEND_MULTIPLE_ENDPOINT {
+            let txs: Vec<Transaction> = serde_json::from_value(payload)?;
+            state.send_multiple_chunk_sizes.push(txs.len());
+            let reject_all = state.reject_all;
+            let mut txs_hashes = HashMap::new();
+            for (index, tx) in txs.iter().enumerate() {
+                let mempool_nonce = state.mempool_nonces.entry(tx.sender.0.clone()).or_default();
+                if !reject_all && tx.nonce == *mempool_nonce {
+                    *mempool_nonce += 1;
+                    txs_hashes.insert(index, format!("hash-{}", tx.nonce));
+                }
+            }
+            return Ok(json!({ "numOfSentTxs": txs_hashes.len(), "txsHashes": txs_hashes }));
+        }
+
+        if let Some(bech32) = endpoint.strip_prefix("address/") {
+            let address = Bech32Address::from_bech32_string(bech32.to_string()).to_address();
+            state.account_requests += 1;
+            let nonce = state
+                .account_nonces
+                .get(&address)
+                .copied()
+                .unwrap_or_default();
+            return Ok(json!({
+                "account": {
+                    "address": bech32,
+                    "nonce": nonce,
+                    "balance": "0",
+                    "username": "",
+                    "code": "",
+                }
+            }));
+        }
+
+        Err(anyhow!("unexpected request: {endpoint}"))
+    }
+}
+
+fn network_config() -> Value {
+    json!({
+        "erd_chain_id": "chain",
+        "erd_denomination": 18,
+        "erd_gas_per_data_byte": 1500,
+        "erd_latest_tag_software_version": "mock",
+        "erd_meta_consensus_group_size": 1,
+        "erd_min_gas_limit": 50000,
+        "erd_min_gas_price": 1000000000,
+        "erd_min_transaction_version": 1,
+        "erd_num_metachain_nodes": 1,
+        "erd_num_nodes_in_shard": 1,
+        "erd_num_shards_without_meta": 1,
+        "erd_round_duration": 6000,
+        "erd_shard_consensus_group_size": 1,
+        "erd_start_time": 0,
+    })
+}
+
+impl GatewayAsyncService for MockGateway {
+    type Instant = ();
+
+    fn from_uri(_uri: &str) -> Self {
+        Self::default()
+    }
+
+    fn request<G>(&self, request: G) -> impl Future<Output = anyhow::Result<G::Result>>
+    where
+        G: GatewayRequest,
+    {
+        let result = serde_json::to_value(request.get_payload())
+            .map_err(anyhow::Error::from)
+            .and_then(|payload| self.handle_request(&request.get_endpoint(), payload))
+            .and_then(|data| {
+                let response = json!({ "data": data, "error": "", "code": "successful" });
+                request.process_json(serde_json::from_value(response)?)
+            });
+        ready(result)
+    }
+
+    fn sleep(&self, _millis: u64) -> impl Future<Output = ()> {
+        ready(())
+    }
+
+    fn now(&self) -> Self::Instant {}
+
+    fn elapsed_seconds(&self, _instant: &Self::Instant) -> f32 {
+        0.0
+    }
+}
+
+fn new_interactor() -> (InteractorBase<MockGateway>, Address) {
+    block_on(async {
+        let mut interactor = InteractorBase::<MockGateway>::new("mock").await;
+        let sender = interactor.register_wallet(test_wallets::alice()).await;
+        (interactor, sender)
+    })
+}
+
+fn new_txs(
+    interactor: &mut InteractorBase<MockGateway>,
+    sender: &Address,
+    count: usize,
+) -> Vec<Transaction> {
+    (0..count)
+        .map(|_| Transaction {
+            nonce: block_on(interactor.next_nonce(sender)),
+            value: "0".to_string(),
+            receiver: sender.clone().into(),
+            sender: sender.clone().into(),
+            gas_price: 1_000_000_000,
+            gas_limit: 50_000,
+            data: None,
+            signature: None,
+            chain_id: "chain".to_string(),
+            version: 1,
+            options: 0,
+            guardian: None,
+            guardian_signature: None,
+            relayer: None,
+            relayer_signature: None,
+        })
+        .collect()
+}
+
+#[test]
+fn interactor_nonce_cached_test() {
+    let (mut interactor, sender) = new_interactor();
+    interactor.proxy.set_account_nonce(&sender, 5);
+
+    assert_eq!(block_on(interactor.next_nonce(&sender)), 5);
+    assert_eq!(block_on(interactor.next_nonce(&sender)), 6);
+    assert_eq!(block_on(interactor.next_nonce(&sender)), 7);
+    assert_eq!(interactor.proxy.state.borrow().account_requests, 1);
+
+    interactor.reset_nonce(&sender);
+    assert_eq!(block_on(interactor.next_nonce(&sender)), 5);
+    assert_eq!(interactor.proxy.state.borrow().account_requests, 2);
+}
+
+#[test]
+fn interactor_send_txs_chunks_test() {
+    let (mut interactor, sender) = new_interactor();
+    interactor.multi_tx_chunk_size = 2;
+
+    let mut txs = new_txs(&mut interactor, &sender, 5);
+    let tx_hashes = block_on(interactor.send_txs(&mut txs));
+
+    let expected: Vec<String> = (0..5).map(|nonce| format!("hash-{nonce}")).collect();
+    assert_eq!(tx_hashes, expected);
+    assert_eq!(
+        interactor.proxy.state.borrow().send_multiple_chunk_sizes,
+        vec![2, 2, 1]
+    );
+}
+
+#[test]
+fn interactor_send_txs_resync_test() {
+    let (mut interactor, sender) = new_interactor();
+    interactor.proxy.set_account_nonce(&sender, 5);
+    let mut txs = new_txs(&mut interactor, &sender, 3);
+
+    // another client sent transactions in the meantime
+    interactor.proxy.set_account_nonce(&sender, 10);
+
+    let tx_hashes = block_on(interactor.send_txs(&mut txs));
+    assert_eq!(tx_hashes.len(), 3);
+    let nonces: Vec<u64> = txs.iter().map(|tx| tx.nonce).collect();
+    assert_eq!(nonces, vec![10, 11, 12]);
+    assert!(txs.iter().all(|tx| tx.signature.is_some()));
+    assert_eq!(block_on(interactor.next_nonce(&sender)), 13);
+}
+
+#[test]
+fn interactor_send_txs_partial_resync_test() {
+    let (mut interactor, sender) = new_interactor();
+    interactor.proxy.set_account_nonce(&sender, 5);
+    let mut txs = new_txs(&mut interactor, &sender, 3);
+
+    // the first transaction got executed in the meantime, with the same nonce
+    interactor.proxy.set_account_nonce(&sender, 6);
+
+    block_on(interactor.send_txs(&mut txs));
+    let nonces: Vec<u64> = txs.iter().map(|tx| tx.nonce).collect();
+    assert_eq!(nonces, vec![8, 6, 7]);
+    assert_eq!(
+        interactor.proxy.state.borrow().send_multiple_chunk_sizes,
+        vec![3, 1]
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "3 out of 3 transactions rejected by the gateway, but not because of their nonces"
+)]
+fn interactor_send_txs_rejected_in_sync_test() {
+    let (mut interactor, sender) = new_interactor();
+    interactor.proxy.set_account_nonce(&sender, 5);
+    let mut txs = new_txs(&mut interactor, &sender, 3);
+
+    interactor.proxy.state.borrow_mut().reject_all = true;
+    block_on(interactor.send_txs(&mut txs));
+}
+
+#[test]
+fn is_nonce_error_test() {
+    assert!(is_nonce_error(&anyhow!(
+        "transaction generation failed: nonce too low"
+    )));
+    assert!(is_nonce_error(&anyhow!("lowerNonceInTx: true")));
+    assert!(is_nonce_error(&anyhow!("veryHighNonceInTx: true")));
+    assert!(!is_nonce_error(&anyhow!("insufficient funds")));
+}
@@ -19,6 +19,8 @@ extern "C" {
     fn getCaller(resultOffset: *mut u8);
 
     fn managedCaller(resultHandle: i32);
+    fn managedGetOriginalCallerAddr(resultHandle: i32);
+    fn managedGetRelayerAddr(resultHandle: i32);
 
     fn getShardOfAddress(address_ptr: *const u8) -> i32;
     fn isSmartContract(address_ptr: *const u8) -> i32;
@@ -38,6 +40,9 @@ extern "C" {
     fn getPrevBlockEpoch() -> i64;
     fn getPrevBlockRandomSeed(resultOffset: *const u8);
     fn getOriginalTxHash(resultOffset: *const u8);
+    fn getCurrentTxHash(resultOffset: *const u8);
+    fn getPrevTxHash(resultOffset: *const u8);
+    fn getBlockHash(nonce: i64, resultOffset: *const u8) -> i32;
 
     // Managed versions of the above
     fn managedGetPrevBlockRandomSeed(resultHandle: i32);
@@ -87,6 +92,11 @@ extern "C" {
     fn managedGetCodeMetadata(addressHandle: i32, resultHandle: i32);
 
     fn managedIsBuiltinFunction(function_name_handle: i32) -> bool;
+
+    fn setStorageLock(keyOffset: *const u8, keyLength: i32, lockTimestamp: i64) -> i32;
+    fn getStorageLock(keyOffset: *const u8, keyLength: i32) -> i64;
+    fn isStorageLocked(keyOffset: *const u8, keyLength: i32) -> i32;
+    fn clearStorageLock(keyOffset: *const u8, keyLength: i32) -> i32;
 }
 
 impl BlockchainApi for VmApiImpl {
@@ -138,6 +148,20 @@ impl BlockchainApiImpl for VmApiImpl {
         }
     }
 
+    #[inline]
+    fn load_original_caller_managed(&self, dest: Self::ManagedBufferHandle) {
+        unsafe {
+            managedGetOriginalCallerAddr(dest);
+        }
+    }
+
+    #[inline]
+    fn load_relayer_managed(&self, dest: Self::ManagedBufferHandle) {
+        unsafe {
+            managedGetRelayerAddr(dest);
+        }
+    }
+
     #[inline]
     fn get_shard_of_address_legacy(&self, address: &Address) -> u32 {
         unsafe { getShardOfAddress(address.as_ref().as_ptr()) as u32 }
@@ -195,6 +219,24 @@ impl BlockchainApiImpl for VmApiImpl {
         }
     }
 
+    #[inline]
+    fn get_current_tx_hash_legacy(&self) -> H256 {
+        unsafe {
+            let mut res = H256::zero();
+            getCurrentTxHash(res.as_mut_ptr());
+            res
+        }
+    }
+
+    #[inline]
+    fn get_prev_tx_hash_legacy(&self) -> H256 {
+        unsafe {
+            let mut res = H256::zero();
+            getPrevTxHash(res.as_mut_ptr());
+            res
+        }
+    }
+
     #[inline]
     fn get_gas_left(&self) -> u64 {
         unsafe { getGasLeft() as u64 }
@@ -263,6 +305,15 @@ impl BlockchainApiImpl for VmApiImpl {
         }
     }
 
+    #[inline]
+    fn get_block_hash_legacy(&self, nonce: u64) -> H256 {
+        unsafe {
+            let mut res = H256::zero();
+            let _ = getBlockHash(nonce as i64, res.as_mut_ptr());
+            res
+        }
+    }
+
     #[inline]
     fn get_current_esdt_nft_nonce(
         &self,
@@ -378,4 +429,28 @@ impl BlockchainApiImpl for VmApiImpl {
             managedGetCodeMetadata(address_handle, response_handle);
         }
     }
+
+    #[inline]
+    fn set_storage_lock(&self, key: &[u8], lock_timestamp: u64) {
+        unsafe {
+            setStorageLock(key.as_ptr(), key.len() as i32, lock_timestamp as i64);
+        }
+    }
+
+    #[inline]
+    fn get_storage_lock(&self, key: &[u8]) -> u64 {
+        unsafe { getStorageLock(key.as_ptr(), key.len() as i32) as u64 }
+    }
+
+    #[inline]
+    fn is_storage_locked(&self, key: &[u8]) -> bool {
+        unsafe { isStorageLocked(key.as_ptr(), key.len() as i32) > 0 }
+    }
+
+    #[inline]
+    fn clear_storage_lock(&self, key: &[u8]) {
+        unsafe {
+            clearStorageLock(key.as_ptr(), key.len() as i32);
+        }
+    }
 }
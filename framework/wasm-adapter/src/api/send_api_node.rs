@@ -1,5 +1,8 @@
 use crate::api::VmApiImpl;
-use multiversx_sc::api::{const_handles, RawHandle, SendApi, SendApiImpl};
+use multiversx_sc::{
+    api::{const_handles, RawHandle, SendApi, SendApiImpl},
+    types::heap::ArgBuffer,
+};
 
 extern "C" {
     fn managedMultiTransferESDTNFTExecute(
@@ -84,6 +87,30 @@ extern "C" {
         resultHandle: i32,
     );
 
+    fn deployFromSourceContract(
+        gas: i64,
+        valueOffset: *const u8,
+        sourceContractAddressOffset: *const u8,
+        codeMetadataOffset: *const u8,
+        resultAddressOffset: *mut u8,
+        numArguments: i32,
+        argumentsLengthOffset: *const u8,
+        dataOffset: *const u8,
+    ) -> i32;
+
+    fn upgradeFromSourceContract(
+        dstOffset: *const u8,
+        gas: i64,
+        valueOffset: *const u8,
+        sourceContractAddressOffset: *const u8,
+        codeMetadataOffset: *const u8,
+        numArguments: i32,
+        argumentsLengthOffset: *const u8,
+        dataOffset: *const u8,
+    );
+
+    fn managedDeleteContract(dstHandle: i32, gasLimit: i64, argumentsHandle: i32);
+
     fn managedAsyncCall(
         dstHandle: i32,
         valueHandle: i32,
@@ -286,6 +313,52 @@ impl SendApiImpl for VmApiImpl {
         }
     }
 
+    fn deploy_from_source_contract_legacy(
+        &self,
+        gas: u64,
+        egld_value: &[u8; 32],
+        source_contract_address: &[u8; 32],
+        code_metadata: &[u8; 2],
+        arg_buffer: &ArgBuffer,
+        new_address: &mut [u8; 32],
+    ) {
+        unsafe {
+            deployFromSourceContract(
+                gas as i64,
+                egld_value.as_ptr(),
+                source_contract_address.as_ptr(),
+                code_metadata.as_ptr(),
+                new_address.as_mut_ptr(),
+                arg_buffer.num_args() as i32,
+                arg_buffer.arg_lengths_bytes_ptr(),
+                arg_buffer.arg_data_ptr(),
+            );
+        }
+    }
+
+    fn upgrade_from_source_contract_legacy(
+        &self,
+        sc_address: &[u8; 32],
+        gas: u64,
+        egld_value: &[u8; 32],
+        source_contract_address: &[u8; 32],
+        code_metadata: &[u8; 2],
+        arg_buffer: &ArgBuffer,
+    ) {
+        unsafe {
+            upgradeFromSourceContract(
+                sc_address.as_ptr(),
+                gas as i64,
+                egld_value.as_ptr(),
+                source_contract_address.as_ptr(),
+                code_metadata.as_ptr(),
+                arg_buffer.num_args() as i32,
+                arg_buffer.arg_lengths_bytes_ptr(),
+                arg_buffer.arg_data_ptr(),
+            );
+        }
+    }
+
     fn upgrade_contract(
         &self,
         sc_address_handle: RawHandle,
@@ -312,6 +385,17 @@ impl SendApiImpl for VmApiImpl {
         }
     }
 
+    fn delete_contract(
+        &self,
+        sc_address_handle: RawHandle,
+        gas: u64,
+        arg_buffer_handle: RawHandle,
+    ) {
+        unsafe {
+            managedDeleteContract(sc_address_handle, gas as i64, arg_buffer_handle);
+        }
+    }
+
     fn execute_on_dest_context_raw(
         &self,
         gas: u64,
@@ -2,9 +2,12 @@
 //!
 //! TODO: de-duplicate, place in chain core crate, if possible.
 
+use anyhow::{anyhow, bail};
 use bech32::{Bech32, Hrp};
 use multiversx_chain_core::types::Address;
 
+const HRP: &str = "erd";
+
 pub fn decode(bech32_address: &str) -> Address {
     let (_hrp, dest_address_bytes) = bech32::decode(bech32_address)
         .unwrap_or_else(|err| panic!("bech32 decode error for {bech32_address}: {err}"));
@@ -15,7 +18,23 @@ pub fn decode(bech32_address: &str) -> Address {
     Address::from_slice(&dest_address_bytes)
 }
 
+/// Decodes addresses coming from user input.
+///
+/// Unlike `decode`, it returns an error instead of panicking, and only accepts the `erd` HRP.
+pub fn try_decode(bech32_address: &str) -> anyhow::Result<Address> {
+    let (hrp, dest_address_bytes) = bech32::decode(bech32_address)
+        .map_err(|err| anyhow!("bech32 decode error for {bech32_address}: {err}"))?;
+    if hrp.as_str() != HRP {
+        bail!("Invalid address HRP `{hrp}`, expected `{HRP}`")
+    }
+    if dest_address_bytes.len() != 32 {
+        bail!("Invalid address length after decoding")
+    }
+
+    Ok(Address::from_slice(&dest_address_bytes))
+}
+
 pub fn encode(address: &Address) -> String {
-    let hrp = Hrp::parse("erd").expect("invalid hrp");
+    let hrp = Hrp::parse(HRP).expect("invalid hrp");
     bech32::encode::<Bech32>(hrp, address.as_bytes()).expect("bech32 encode error")
 }
@@ -20,8 +20,37 @@ pub struct Transaction {
     #[serde(rename = "chainID")]
     pub chain_id: String,
     pub version: u32,
-    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default, skip_serializing_if = "is_zero")]
     pub options: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guardian: Option<SdkAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guardian_signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relayer: Option<SdkAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relayer_signature: Option<String>,
+}
+
+/// Options bit signaling that the signature is computed over the transaction hash.
+pub const TX_OPTION_SIGN_ON_HASH: u32 = 0b01;
+
+/// Options bit signaling that the transaction is co-signed by a guardian.
+pub const TX_OPTION_GUARDED: u32 = 0b10;
+
+impl Transaction {
+    /// Marks the transaction as guarded by the given guardian.
+    ///
+    /// Must be called before signing, since the guardian is part of the signed payload.
+    pub fn set_guardian(&mut self, guardian: SdkAddress) {
+        self.guardian = Some(guardian);
+        self.options |= TX_OPTION_GUARDED;
+        self.version = self.version.max(2);
+    }
+
+    pub fn is_guarded(&self) -> bool {
+        self.version >= 2 && self.options & TX_OPTION_GUARDED != 0
+    }
 }
 
 /// This is only used for serialize
@@ -32,10 +32,17 @@ pub use gateway_tx_cost::GetTxCost;
 pub use gateway_tx_info::GetTxInfo;
 pub use gateway_tx_process_status::GetTxProcessStatus;
 pub use gateway_tx_send::SendTxRequest;
-pub use gateway_tx_send_multi::SendMultiTxRequest;
+pub use gateway_tx_send_multi::{SendMultiTxIndexedRequest, SendMultiTxRequest};
 pub use gateway_tx_status::GetTxStatus;
 pub use gateway_tx_vmquery::VMQueryRequest;
 
+use multiversx_chain_core::types::ReturnCode;
+
+use crate::{
+    data::transaction::TransactionOnNetwork,
+    tx_watcher::{TxWatchError, TxWatcher},
+};
+
 pub const MAINNET_GATEWAY: &str = "https://gateway.multiversx.com";
 pub const TESTNET_GATEWAY: &str = "https://testnet-gateway.multiversx.com";
 pub const DEVNET_GATEWAY: &str = "https://devnet-gateway.multiversx.com";
@@ -107,4 +114,16 @@ pub trait GatewayAsyncService {
     fn now(&self) -> Self::Instant;
 
     fn elapsed_seconds(&self, instant: &Self::Instant) -> f32;
+
+    /// Waits for a sent transaction to reach the finality configured in the watcher.
+    fn watch_tx(
+        &self,
+        tx_watcher: &TxWatcher,
+        tx_hash: &str,
+    ) -> impl std::future::Future<Output = Result<(TransactionOnNetwork, ReturnCode), TxWatchError>>
+    where
+        Self: Sized,
+    {
+        tx_watcher.watch(self, tx_hash)
+    }
 }
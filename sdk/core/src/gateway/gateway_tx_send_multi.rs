@@ -38,3 +38,35 @@ impl GatewayRequest for SendMultiTxRequest<'_> {
         }
     }
 }
+
+/// Sends multiple transactions at once, keeping track of which of them were accepted.
+///
+/// The result has one entry for each transaction, `None` for the ones rejected by the gateway.
+pub struct SendMultiTxIndexedRequest<'a>(pub &'a [Transaction]);
+
+impl GatewayRequest for SendMultiTxIndexedRequest<'_> {
+    type Payload = [Transaction];
+    type DecodedJson = SendTransactionsResponse;
+    type Result = Vec<Option<String>>;
+
+    fn request_type(&self) -> GatewayRequestType {
+        GatewayRequestType::Post
+    }
+
+    fn get_payload(&self) -> Option<&Self::Payload> {
+        Some(self.0)
+    }
+
+    fn get_endpoint(&self) -> String {
+        SEND_MULTIPLE_TRANSACTIONS_ENDPOINT.to_owned()
+    }
+
+    fn process_json(&self, decoded: Self::DecodedJson) -> anyhow::Result<Self::Result> {
+        match decoded.data {
+            None => Err(anyhow!("{}", decoded.error)),
+            Some(mut b) => Ok((0..self.0.len())
+                .map(|index| b.txs_hashes.remove(&(index as i32)))
+                .collect()),
+        }
+    }
+}
@@ -3,9 +3,12 @@ pub mod crypto;
 pub mod data;
 pub mod gateway;
 pub mod retrieve_tx_on_network;
+pub mod signer;
 pub mod test_wallets;
+pub mod tx_watcher;
 pub mod utils;
 pub mod wallet;
 
 pub use multiversx_chain_core as chain_core;
 pub use retrieve_tx_on_network::retrieve_tx_on_network;
+pub use tx_watcher::{TxFinality, TxWatchError, TxWatcher};
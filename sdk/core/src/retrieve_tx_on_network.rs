@@ -3,86 +3,33 @@ use crate::{
         sdk_address::SdkAddress,
         transaction::{ApiLogs, Events, LogData, TransactionOnNetwork},
     },
-    gateway::{GetTxInfo, GetTxProcessStatus},
+    tx_watcher::{TxWatchError, TxWatcher},
 };
 use log::info;
 use multiversx_chain_core::types::{Address, ReturnCode};
 
 use crate::gateway::GatewayAsyncService;
 
-const INITIAL_BACKOFF_DELAY: u64 = 1400;
-const MAX_RETRIES: usize = 8;
-const MAX_BACKOFF_DELAY: u64 = 6000;
 const LOG_IDENTIFIER_SIGNAL_ERROR: &str = "signalError";
 
-/// Retrieves a transaction from the network.
+/// Retrieves a transaction from the network, using the default [`TxWatcher`] settings.
+///
+/// If the transaction cannot be retrieved, a failed transaction is returned,
+/// carrying the watcher error message and its return code.
 pub async fn retrieve_tx_on_network<GatewayProxy: GatewayAsyncService>(
     proxy: &GatewayProxy,
     tx_hash: String,
 ) -> (TransactionOnNetwork, ReturnCode) {
-    let mut retries = 0;
-    let mut backoff_delay = INITIAL_BACKOFF_DELAY;
-    let start_time = proxy.now();
-
-    loop {
-        match proxy.request(GetTxProcessStatus::new(&tx_hash)).await {
-            Ok((status, reason)) => {
-                // checks if transaction status is final
-                match status.as_str() {
-                    "success" => {
-                        // retrieve transaction info with results
-                        let transaction_info_with_results = proxy
-                            .request(GetTxInfo::new(&tx_hash).with_results())
-                            .await
-                            .unwrap();
-
-                        info!(
-                            "Transaction retrieved successfully, with status {}: {:#?}",
-                            status, transaction_info_with_results
-                        );
-                        return (transaction_info_with_results, ReturnCode::Success);
-                    },
-                    "fail" => {
-                        let (error_code, error_message) = parse_reason(&reason);
-                        let failed_transaction_info: TransactionOnNetwork =
-                            create_tx_failed(&error_message);
-
-                        info!(
-                            "Transaction failed with error code: {} and message: {error_message}",
-                            error_code.as_u64()
-                        );
-                        return (failed_transaction_info, error_code);
-                    },
-                    _ => {
-                        continue;
-                    },
-                }
-            },
-            Err(err) => {
-                retries += 1;
-                if retries >= MAX_RETRIES {
-                    info!("Transaction failed, max retries exceeded: {}", err);
-                    println!("Transaction failed, max retries exceeded: {}", err);
-                    break;
-                }
-
-                let backoff_time = backoff_delay.min(MAX_BACKOFF_DELAY);
-                proxy.sleep(backoff_time).await;
-                backoff_delay *= 2; // exponential backoff
-            },
-        }
-    }
-
-    // retries have been exhausted
-    println!(
-            "Fetching transaction failed and retries exhausted, returning default transaction. Total elapsed time: {:?}s",
-            proxy.elapsed_seconds(&start_time)
-        );
-
-    let error_message = ReturnCode::message(ReturnCode::NetworkTimeout);
-    let failed_transaction: TransactionOnNetwork = create_tx_failed(error_message);
+    proxy
+        .watch_tx(&TxWatcher::default(), &tx_hash)
+        .await
+        .unwrap_or_else(tx_watch_error_to_failed_tx)
+}
 
-    (failed_transaction, ReturnCode::NetworkTimeout)
+fn tx_watch_error_to_failed_tx(err: TxWatchError) -> (TransactionOnNetwork, ReturnCode) {
+    info!("Fetching transaction failed: {err}");
+    let return_code = err.return_code();
+    (create_tx_failed(&err.to_string()), return_code)
 }
 
 pub fn parse_reason(reason: &str) -> (ReturnCode, String) {
@@ -141,7 +88,7 @@ pub fn extract_message_from_string_reason(reason: &str) -> String {
     contract_error.last().unwrap_or(&"").split(']').collect()
 }
 
-fn create_tx_failed(error_message: &str) -> TransactionOnNetwork {
+pub(crate) fn create_tx_failed(error_message: &str) -> TransactionOnNetwork {
     let mut failed_transaction_info = TransactionOnNetwork::default();
 
     let log: ApiLogs = ApiLogs {
@@ -0,0 +1,104 @@
+mod external_signer;
+mod mock_signer;
+
+pub use external_signer::ExternalSigner;
+pub use mock_signer::MockSigner;
+
+use anyhow::{anyhow, ensure};
+use multiversx_chain_core::types::Address;
+use serde_json::json;
+use sha3::{Digest, Keccak256};
+
+use crate::{
+    crypto::private_key::SIGNATURE_LENGTH,
+    data::transaction::{Transaction, TX_OPTION_SIGN_ON_HASH},
+    wallet::Wallet,
+};
+
+/// Signs transactions on behalf of an account.
+///
+/// Abstracts away where the private key lives: in memory, on a hardware device, or in a separate process.
+pub trait Signer: Send + Sync {
+    /// The address of the account whose key is used for signing.
+    fn to_address(&self) -> Address;
+
+    /// Signs an arbitrary payload, with the Ed25519 key of the account.
+    fn sign_payload(&self, payload: &[u8]) -> anyhow::Result<[u8; SIGNATURE_LENGTH]>;
+
+    /// Signs the transaction payload, which excludes all signatures.
+    ///
+    /// Signers that need to inspect the transaction (e.g. to display it on a device) can override it.
+    fn sign_transaction(&self, tx: &Transaction) -> anyhow::Result<[u8; SIGNATURE_LENGTH]> {
+        self.sign_payload(&tx_signing_payload(tx))
+    }
+
+    /// Signs a relayed transaction (v3) as its relayer.
+    fn sign_transaction_as_relayer(
+        &self,
+        tx: &Transaction,
+    ) -> anyhow::Result<[u8; SIGNATURE_LENGTH]> {
+        let relayer = tx
+            .relayer
+            .as_ref()
+            .ok_or_else(|| anyhow!("transaction has no relayer"))?;
+        ensure!(
+            relayer.0 == self.to_address(),
+            "signer does not match the transaction relayer"
+        );
+        self.sign_transaction(tx)
+    }
+
+    /// Co-signs a guarded transaction as its guardian.
+    fn sign_transaction_as_guardian(
+        &self,
+        tx: &Transaction,
+    ) -> anyhow::Result<[u8; SIGNATURE_LENGTH]> {
+        ensure!(tx.is_guarded(), "transaction is not marked as guarded");
+        let guardian = tx
+            .guardian
+            .as_ref()
+            .ok_or_else(|| anyhow!("transaction has no guardian"))?;
+        ensure!(
+            guardian.0 == self.to_address(),
+            "signer does not match the transaction guardian"
+        );
+        self.sign_transaction(tx)
+    }
+}
+
+/// The bytes that get signed for a transaction.
+///
+/// The sender, the relayer and the guardian all sign the same payload, which excludes all signatures.
+pub fn tx_signing_payload(tx: &Transaction) -> Vec<u8> {
+    let unsigned_tx = unsigned_tx(tx);
+    let tx_bytes = json!(unsigned_tx).to_string().into_bytes();
+
+    let should_sign_on_tx_hash =
+        unsigned_tx.version >= 2 && unsigned_tx.options & TX_OPTION_SIGN_ON_HASH > 0;
+    if should_sign_on_tx_hash {
+        let mut h = Keccak256::new();
+        h.update(tx_bytes);
+        return h.finalize().as_slice().to_vec();
+    }
+
+    tx_bytes
+}
+
+/// Copy of the transaction, with all signatures cleared.
+pub fn unsigned_tx(tx: &Transaction) -> Transaction {
+    let mut unsigned_tx = tx.clone();
+    unsigned_tx.signature = None;
+    unsigned_tx.guardian_signature = None;
+    unsigned_tx.relayer_signature = None;
+    unsigned_tx
+}
+
+impl Signer for Wallet {
+    fn to_address(&self) -> Address {
+        Wallet::to_address(self)
+    }
+
+    fn sign_payload(&self, payload: &[u8]) -> anyhow::Result<[u8; SIGNATURE_LENGTH]> {
+        Ok(self.sign_bytes(payload))
+    }
+}
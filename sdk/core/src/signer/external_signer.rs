@@ -0,0 +1,196 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    process::{Child, Command, Stdio},
+    sync::Mutex,
+};
+
+use anyhow::{anyhow, bail, Context};
+use multiversx_chain_core::types::Address;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{crypto::private_key::SIGNATURE_LENGTH, data::transaction::Transaction};
+
+use super::{unsigned_tx, Signer};
+
+const METHOD_GET_ADDRESS: &str = "getAddress";
+const METHOD_SIGN_TRANSACTION: &str = "signTransaction";
+const METHOD_SIGN_PAYLOAD: &str = "signPayload";
+
+/// Delegates signing to an external process, e.g. a hardware wallet bridge or an HSM daemon.
+///
+/// The process receives JSON-RPC 2.0 requests on its stdin, one per line,
+/// and answers on its stdout, also one per line. The supported methods are:
+/// - `getAddress`, no params, returns the bech32 address of the account;
+/// - `signTransaction`, params `{"transaction": <tx, without signatures>}`, returns the hex-encoded signature;
+/// - `signPayload`, params `{"payload": <hex>}`, returns the hex-encoded signature.
+///
+/// The private key never needs to leave the external process.
+pub struct ExternalSigner {
+    address: Address,
+    channel: Mutex<JsonRpcChannel>,
+    child: Option<Child>,
+}
+
+struct JsonRpcChannel {
+    reader: Box<dyn BufRead + Send>,
+    writer: Box<dyn Write + Send>,
+    next_id: u64,
+}
+
+#[derive(Deserialize)]
+struct JsonRpcResponse {
+    id: u64,
+    #[serde(default)]
+    result: Option<Value>,
+    #[serde(default)]
+    error: Option<JsonRpcError>,
+}
+
+#[derive(Deserialize)]
+struct JsonRpcError {
+    code: i64,
+    message: String,
+}
+
+impl ExternalSigner {
+    /// Starts the signer process and retrieves the address it signs for.
+    pub fn spawn(mut command: Command) -> anyhow::Result<Self> {
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .context("failed to start external signer process")?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = child.stdout.take().expect("stdout is piped");
+
+        match Self::from_io(BufReader::new(stdout), stdin) {
+            Ok(mut signer) => {
+                signer.child = Some(child);
+                Ok(signer)
+            },
+            Err(err) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                Err(err)
+            },
+        }
+    }
+
+    /// Communicates with an already running signer over the given streams.
+    pub fn from_io<R, W>(reader: R, writer: W) -> anyhow::Result<Self>
+    where
+        R: BufRead + Send + 'static,
+        W: Write + Send + 'static,
+    {
+        let mut channel = JsonRpcChannel {
+            reader: Box::new(reader),
+            writer: Box::new(writer),
+            next_id: 1,
+        };
+        let address_value = channel.call(METHOD_GET_ADDRESS, json!({}))?;
+        let bech32_address = address_value
+            .as_str()
+            .ok_or_else(|| anyhow!("external signer returned an invalid address"))?;
+        let (_hrp, address_bytes) = ::bech32::decode(bech32_address)
+            .with_context(|| format!("invalid bech32 address: {bech32_address}"))?;
+        if address_bytes.len() != 32 {
+            bail!("invalid address length: {bech32_address}");
+        }
+
+        Ok(ExternalSigner {
+            address: Address::from_slice(&address_bytes),
+            channel: Mutex::new(channel),
+            child: None,
+        })
+    }
+
+    fn call_for_signature(
+        &self,
+        method: &str,
+        params: Value,
+    ) -> anyhow::Result<[u8; SIGNATURE_LENGTH]> {
+        let result = self
+            .channel
+            .lock()
+            .map_err(|_| anyhow!("external signer channel poisoned"))?
+            .call(method, params)?;
+        let signature_hex = result
+            .as_str()
+            .ok_or_else(|| anyhow!("external signer returned an invalid signature"))?;
+        let signature = hex::decode(signature_hex).context("signature is not valid hex")?;
+        signature
+            .try_into()
+            .map_err(|_| anyhow!("signature must be {SIGNATURE_LENGTH} bytes long"))
+    }
+}
+
+impl JsonRpcChannel {
+    fn call(&mut self, method: &str, params: Value) -> anyhow::Result<Value> {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        });
+        writeln!(self.writer, "{request}").context("failed to write to external signer")?;
+        self.writer.flush()?;
+
+        let mut line = String::new();
+        let read = self
+            .reader
+            .read_line(&mut line)
+            .context("failed to read from external signer")?;
+        if read == 0 {
+            bail!("external signer closed the connection");
+        }
+
+        let response: JsonRpcResponse =
+            serde_json::from_str(&line).context("invalid JSON-RPC response")?;
+        if response.id != id {
+            bail!(
+                "JSON-RPC response id mismatch: expected {id}, got {}",
+                response.id
+            );
+        }
+        if let Some(error) = response.error {
+            bail!("external signer error {}: {}", error.code, error.message);
+        }
+        response
+            .result
+            .ok_or_else(|| anyhow!("JSON-RPC response has no result"))
+    }
+}
+
+impl Signer for ExternalSigner {
+    fn to_address(&self) -> Address {
+        self.address.clone()
+    }
+
+    fn sign_payload(&self, payload: &[u8]) -> anyhow::Result<[u8; SIGNATURE_LENGTH]> {
+        self.call_for_signature(
+            METHOD_SIGN_PAYLOAD,
+            json!({ "payload": hex::encode(payload) }),
+        )
+    }
+
+    /// Sends the whole transaction, so that the external signer can display it before signing.
+    fn sign_transaction(&self, tx: &Transaction) -> anyhow::Result<[u8; SIGNATURE_LENGTH]> {
+        self.call_for_signature(
+            METHOD_SIGN_TRANSACTION,
+            json!({ "transaction": unsigned_tx(tx) }),
+        )
+    }
+}
+
+impl Drop for ExternalSigner {
+    fn drop(&mut self) {
+        if let Some(child) = &mut self.child {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
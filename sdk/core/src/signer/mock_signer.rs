@@ -0,0 +1,47 @@
+use std::sync::Mutex;
+
+use multiversx_chain_core::types::Address;
+use sha2::{Digest, Sha256};
+
+use crate::crypto::{
+    private_key::{PrivateKey, SIGNATURE_LENGTH},
+    public_key::PublicKey,
+};
+
+use super::Signer;
+
+/// Deterministic signer, for tests.
+///
+/// The key is derived from a seed string, so the same seed always yields the same address and signatures.
+/// All signed payloads are recorded, for inspection.
+pub struct MockSigner {
+    priv_key: PrivateKey,
+    signed_payloads: Mutex<Vec<Vec<u8>>>,
+}
+
+impl MockSigner {
+    pub fn new(seed: &str) -> Self {
+        let key_seed = Sha256::digest(seed.as_bytes());
+        MockSigner {
+            priv_key: PrivateKey::from_bytes(key_seed.as_slice())
+                .expect("32 bytes is a valid private key seed"),
+            signed_payloads: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// All payloads signed so far, in order.
+    pub fn signed_payloads(&self) -> Vec<Vec<u8>> {
+        self.signed_payloads.lock().unwrap().clone()
+    }
+}
+
+impl Signer for MockSigner {
+    fn to_address(&self) -> Address {
+        PublicKey::from(&self.priv_key).to_address()
+    }
+
+    fn sign_payload(&self, payload: &[u8]) -> anyhow::Result<[u8; SIGNATURE_LENGTH]> {
+        self.signed_payloads.lock().unwrap().push(payload.to_vec());
+        Ok(self.priv_key.sign(payload.to_vec()))
+    }
+}
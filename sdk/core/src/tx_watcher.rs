@@ -0,0 +1,259 @@
+use core::fmt;
+
+use log::info;
+use multiversx_chain_core::types::ReturnCode;
+
+use crate::{
+    data::transaction::TransactionOnNetwork,
+    gateway::{GatewayAsyncService, GetTxInfo, GetTxProcessStatus},
+    retrieve_tx_on_network::{create_tx_failed, parse_reason},
+};
+
+const DEFAULT_POLLING_INTERVAL: u64 = 1400;
+const DEFAULT_MAX_BACKOFF_DELAY: u64 = 6000;
+const DEFAULT_MAX_RETRIES: usize = 8;
+const DEFAULT_TIMEOUT: u64 = 120_000;
+
+const STATUS_SUCCESS: &str = "success";
+const STATUS_FAIL: &str = "fail";
+const STATUS_INVALID: &str = "invalid";
+const STATUS_REWARD_REVERTED: &str = "reward-reverted";
+
+/// Specifies when a transaction is considered done by the [`TxWatcher`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TxFinality {
+    /// The transaction and all of its smart contract results have been executed.
+    #[default]
+    Completed,
+
+    /// Additionally waits for the transaction to be included in a hyperblock,
+    /// i.e. notarized by the metachain.
+    Hyperblock,
+}
+
+/// Error produced when a transaction could not be watched to completion.
+#[derive(Debug)]
+pub enum TxWatchError {
+    /// The global timeout expired before the transaction reached the expected finality.
+    Timeout {
+        tx_hash: String,
+        elapsed_seconds: f32,
+    },
+
+    /// Too many consecutive gateway errors.
+    RetriesExhausted {
+        tx_hash: String,
+        last_error: anyhow::Error,
+    },
+
+    /// The transaction reached a final status that is neither success nor failure,
+    /// e.g. it was deemed invalid by the protocol.
+    Rejected {
+        tx_hash: String,
+        status: String,
+        reason: String,
+    },
+}
+
+impl TxWatchError {
+    pub fn return_code(&self) -> ReturnCode {
+        match self {
+            TxWatchError::Timeout { .. } | TxWatchError::RetriesExhausted { .. } => {
+                ReturnCode::NetworkTimeout
+            },
+            TxWatchError::Rejected { reason, .. } => parse_reason(reason).0,
+        }
+    }
+}
+
+impl fmt::Display for TxWatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TxWatchError::Timeout {
+                tx_hash,
+                elapsed_seconds,
+            } => write!(
+                f,
+                "timed out waiting for transaction {tx_hash} after {elapsed_seconds}s"
+            ),
+            TxWatchError::RetriesExhausted {
+                tx_hash,
+                last_error,
+            } => write!(
+                f,
+                "retries exhausted while watching transaction {tx_hash}, last error: {last_error}"
+            ),
+            TxWatchError::Rejected {
+                tx_hash,
+                status,
+                reason,
+            } => write!(
+                f,
+                "transaction {tx_hash} ended with status {status}: {}",
+                parse_reason(reason).1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TxWatchError {}
+
+/// Result of a single round of gateway requests.
+enum PollOutcome {
+    Pending,
+    Done(Box<(TransactionOnNetwork, ReturnCode)>),
+    Rejected { status: String, reason: String },
+}
+
+/// Polls the gateway until a transaction reaches the configured finality.
+///
+/// Pending transactions are polled at a fixed interval,
+/// gateway errors cause an exponential backoff, up to a maximum number of consecutive retries.
+/// The whole operation is bounded by a global timeout.
+#[derive(Debug, Clone)]
+pub struct TxWatcher {
+    pub polling_interval_ms: u64,
+    pub max_backoff_delay_ms: u64,
+    pub max_retries: usize,
+    pub timeout_ms: u64,
+    pub finality: TxFinality,
+}
+
+impl Default for TxWatcher {
+    fn default() -> Self {
+        TxWatcher {
+            polling_interval_ms: DEFAULT_POLLING_INTERVAL,
+            max_backoff_delay_ms: DEFAULT_MAX_BACKOFF_DELAY,
+            max_retries: DEFAULT_MAX_RETRIES,
+            timeout_ms: DEFAULT_TIMEOUT,
+            finality: TxFinality::default(),
+        }
+    }
+}
+
+impl TxWatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_polling_interval(mut self, polling_interval_ms: u64) -> Self {
+        self.polling_interval_ms = polling_interval_ms;
+        self
+    }
+
+    pub fn with_max_backoff_delay(mut self, max_backoff_delay_ms: u64) -> Self {
+        self.max_backoff_delay_ms = max_backoff_delay_ms;
+        self
+    }
+
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout_ms: u64) -> Self {
+        self.timeout_ms = timeout_ms;
+        self
+    }
+
+    pub fn with_finality(mut self, finality: TxFinality) -> Self {
+        self.finality = finality;
+        self
+    }
+
+    /// Waits for the transaction to complete.
+    ///
+    /// Failed transactions are not an error, they are returned with the corresponding return code.
+    /// Transactions ending with any other final status, such as `invalid`, yield [`TxWatchError::Rejected`].
+    pub async fn watch<GatewayProxy: GatewayAsyncService>(
+        &self,
+        proxy: &GatewayProxy,
+        tx_hash: &str,
+    ) -> Result<(TransactionOnNetwork, ReturnCode), TxWatchError> {
+        let start_time = proxy.now();
+        let mut retries = 0;
+        let mut backoff_delay = self.polling_interval_ms;
+
+        loop {
+            let elapsed_seconds = proxy.elapsed_seconds(&start_time);
+            if elapsed_seconds * 1000.0 >= self.timeout_ms as f32 {
+                return Err(TxWatchError::Timeout {
+                    tx_hash: tx_hash.to_string(),
+                    elapsed_seconds,
+                });
+            }
+
+            match self.poll(proxy, tx_hash).await {
+                Ok(PollOutcome::Done(result)) => return Ok(*result),
+                Ok(PollOutcome::Rejected { status, reason }) => {
+                    return Err(TxWatchError::Rejected {
+                        tx_hash: tx_hash.to_string(),
+                        status,
+                        reason,
+                    });
+                },
+                Ok(PollOutcome::Pending) => {
+                    retries = 0;
+                    backoff_delay = self.polling_interval_ms;
+                    proxy.sleep(self.polling_interval_ms).await;
+                },
+                Err(err) => {
+                    retries += 1;
+                    if retries >= self.max_retries {
+                        return Err(TxWatchError::RetriesExhausted {
+                            tx_hash: tx_hash.to_string(),
+                            last_error: err,
+                        });
+                    }
+
+                    info!("Transaction status request failed, retrying: {err}");
+                    proxy
+                        .sleep(backoff_delay.min(self.max_backoff_delay_ms))
+                        .await;
+                    backoff_delay = backoff_delay.saturating_mul(2); // exponential backoff
+                },
+            }
+        }
+    }
+
+    /// Performs a single round of requests.
+    async fn poll<GatewayProxy: GatewayAsyncService>(
+        &self,
+        proxy: &GatewayProxy,
+        tx_hash: &str,
+    ) -> anyhow::Result<PollOutcome> {
+        let (status, reason) = proxy.request(GetTxProcessStatus::new(tx_hash)).await?;
+        match status.as_str() {
+            STATUS_SUCCESS => {
+                let tx_info = proxy
+                    .request(GetTxInfo::new(tx_hash).with_results())
+                    .await?;
+                if self.finality == TxFinality::Hyperblock && tx_info.hyperblock_nonce.is_none() {
+                    return Ok(PollOutcome::Pending);
+                }
+
+                info!(
+                    "Transaction retrieved successfully, with status {}: {:#?}",
+                    status, tx_info
+                );
+                Ok(PollOutcome::Done(Box::new((tx_info, ReturnCode::Success))))
+            },
+            STATUS_FAIL => {
+                let (error_code, error_message) = parse_reason(&reason);
+                info!(
+                    "Transaction failed with error code: {} and message: {error_message}",
+                    error_code.as_u64()
+                );
+                Ok(PollOutcome::Done(Box::new((
+                    create_tx_failed(&error_message),
+                    error_code,
+                ))))
+            },
+            STATUS_INVALID | STATUS_REWARD_REVERTED => {
+                info!("Transaction ended with status {status}, reason: {reason}");
+                Ok(PollOutcome::Rejected { status, reason })
+            },
+            _ => Ok(PollOutcome::Pending),
+        }
+    }
+}
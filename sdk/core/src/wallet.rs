@@ -15,9 +15,7 @@ use multiversx_chain_core::types::Address;
 use pbkdf2::pbkdf2;
 use rand::RngCore;
 use scrypt::{scrypt, Params};
-use serde_json::json;
-use sha2::{Digest, Sha256, Sha512};
-use sha3::Keccak256;
+use sha2::{Sha256, Sha512};
 use zeroize::Zeroize;
 
 use crate::{
@@ -26,6 +24,7 @@ use crate::{
         public_key::PublicKey,
     },
     data::{keystore::*, transaction::Transaction},
+    signer::{tx_signing_payload, Signer},
     utils::*,
 };
 
@@ -205,20 +204,25 @@ impl Wallet {
         PublicKey::from(&self.priv_key).to_address()
     }
 
+    /// Signs the transaction payload, which excludes all signatures.
+    ///
+    /// The sender, the relayer and the guardian all sign the same payload.
     pub fn sign_tx(&self, unsign_tx: &Transaction) -> [u8; 64] {
-        let mut unsign_tx = unsign_tx.clone();
-        unsign_tx.signature = None;
+        self.sign_bytes(&tx_signing_payload(unsign_tx))
+    }
 
-        let mut tx_bytes = json!(unsign_tx).to_string().as_bytes().to_vec();
+    /// Signs a relayed transaction (v3) as its relayer.
+    pub fn sign_tx_as_relayer(&self, tx: &Transaction) -> [u8; 64] {
+        Signer::sign_transaction_as_relayer(self, tx).unwrap_or_else(|err| panic!("{err}"))
+    }
 
-        let should_sign_on_tx_hash = unsign_tx.version >= 2 && unsign_tx.options & 1 > 0;
-        if should_sign_on_tx_hash {
-            let mut h = Keccak256::new();
-            h.update(tx_bytes);
-            tx_bytes = h.finalize().as_slice().to_vec();
-        }
+    /// Co-signs a guarded transaction as its guardian.
+    pub fn sign_tx_as_guardian(&self, tx: &Transaction) -> [u8; 64] {
+        Signer::sign_transaction_as_guardian(self, tx).unwrap_or_else(|err| panic!("{err}"))
+    }
 
-        self.priv_key.sign(tx_bytes)
+    pub(crate) fn sign_bytes(&self, payload: &[u8]) -> [u8; 64] {
+        self.priv_key.sign(payload.to_vec())
     }
 
     pub fn get_keystore_password() -> String {
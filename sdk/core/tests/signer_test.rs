@@ -0,0 +1,121 @@
+use std::{
+    io::{Cursor, Write},
+    sync::{Arc, Mutex},
+};
+
+use multiversx_chain_core::types::Address;
+use multiversx_sdk::{
+    bech32,
+    data::transaction::Transaction,
+    signer::{tx_signing_payload, ExternalSigner, MockSigner, Signer},
+    wallet::Wallet,
+};
+
+const ALICE_PEM_PATH: &str = "tests/alice.pem";
+const ALICE_BECH32: &str = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+
+fn test_tx(sender: &Address) -> Transaction {
+    Transaction {
+        nonce: 7,
+        value: "0".to_string(),
+        receiver: sender.clone().into(),
+        sender: sender.clone().into(),
+        gas_price: 1_000_000_000,
+        gas_limit: 100_000,
+        data: None,
+        signature: None,
+        chain_id: "D".to_string(),
+        version: 2,
+        options: 0,
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
+    }
+}
+
+/// Shared in-memory stream, to inspect what was sent to the external signer.
+#[derive(Clone, Default)]
+struct SharedWriter(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_wallet_signer() {
+    let wallet = Wallet::from_pem_file(ALICE_PEM_PATH).unwrap();
+    let tx = test_tx(&wallet.to_address());
+
+    let signer: &dyn Signer = &wallet;
+    assert_eq!(signer.to_address(), wallet.to_address());
+    assert_eq!(signer.sign_transaction(&tx).unwrap(), wallet.sign_tx(&tx));
+    assert!(signer.sign_transaction_as_relayer(&tx).is_err());
+}
+
+#[test]
+fn test_mock_signer() {
+    let signer = MockSigner::new("alice");
+    let same_signer = MockSigner::new("alice");
+    let other_signer = MockSigner::new("bob");
+    assert_eq!(signer.to_address(), same_signer.to_address());
+    assert_ne!(signer.to_address(), other_signer.to_address());
+
+    let mut tx = test_tx(&signer.to_address());
+    let signature = signer.sign_transaction(&tx).unwrap();
+    assert_eq!(signature, same_signer.sign_transaction(&tx).unwrap());
+    assert_eq!(signer.signed_payloads(), vec![tx_signing_payload(&tx)]);
+
+    tx.set_guardian(other_signer.to_address().into());
+    assert!(signer.sign_transaction_as_guardian(&tx).is_err());
+    assert!(other_signer.sign_transaction_as_guardian(&tx).is_ok());
+}
+
+#[test]
+fn test_external_signer() {
+    let wallet = Wallet::from_pem_file(ALICE_PEM_PATH).unwrap();
+    let tx = test_tx(&wallet.to_address());
+    let signature = wallet.sign_tx(&tx);
+
+    let responses = format!(
+        "{}\n{}\n{}\n",
+        r#"{"jsonrpc":"2.0","id":1,"result":"ALICE"}"#.replace("ALICE", ALICE_BECH32),
+        r#"{"jsonrpc":"2.0","id":2,"result":"SIG"}"#.replace("SIG", &hex::encode(signature)),
+        r#"{"jsonrpc":"2.0","id":3,"error":{"code":-32000,"message":"rejected by user"}}"#,
+    );
+    let requests = SharedWriter::default();
+    let signer = ExternalSigner::from_io(Cursor::new(responses), requests.clone()).unwrap();
+    assert_eq!(signer.to_address(), bech32::decode(ALICE_BECH32));
+    assert_eq!(signer.sign_transaction(&tx).unwrap(), signature);
+
+    let err = signer.sign_transaction(&tx).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "external signer error -32000: rejected by user"
+    );
+
+    let sent = String::from_utf8(requests.0.lock().unwrap().clone()).unwrap();
+    let sent_requests: Vec<serde_json::Value> = sent
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(sent_requests.len(), 3);
+    assert_eq!(sent_requests[0]["method"], "getAddress");
+    assert_eq!(sent_requests[1]["method"], "signTransaction");
+    assert_eq!(sent_requests[1]["params"]["transaction"]["nonce"], 7);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_external_signer_spawn_invalid_handshake() {
+    let mut command = std::process::Command::new("sh");
+    command.args(["-c", "echo invalid; sleep 60"]);
+    let err = ExternalSigner::spawn(command).err().unwrap();
+    assert_eq!(err.to_string(), "invalid JSON-RPC response");
+}
@@ -0,0 +1,226 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::{HashMap, VecDeque},
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+};
+
+use anyhow::anyhow;
+use multiversx_chain_core::types::ReturnCode;
+use multiversx_sdk::{
+    data::transaction::TransactionOnNetwork,
+    gateway::{GatewayAsyncService, GatewayRequest},
+    retrieve_tx_on_network, TxFinality, TxWatchError, TxWatcher,
+};
+use serde_json::{json, Value};
+
+const TX_HASH: &str = "abcd";
+const PROCESS_STATUS_ENDPOINT: &str = "transaction/abcd/process-status";
+const TX_INFO_ENDPOINT: &str = "transaction/abcd?withResults=true";
+
+/// Gateway mock with a simulated clock, replaying canned responses per endpoint.
+///
+/// The last response for each endpoint is repeated indefinitely.
+#[derive(Default)]
+struct MockGateway {
+    time_ms: Cell<u64>,
+    requests: Cell<usize>,
+    responses: RefCell<HashMap<String, VecDeque<Option<Value>>>>,
+}
+
+impl MockGateway {
+    fn push(&self, endpoint: &str, response: Option<Value>) {
+        self.responses
+            .borrow_mut()
+            .entry(endpoint.to_string())
+            .or_default()
+            .push_back(response);
+    }
+
+    fn push_status(&self, status: &str) {
+        self.push(
+            PROCESS_STATUS_ENDPOINT,
+            Some(json!({"error": "", "code": "successful", "data": {"status": status, "reason": ""}})),
+        );
+    }
+
+    fn push_tx_info(&self, hyperblock_nonce: Option<u64>) {
+        let mut tx = TransactionOnNetwork {
+            hyperblock_nonce,
+            ..Default::default()
+        };
+        tx.status = "success".to_string();
+        self.push(
+            TX_INFO_ENDPOINT,
+            Some(json!({"error": "", "code": "successful", "data": {"transaction": tx}})),
+        );
+    }
+}
+
+impl GatewayAsyncService for MockGateway {
+    type Instant = u64;
+
+    fn from_uri(_uri: &str) -> Self {
+        Self::default()
+    }
+
+    async fn request<G>(&self, request: G) -> anyhow::Result<G::Result>
+    where
+        G: GatewayRequest,
+    {
+        self.requests.set(self.requests.get() + 1);
+        let endpoint = request.get_endpoint();
+        let response = {
+            let mut responses = self.responses.borrow_mut();
+            let queue = responses
+                .get_mut(&endpoint)
+                .unwrap_or_else(|| panic!("unexpected request: {endpoint}"));
+            if queue.len() > 1 {
+                queue.pop_front().unwrap()
+            } else {
+                queue.front().unwrap().clone()
+            }
+        };
+        let json = response.ok_or_else(|| anyhow!("gateway unavailable"))?;
+        request.process_json(serde_json::from_value(json)?)
+    }
+
+    async fn sleep(&self, millis: u64) {
+        self.time_ms.set(self.time_ms.get() + millis);
+    }
+
+    fn now(&self) -> Self::Instant {
+        self.time_ms.get()
+    }
+
+    fn elapsed_seconds(&self, instant: &Self::Instant) -> f32 {
+        (self.time_ms.get() - instant) as f32 / 1000.0
+    }
+}
+
+const NOOP_WAKER_VTABLE: RawWakerVTable = RawWakerVTable::new(
+    |_| RawWaker::new(std::ptr::null(), &NOOP_WAKER_VTABLE),
+    |_| {},
+    |_| {},
+    |_| {},
+);
+
+/// The mock never yields, so a single poll is enough.
+fn run<F: Future>(future: F) -> F::Output {
+    let waker = unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &NOOP_WAKER_VTABLE)) };
+    let mut context = Context::from_waker(&waker);
+    match pin!(future).poll(&mut context) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("mock future should not be pending"),
+    }
+}
+
+#[test]
+fn tx_watcher_pending_then_success_test() {
+    let gateway = MockGateway::default();
+    gateway.push_status("pending");
+    gateway.push_status("pending");
+    gateway.push_status("success");
+    gateway.push_tx_info(None);
+
+    let watcher = TxWatcher::new().with_polling_interval(500);
+    let (tx, return_code) = run(watcher.watch(&gateway, TX_HASH)).unwrap();
+    assert_eq!(return_code, ReturnCode::Success);
+    assert_eq!(tx.status, "success");
+
+    // pending statuses do not busy-poll the gateway
+    assert_eq!(gateway.time_ms.get(), 1000);
+    assert_eq!(gateway.requests.get(), 4);
+}
+
+#[test]
+fn tx_watcher_fail_test() {
+    let gateway = MockGateway::default();
+    gateway.push(
+        PROCESS_STATUS_ENDPOINT,
+        Some(json!({"error": "", "code": "successful", "data": {"status": "fail", "reason": "@04@6f7574206f662066756e6473"}})),
+    );
+
+    let (_, return_code) = run(TxWatcher::new().watch(&gateway, TX_HASH)).unwrap();
+    assert_eq!(return_code, ReturnCode::UserError);
+}
+
+#[test]
+fn tx_watcher_timeout_test() {
+    let gateway = MockGateway::default();
+    gateway.push_status("pending");
+
+    let watcher = TxWatcher::new()
+        .with_polling_interval(1000)
+        .with_timeout(10_000);
+    let err = run(watcher.watch(&gateway, TX_HASH)).unwrap_err();
+    assert!(matches!(err, TxWatchError::Timeout { .. }));
+    assert_eq!(err.return_code(), ReturnCode::NetworkTimeout);
+    assert_eq!(gateway.time_ms.get(), 10_000);
+}
+
+#[test]
+fn tx_watcher_retries_exhausted_test() {
+    let gateway = MockGateway::default();
+    gateway.push(PROCESS_STATUS_ENDPOINT, None);
+
+    let watcher = TxWatcher::new()
+        .with_polling_interval(100)
+        .with_max_backoff_delay(300)
+        .with_max_retries(4);
+    let err = run(watcher.watch(&gateway, TX_HASH)).unwrap_err();
+    assert!(matches!(err, TxWatchError::RetriesExhausted { .. }));
+
+    // exponential backoff, capped: 100 + 200 + 300
+    assert_eq!(gateway.time_ms.get(), 600);
+}
+
+#[test]
+fn tx_watcher_hyperblock_finality_test() {
+    let gateway = MockGateway::default();
+    gateway.push_status("success");
+    gateway.push_tx_info(None);
+    gateway.push_tx_info(Some(42));
+
+    let watcher = TxWatcher::new()
+        .with_polling_interval(500)
+        .with_finality(TxFinality::Hyperblock);
+    let (tx, return_code) = run(gateway.watch_tx(&watcher, TX_HASH)).unwrap();
+    assert_eq!(return_code, ReturnCode::Success);
+    assert_eq!(tx.hyperblock_nonce, Some(42));
+    assert_eq!(gateway.time_ms.get(), 500);
+}
+
+#[test]
+fn tx_watcher_invalid_test() {
+    let gateway = MockGateway::default();
+    gateway.push_status("pending");
+    gateway.push(
+        PROCESS_STATUS_ENDPOINT,
+        Some(json!({"error": "", "code": "successful", "data": {"status": "invalid", "reason": "out of funds"}})),
+    );
+
+    let err = run(TxWatcher::new().watch(&gateway, TX_HASH)).unwrap_err();
+    assert!(matches!(err, TxWatchError::Rejected { ref status, .. } if status == "invalid"));
+    assert_eq!(err.return_code(), ReturnCode::OutOfFunds);
+
+    // final statuses are not polled again
+    assert_eq!(gateway.requests.get(), 2);
+}
+
+#[test]
+fn retrieve_tx_on_network_invalid_test() {
+    let gateway = MockGateway::default();
+    gateway.push_status("invalid");
+
+    let (tx, return_code) = run(retrieve_tx_on_network(&gateway, TX_HASH.to_string()));
+    assert_eq!(return_code, ReturnCode::UserError);
+
+    let events = tx.logs.unwrap().events;
+    let topics = events[0].topics.as_ref().unwrap();
+    assert_eq!(
+        topics[0],
+        "transaction abcd ended with status invalid: invalid transaction"
+    );
+}
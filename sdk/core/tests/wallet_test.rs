@@ -2,7 +2,9 @@ use bip39::Mnemonic;
 
 use multiversx_chain_core::types::Address;
 use multiversx_sdk::bech32;
-use multiversx_sdk::{crypto::public_key::PublicKey, wallet::Wallet};
+use multiversx_sdk::{
+    crypto::public_key::PublicKey, data::transaction::Transaction, test_wallets, wallet::Wallet,
+};
 use std::fs::{self, File};
 use std::io::Write;
 
@@ -116,3 +118,77 @@ fn test_wallet_convert_keystore_to_pem() {
     fs::remove_file(ALICE_PEM_PATH_TEST).unwrap();
     fs::remove_file(ALICE_KEYSTORE_PATH_TEST_2).unwrap();
 }
+
+#[test]
+fn test_sign_relayed_tx() {
+    let sender = Wallet::from_pem_file(ALICE_PEM_PATH).unwrap();
+    let relayer = test_wallets::bob();
+    let mut tx = Transaction {
+        nonce: 0,
+        value: "0".to_string(),
+        receiver: sender.to_address().into(),
+        sender: sender.to_address().into(),
+        gas_price: 1_000_000_000,
+        gas_limit: 100_000,
+        data: None,
+        signature: None,
+        chain_id: "D".to_string(),
+        version: 2,
+        options: 0,
+        guardian: None,
+        guardian_signature: None,
+        relayer: Some(relayer.to_address().into()),
+        relayer_signature: None,
+    };
+
+    // the sender and the relayer sign the same payload, which excludes all signatures
+    let sender_signature = sender.sign_tx(&tx);
+    let unsigned_relayer_signature = relayer.sign_tx(&tx);
+    tx.signature = Some(hex::encode(sender_signature));
+    let relayer_signature = relayer.sign_tx_as_relayer(&tx);
+    assert_eq!(relayer_signature, unsigned_relayer_signature);
+    assert_ne!(relayer_signature, sender_signature);
+
+    // only the relayer of the transaction can sign as relayer
+    let result = std::panic::catch_unwind(|| sender.sign_tx_as_relayer(&tx));
+    assert!(result.is_err());
+
+    // the relayer address is part of the signed payload
+    tx.relayer = None;
+    assert_ne!(sender.sign_tx(&tx), sender_signature);
+}
+
+#[test]
+fn test_sign_guarded_tx() {
+    let wallet = Wallet::from_pem_file(ALICE_PEM_PATH).unwrap();
+    let mut tx = Transaction {
+        nonce: 0,
+        value: "0".to_string(),
+        receiver: wallet.to_address().into(),
+        sender: wallet.to_address().into(),
+        gas_price: 1_000_000_000,
+        gas_limit: 100_000,
+        data: None,
+        signature: None,
+        chain_id: "D".to_string(),
+        version: 1,
+        options: 0,
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
+    };
+    let unguarded_signature = wallet.sign_tx(&tx);
+
+    tx.set_guardian(wallet.to_address().into());
+    assert!(tx.is_guarded());
+    assert_eq!(tx.version, 2);
+    assert_eq!(tx.options, 0b10);
+
+    // the guardian co-signs the same payload as the sender, which includes the guardian
+    let signature = wallet.sign_tx(&tx);
+    assert_ne!(signature, unguarded_signature);
+    tx.signature = Some("00".to_string());
+    let guardian_signature = wallet.sign_tx_as_guardian(&tx);
+    assert_eq!(signature, guardian_signature);
+}
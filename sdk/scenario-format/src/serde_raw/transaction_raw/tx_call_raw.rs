@@ -32,4 +32,9 @@ pub struct TxCallRaw {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gas_price: Option<ValueSubTree>,
+
+    /// Only present for relayed transactions (v3).
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relayer: Option<ValueSubTree>,
 }
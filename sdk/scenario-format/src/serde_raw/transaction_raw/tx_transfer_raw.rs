@@ -28,4 +28,8 @@ pub struct TxTransferRaw {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gas_price: Option<ValueSubTree>,
+    /// Only present for relayed transactions (v3).
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relayer: Option<ValueSubTree>,
 }